@@ -1,18 +1,31 @@
 //! Benchmarking utilities for performance validation (NFR1)
 //!
 //! This module provides benchmarking capabilities to validate that xchecker
-//! meets its performance targets: empty run ≤ 5s, packetization ≤ 200ms for 100 files.
+//! meets its performance targets: empty run ≤ 5s, packetization ≤ 200ms for 100 files,
+//! and lock acquisition p99 ≤ 250ms under contention.
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use camino::{Utf8Path, Utf8PathBuf};
+use serde::{Deserialize, Serialize};
 use std::fs;
+use std::thread;
 use std::time::{Duration, Instant};
 use tempfile::TempDir;
 
 use xchecker_packet::ContentSelector;
+use xchecker_utils::lock::{FileLock, LockError};
 use xchecker_utils::logging::{Logger, PerformanceMetrics};
 use xchecker_utils::process_memory::ProcessMemory;
 
+/// Number of threads contending for the same spec lock
+const LOCK_CONTENTION_THREADS: usize = 4;
+
+/// Lock acquire/release cycles performed by each thread per iteration
+const LOCK_ACQUISITIONS_PER_THREAD: usize = 10;
+
+/// Spec ID used for the lock contention benchmark
+const LOCK_BENCHMARK_SPEC_ID: &str = "benchmark-lock-contention";
+
 /// Benchmark configuration
 #[derive(Debug, Clone)]
 pub struct BenchmarkConfig {
@@ -39,6 +52,8 @@ pub struct BenchmarkThresholds {
     pub max_rss_mb: Option<f64>,
     /// Maximum allowed commit memory in MB (Windows only, optional)
     pub max_commit_mb: Option<f64>,
+    /// Maximum allowed p99 lock acquisition latency under contention in milliseconds (default: 250.0)
+    pub lock_acquire_max_ms: f64,
 }
 
 impl Default for BenchmarkThresholds {
@@ -48,6 +63,7 @@ impl Default for BenchmarkThresholds {
             packetization_max_ms_per_100_files: 200.0,
             max_rss_mb: None,
             max_commit_mb: None,
+            lock_acquire_max_ms: 250.0,
         }
     }
 }
@@ -65,7 +81,11 @@ impl Default for BenchmarkConfig {
 }
 
 /// Benchmark results (FR-BENCH-004, FR-BENCH-005, FR-BENCH-006)
-#[derive(Debug, Clone)]
+///
+/// Serializes to the stable shape returned by [`BenchmarkResults::to_json`];
+/// per-run samples and legacy fields are not serialized and come back empty
+/// from [`BenchmarkResults::load_baseline`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BenchmarkResults {
     /// Whether all performance thresholds were met (FR-BENCH-004)
     pub ok: bool,
@@ -76,24 +96,98 @@ pub struct BenchmarkResults {
     /// Process commit memory in MB (Windows only, FR-BENCH-003)
     pub commit_mb: Option<f64>,
     /// Empty run timing results (all runs including warm-up)
+    #[serde(skip)]
     pub empty_run_results: Vec<Duration>,
     /// Packetization timing results (all runs including warm-up)
+    #[serde(skip)]
     pub packetization_results: Vec<Duration>,
     /// Median empty run timing (excluding warm-up)
+    #[serde(rename = "empty_run_median_ms", with = "optional_ms")]
     pub empty_run_median: Option<Duration>,
     /// Median packetization timing (excluding warm-up)
+    #[serde(rename = "packetization_median_ms", with = "optional_ms")]
     pub packetization_median: Option<Duration>,
+    /// Individual lock acquisition latencies under contention (excluding warm-up)
+    #[serde(skip)]
+    pub lock_acquire_results: Vec<Duration>,
+    /// Median lock acquisition latency under contention
+    #[serde(rename = "lock_acquire_median_ms", with = "optional_ms")]
+    pub lock_acquire_median: Option<Duration>,
+    /// 99th percentile lock acquisition latency under contention
+    #[serde(skip)]
+    pub lock_acquire_p99: Option<Duration>,
     /// Performance metrics from the last run
     #[allow(dead_code)] // Performance data for receipts
+    #[serde(skip)]
     pub performance_metrics: Option<PerformanceMetrics>,
     /// Process memory at benchmark completion
+    #[serde(skip)]
     pub process_memory: Option<ProcessMemory>,
     /// Whether all performance targets were met (legacy field, use 'ok' instead)
+    #[serde(skip)]
     pub targets_met: bool,
     /// Any performance violations (FR-BENCH-006)
     pub violations: Vec<String>,
 }
 
+/// Optional durations as fractional milliseconds, `null` if absent
+mod optional_ms {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(
+        duration: &Option<Duration>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        duration
+            .map(|d| d.as_secs_f64() * 1000.0)
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Duration>, D::Error> {
+        let ms = Option::<f64>::deserialize(deserializer)?;
+        ms.map(|ms| Duration::try_from_secs_f64(ms / 1000.0).map_err(serde::de::Error::custom))
+            .transpose()
+    }
+}
+
+impl BenchmarkResults {
+    /// Machine-readable results for CI trend tracking
+    ///
+    /// Always has the same keys: `ok`, `timings_ms`, `rss_mb`, `commit_mb`,
+    /// `empty_run_median_ms`, `packetization_median_ms`,
+    /// `lock_acquire_median_ms` and `violations`. Values that were not
+    /// measured are `null`.
+    #[must_use]
+    pub fn to_json(&self) -> serde_json::Value {
+        // Cannot fail: every map key is a string
+        serde_json::to_value(self).unwrap_or_default()
+    }
+
+    /// Save these results as a baseline for [`BenchmarkRunner::compare_to_baseline`]
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the file cannot be written.
+    pub fn save_baseline(&self, path: &Utf8Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json).with_context(|| format!("Failed to write baseline: {path}"))
+    }
+
+    /// Load results saved with [`save_baseline`](Self::save_baseline)
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the file cannot be read or is not a saved baseline.
+    pub fn load_baseline(path: &Utf8Path) -> Result<Self> {
+        let json =
+            fs::read_to_string(path).with_context(|| format!("Failed to read baseline: {path}"))?;
+        serde_json::from_str(&json).with_context(|| format!("Invalid baseline: {path}"))
+    }
+}
+
 /// Benchmark runner for performance validation
 pub struct BenchmarkRunner {
     pub config: BenchmarkConfig,
@@ -129,9 +223,14 @@ impl BenchmarkRunner {
         // Run packetization benchmarks (includes warm-up)
         let packetization_results = self.benchmark_packetization(&mut logger)?;
 
+        // Run lock contention benchmark (warm-up already excluded)
+        let lock_acquire_results = self.benchmark_lock_contention()?;
+
         // Calculate medians (excluding first warm-up run)
         let empty_run_median = Self::calculate_median(&empty_run_results[1..]);
         let packetization_median = Self::calculate_median(&packetization_results[1..]);
+        let lock_acquire_median = Self::calculate_median(&lock_acquire_results);
+        let lock_acquire_p99 = Self::calculate_percentile(&lock_acquire_results, 99.0);
 
         // Generate performance metrics
         let performance_metrics = logger.generate_performance_metrics();
@@ -147,6 +246,12 @@ impl BenchmarkRunner {
         if let Some(median) = packetization_median {
             timings_ms.insert("packetization".to_string(), median.as_millis() as f64);
         }
+        if let Some(median) = lock_acquire_median {
+            timings_ms.insert("lock_acquire".to_string(), median.as_secs_f64() * 1000.0);
+        }
+        if let Some(p99) = lock_acquire_p99 {
+            timings_ms.insert("lock_acquire_p99".to_string(), p99.as_secs_f64() * 1000.0);
+        }
 
         // Extract memory metrics (FR-BENCH-003)
         let rss_mb = process_memory.as_ref().map_or(0.0, |m| m.rss_mb);
@@ -167,8 +272,13 @@ impl BenchmarkRunner {
         });
 
         // Perform threshold comparison (FR-BENCH-005, FR-BENCH-006)
-        let (ok, violations) =
-            self.check_thresholds(empty_run_median, packetization_median, rss_mb, commit_mb);
+        let (ok, violations) = self.check_thresholds(
+            empty_run_median,
+            packetization_median,
+            lock_acquire_p99,
+            rss_mb,
+            commit_mb,
+        );
 
         // Legacy validation for backwards compatibility
         let legacy_violations = logger.validate_performance_targets();
@@ -187,6 +297,9 @@ impl BenchmarkRunner {
             packetization_results,
             empty_run_median,
             packetization_median,
+            lock_acquire_results,
+            lock_acquire_median,
+            lock_acquire_p99,
             performance_metrics: Some(performance_metrics),
             process_memory,
             targets_met,
@@ -194,11 +307,61 @@ impl BenchmarkRunner {
         })
     }
 
+    /// Compare `results` with a saved `baseline` and return the regressions
+    ///
+    /// Checks the empty run and packetization medians and RSS memory. Each
+    /// metric that is more than `max_regression_pct` percent worse than the
+    /// baseline yields one message starting with its name (`empty_run`,
+    /// `packetization` or `rss`). Metrics missing from either side, or zero
+    /// in the baseline, are not compared.
+    #[must_use]
+    pub fn compare_to_baseline(
+        &self,
+        results: &BenchmarkResults,
+        baseline: &BenchmarkResults,
+        max_regression_pct: f64,
+    ) -> Vec<String> {
+        let ms = |d: Option<Duration>| d.map(|d| d.as_secs_f64() * 1000.0);
+        let metrics = [
+            (
+                "empty_run",
+                "ms",
+                ms(results.empty_run_median),
+                ms(baseline.empty_run_median),
+            ),
+            (
+                "packetization",
+                "ms",
+                ms(results.packetization_median),
+                ms(baseline.packetization_median),
+            ),
+            ("rss", "MB", Some(results.rss_mb), Some(baseline.rss_mb)),
+        ];
+
+        let mut regressions = Vec::new();
+        for (name, unit, current, base) in metrics {
+            let (Some(current), Some(base)) = (current, base) else {
+                continue;
+            };
+            if base <= 0.0 {
+                continue;
+            }
+            let regression_pct = (current - base) / base * 100.0;
+            if regression_pct > max_regression_pct {
+                regressions.push(format!(
+                    "{name}: {current:.1}{unit} is {regression_pct:.1}% worse than baseline {base:.1}{unit} (max {max_regression_pct:.1}%)"
+                ));
+            }
+        }
+        regressions
+    }
+
     /// Check performance thresholds and return (ok, violations) (FR-BENCH-005, FR-BENCH-006)
     fn check_thresholds(
         &self,
         empty_run_median: Option<Duration>,
         packetization_median: Option<Duration>,
+        lock_acquire_p99: Option<Duration>,
         rss_mb: f64,
         commit_mb: Option<f64>,
     ) -> (bool, Vec<String>) {
@@ -230,6 +393,17 @@ impl BenchmarkRunner {
             }
         }
 
+        // Check lock acquisition tail latency (guards the retry/backoff path)
+        if let Some(p99) = lock_acquire_p99 {
+            let p99_ms = p99.as_secs_f64() * 1000.0;
+            if p99_ms > self.config.thresholds.lock_acquire_max_ms {
+                violations.push(format!(
+                    "Lock acquire p99 {:.1}ms exceeds threshold {:.1}ms",
+                    p99_ms, self.config.thresholds.lock_acquire_max_ms
+                ));
+            }
+        }
+
         // Check RSS threshold if configured
         if let Some(max_rss) = self.config.thresholds.max_rss_mb
             && rss_mb > max_rss
@@ -272,6 +446,19 @@ impl BenchmarkRunner {
         }
     }
 
+    /// Calculate a nearest-rank percentile (0-100) from a slice of durations
+    fn calculate_percentile(durations: &[Duration], percentile: f64) -> Option<Duration> {
+        if durations.is_empty() {
+            return None;
+        }
+
+        let mut sorted = durations.to_vec();
+        sorted.sort();
+
+        let rank = ((percentile / 100.0) * sorted.len() as f64).ceil() as usize;
+        Some(sorted[rank.clamp(1, sorted.len()) - 1])
+    }
+
     /// Benchmark empty run performance (NFR1: ≤ 5s)
     /// First iteration is a warm-up pass, remaining iterations are measured
     fn benchmark_empty_run(&self, logger: &mut Logger) -> Result<Vec<Duration>> {
@@ -380,6 +567,93 @@ impl BenchmarkRunner {
         Ok(results)
     }
 
+    /// Benchmark lock acquisition latency under contention
+    ///
+    /// Each iteration spawns `LOCK_CONTENTION_THREADS` threads that repeatedly
+    /// acquire and release the lock for the same spec, spinning while another
+    /// thread holds it. Each sample is the time from the first attempt to a
+    /// successful acquisition. The first iteration is a warm-up pass and its
+    /// samples are discarded.
+    fn benchmark_lock_contention(&self) -> Result<Vec<Duration>> {
+        let mut results = Vec::new();
+
+        for i in 0..self.config.iterations {
+            let is_warmup = i == 0;
+
+            if self.config.verbose {
+                if is_warmup {
+                    println!("\nRunning lock contention warm-up pass...");
+                } else {
+                    println!(
+                        "Running lock contention benchmark iteration {}/{}",
+                        i,
+                        self.config.iterations - 1
+                    );
+                }
+            }
+
+            // Lock lives in a throwaway spec directory, never under XCHECKER_HOME
+            let temp_dir = TempDir::new()?;
+            let spec_root = Utf8PathBuf::try_from(temp_dir.path().join(LOCK_BENCHMARK_SPEC_ID))?;
+
+            let samples = thread::scope(|s| {
+                let handles: Vec<_> = (0..LOCK_CONTENTION_THREADS)
+                    .map(|_| s.spawn(|| Self::contend_for_lock(&spec_root)))
+                    .collect();
+
+                let mut samples = Vec::new();
+                for handle in handles {
+                    let thread_samples = handle
+                        .join()
+                        .map_err(|_| anyhow::anyhow!("Lock contention worker panicked"))??;
+                    samples.extend(thread_samples);
+                }
+                Ok::<_, anyhow::Error>(samples)
+            })?;
+
+            if self.config.verbose {
+                let median = Self::calculate_median(&samples).unwrap_or_default();
+                let label = if is_warmup {
+                    "Warm-up".to_string()
+                } else {
+                    format!("Run {i}")
+                };
+                println!(
+                    "  {label}: median {:.2}ms over {} acquisitions{}",
+                    median.as_secs_f64() * 1000.0,
+                    samples.len(),
+                    if is_warmup { " (not counted)" } else { "" }
+                );
+            }
+
+            if !is_warmup {
+                results.extend(samples);
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// Repeatedly acquire and release the benchmark spec lock, returning per-acquisition latency
+    fn contend_for_lock(spec_root: &Utf8Path) -> Result<Vec<Duration>> {
+        let mut samples = Vec::with_capacity(LOCK_ACQUISITIONS_PER_THREAD);
+
+        for _ in 0..LOCK_ACQUISITIONS_PER_THREAD {
+            let start = Instant::now();
+            let lock = loop {
+                match FileLock::acquire_in(spec_root, LOCK_BENCHMARK_SPEC_ID, false, None) {
+                    Ok(lock) => break lock,
+                    Err(LockError::ConcurrentExecution { .. }) => thread::yield_now(),
+                    Err(e) => return Err(e.into()),
+                }
+            };
+            samples.push(start.elapsed());
+            lock.release()?;
+        }
+
+        Ok(samples)
+    }
+
     /// Simulate empty run operations (configuration loading, validation, etc.)
     fn simulate_empty_run_operations(&self) -> Result<()> {
         // Simulate configuration loading
@@ -479,6 +753,98 @@ impl BenchmarkRunner {
         Ok(())
     }
 
+    /// Structured form of [`print_summary`](Self::print_summary)
+    ///
+    /// Has an `empty_run`, `packetization` and `lock_acquire` object for each
+    /// benchmark that ran, with the same figures and a `status` of `"pass"`
+    /// or `"fail"`, plus `overall` and `memory` (`null` if not sampled).
+    #[must_use]
+    pub fn summary_json(&self, results: &BenchmarkResults) -> serde_json::Value {
+        use serde_json::json;
+
+        let status = |pass: bool| if pass { "pass" } else { "fail" };
+        let mut summary = serde_json::Map::new();
+
+        if !results.empty_run_results.is_empty() {
+            let measured_runs = &results.empty_run_results[1..]; // Exclude warm-up
+            let (avg, max) = average_and_max(measured_runs);
+            let median = results.empty_run_median.unwrap_or_default();
+            summary.insert(
+                "empty_run".to_string(),
+                json!({
+                    "runs": measured_runs.len(),
+                    "median_secs": median.as_secs_f64(),
+                    "average_secs": avg.as_secs_f64(),
+                    "max_secs": max.as_secs_f64(),
+                    "target_secs": 5.0,
+                    "status": status(median <= Duration::from_secs(5)),
+                }),
+            );
+        }
+
+        if !results.packetization_results.is_empty() {
+            let measured_runs = &results.packetization_results[1..]; // Exclude warm-up
+            let (avg, max) = average_and_max(measured_runs);
+            let median = results.packetization_median.unwrap_or_default();
+            let target_ms = (self.config.thresholds.packetization_max_ms_per_100_files
+                * self.config.file_count as f64)
+                / 100.0;
+            summary.insert(
+                "packetization".to_string(),
+                json!({
+                    "file_count": self.config.file_count,
+                    "runs": measured_runs.len(),
+                    "median_ms": median.as_millis(),
+                    "average_ms": avg.as_millis(),
+                    "max_ms": max.as_millis(),
+                    "target_ms": target_ms,
+                    "status": status(median.as_millis() as f64 <= target_ms),
+                }),
+            );
+        }
+
+        if !results.lock_acquire_results.is_empty() {
+            let median = results.lock_acquire_median.unwrap_or_default();
+            let p99 = results.lock_acquire_p99.unwrap_or_default();
+            let max = results
+                .lock_acquire_results
+                .iter()
+                .max()
+                .copied()
+                .unwrap_or_default();
+            let p99_ms = p99.as_secs_f64() * 1000.0;
+            summary.insert(
+                "lock_acquire".to_string(),
+                json!({
+                    "threads": LOCK_CONTENTION_THREADS,
+                    "samples": results.lock_acquire_results.len(),
+                    "median_ms": median.as_secs_f64() * 1000.0,
+                    "p99_ms": p99_ms,
+                    "max_ms": max.as_secs_f64() * 1000.0,
+                    "target_ms": self.config.thresholds.lock_acquire_max_ms,
+                    "status": status(p99_ms <= self.config.thresholds.lock_acquire_max_ms),
+                }),
+            );
+        }
+
+        summary.insert(
+            "overall".to_string(),
+            json!({
+                "status": status(results.targets_met),
+                "violations": results.violations,
+            }),
+        );
+        summary.insert(
+            "memory".to_string(),
+            results.process_memory.as_ref().map_or(
+                json!(null),
+                |mem| json!({ "rss_mb": mem.rss_mb, "commit_mb": results.commit_mb }),
+            ),
+        );
+
+        serde_json::Value::Object(summary)
+    }
+
     /// Print benchmark summary
     pub fn print_summary(&self, results: &BenchmarkResults) {
         println!("\n=== Benchmark Results ===");
@@ -545,6 +911,37 @@ impl BenchmarkRunner {
             }
         }
 
+        // Lock contention results
+        if !results.lock_acquire_results.is_empty() {
+            let median = results.lock_acquire_median.unwrap_or_default();
+            let p99 = results.lock_acquire_p99.unwrap_or_default();
+            let max = results
+                .lock_acquire_results
+                .iter()
+                .max()
+                .copied()
+                .unwrap_or_default();
+
+            println!("\nLock Acquisition Under Contention ({LOCK_CONTENTION_THREADS} threads):");
+            println!(
+                "  Samples: {} acquisitions",
+                results.lock_acquire_results.len()
+            );
+            println!("  Median:  {:.2}ms", median.as_secs_f64() * 1000.0);
+            println!("  p99:     {:.2}ms", p99.as_secs_f64() * 1000.0);
+            println!("  Maximum: {:.2}ms", max.as_secs_f64() * 1000.0);
+            println!(
+                "  Target:  {:.0}ms (p99)",
+                self.config.thresholds.lock_acquire_max_ms
+            );
+
+            if p99.as_secs_f64() * 1000.0 <= self.config.thresholds.lock_acquire_max_ms {
+                println!("  Status:  ✓ PASS (p99 ≤ target)");
+            } else {
+                println!("  Status:  ✗ FAIL (p99 > target)");
+            }
+        }
+
         // Overall status
         println!("\nOverall Performance:");
         if results.targets_met {
@@ -564,10 +961,52 @@ impl BenchmarkRunner {
     }
 }
 
+/// Average and maximum of measured runs, zero when there are none
+fn average_and_max(runs: &[Duration]) -> (Duration, Duration) {
+    if runs.is_empty() {
+        return (Duration::ZERO, Duration::ZERO);
+    }
+    let avg = runs.iter().sum::<Duration>() / runs.len() as u32;
+    (avg, runs.iter().max().copied().unwrap_or_default())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_summary_json_reports_empty_run_status() {
+        let mut results: BenchmarkResults = serde_json::from_value(serde_json::json!({
+            "ok": true,
+            "timings_ms": {},
+            "rss_mb": 0.0,
+            "commit_mb": null,
+            "empty_run_median_ms": 120.0,
+            "packetization_median_ms": null,
+            "lock_acquire_median_ms": null,
+            "violations": [],
+        }))
+        .unwrap();
+        results.empty_run_results = vec![
+            Duration::from_millis(300),
+            Duration::from_millis(100),
+            Duration::from_millis(140),
+        ];
+        results.targets_met = true;
+        let runner = BenchmarkRunner::new(BenchmarkConfig::default());
+
+        let summary = runner.summary_json(&results);
+        let status = summary["empty_run"]["status"].as_str().unwrap();
+        assert!(status == "pass" || status == "fail");
+        assert_eq!(status, "pass");
+        assert_eq!(summary["empty_run"]["runs"], 2);
+        assert_eq!(summary["empty_run"]["average_secs"], 0.12);
+        assert_eq!(summary["empty_run"]["max_secs"], 0.14);
+        assert_eq!(summary["overall"]["status"], "pass");
+        assert!(summary.get("packetization").is_none());
+        assert!(summary["memory"].is_null());
+    }
+
     #[test]
     fn test_benchmark_config_default() {
         let config = BenchmarkConfig::default();
@@ -1005,6 +1444,7 @@ mod tests {
                 packetization_max_ms_per_100_files: 10000.0, // Very generous for test env
                 max_rss_mb: Some(4096.0),                    // 4GB threshold
                 max_commit_mb: Some(8192.0),                 // 8GB threshold
+                lock_acquire_max_ms: 60_000.0,               // Very generous for test env
             },
         };
         let runner = BenchmarkRunner::new(config);
@@ -1048,6 +1488,42 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_benchmark_results_to_json() -> Result<()> {
+        let config = BenchmarkConfig {
+            file_count: 5,
+            file_size_bytes: 50,
+            iterations: 2, // 1 warm-up + 1 measured
+            verbose: false,
+            thresholds: BenchmarkThresholds {
+                empty_run_max_secs: 60.0,
+                packetization_max_ms_per_100_files: 10000.0,
+                max_rss_mb: None,
+                max_commit_mb: None,
+                lock_acquire_max_ms: 60_000.0,
+            },
+        };
+        let results = BenchmarkRunner::new(config).run_all_benchmarks()?;
+
+        let json = results.to_json();
+
+        assert!(json["ok"].is_boolean());
+        let timings = json["timings_ms"].as_object().expect("timings_ms object");
+        assert!(timings.contains_key("empty_run"));
+        assert!(timings.contains_key("packetization"));
+        assert!(json["rss_mb"].is_number());
+        assert!(json["empty_run_median_ms"].is_number());
+        assert!(json["packetization_median_ms"].is_number());
+        assert!(json["violations"].is_array());
+        // Stable shape: absent values are null rather than missing
+        assert!(json.as_object().unwrap().contains_key("commit_mb"));
+        // Raw samples are not part of the output
+        assert!(json.get("empty_run_results").is_none());
+        assert!(json.get("targets_met").is_none());
+
+        Ok(())
+    }
+
     #[test]
     fn test_threshold_comparison_pass() -> Result<()> {
         // Test that thresholds pass when performance is good (FR-BENCH-005)
@@ -1061,6 +1537,7 @@ mod tests {
                 packetization_max_ms_per_100_files: 100_000.0, // Very generous threshold
                 max_rss_mb: None,    // Avoid flakiness from process-wide RSS
                 max_commit_mb: None, // Avoid flakiness from process-wide commit
+                lock_acquire_max_ms: 60_000.0, // Very generous threshold
             },
         };
         let runner = BenchmarkRunner::new(config);
@@ -1094,6 +1571,7 @@ mod tests {
                 packetization_max_ms_per_100_files: 0.001, // Impossibly low threshold
                 max_rss_mb: Some(0.1),                     // Impossibly low threshold
                 max_commit_mb: Some(0.1),                  // Impossibly low threshold
+                lock_acquire_max_ms: 0.001,                // Impossibly low threshold
             },
         };
         let runner = BenchmarkRunner::new(config);
@@ -1136,6 +1614,7 @@ mod tests {
             packetization_max_ms_per_100_files: 150.0,
             max_rss_mb: Some(512.0),
             max_commit_mb: Some(1024.0),
+            lock_acquire_max_ms: 100.0,
         };
 
         let config = BenchmarkConfig {
@@ -1151,6 +1630,7 @@ mod tests {
         assert_eq!(config.thresholds.packetization_max_ms_per_100_files, 150.0);
         assert_eq!(config.thresholds.max_rss_mb, Some(512.0));
         assert_eq!(config.thresholds.max_commit_mb, Some(1024.0));
+        assert_eq!(config.thresholds.lock_acquire_max_ms, 100.0);
 
         Ok(())
     }
@@ -1176,6 +1656,10 @@ mod tests {
             thresholds.max_commit_mb, None,
             "Default commit threshold should be None"
         );
+        assert_eq!(
+            thresholds.lock_acquire_max_ms, 250.0,
+            "Default lock acquire threshold should be 250ms"
+        );
 
         Ok(())
     }
@@ -1237,6 +1721,7 @@ mod tests {
                 packetization_max_ms_per_100_files: 10000.0, // High enough to pass
                 max_rss_mb: Some(0.1),                       // Very low to trigger violation
                 max_commit_mb: None,
+                lock_acquire_max_ms: 60_000.0, // High enough to pass
             },
         };
         let runner = BenchmarkRunner::new(config);
@@ -1271,6 +1756,7 @@ mod tests {
                 packetization_max_ms_per_100_files: 10000.0, // High enough to pass
                 max_rss_mb: Some(10000.0),                   // High enough to pass
                 max_commit_mb: Some(0.1),                    // Very low to trigger violation
+                lock_acquire_max_ms: 60_000.0,               // High enough to pass
             },
         };
         let runner = BenchmarkRunner::new(config);
@@ -1309,6 +1795,7 @@ mod tests {
                 packetization_max_ms_per_100_files: 10000.0, // Will pass
                 max_rss_mb: None,
                 max_commit_mb: None,
+                lock_acquire_max_ms: 60_000.0, // Will pass
             },
         };
         let runner = BenchmarkRunner::new(config);
@@ -1348,8 +1835,16 @@ mod tests {
         // Should be sorted alphabetically (BTreeMap property)
         assert_eq!(keys[0], "empty_run", "First key should be 'empty_run'");
         assert_eq!(
-            keys[1], "packetization",
-            "Second key should be 'packetization'"
+            keys[1], "lock_acquire",
+            "Second key should be 'lock_acquire'"
+        );
+        assert_eq!(
+            keys[2], "lock_acquire_p99",
+            "Third key should be 'lock_acquire_p99'"
+        );
+        assert_eq!(
+            keys[3], "packetization",
+            "Fourth key should be 'packetization'"
         );
 
         Ok(())
@@ -1368,6 +1863,7 @@ mod tests {
                 packetization_max_ms_per_100_files: 200.0, // 200ms per 100 files
                 max_rss_mb: None,
                 max_commit_mb: None,
+                lock_acquire_max_ms: 60_000.0,
             },
         };
 
@@ -1381,6 +1877,7 @@ mod tests {
                 packetization_max_ms_per_100_files: 200.0, // 200ms per 100 files
                 max_rss_mb: None,
                 max_commit_mb: None,
+                lock_acquire_max_ms: 60_000.0,
             },
         };
 
@@ -1416,6 +1913,7 @@ mod tests {
                 packetization_max_ms_per_100_files: 0.001,
                 max_rss_mb: Some(0.1),
                 max_commit_mb: Some(0.1),
+                lock_acquire_max_ms: 0.001,
             },
         };
         let runner = BenchmarkRunner::new(config);
@@ -1440,4 +1938,191 @@ mod tests {
 
         Ok(())
     }
+
+    // ===== Lock contention benchmark tests =====
+
+    fn lock_benchmark_config(lock_acquire_max_ms: f64) -> BenchmarkConfig {
+        BenchmarkConfig {
+            file_count: 5,
+            file_size_bytes: 50,
+            iterations: 2, // 1 warm-up + 1 measured
+            verbose: false,
+            thresholds: BenchmarkThresholds {
+                empty_run_max_secs: 60.0,                      // Very generous threshold
+                packetization_max_ms_per_100_files: 100_000.0, // Very generous threshold
+                max_rss_mb: None,
+                max_commit_mb: None,
+                lock_acquire_max_ms,
+            },
+        }
+    }
+
+    #[test]
+    fn test_lock_contention_results_structure() -> Result<()> {
+        let runner = BenchmarkRunner::new(lock_benchmark_config(60_000.0));
+
+        let results = runner.run_all_benchmarks()?;
+
+        assert!(
+            results.timings_ms.contains_key("lock_acquire"),
+            "timings_ms should contain 'lock_acquire'"
+        );
+        assert!(
+            results.timings_ms.contains_key("lock_acquire_p99"),
+            "timings_ms should contain 'lock_acquire_p99'"
+        );
+
+        // Warm-up samples are discarded; one measured iteration remains
+        assert_eq!(
+            results.lock_acquire_results.len(),
+            LOCK_CONTENTION_THREADS * LOCK_ACQUISITIONS_PER_THREAD
+        );
+        let median = results.lock_acquire_median.unwrap();
+        let p99 = results.lock_acquire_p99.unwrap();
+        assert!(median <= p99, "median should not exceed p99");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_lock_contention_threshold_pass() -> Result<()> {
+        let runner = BenchmarkRunner::new(lock_benchmark_config(60_000.0));
+
+        let results = runner.run_all_benchmarks()?;
+
+        assert!(
+            !results
+                .violations
+                .iter()
+                .any(|v| v.contains("Lock acquire")),
+            "Should have no lock violation with generous threshold: {:?}",
+            results.violations
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_lock_contention_threshold_fail() -> Result<()> {
+        let runner = BenchmarkRunner::new(lock_benchmark_config(0.000_001)); // Impossibly low
+
+        let results = runner.run_all_benchmarks()?;
+
+        assert!(!results.ok, "Benchmark should fail with low lock threshold");
+        assert!(
+            results
+                .violations
+                .iter()
+                .any(|v| v.contains("Lock acquire")),
+            "Should have lock acquire violation: {:?}",
+            results.violations
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_percentile_calculation() {
+        let durations: Vec<Duration> = (1..=100).map(Duration::from_millis).collect();
+
+        assert_eq!(
+            BenchmarkRunner::calculate_percentile(&durations, 99.0),
+            Some(Duration::from_millis(99))
+        );
+        assert_eq!(
+            BenchmarkRunner::calculate_percentile(&durations, 50.0),
+            Some(Duration::from_millis(50))
+        );
+        assert_eq!(
+            BenchmarkRunner::calculate_percentile(&[Duration::from_millis(7)], 99.0),
+            Some(Duration::from_millis(7))
+        );
+        assert_eq!(BenchmarkRunner::calculate_percentile(&[], 99.0), None);
+    }
+
+    /// Results with only the metrics compared against a baseline filled in
+    fn baseline_results(empty_run_ms: u64, packetization_ms: u64, rss_mb: f64) -> BenchmarkResults {
+        BenchmarkResults {
+            ok: true,
+            timings_ms: std::collections::BTreeMap::new(),
+            rss_mb,
+            commit_mb: None,
+            empty_run_results: vec![],
+            packetization_results: vec![],
+            empty_run_median: Some(Duration::from_millis(empty_run_ms)),
+            packetization_median: Some(Duration::from_millis(packetization_ms)),
+            lock_acquire_results: vec![],
+            lock_acquire_median: None,
+            lock_acquire_p99: None,
+            performance_metrics: None,
+            process_memory: None,
+            targets_met: true,
+            violations: vec![],
+        }
+    }
+
+    #[test]
+    fn test_compare_to_baseline_slowdown() {
+        let runner = BenchmarkRunner::new(BenchmarkConfig::default());
+        let baseline = baseline_results(400, 100, 50.0);
+        let results = baseline_results(500, 100, 50.0); // 25% slower empty run
+
+        let regressions = runner.compare_to_baseline(&results, &baseline, 10.0);
+        assert_eq!(regressions.len(), 1, "{regressions:?}");
+        assert!(regressions[0].starts_with("empty_run:"), "{regressions:?}");
+        assert!(regressions[0].contains("25.0%"), "{regressions:?}");
+
+        assert!(
+            runner
+                .compare_to_baseline(&results, &baseline, 50.0)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_compare_to_baseline_all_metrics() {
+        let runner = BenchmarkRunner::new(BenchmarkConfig::default());
+        let baseline = baseline_results(400, 100, 50.0);
+
+        // Faster runs and less memory are never regressions
+        let faster = baseline_results(200, 50, 40.0);
+        assert!(
+            runner
+                .compare_to_baseline(&faster, &baseline, 0.0)
+                .is_empty()
+        );
+
+        let slower = baseline_results(500, 125, 62.5);
+        let regressions = runner.compare_to_baseline(&slower, &baseline, 10.0);
+        let names: Vec<&str> = regressions
+            .iter()
+            .map(|r| r.split(':').next().unwrap())
+            .collect();
+        assert_eq!(names, ["empty_run", "packetization", "rss"]);
+    }
+
+    #[test]
+    fn test_baseline_round_trip() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let path = Utf8PathBuf::from_path_buf(temp_dir.path().join("baseline.json")).unwrap();
+        let mut baseline = baseline_results(400, 100, 50.0);
+        baseline.timings_ms.insert("empty_run".to_string(), 400.0);
+        baseline.violations.push("example".to_string());
+
+        baseline.save_baseline(&path)?;
+        let loaded = BenchmarkResults::load_baseline(&path)?;
+
+        assert_eq!(loaded.to_json(), baseline.to_json());
+        assert_eq!(loaded.empty_run_median, baseline.empty_run_median);
+        assert_eq!(loaded.packetization_median, baseline.packetization_median);
+        assert!(loaded.empty_run_results.is_empty());
+
+        let runner = BenchmarkRunner::new(BenchmarkConfig::default());
+        assert!(
+            runner
+                .compare_to_baseline(&baseline, &loaded, 0.0)
+                .is_empty()
+        );
+        Ok(())
+    }
 }
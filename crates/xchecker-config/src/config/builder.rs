@@ -2,10 +2,12 @@ use std::collections::HashMap;
 use std::path::PathBuf;
 
 use crate::error::XCheckerError;
+use crate::types::PhaseId;
 
 use super::{
-    Config, ConfigSource, Defaults, HooksConfig, LlmConfig, PhasesConfig, RunnerConfig,
-    SecurityConfig, Selectors,
+    Config, ConfigSource, Defaults, ExtraSecretPattern, HooksConfig, LlmConfig, PacketBudget,
+    PacketConfig, PacketOrder, PhasesConfig, PriorArtifactForm, RunnerConfig, SecurityConfig,
+    Selectors,
 };
 
 impl Config {
@@ -74,8 +76,14 @@ pub struct ConfigBuilder {
     verbose: Option<bool>,
     llm_provider: Option<String>,
     execution_strategy: Option<String>,
-    extra_secret_patterns: Vec<String>,
+    extra_secret_patterns: Vec<ExtraSecretPattern>,
     ignore_secret_patterns: Vec<String>,
+    prior_artifact_form: Option<PriorArtifactForm>,
+    packet_order: Option<PacketOrder>,
+    packet_preview_max_bytes: Option<usize>,
+    packet_compress_preview: Option<bool>,
+    packet_cache: Option<bool>,
+    packet_phase_budgets: Vec<(PhaseId, PacketBudget)>,
 }
 
 impl Default for ConfigBuilder {
@@ -103,6 +111,12 @@ impl ConfigBuilder {
             execution_strategy: None,
             extra_secret_patterns: Vec::new(),
             ignore_secret_patterns: Vec::new(),
+            prior_artifact_form: None,
+            packet_order: None,
+            packet_preview_max_bytes: None,
+            packet_compress_preview: None,
+            packet_cache: None,
+            packet_phase_budgets: Vec::new(),
         }
     }
 
@@ -267,8 +281,8 @@ impl ConfigBuilder {
     ///     .expect("Failed to build config");
     /// ```
     #[must_use]
-    pub fn extra_secret_patterns(mut self, patterns: Vec<String>) -> Self {
-        self.extra_secret_patterns = patterns;
+    pub fn extra_secret_patterns<P: Into<ExtraSecretPattern>>(mut self, patterns: Vec<P>) -> Self {
+        self.extra_secret_patterns = patterns.into_iter().map(Into::into).collect();
         self
     }
 
@@ -279,9 +293,10 @@ impl ConfigBuilder {
     ///
     /// # Arguments
     ///
-    /// * `pattern` - Regex pattern to add
+    /// * `pattern` - Regex pattern to add, or an [`ExtraSecretPattern::Named`]
+    ///   to give it a stable ID
     #[must_use]
-    pub fn add_extra_secret_pattern(mut self, pattern: impl Into<String>) -> Self {
+    pub fn add_extra_secret_pattern(mut self, pattern: impl Into<ExtraSecretPattern>) -> Self {
         self.extra_secret_patterns.push(pattern.into());
         self
     }
@@ -328,6 +343,77 @@ impl ConfigBuilder {
         self
     }
 
+    /// Set which form of prior-phase artifacts packets include.
+    ///
+    /// # Arguments
+    ///
+    /// * `form` - Markdown documents, `.core.yaml` summaries, or both
+    #[must_use]
+    pub fn prior_artifact_form(mut self, form: PriorArtifactForm) -> Self {
+        self.prior_artifact_form = Some(form);
+        self
+    }
+
+    /// Set the order of included files within packets.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - Priority order (default) or lexicographic path order
+    #[must_use]
+    pub fn packet_order(mut self, order: PacketOrder) -> Self {
+        self.packet_order = Some(order);
+        self
+    }
+
+    /// Set the maximum size of the human-readable packet preview.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - Size at which `context/<phase>-packet.txt` is truncated
+    #[must_use]
+    pub fn packet_preview_max_bytes(mut self, bytes: usize) -> Self {
+        self.packet_preview_max_bytes = Some(bytes);
+        self
+    }
+
+    /// Set whether the packet preview is written gzip-compressed.
+    ///
+    /// # Arguments
+    ///
+    /// * `compress` - Write `context/<phase>-packet.txt.gz` instead of
+    ///   `context/<phase>-packet.txt`
+    #[must_use]
+    pub fn packet_compress_preview(mut self, compress: bool) -> Self {
+        self.packet_compress_preview = Some(compress);
+        self
+    }
+
+    /// Set whether phase packets are reused when their inputs are unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Cache each phase's packet under
+    ///   `<XCHECKER_HOME>/cache/<spec_id>`
+    #[must_use]
+    pub fn packet_cache(mut self, enabled: bool) -> Self {
+        self.packet_cache = Some(enabled);
+        self
+    }
+
+    /// Set the packet budget for a single phase.
+    ///
+    /// Unset fields of `budget` fall back to the global packet limits.
+    ///
+    /// # Arguments
+    ///
+    /// * `phase` - Phase whose packets use this budget
+    /// * `budget` - Byte and line limits for that phase
+    #[must_use]
+    pub fn packet_budget_for_phase(mut self, phase: PhaseId, budget: PacketBudget) -> Self {
+        self.packet_phase_budgets.push((phase, budget));
+        self
+    }
+
     /// Build the `Config` from the builder values.
     ///
     /// This creates a `Config` using the values set on the builder, with
@@ -357,6 +443,7 @@ impl ConfigBuilder {
             gemini: None,
             openrouter: None,
             anthropic: None,
+            retry: None,
             execution_strategy: None,
             prompt_template: None,
         };
@@ -446,12 +533,41 @@ impl ConfigBuilder {
         let security = SecurityConfig {
             extra_secret_patterns: self.extra_secret_patterns,
             ignore_secret_patterns: self.ignore_secret_patterns,
+            near_miss_tolerance: 0,
+            allowlist_values: Vec::new(),
+            secret_patterns_file: None,
         };
         if !security.extra_secret_patterns.is_empty() || !security.ignore_secret_patterns.is_empty()
         {
             source_attribution.insert("security".to_string(), ConfigSource::Programmatic);
         }
 
+        let mut packet = PacketConfig::default();
+        if let Some(form) = self.prior_artifact_form {
+            packet.prior_artifact_form = form;
+            source_attribution.insert("packet".to_string(), ConfigSource::Programmatic);
+        }
+        if let Some(order) = self.packet_order {
+            packet.order = order;
+            source_attribution.insert("packet".to_string(), ConfigSource::Programmatic);
+        }
+        if let Some(bytes) = self.packet_preview_max_bytes {
+            packet.preview_max_bytes = Some(bytes);
+            source_attribution.insert("packet".to_string(), ConfigSource::Programmatic);
+        }
+        if let Some(compress) = self.packet_compress_preview {
+            packet.compress_preview = compress;
+            source_attribution.insert("packet".to_string(), ConfigSource::Programmatic);
+        }
+        if let Some(enabled) = self.packet_cache {
+            packet.cache = enabled;
+            source_attribution.insert("packet".to_string(), ConfigSource::Programmatic);
+        }
+        for (phase, budget) in self.packet_phase_budgets {
+            *packet.budget_for_mut(phase) = Some(budget);
+            source_attribution.insert("packet".to_string(), ConfigSource::Programmatic);
+        }
+
         let config = Config {
             defaults,
             selectors,
@@ -460,6 +576,7 @@ impl ConfigBuilder {
             phases,
             hooks,
             security,
+            packet,
             source_attribution,
         };
 
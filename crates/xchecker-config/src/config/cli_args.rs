@@ -4,6 +4,8 @@ use std::path::PathBuf;
 #[derive(Debug, Clone, Default)]
 pub struct CliArgs {
     pub config_path: Option<PathBuf>,
+    /// Config profile to apply over the base config (`[profiles.<name>]`)
+    pub profile: Option<String>,
     pub model: Option<String>,
     pub max_turns: Option<u32>,
     pub packet_max_bytes: Option<usize>,
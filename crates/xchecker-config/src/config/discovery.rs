@@ -6,8 +6,8 @@ use std::path::{Path, PathBuf};
 use crate::error::{ConfigError, XCheckerError};
 
 use super::{
-    ClaudeConfig, CliArgs, Config, ConfigSource, Defaults, GeminiConfig, HooksConfig, LlmConfig,
-    PhasesConfig, RunnerConfig, SecurityConfig, Selectors,
+    ClaudeConfig, CliArgs, Config, ConfigSource, Defaults, ExtraSecretPattern, GeminiConfig,
+    HooksConfig, LlmConfig, PacketConfig, PhasesConfig, RunnerConfig, SecurityConfig, Selectors,
 };
 
 /// TOML configuration file structure
@@ -20,6 +20,7 @@ struct TomlConfig {
     phases: Option<PhasesConfig>,
     hooks: Option<HooksConfig>,
     security: Option<SecurityConfig>,
+    packet: Option<PacketConfig>,
 }
 
 impl Config {
@@ -55,12 +56,14 @@ impl Config {
             gemini: None,
             openrouter: None,
             anthropic: None,
+            retry: None,
             execution_strategy: None,
             prompt_template: None,
         };
         let mut hooks = HooksConfig::default();
         let mut phases = PhasesConfig::default();
         let mut security = SecurityConfig::default();
+        let mut packet = PacketConfig::default();
 
         // Track default sources
         source_attribution.insert("max_turns".to_string(), ConfigSource::Default);
@@ -83,8 +86,21 @@ impl Config {
             Self::discover_config_file_from(start_dir)?
         };
 
+        // Select profile with precedence: CLI > env
+        let profile = cli_args.profile.clone().or_else(|| {
+            env::var("XCHECKER_PROFILE")
+                .ok()
+                .filter(|profile| !profile.is_empty())
+        });
+
+        if config_path.is_none()
+            && let Some(name) = &profile
+        {
+            return Err(unknown_profile(name, None));
+        }
+
         if let Some(path) = &config_path {
-            let file_config = Self::load_config_file(path)?;
+            let file_config = Self::load_config_file(path, profile.as_deref())?;
 
             let config_source = ConfigSource::Config;
 
@@ -135,6 +151,16 @@ impl Config {
                     source_attribution
                         .insert("lock_ttl_seconds".to_string(), config_source.clone());
                 }
+                if file_defaults.dead_process_grace_secs.is_some() {
+                    defaults.dead_process_grace_secs = file_defaults.dead_process_grace_secs;
+                    source_attribution
+                        .insert("dead_process_grace_secs".to_string(), config_source.clone());
+                }
+                if file_defaults.max_concurrent_specs.is_some() {
+                    defaults.max_concurrent_specs = file_defaults.max_concurrent_specs;
+                    source_attribution
+                        .insert("max_concurrent_specs".to_string(), config_source.clone());
+                }
                 if file_defaults.debug_packet.is_some() {
                     defaults.debug_packet = file_defaults.debug_packet;
                     source_attribution.insert("debug_packet".to_string(), config_source.clone());
@@ -148,6 +174,22 @@ impl Config {
                     source_attribution
                         .insert("strict_validation".to_string(), config_source.clone());
                 }
+                if file_defaults.validation_mode.is_some() {
+                    defaults.validation_mode = file_defaults.validation_mode;
+                    source_attribution.insert("validation_mode".to_string(), config_source.clone());
+                }
+                if file_defaults.forbidden_opening_phrases.is_some() {
+                    defaults.forbidden_opening_phrases = file_defaults.forbidden_opening_phrases;
+                    source_attribution.insert(
+                        "forbidden_opening_phrases".to_string(),
+                        config_source.clone(),
+                    );
+                }
+                if file_defaults.normalize_line_endings.is_some() {
+                    defaults.normalize_line_endings = file_defaults.normalize_line_endings;
+                    source_attribution
+                        .insert("normalize_line_endings".to_string(), config_source.clone());
+                }
             }
 
             if let Some(file_selectors) = file_config.selectors {
@@ -161,6 +203,11 @@ impl Config {
                     source_attribution
                         .insert("selectors_exclude".to_string(), config_source.clone());
                 }
+                if !file_selectors.ignore.is_empty() {
+                    selectors.ignore = file_selectors.ignore;
+                    source_attribution
+                        .insert("selectors_ignore".to_string(), config_source.clone());
+                }
             }
 
             if let Some(file_runner) = file_config.runner {
@@ -176,6 +223,11 @@ impl Config {
                     runner.claude_path = file_runner.claude_path;
                     source_attribution.insert("claude_path".to_string(), config_source.clone());
                 }
+                if file_runner.fixture_dir.is_some() {
+                    runner.fixture_dir = file_runner.fixture_dir;
+                    source_attribution
+                        .insert("runner_fixture_dir".to_string(), config_source.clone());
+                }
             }
 
             if let Some(file_llm) = file_config.llm {
@@ -188,12 +240,14 @@ impl Config {
                     source_attribution
                         .insert("llm_fallback_provider".to_string(), config_source.clone());
                 }
-                if let Some(file_claude) = file_llm.claude
-                    && file_claude.binary.is_some()
-                {
-                    llm.claude = Some(file_claude);
-                    source_attribution
-                        .insert("llm_claude_binary".to_string(), config_source.clone());
+                if let Some(file_claude) = file_llm.claude {
+                    if file_claude.binary.is_some() {
+                        source_attribution
+                            .insert("llm_claude_binary".to_string(), config_source.clone());
+                    }
+                    if file_claude.binary.is_some() || file_claude.timeout_secs.is_some() {
+                        llm.claude = Some(file_claude);
+                    }
                 }
                 if let Some(file_gemini) = file_llm.gemini {
                     llm.gemini = Some(file_gemini);
@@ -210,6 +264,11 @@ impl Config {
                     source_attribution
                         .insert("llm_anthropic_config".to_string(), config_source.clone());
                 }
+                if let Some(file_retry) = file_llm.retry {
+                    llm.retry = Some(file_retry);
+                    source_attribution
+                        .insert("llm_retry_config".to_string(), config_source.clone());
+                }
                 if file_llm.execution_strategy.is_some() {
                     llm.execution_strategy = file_llm.execution_strategy;
                     source_attribution
@@ -234,9 +293,21 @@ impl Config {
             }
 
             // Load security configuration from file
-            if let Some(file_security) = file_config.security {
+            if let Some(mut file_security) = file_config.security {
+                if let Some(patterns_file) = &mut file_security.secret_patterns_file
+                    && patterns_file.is_relative()
+                    && let Some(config_dir) = path.parent()
+                {
+                    *patterns_file = config_dir.join(&*patterns_file);
+                }
                 security = file_security;
-                source_attribution.insert("security".to_string(), config_source);
+                source_attribution.insert("security".to_string(), config_source.clone());
+            }
+
+            // Load packet configuration from file
+            if let Some(file_packet) = file_config.packet {
+                packet = file_packet;
+                source_attribution.insert("packet".to_string(), config_source);
             }
         }
 
@@ -304,13 +375,21 @@ impl Config {
         if let Some(strict_validation) = cli_args.strict_validation {
             defaults.strict_validation = Some(strict_validation);
             source_attribution.insert("strict_validation".to_string(), ConfigSource::Cli);
+            // An explicit CLI choice outranks a validation_mode from the config file
+            if defaults.validation_mode.take().is_some() {
+                source_attribution.remove("validation_mode");
+            }
         }
 
         // Apply security pattern overrides (CLI > file > defaults)
         if !cli_args.extra_secret_pattern.is_empty() {
-            security
-                .extra_secret_patterns
-                .extend(cli_args.extra_secret_pattern.clone());
+            security.extra_secret_patterns.extend(
+                cli_args
+                    .extra_secret_pattern
+                    .iter()
+                    .cloned()
+                    .map(ExtraSecretPattern::from),
+            );
             source_attribution.insert("security".to_string(), ConfigSource::Cli);
         }
         if !cli_args.ignore_secret_pattern.is_empty() {
@@ -370,7 +449,10 @@ impl Config {
         // Apply Claude binary configuration
         if let Some(binary) = &cli_args.llm_claude_binary {
             if llm.claude.is_none() {
-                llm.claude = Some(ClaudeConfig { binary: None });
+                llm.claude = Some(ClaudeConfig {
+                    binary: None,
+                    timeout_secs: None,
+                });
             }
             if let Some(claude_config) = &mut llm.claude {
                 claude_config.binary = Some(binary.clone());
@@ -385,6 +467,7 @@ impl Config {
                     binary: None,
                     default_model: None,
                     profiles: None,
+                    timeout_secs: None,
                 });
             }
             if let Some(gemini_config) = &mut llm.gemini {
@@ -402,6 +485,7 @@ impl Config {
                     binary: None,
                     default_model: None,
                     profiles: None,
+                    timeout_secs: None,
                 });
             }
             if let Some(gemini_config) = &mut llm.gemini {
@@ -417,6 +501,7 @@ impl Config {
                     binary: None,
                     default_model: None,
                     profiles: None,
+                    timeout_secs: None,
                 });
             }
             if let Some(gemini_config) = &mut llm.gemini {
@@ -455,6 +540,7 @@ impl Config {
             phases,
             hooks,
             security,
+            packet,
             source_attribution,
         };
 
@@ -499,15 +585,57 @@ impl Config {
     }
 
     /// Load configuration from TOML file
-    fn load_config_file(path: &Path) -> Result<TomlConfig, XCheckerError> {
+    ///
+    /// When `profile` is set, `[profiles.<profile>]` (or its alias
+    /// `[profile.<profile>]`) is merged over the base sections before
+    /// deserializing: nested tables merge key by key, any other value replaces
+    /// the base value.
+    fn load_config_file(path: &Path, profile: Option<&str>) -> Result<TomlConfig, XCheckerError> {
+        let invalid = |e: &dyn std::fmt::Display| {
+            XCheckerError::Config(ConfigError::InvalidFile(format!(
+                "Failed to parse TOML config file {}: {e}",
+                path.display()
+            )))
+        };
+
         match std::fs::read_to_string(path) {
-            Ok(content) => toml::from_str(&content).map_err(|e| {
-                XCheckerError::Config(ConfigError::InvalidFile(format!(
-                    "Failed to parse TOML config file {}: {e}",
-                    path.display()
-                )))
-            }),
+            Ok(content) => {
+                let mut table: toml::Table = toml::from_str(&content).map_err(|e| invalid(&e))?;
+                // `[profile.<name>]` is accepted as an alias of `[profiles.<name>]`
+                let (key, profiles) = match (table.remove("profiles"), table.remove("profile")) {
+                    (None, None) => ("profiles", toml::Value::Table(toml::Table::new())),
+                    (Some(profiles), None) => ("profiles", profiles),
+                    (None, Some(profiles)) => ("profile", profiles),
+                    (Some(_), Some(_)) => {
+                        return Err(invalid(&"use either [profiles] or [profile], not both"));
+                    }
+                };
+                // Errors name the table as the user wrote it
+                let toml::Value::Table(profiles) = profiles else {
+                    return Err(invalid(&format!("[{key}] must be a table")));
+                };
+
+                if let Some(name) = profile {
+                    match profiles.get(name) {
+                        Some(toml::Value::Table(overlay)) => {
+                            merge_toml_tables(&mut table, overlay.clone());
+                        }
+                        Some(_) => {
+                            return Err(invalid(&format!("[{key}.{name}] must be a table")));
+                        }
+                        None => return Err(unknown_profile(name, Some(&profiles))),
+                    }
+                }
+
+                toml::Value::Table(table)
+                    .try_into()
+                    .map_err(|e: toml::de::Error| invalid(&e))
+            }
             Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                if let Some(name) = profile {
+                    return Err(unknown_profile(name, None));
+                }
+
                 // Missing config file is OK - return empty config (will use defaults)
                 Ok(TomlConfig {
                     defaults: None,
@@ -517,6 +645,7 @@ impl Config {
                     phases: None,
                     hooks: None,
                     security: None,
+                    packet: None,
                 })
             }
             Err(e) => Err(XCheckerError::Config(ConfigError::DiscoveryFailed {
@@ -559,3 +688,29 @@ impl Config {
         Self::discover(&cli_args)
     }
 }
+
+/// Recursively merge `overlay` into `base`; non-table values replace
+fn merge_toml_tables(base: &mut toml::Table, overlay: toml::Table) {
+    for (key, value) in overlay {
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(base_table)), toml::Value::Table(overlay_table)) => {
+                merge_toml_tables(base_table, overlay_table);
+            }
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+/// Error for a selected profile that the config file does not define
+fn unknown_profile(name: &str, profiles: Option<&toml::Table>) -> XCheckerError {
+    let available = profiles
+        .map(|profiles| profiles.keys().cloned().collect::<Vec<_>>().join(", "))
+        .filter(|names| !names.is_empty())
+        .unwrap_or_else(|| "none defined".to_string());
+    XCheckerError::Config(ConfigError::InvalidValue {
+        key: "profile".to_string(),
+        value: format!("unknown profile '{name}' (available: {available})"),
+    })
+}
@@ -58,6 +58,7 @@ impl Config {
         add_config("runner_mode", self.runner.mode.as_deref());
         add_config("runner_distro", self.runner.distro.as_deref());
         add_config("claude_path", self.runner.claude_path.as_deref());
+        add_config("runner_fixture_dir", self.runner.fixture_dir.as_deref());
         add_config("llm_provider", self.llm.provider.as_deref());
         add_config(
             "llm_fallback_provider",
@@ -81,6 +82,15 @@ impl Config {
             "selectors_exclude".to_string(),
             (exclude_patterns, exclude_source),
         );
+        if !self.selectors.ignore.is_empty() {
+            config.insert(
+                "selectors_ignore".to_string(),
+                (
+                    self.selectors.ignore.join(", "),
+                    source_label(self.source_attribution.get("selectors_ignore")),
+                ),
+            );
+        }
 
         config
     }
@@ -1,38 +1,40 @@
 use crate::error::{ConfigError, XCheckerError};
 
-use super::{Config, PromptTemplate};
+use crate::types::PhaseId;
+
+use super::{Config, ExtraSecretPattern, PromptTemplate};
 
 impl Config {
     /// Validate configuration values
     pub(crate) fn validate(&self) -> Result<(), XCheckerError> {
         // Validate packet limits
-        if let Some(max_bytes) = self.defaults.packet_max_bytes {
-            if max_bytes == 0 {
-                return Err(XCheckerError::Config(ConfigError::InvalidValue {
-                    key: "packet_max_bytes".to_string(),
-                    value: "must be greater than 0".to_string(),
-                }));
-            }
-            if max_bytes > 10_000_000 {
-                // 10MB limit
-                return Err(XCheckerError::Config(ConfigError::InvalidValue {
-                    key: "packet_max_bytes".to_string(),
-                    value: "exceeds maximum limit of 10MB".to_string(),
-                }));
+        validate_packet_max_bytes("packet_max_bytes", self.defaults.packet_max_bytes)?;
+        validate_packet_max_lines("packet_max_lines", self.defaults.packet_max_lines)?;
+        for phase in PhaseId::ALL {
+            if let Some(budget) = self.packet.budget_for(phase) {
+                validate_packet_max_bytes(
+                    &format!("packet.{}.max_bytes", phase.as_str()),
+                    budget.max_bytes,
+                )?;
+                validate_packet_max_lines(
+                    &format!("packet.{}.max_lines", phase.as_str()),
+                    budget.max_lines,
+                )?;
             }
         }
 
-        if let Some(max_lines) = self.defaults.packet_max_lines {
-            if max_lines == 0 {
-                return Err(XCheckerError::Config(ConfigError::InvalidValue {
-                    key: "packet_max_lines".to_string(),
-                    value: "must be greater than 0".to_string(),
-                }));
-            }
-            if max_lines > 100_000 {
+        if self.packet.preview_max_bytes == Some(0) {
+            return Err(XCheckerError::Config(ConfigError::InvalidValue {
+                key: "packet.preview_max_bytes".to_string(),
+                value: "must be greater than 0".to_string(),
+            }));
+        }
+
+        for glob in &self.packet.redaction_exempt {
+            if let Err(e) = globset::Glob::new(glob) {
                 return Err(XCheckerError::Config(ConfigError::InvalidValue {
-                    key: "packet_max_lines".to_string(),
-                    value: "exceeds maximum limit of 100,000".to_string(),
+                    key: "packet.redaction_exempt".to_string(),
+                    value: format!("invalid glob '{glob}': {e}"),
                 }));
             }
         }
@@ -53,6 +55,13 @@ impl Config {
             }
         }
 
+        if self.defaults.max_concurrent_specs == Some(0) {
+            return Err(XCheckerError::Config(ConfigError::InvalidValue {
+                key: "max_concurrent_specs".to_string(),
+                value: "must be greater than 0".to_string(),
+            }));
+        }
+
         // Validate phase_timeout
         if let Some(phase_timeout) = self.defaults.phase_timeout {
             if phase_timeout < 5 {
@@ -69,6 +78,48 @@ impl Config {
             }
         }
 
+        // Validate per-provider timeouts against the same bounds as phase_timeout
+        for provider in ["claude-cli", "gemini-cli", "openrouter", "anthropic"] {
+            if let Some(timeout_secs) = self.provider_timeout_secs(provider)
+                && !(5..=7200).contains(&timeout_secs)
+            {
+                let section = provider.trim_end_matches("-cli");
+                return Err(XCheckerError::Config(ConfigError::InvalidValue {
+                    key: format!("llm.{section}.timeout_secs"),
+                    value: "must be between 5 and 7200 seconds".to_string(),
+                }));
+            }
+        }
+
+        // Validate the HTTP retry policy
+        if let Some(retry) = &self.llm.retry {
+            if retry.max_retries.is_some_and(|retries| retries > 10) {
+                return Err(XCheckerError::Config(ConfigError::InvalidValue {
+                    key: "llm.retry.max_retries".to_string(),
+                    value: "must be at most 10".to_string(),
+                }));
+            }
+            if let (Some(base), Some(max)) = (retry.base_delay_ms, retry.max_delay_ms)
+                && base > max
+            {
+                return Err(XCheckerError::Config(ConfigError::InvalidValue {
+                    key: "llm.retry.base_delay_ms".to_string(),
+                    value: format!("must not exceed llm.retry.max_delay_ms ({max})"),
+                }));
+            }
+            if let Some(status) = retry
+                .retry_on
+                .iter()
+                .flatten()
+                .find(|status| !(400..=599).contains(*status))
+            {
+                return Err(XCheckerError::Config(ConfigError::InvalidValue {
+                    key: "llm.retry.retry_on".to_string(),
+                    value: format!("{status} is not an HTTP error status (400-599)"),
+                }));
+            }
+        }
+
         // Validate stdout_cap_bytes
         if let Some(stdout_cap) = self.defaults.stdout_cap_bytes {
             if stdout_cap < 1024 {
@@ -250,6 +301,20 @@ impl Config {
             }
         }
 
+        // Named extra patterns must have distinct, non-empty IDs, otherwise
+        // ignore references would be ambiguous
+        let mut extra_ids = std::collections::HashSet::new();
+        for pattern in &self.security.extra_secret_patterns {
+            if let ExtraSecretPattern::Named { id, .. } = pattern
+                && (id.trim().is_empty() || !extra_ids.insert(id.as_str()))
+            {
+                return Err(XCheckerError::Config(ConfigError::InvalidValue {
+                    key: "security.extra_secret_patterns".to_string(),
+                    value: format!("pattern id '{id}' is empty or used more than once"),
+                }));
+            }
+        }
+
         Ok(())
     }
 
@@ -306,3 +371,40 @@ impl Config {
         }))
     }
 }
+
+fn validate_packet_max_bytes(key: &str, max_bytes: Option<usize>) -> Result<(), XCheckerError> {
+    if let Some(max_bytes) = max_bytes {
+        if max_bytes == 0 {
+            return Err(XCheckerError::Config(ConfigError::InvalidValue {
+                key: key.to_string(),
+                value: "must be greater than 0".to_string(),
+            }));
+        }
+        if max_bytes > 10_000_000 {
+            // 10MB limit
+            return Err(XCheckerError::Config(ConfigError::InvalidValue {
+                key: key.to_string(),
+                value: "exceeds maximum limit of 10MB".to_string(),
+            }));
+        }
+    }
+    Ok(())
+}
+
+fn validate_packet_max_lines(key: &str, max_lines: Option<usize>) -> Result<(), XCheckerError> {
+    if let Some(max_lines) = max_lines {
+        if max_lines == 0 {
+            return Err(XCheckerError::Config(ConfigError::InvalidValue {
+                key: key.to_string(),
+                value: "must be greater than 0".to_string(),
+            }));
+        }
+        if max_lines > 100_000 {
+            return Err(XCheckerError::Config(ConfigError::InvalidValue {
+                key: key.to_string(),
+                value: "exceeds maximum limit of 100,000".to_string(),
+            }));
+        }
+    }
+    Ok(())
+}
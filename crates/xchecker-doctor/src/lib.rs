@@ -1,7 +1,7 @@
 //! Doctor command for environment health checks
 //!
 //! Provides preflight checks for Claude CLI availability, runner configuration,
-//! write permissions, and configuration validity.
+//! write permissions, free disk space, and configuration validity.
 
 // Re-export shared types from xchecker-utils
 pub use xchecker_utils::types::{CheckStatus, DoctorCheck, DoctorOutput};
@@ -9,19 +9,83 @@ pub use xchecker_utils::types::{CheckStatus, DoctorCheck, DoctorOutput};
 pub mod wsl;
 
 use anyhow::Result;
+use camino::Utf8Path;
 use chrono::Utc;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use xchecker_config::Config;
+use xchecker_redaction::{SecretRedactor, default_pattern_defs};
 use xchecker_utils::cache;
+use xchecker_utils::encoding::decode_console_output;
 use xchecker_utils::logging;
 use xchecker_utils::paths;
 use xchecker_utils::runner::{CommandSpec, Runner, RunnerMode, WslOptions};
 
+/// Default limit on how long PATH scanning for a binary may take
+const DEFAULT_PATH_SCAN_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Install command suggested when the Claude CLI cannot be found
+const CLAUDE_INSTALL_REMEDIATION: &str =
+    "npm install -g @anthropic-ai/claude-code  # or pass --llm-claude-binary /path/to/claude";
+
+/// Install command suggested when the Gemini CLI cannot be found
+const GEMINI_INSTALL_REMEDIATION: &str =
+    "npm install -g @google/gemini-cli  # or pass --llm-gemini-binary /path/to/gemini";
+
+/// Free space in the xchecker home below which `disk_space` warns
+const DISK_SPACE_WARN_BYTES: u64 = 500 * 1024 * 1024;
+
+/// Free space in the xchecker home below which `disk_space` fails
+const DISK_SPACE_FAIL_BYTES: u64 = 50 * 1024 * 1024;
+
+/// Earliest believable system time (2024-01-01T00:00:00Z), in seconds since the epoch
+const CLOCK_SANE_FLOOR_SECS: u64 = 1_704_067_200;
+
+/// Slack allowed between the process start time (whole seconds) and now
+const CLOCK_START_TOLERANCE_SECS: u64 = 2;
+
+/// Remediation suggested when the system clock looks wrong
+const CLOCK_REMEDIATION: &str =
+    "Sync the system clock (e.g. enable NTP); stale lock detection relies on it";
+
+/// Outcome of a time-limited binary lookup
+#[derive(Debug, PartialEq, Eq)]
+enum BinaryDiscovery {
+    Found(PathBuf),
+    NotFound,
+    TimedOut,
+}
+
+/// Run `discover` on a helper thread and give up after `timeout`
+///
+/// Slow PATH entries (e.g. network mounts) can stall `which` for a long time.
+/// On timeout the helper thread is left to finish in the background; its
+/// result is discarded.
+fn discover_binary_with_timeout<F>(name: &str, timeout: Duration, discover: F) -> BinaryDiscovery
+where
+    F: FnOnce(&str) -> Option<PathBuf> + Send + 'static,
+{
+    let (tx, rx) = mpsc::channel();
+    let name = name.to_string();
+    std::thread::spawn(move || {
+        let _ = tx.send(discover(&name));
+    });
+
+    match rx.recv_timeout(timeout) {
+        Ok(Some(path)) => BinaryDiscovery::Found(path),
+        Ok(None) => BinaryDiscovery::NotFound,
+        Err(mpsc::RecvTimeoutError::Timeout) => BinaryDiscovery::TimedOut,
+        Err(mpsc::RecvTimeoutError::Disconnected) => BinaryDiscovery::NotFound,
+    }
+}
+
 /// Doctor command implementation
 pub struct DoctorCommand {
     config: Config,
     cache: Option<cache::InsightCache>,
+    path_scan_timeout: Duration,
 }
 
 impl DoctorCommand {
@@ -32,7 +96,18 @@ impl DoctorCommand {
         let cache_dir = paths::cache_dir();
         let cache = cache::InsightCache::new(cache_dir).ok();
 
-        Self { config, cache }
+        Self {
+            config,
+            cache,
+            path_scan_timeout: DEFAULT_PATH_SCAN_TIMEOUT,
+        }
+    }
+
+    /// Limit how long binary discovery may spend scanning PATH
+    #[must_use]
+    pub const fn with_path_scan_timeout(mut self, timeout: Duration) -> Self {
+        self.path_scan_timeout = timeout;
+        self
     }
 
     /// Create from CLI args (wired from cli module)
@@ -44,6 +119,7 @@ impl DoctorCommand {
         Ok(Self {
             config: cfg.clone(),
             cache,
+            path_scan_timeout: DEFAULT_PATH_SCAN_TIMEOUT,
         })
     }
 
@@ -66,6 +142,7 @@ impl DoctorCommand {
                 name: force_fail_check.clone(),
                 status: CheckStatus::Fail,
                 details: format!("Forced failure for testing: {force_fail_check}"),
+                remediation: None,
             });
 
             // Sort checks by name for stable output (required for JCS canonical emission)
@@ -102,14 +179,17 @@ impl DoctorCommand {
             }
         }
 
-        // 2. Runner selection & WSL
-        checks.push(self.check_runner_selection());
-        checks.push(self.check_wsl_availability());
+        // 2. Runner selection & WSL - only relevant for CLI providers, which spawn
+        // subprocesses. HTTP providers never touch the runner.
+        if !Self::is_http_provider(provider) {
+            checks.push(self.check_runner_selection());
+            checks.push(self.check_wsl_availability());
 
-        // On Windows, check WSL default distro and list all distros
-        if cfg!(target_os = "windows") {
-            checks.push(self.check_wsl_default_distro());
-            checks.push(self.check_wsl_distros());
+            // On Windows, check WSL default distro and list all distros
+            if cfg!(target_os = "windows") {
+                checks.push(self.check_wsl_default_distro());
+                checks.push(self.check_wsl_distros());
+            }
         }
 
         // 3. Write permissions
@@ -118,11 +198,21 @@ impl DoctorCommand {
         // 4. Same-volume atomic rename test
         checks.push(self.check_atomic_rename());
 
-        // 5. Config parsing
+        // 5. Free space for packets and artifacts
+        checks.push(self.check_disk_space());
+
+        // 6. Clock sanity for lock timestamps
+        checks.push(self.check_clock_sanity());
+
+        // 7. Config parsing
         checks.push(self.check_config_parse());
 
-        // 6. LLM provider validation
+        // 8. Custom secret patterns compile and redaction is still active
+        checks.push(self.check_secret_patterns());
+
+        // 9. LLM provider validation
         checks.push(self.check_llm_provider());
+        checks.push(self.check_model_name());
 
         // Sort checks by name for stable output (required for JCS canonical emission)
         checks.sort_by(|a, b| a.name.cmp(&b.name));
@@ -149,13 +239,60 @@ impl DoctorCommand {
         })
     }
 
+    /// Whether the provider talks to an HTTP API rather than a local CLI binary
+    fn is_http_provider(provider: &str) -> bool {
+        matches!(provider, "openrouter" | "anthropic")
+    }
+
     /// Check if claude is in PATH
     fn check_claude_path(&self) -> DoctorCheck {
-        if let Ok(path) = which::which("claude") {
+        self.check_claude_path_with(|name| which::which(name).ok())
+    }
+
+    /// Check claude discoverability using the given PATH lookup
+    ///
+    /// A configured `[llm.claude] binary` that exists is accepted without
+    /// scanning PATH; otherwise the lookup is bounded by `path_scan_timeout`.
+    fn check_claude_path_with<F>(&self, discover: F) -> DoctorCheck
+    where
+        F: FnOnce(&str) -> Option<PathBuf> + Send + 'static,
+    {
+        if let Some(binary) = self
+            .config
+            .llm
+            .claude
+            .as_ref()
+            .and_then(|c| c.binary.as_deref())
+            && Path::new(binary).exists()
+        {
+            return DoctorCheck {
+                name: "claude_path".to_string(),
+                status: CheckStatus::Pass,
+                details: format!("Found claude at {binary} ([llm.claude] binary)"),
+                remediation: None,
+            };
+        }
+
+        let discovery = discover_binary_with_timeout("claude", self.path_scan_timeout, discover);
+        if let BinaryDiscovery::Found(path) = discovery {
             DoctorCheck {
                 name: "claude_path".to_string(),
                 status: CheckStatus::Pass,
                 details: format!("Found claude at {}", path.display()),
+                remediation: None,
+            }
+        } else if discovery == BinaryDiscovery::TimedOut {
+            DoctorCheck {
+                name: "claude_path".to_string(),
+                status: CheckStatus::Warn,
+                details: format!(
+                    "PATH scan timed out after {}ms while looking for claude. Set [llm.claude] binary to skip PATH discovery",
+                    self.path_scan_timeout.as_millis()
+                ),
+                remediation: Some(
+                    "Add to .xchecker/config.toml:\n[llm.claude]\nbinary = \"/path/to/claude\""
+                        .to_string(),
+                ),
             }
         } else {
             // On Windows, provide actionable suggestion if WSL is available
@@ -168,6 +305,7 @@ impl DoctorCommand {
                             name: "claude_path".to_string(),
                             status: CheckStatus::Warn,
                             details: "Claude CLI not found in native PATH, but is available in WSL. Consider using --runner-mode wsl or --runner-mode auto".to_string(),
+                            remediation: Some("xchecker <command> --runner-mode wsl".to_string()),
                         };
                     }
                 }
@@ -177,6 +315,7 @@ impl DoctorCommand {
                 name: "claude_path".to_string(),
                 status: CheckStatus::Fail,
                 details: "Claude CLI not found in PATH".to_string(),
+                remediation: Some(CLAUDE_INSTALL_REMEDIATION.to_string()),
             }
         }
     }
@@ -195,6 +334,7 @@ impl DoctorCommand {
                     name: "claude_version".to_string(),
                     status: CheckStatus::Pass,
                     details: version,
+                    remediation: None,
                 }
             }
             Ok(output) => DoctorCheck {
@@ -204,11 +344,13 @@ impl DoctorCommand {
                     "claude --version failed with exit code: {}",
                     output.status.code().unwrap_or(-1)
                 ),
+                remediation: None,
             },
             Err(e) => DoctorCheck {
                 name: "claude_version".to_string(),
                 status: CheckStatus::Fail,
                 details: format!("Failed to execute claude --version: {e}"),
+                remediation: None,
             },
         }
     }
@@ -220,12 +362,14 @@ impl DoctorCommand {
                 name: "gemini_path".to_string(),
                 status: CheckStatus::Pass,
                 details: format!("Found gemini at {}", path.display()),
+                remediation: None,
             }
         } else {
             DoctorCheck {
                 name: "gemini_path".to_string(),
                 status: CheckStatus::Fail,
                 details: "Gemini CLI not found in PATH".to_string(),
+                remediation: Some(GEMINI_INSTALL_REMEDIATION.to_string()),
             }
         }
     }
@@ -239,6 +383,7 @@ impl DoctorCommand {
                 name: "gemini_help".to_string(),
                 status: CheckStatus::Pass,
                 details: "Gemini CLI responds to -h flag".to_string(),
+                remediation: None,
             },
             Ok(output) => DoctorCheck {
                 name: "gemini_help".to_string(),
@@ -247,11 +392,13 @@ impl DoctorCommand {
                     "gemini -h failed with exit code: {}",
                     output.status.code().unwrap_or(-1)
                 ),
+                remediation: None,
             },
             Err(e) => DoctorCheck {
                 name: "gemini_help".to_string(),
                 status: CheckStatus::Fail,
                 details: format!("Failed to execute gemini -h: {e}"),
+                remediation: None,
             },
         }
     }
@@ -280,11 +427,13 @@ impl DoctorCommand {
                         name: "runner_selection".to_string(),
                         status: CheckStatus::Pass,
                         details: format!("Runner mode: {mode_str}"),
+                        remediation: None,
                     },
                     Err(e) => DoctorCheck {
                         name: "runner_selection".to_string(),
                         status: CheckStatus::Fail,
                         details: format!("Runner validation failed: {e}"),
+                        remediation: Some(Self::runner_remediation(mode)),
                     },
                 }
             }
@@ -292,10 +441,24 @@ impl DoctorCommand {
                 name: "runner_selection".to_string(),
                 status: CheckStatus::Fail,
                 details: format!("Invalid runner mode: {e}"),
+                remediation: Some(
+                    "xchecker <command> --runner-mode auto  # or: native, wsl".to_string(),
+                ),
             },
         }
     }
 
+    /// Suggested fix when the runner for `mode` fails validation
+    fn runner_remediation(mode: RunnerMode) -> String {
+        match mode {
+            RunnerMode::Wsl => {
+                "wsl --install  # then install claude inside WSL, or use --runner-mode native"
+                    .to_string()
+            }
+            RunnerMode::Native | RunnerMode::Auto => CLAUDE_INSTALL_REMEDIATION.to_string(),
+        }
+    }
+
     /// Check WSL availability (Windows only)
     fn check_wsl_availability(&self) -> DoctorCheck {
         if !cfg!(target_os = "windows") {
@@ -303,6 +466,7 @@ impl DoctorCommand {
                 name: "wsl_availability".to_string(),
                 status: CheckStatus::Pass,
                 details: "WSL not applicable (not Windows)".to_string(),
+                remediation: None,
             };
         }
 
@@ -315,16 +479,19 @@ impl DoctorCommand {
                         name: "wsl_availability".to_string(),
                         status: CheckStatus::Pass,
                         details: "WSL is available and Claude CLI is installed".to_string(),
+                        remediation: None,
                     },
                     Ok(false) => DoctorCheck {
                         name: "wsl_availability".to_string(),
                         status: CheckStatus::Warn,
                         details: "WSL is available but Claude CLI not found in WSL. Install Claude in WSL to use --runner-mode wsl".to_string(),
+                        remediation: None,
                     },
                     Err(e) => DoctorCheck {
                         name: "wsl_availability".to_string(),
                         status: CheckStatus::Warn,
                         details: format!("WSL is available but Claude check failed: {e}"),
+                        remediation: None,
                     },
                 }
             }
@@ -332,11 +499,13 @@ impl DoctorCommand {
                 name: "wsl_availability".to_string(),
                 status: CheckStatus::Warn,
                 details: "WSL not installed or no distributions available".to_string(),
+                remediation: None,
             },
             Err(e) => DoctorCheck {
                 name: "wsl_availability".to_string(),
                 status: CheckStatus::Warn,
                 details: format!("Failed to check WSL availability: {e}"),
+                remediation: None,
             },
         }
     }
@@ -348,6 +517,7 @@ impl DoctorCommand {
                 name: "wsl_default_distro".to_string(),
                 status: CheckStatus::Pass,
                 details: "WSL not applicable (not Windows)".to_string(),
+                remediation: None,
             };
         }
 
@@ -359,7 +529,7 @@ impl DoctorCommand {
         {
             Ok(output) if output.status.success() => {
                 // Normalize WSL output (may be UTF-16LE on some Windows locales)
-                let distros = Self::normalize_wsl_output(&output.stdout);
+                let distros = decode_console_output(&output.stdout);
 
                 // Parse the output to find the default distro (marked with *)
                 let mut default_distro = None;
@@ -383,16 +553,19 @@ impl DoctorCommand {
                                 name: "wsl_default_distro".to_string(),
                                 status: CheckStatus::Pass,
                                 details: format!("Default WSL distro: {distro} (Claude available)"),
+                                remediation: None,
                             },
                             Ok(false) => DoctorCheck {
                                 name: "wsl_default_distro".to_string(),
                                 status: CheckStatus::Warn,
                                 details: format!("Default WSL distro: {distro} (Claude not found)"),
+                                remediation: None,
                             },
                             Err(_) => DoctorCheck {
                                 name: "wsl_default_distro".to_string(),
                                 status: CheckStatus::Pass,
                                 details: format!("Default WSL distro: {distro}"),
+                                remediation: None,
                             },
                         }
                     }
@@ -400,6 +573,7 @@ impl DoctorCommand {
                         name: "wsl_default_distro".to_string(),
                         status: CheckStatus::Warn,
                         details: "Could not determine default WSL distro".to_string(),
+                        remediation: None,
                     },
                 }
             }
@@ -407,11 +581,13 @@ impl DoctorCommand {
                 name: "wsl_default_distro".to_string(),
                 status: CheckStatus::Warn,
                 details: "wsl -l -v command failed".to_string(),
+                remediation: None,
             },
             Err(e) => DoctorCheck {
                 name: "wsl_default_distro".to_string(),
                 status: CheckStatus::Warn,
                 details: format!("Failed to execute wsl -l -v: {e}"),
+                remediation: None,
             },
         }
     }
@@ -423,6 +599,7 @@ impl DoctorCommand {
                 name: "wsl_distros".to_string(),
                 status: CheckStatus::Pass,
                 details: "WSL not applicable (not Windows)".to_string(),
+                remediation: None,
             };
         }
 
@@ -457,17 +634,20 @@ impl DoctorCommand {
                             name: "wsl_distros".to_string(),
                             status: CheckStatus::Pass,
                             details: details_parts.join("\n"),
+                            remediation: None,
                         }
                     }
                     Ok(_) => DoctorCheck {
                         name: "wsl_distros".to_string(),
                         status: CheckStatus::Warn,
                         details: "WSL is installed but no distributions found".to_string(),
+                        remediation: None,
                     },
                     Err(e) => DoctorCheck {
                         name: "wsl_distros".to_string(),
                         status: CheckStatus::Warn,
                         details: format!("Failed to parse WSL distro list: {e}"),
+                        remediation: None,
                     },
                 }
             }
@@ -475,45 +655,17 @@ impl DoctorCommand {
                 name: "wsl_distros".to_string(),
                 status: CheckStatus::Warn,
                 details: "wsl -l -q command failed".to_string(),
+                remediation: None,
             },
             Err(_) => DoctorCheck {
                 name: "wsl_distros".to_string(),
                 status: CheckStatus::Warn,
                 details: "WSL not installed or not available".to_string(),
+                remediation: None,
             },
         }
     }
 
-    /// Normalize WSL output which may be UTF-16LE on some Windows locales
-    fn normalize_wsl_output(raw: &[u8]) -> String {
-        // Check if this looks like UTF-16LE (every other byte is 0x00 for ASCII)
-        let looks_like_utf16le = raw.len() >= 4
-            && raw.len().is_multiple_of(2)
-            && raw
-                .iter()
-                .skip(1)
-                .step_by(2)
-                .take(10)
-                .filter(|&&b| b == 0x00)
-                .count()
-                >= 5;
-
-        if looks_like_utf16le {
-            // Decode as UTF-16LE
-            let mut u16_vec: Vec<u16> = Vec::new();
-            let mut i = 0;
-            while i + 1 < raw.len() {
-                let u = u16::from_le_bytes([raw[i], raw[i + 1]]);
-                u16_vec.push(u);
-                i += 2;
-            }
-            return String::from_utf16_lossy(&u16_vec);
-        }
-
-        // Try UTF-8
-        String::from_utf8_lossy(raw).to_string()
-    }
-
     /// Check write permissions to .xchecker directory
     fn check_write_permissions(&self) -> DoctorCheck {
         let xchecker_dir = Path::new(".xchecker");
@@ -526,6 +678,7 @@ impl DoctorCommand {
                         name: "write_permissions".to_string(),
                         status: CheckStatus::Pass,
                         details: "Created .xchecker directory successfully".to_string(),
+                        remediation: None,
                     };
                 }
                 Err(e) => {
@@ -533,6 +686,7 @@ impl DoctorCommand {
                         name: "write_permissions".to_string(),
                         status: CheckStatus::Fail,
                         details: format!("Cannot create .xchecker directory: {e}"),
+                        remediation: None,
                     };
                 }
             }
@@ -548,12 +702,14 @@ impl DoctorCommand {
                     name: "write_permissions".to_string(),
                     status: CheckStatus::Pass,
                     details: ".xchecker directory is writable".to_string(),
+                    remediation: None,
                 }
             }
             Err(e) => DoctorCheck {
                 name: "write_permissions".to_string(),
                 status: CheckStatus::Fail,
                 details: format!("Cannot write to .xchecker directory: {e}"),
+                remediation: None,
             },
         }
     }
@@ -568,6 +724,7 @@ impl DoctorCommand {
                 name: "atomic_rename".to_string(),
                 status: CheckStatus::Fail,
                 details: format!("Cannot create .xchecker directory: {e}"),
+                remediation: None,
             };
         }
 
@@ -586,6 +743,7 @@ impl DoctorCommand {
                             name: "atomic_rename".to_string(),
                             status: CheckStatus::Pass,
                             details: "Atomic rename works on same volume".to_string(),
+                            remediation: None,
                         }
                     }
                     Err(e) => {
@@ -595,6 +753,7 @@ impl DoctorCommand {
                             name: "atomic_rename".to_string(),
                             status: CheckStatus::Fail,
                             details: format!("Atomic rename failed: {e}"),
+                            remediation: None,
                         }
                     }
                 }
@@ -603,18 +762,285 @@ impl DoctorCommand {
                 name: "atomic_rename".to_string(),
                 status: CheckStatus::Fail,
                 details: format!("Cannot create test file: {e}"),
+                remediation: None,
+            },
+        }
+    }
+
+    /// Check free space on the volume holding the xchecker home
+    fn check_disk_space(&self) -> DoctorCheck {
+        let home = paths::xchecker_home();
+
+        if let Err(e) = paths::ensure_dir_all(&home) {
+            return DoctorCheck {
+                name: "disk_space".to_string(),
+                status: CheckStatus::Fail,
+                details: format!("Cannot create {home} directory: {e}"),
+                remediation: None,
+            };
+        }
+
+        match Self::free_bytes_at(home.as_std_path()) {
+            Some(free) => Self::disk_space_check(&home, free),
+            None => DoctorCheck {
+                name: "disk_space".to_string(),
+                status: CheckStatus::Warn,
+                details: format!("Could not determine free space for {home}"),
+                remediation: None,
             },
         }
     }
 
+    /// Free bytes on the mounted volume that contains `path`
+    fn free_bytes_at(path: &Path) -> Option<u64> {
+        let path = std::fs::canonicalize(path).ok()?;
+        let disks = sysinfo::Disks::new_with_refreshed_list();
+        // The deepest mount point containing the path is the volume it lives on
+        disks
+            .list()
+            .iter()
+            .filter(|disk| path.starts_with(disk.mount_point()))
+            .max_by_key(|disk| disk.mount_point().components().count())
+            .map(sysinfo::Disk::available_space)
+    }
+
+    /// Grade `free` bytes in `home` against the warn and fail thresholds
+    fn disk_space_check(home: &Utf8Path, free: u64) -> DoctorCheck {
+        let free_mb = free / (1024 * 1024);
+        let (status, details) = if free < DISK_SPACE_FAIL_BYTES {
+            (
+                CheckStatus::Fail,
+                format!(
+                    "Only {free_mb} MB free for {home} (need at least {} MB)",
+                    DISK_SPACE_FAIL_BYTES / (1024 * 1024)
+                ),
+            )
+        } else if free < DISK_SPACE_WARN_BYTES {
+            (
+                CheckStatus::Warn,
+                format!(
+                    "Only {free_mb} MB free for {home} (recommended at least {} MB)",
+                    DISK_SPACE_WARN_BYTES / (1024 * 1024)
+                ),
+            )
+        } else {
+            (CheckStatus::Pass, format!("{free_mb} MB free for {home}"))
+        };
+
+        DoctorCheck {
+            name: "disk_space".to_string(),
+            status,
+            details,
+            remediation: None,
+        }
+    }
+
+    /// Check that the system clock is plausible for lock timestamps
+    ///
+    /// Lock staleness is judged from `created_at` seconds, and future
+    /// timestamps are tolerated, so a badly set clock silently breaks stale
+    /// lock detection. Round-trips a lock-style timestamp through a file in
+    /// the xchecker home, then grades it.
+    fn check_clock_sanity(&self) -> DoctorCheck {
+        let home = paths::xchecker_home();
+        let probe = home.join(".doctor_clock_test");
+
+        let now_secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        let written = serde_json::json!({ "created_at": now_secs }).to_string();
+        let read_back = paths::ensure_dir_all(&home)
+            .and_then(|()| std::fs::write(&probe, &written))
+            .and_then(|()| std::fs::read_to_string(&probe));
+        let _ = std::fs::remove_file(&probe);
+
+        let created_at = match read_back {
+            Ok(content) => serde_json::from_str::<serde_json::Value>(&content)
+                .ok()
+                .and_then(|value| value["created_at"].as_u64()),
+            Err(e) => {
+                return DoctorCheck {
+                    name: "clock_sanity".to_string(),
+                    status: CheckStatus::Warn,
+                    details: format!("Cannot write lock timestamp probe in {home}: {e}"),
+                    remediation: None,
+                };
+            }
+        };
+        let Some(created_at) = created_at.filter(|&secs| secs == now_secs) else {
+            return DoctorCheck {
+                name: "clock_sanity".to_string(),
+                status: CheckStatus::Warn,
+                details: "Lock timestamp did not survive a write and read back".to_string(),
+                remediation: None,
+            };
+        };
+
+        Self::clock_sanity_check(created_at, Self::process_start_secs())
+    }
+
+    /// Start time of this process in seconds since the epoch, if known
+    fn process_start_secs() -> Option<u64> {
+        let pid = sysinfo::get_current_pid().ok()?;
+        let mut system = sysinfo::System::new();
+        system.refresh_processes(sysinfo::ProcessesToUpdate::Some(&[pid]), false);
+        system
+            .process(pid)
+            .map(sysinfo::Process::start_time)
+            .filter(|&secs| secs > 0)
+    }
+
+    /// Grade a lock timestamp `now_secs` against the sane floor and the process start
+    fn clock_sanity_check(now_secs: u64, process_start_secs: Option<u64>) -> DoctorCheck {
+        let (status, details) = if now_secs < CLOCK_SANE_FLOOR_SECS {
+            (
+                CheckStatus::Warn,
+                format!(
+                    "System clock reads {now_secs}s since the epoch, before 2024-01-01 ({CLOCK_SANE_FLOOR_SECS}s)"
+                ),
+            )
+        } else if let Some(start) = process_start_secs
+            && now_secs + CLOCK_START_TOLERANCE_SECS < start
+        {
+            (
+                CheckStatus::Warn,
+                format!(
+                    "System clock reads {now_secs}s, {}s before this process started ({start}s)",
+                    start - now_secs
+                ),
+            )
+        } else {
+            (
+                CheckStatus::Pass,
+                format!("System clock reads {now_secs}s since the epoch"),
+            )
+        };
+
+        let remediation = (status != CheckStatus::Pass).then(|| CLOCK_REMEDIATION.to_string());
+        DoctorCheck {
+            name: "clock_sanity".to_string(),
+            status,
+            details,
+            remediation,
+        }
+    }
+
     /// Validate config parsing
     fn check_config_parse(&self) -> DoctorCheck {
         // Config is already parsed and validated in the constructor
-        // If we got here, config parsing succeeded
+        // If we got here, config parsing succeeded. Positional extra pattern
+        // IDs still deserve a warning: inserting a pattern renumbers them and
+        // silently breaks ignore_secret_patterns entries that reference them.
+        let positional: Vec<String> = self
+            .config
+            .security
+            .extra_secret_patterns
+            .iter()
+            .enumerate()
+            .filter(|(_, pattern)| pattern.is_positional())
+            .map(|(idx, pattern)| pattern.id(idx))
+            .collect();
+        if !positional.is_empty() {
+            return DoctorCheck {
+                name: "config_parse".to_string(),
+                status: CheckStatus::Warn,
+                details: format!(
+                    "Configuration is valid, but extra secret patterns {} have positional IDs \
+                     that change when the list is reordered; give them an `id` to keep \
+                     ignore_secret_patterns references stable",
+                    positional.join(", ")
+                ),
+                remediation: None,
+            };
+        }
+
         DoctorCheck {
             name: "config_parse".to_string(),
             status: CheckStatus::Pass,
             details: "Configuration parsed and validated successfully".to_string(),
+            remediation: None,
+        }
+    }
+
+    /// Validate the configured secret patterns and that redaction is still active
+    ///
+    /// Compiles every `extra_secret_patterns` entry and every pattern in
+    /// `secret_patterns_file`, naming the source of each failure.
+    fn check_secret_patterns(&self) -> DoctorCheck {
+        let security = &self.config.security;
+        let inline_source = "[security] extra_secret_patterns";
+        let file_source = security
+            .secret_patterns_file
+            .as_ref()
+            .map(|path| format!("secret_patterns_file {}", path.display()));
+
+        if let Err(errors) = SecretRedactor::validate_patterns(&self.config) {
+            // Inline errors are keyed by list index; anything else came from the file
+            let (inline_errors, file_errors): (Vec<String>, Vec<String>) = errors
+                .into_iter()
+                .partition(|e| e.starts_with("extra_secret_patterns["));
+
+            let mut sections = Vec::new();
+            let mut sources = Vec::new();
+            if !inline_errors.is_empty() {
+                sections.push(format!("{inline_source}:\n{}", inline_errors.join("\n")));
+                sources.push(inline_source.to_string());
+            }
+            if let Some(file_source) = file_source.as_ref()
+                && !file_errors.is_empty()
+            {
+                sections.push(format!("{file_source}:\n{}", file_errors.join("\n")));
+                sources.push(file_source.clone());
+            }
+
+            return DoctorCheck {
+                name: "secret_patterns".to_string(),
+                status: CheckStatus::Fail,
+                details: format!(
+                    "{} secret pattern error(s):\n{}",
+                    inline_errors.len() + file_errors.len(),
+                    sections.join("\n")
+                ),
+                remediation: Some(format!(
+                    "Fix or remove the listed entries in {}",
+                    sources.join(" and ")
+                )),
+            };
+        }
+
+        let ignored = &security.ignore_secret_patterns;
+        let all_defaults_ignored = default_pattern_defs()
+            .iter()
+            .all(|def| ignored.iter().any(|id| id == def.id));
+        if all_defaults_ignored {
+            return DoctorCheck {
+                name: "secret_patterns".to_string(),
+                status: CheckStatus::Warn,
+                details: "Every default secret pattern is listed in \
+                          [security] ignore_secret_patterns; redaction is effectively disabled"
+                    .to_string(),
+                remediation: Some(
+                    "Remove default pattern IDs from ignore_secret_patterns unless they \
+                     truly produce false positives"
+                        .to_string(),
+                ),
+            };
+        }
+
+        let mut checked = vec![format!(
+            "{} pattern(s) in {inline_source}",
+            security.extra_secret_patterns.len()
+        )];
+        checked.extend(file_source);
+        DoctorCheck {
+            name: "secret_patterns".to_string(),
+            status: CheckStatus::Pass,
+            details: format!(
+                "All secret patterns compile ({}); {} pattern(s) ignored",
+                checked.join(", "),
+                ignored.len()
+            ),
+            remediation: None,
         }
     }
 
@@ -647,6 +1073,7 @@ impl DoctorCommand {
                         "Unknown provider '{}'. Supported providers: claude-cli, gemini-cli, openrouter, anthropic",
                         unknown
                     ),
+                    remediation: Some("xchecker <command> --llm-provider claude-cli".to_string()),
                 };
             }
         }
@@ -667,6 +1094,7 @@ impl DoctorCommand {
                     name: "llm_provider".to_string(),
                     status: CheckStatus::Pass,
                     details: format!("Provider: claude-cli (custom binary at {})", binary_path),
+                    remediation: None,
                 };
             } else {
                 return DoctorCheck {
@@ -676,6 +1104,10 @@ impl DoctorCommand {
                         "Custom Claude binary path '{}' does not exist. Please check [llm.claude] binary configuration",
                         binary_path
                     ),
+                    remediation: Some(
+                        "xchecker <command> --llm-claude-binary \"$(command -v claude)\""
+                            .to_string(),
+                    ),
                 };
             }
         }
@@ -700,6 +1132,7 @@ impl DoctorCommand {
                         name: "llm_provider".to_string(),
                         status: CheckStatus::Pass,
                         details: format!("Provider: claude-cli (found at {})", path),
+                        remediation: None,
                     }
                 }
                 _ => {
@@ -711,6 +1144,7 @@ impl DoctorCommand {
                                 name: "llm_provider".to_string(),
                                 status: CheckStatus::Warn,
                                 details: "Provider: claude-cli (not in native PATH, but available in WSL. Consider using --runner-mode wsl)".to_string(),
+                                remediation: Some("xchecker <command> --runner-mode wsl".to_string()),
                             };
                     }
 
@@ -718,6 +1152,7 @@ impl DoctorCommand {
                         name: "llm_provider".to_string(),
                         status: CheckStatus::Fail,
                         details: "Provider: claude-cli (binary not found in PATH or WSL. Install Claude CLI or specify path with --llm-claude-binary)".to_string(),
+                        remediation: Some(CLAUDE_INSTALL_REMEDIATION.to_string()),
                     }
                 }
             }
@@ -733,6 +1168,7 @@ impl DoctorCommand {
                         name: "llm_provider".to_string(),
                         status: CheckStatus::Pass,
                         details: format!("Provider: claude-cli (found at {})", path),
+                        remediation: None,
                     }
                 }
                 _ => DoctorCheck {
@@ -741,11 +1177,104 @@ impl DoctorCommand {
                     details:
                         "Provider: claude-cli (binary not found in PATH. Install Claude CLI or specify path with --llm-claude-binary)"
                             .to_string(),
+                    remediation: Some(CLAUDE_INSTALL_REMEDIATION.to_string()),
                 },
             }
         }
     }
 
+    /// Validate the model configured for the active provider
+    ///
+    /// Names must match `^[A-Za-z0-9._/:-]+$`; anything else (shell
+    /// metacharacters, whitespace) fails. A syntactically valid name that
+    /// doesn't look like one of the provider's models warns, since a typo
+    /// otherwise only surfaces when the LLM is called.
+    fn check_model_name(&self) -> DoctorCheck {
+        let llm = &self.config.llm;
+        let provider = llm.provider.as_deref().unwrap_or("claude-cli");
+        let (key, model) = match provider {
+            "claude-cli" => ("defaults.model", self.config.defaults.model.clone()),
+            "gemini-cli" => match llm.gemini.as_ref().and_then(|g| g.default_model.clone()) {
+                Some(model) => ("llm.gemini.default_model", Some(model)),
+                None => ("defaults.model", self.config.defaults.model.clone()),
+            },
+            "openrouter" => (
+                "llm.openrouter.model",
+                llm.openrouter.as_ref().and_then(|or| or.model.clone()),
+            ),
+            "anthropic" => (
+                "llm.anthropic.model",
+                llm.anthropic.as_ref().and_then(|a| a.model.clone()),
+            ),
+            // Unknown providers are reported by check_llm_provider
+            _ => ("defaults.model", None),
+        };
+
+        let Some(model) = model else {
+            return DoctorCheck {
+                name: "model_name".to_string(),
+                status: CheckStatus::Pass,
+                details: format!("No model configured for {provider}; nothing to validate"),
+                remediation: None,
+            };
+        };
+
+        Self::classify_model_name(provider, key, &model)
+    }
+
+    /// Classify `model` for `provider`; `key` names the setting it came from
+    fn classify_model_name(provider: &str, key: &str, model: &str) -> DoctorCheck {
+        let valid_char =
+            |c: char| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '/' | ':' | '-');
+        if model.is_empty() || !model.chars().all(valid_char) {
+            return DoctorCheck {
+                name: "model_name".to_string(),
+                status: CheckStatus::Fail,
+                details: format!(
+                    "Model {model:?} ({key}) contains characters outside [A-Za-z0-9._/:-]"
+                ),
+                remediation: Some(format!(
+                    "Set {key} to a plain model name, e.g. \"sonnet\" or \"claude-sonnet-4-5\""
+                )),
+            };
+        }
+
+        let recognized = match provider {
+            "claude-cli" => {
+                matches!(
+                    model.strip_suffix("-latest").unwrap_or(model),
+                    "haiku" | "sonnet" | "opus" | "default"
+                ) || model.starts_with("claude-")
+            }
+            "anthropic" => model.starts_with("claude-"),
+            "gemini-cli" => model.starts_with("gemini-"),
+            // OpenRouter models are `<vendor>/<model>`
+            "openrouter" => model
+                .split_once('/')
+                .is_some_and(|(vendor, name)| !vendor.is_empty() && !name.is_empty()),
+            _ => true,
+        };
+
+        if recognized {
+            DoctorCheck {
+                name: "model_name".to_string(),
+                status: CheckStatus::Pass,
+                details: format!("Model '{model}' ({key}) is valid for {provider}"),
+                remediation: None,
+            }
+        } else {
+            DoctorCheck {
+                name: "model_name".to_string(),
+                status: CheckStatus::Warn,
+                details: format!(
+                    "Model '{model}' ({key}) is not a recognized {provider} model name; \
+                     check it for typos"
+                ),
+                remediation: None,
+            }
+        }
+    }
+
     /// Check HTTP provider configuration (requirement 3.5.3)
     ///
     /// For HTTP providers:
@@ -784,6 +1313,7 @@ impl DoctorCommand {
                                     "Provider: openrouter (API key present in {}, model: {})",
                                     api_key_env, model_name
                                 ),
+                                remediation: None,
                             },
                             None => DoctorCheck {
                                 name: "llm_provider".to_string(),
@@ -792,6 +1322,10 @@ impl DoctorCommand {
                                     "Provider: openrouter (API key present in {}, but model not configured. Set [llm.openrouter] model = \"model-name\")",
                                     api_key_env
                                 ),
+                                remediation: Some(
+                                    "Add to .xchecker/config.toml:\n[llm.openrouter]\nmodel = \"model-name\""
+                                        .to_string(),
+                                ),
                             },
                         }
                     }
@@ -802,6 +1336,7 @@ impl DoctorCommand {
                             "Provider: openrouter (API key not found in environment variable '{}'. Set this variable or configure api_key_env in [llm.openrouter])",
                             api_key_env
                         ),
+                        remediation: Some(format!("export {api_key_env}=<your-api-key>")),
                     },
                 }
             }
@@ -835,6 +1370,7 @@ impl DoctorCommand {
                                     "Provider: anthropic (API key present in {}, model: {})",
                                     api_key_env, model_name
                                 ),
+                                remediation: None,
                             },
                             None => DoctorCheck {
                                 name: "llm_provider".to_string(),
@@ -843,6 +1379,10 @@ impl DoctorCommand {
                                     "Provider: anthropic (API key present in {}, but model not configured. Set [llm.anthropic] model = \"model-name\")",
                                     api_key_env
                                 ),
+                                remediation: Some(
+                                    "Add to .xchecker/config.toml:\n[llm.anthropic]\nmodel = \"model-name\""
+                                        .to_string(),
+                                ),
                             },
                         }
                     }
@@ -853,6 +1393,7 @@ impl DoctorCommand {
                             "Provider: anthropic (API key not found in environment variable '{}'. Set this variable or configure api_key_env in [llm.anthropic])",
                             api_key_env
                         ),
+                        remediation: Some(format!("export {api_key_env}=<your-api-key>")),
                     },
                 }
             }
@@ -860,6 +1401,7 @@ impl DoctorCommand {
                 name: "llm_provider".to_string(),
                 status: CheckStatus::Fail,
                 details: format!("Unknown HTTP provider: {}", provider),
+                remediation: None,
             },
         }
     }
@@ -868,7 +1410,7 @@ impl DoctorCommand {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use xchecker_config::CliArgs;
+    use xchecker_config::{CliArgs, ExtraSecretPattern};
 
     #[test]
     fn test_doctor_output_structure() {
@@ -896,16 +1438,19 @@ mod tests {
                 name: "zebra".to_string(),
                 status: CheckStatus::Pass,
                 details: "test".to_string(),
+                remediation: None,
             },
             DoctorCheck {
                 name: "alpha".to_string(),
                 status: CheckStatus::Pass,
                 details: "test".to_string(),
+                remediation: None,
             },
             DoctorCheck {
                 name: "middle".to_string(),
                 status: CheckStatus::Pass,
                 details: "test".to_string(),
+                remediation: None,
             },
         ];
 
@@ -924,6 +1469,7 @@ mod tests {
             name: "test".to_string(),
             status: CheckStatus::Pass,
             details: "test details".to_string(),
+            remediation: None,
         };
 
         let json = serde_json::to_string(&check).unwrap();
@@ -934,16 +1480,19 @@ mod tests {
             name: "test".to_string(),
             status: CheckStatus::Pass,
             details: "test".to_string(),
+            remediation: None,
         };
         let warn_check = DoctorCheck {
             name: "test".to_string(),
             status: CheckStatus::Warn,
             details: "test".to_string(),
+            remediation: None,
         };
         let fail_check = DoctorCheck {
             name: "test".to_string(),
             status: CheckStatus::Fail,
             details: "test".to_string(),
+            remediation: None,
         };
 
         assert!(
@@ -990,6 +1539,76 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_claude_path_times_out_on_slow_discovery() {
+        let cli_args = CliArgs::default();
+        let config = Config::discover(&cli_args).unwrap();
+        let doctor = DoctorCommand::new(config).with_path_scan_timeout(Duration::from_millis(50));
+
+        let started = std::time::Instant::now();
+        let check = doctor.check_claude_path_with(|_| {
+            std::thread::sleep(Duration::from_secs(5));
+            Some(PathBuf::from("/slow/claude"))
+        });
+
+        assert!(started.elapsed() < Duration::from_secs(2));
+        assert_eq!(check.name, "claude_path");
+        assert_eq!(check.status, CheckStatus::Warn);
+        assert!(check.details.contains("PATH scan timed out"));
+    }
+
+    #[test]
+    fn test_claude_path_prefers_configured_binary() {
+        let temp_file = tempfile::NamedTempFile::new().unwrap();
+        let temp_path = temp_file.path().to_str().unwrap().to_string();
+        let cli_args = CliArgs {
+            llm_claude_binary: Some(temp_path.clone()),
+            ..Default::default()
+        };
+        let config = Config::discover(&cli_args).unwrap();
+        let doctor = DoctorCommand::new(config);
+
+        let check = doctor.check_claude_path_with(|_| panic!("PATH must not be scanned"));
+
+        assert_eq!(check.status, CheckStatus::Pass);
+        assert!(check.details.contains(&temp_path));
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn test_claude_path_failure_has_remediation() {
+        let cli_args = CliArgs::default();
+        let config = Config::discover(&cli_args).unwrap();
+        let doctor = DoctorCommand::new(config);
+
+        let check = doctor.check_claude_path_with(|_| None);
+
+        assert_eq!(check.status, CheckStatus::Fail);
+        let remediation = check.remediation.as_deref().unwrap_or_default();
+        assert!(!remediation.trim().is_empty());
+        assert!(remediation.contains("--llm-claude-binary"), "{remediation}");
+        let json = serde_json::to_value(&check).unwrap();
+        assert_eq!(json["remediation"], remediation);
+    }
+
+    #[test]
+    fn test_passing_check_omits_remediation() {
+        let temp_file = tempfile::NamedTempFile::new().unwrap();
+        let cli_args = CliArgs {
+            llm_claude_binary: Some(temp_file.path().to_str().unwrap().to_string()),
+            ..Default::default()
+        };
+        let config = Config::discover(&cli_args).unwrap();
+        let doctor = DoctorCommand::new(config);
+
+        let check = doctor.check_claude_path_with(|_| None);
+
+        assert_eq!(check.status, CheckStatus::Pass);
+        assert!(check.remediation.is_none());
+        let json = serde_json::to_string(&check).unwrap();
+        assert!(!json.contains("remediation"), "{json}");
+    }
+
     #[test]
     fn test_write_permissions_check() {
         let cli_args = CliArgs::default();
@@ -1012,6 +1631,79 @@ mod tests {
         // Status depends on filesystem capabilities
     }
 
+    #[test]
+    fn test_disk_space_check() {
+        let cli_args = CliArgs::default();
+        let config = Config::discover(&cli_args).unwrap();
+        let doctor = DoctorCommand::new(config);
+
+        let check = doctor.check_disk_space();
+        assert_eq!(check.name, "disk_space");
+        assert!(check.details.contains("free"), "{}", check.details);
+        // Status depends on the volume; it must still round-trip as a CheckStatus
+        let json = serde_json::to_value(&check).unwrap();
+        let status: CheckStatus = serde_json::from_value(json["status"].clone()).unwrap();
+        assert_eq!(status, check.status);
+    }
+
+    #[test]
+    fn test_disk_space_thresholds() {
+        let home = Utf8Path::new(".xchecker");
+        let mb = 1024 * 1024;
+
+        let check = DoctorCommand::disk_space_check(home, 10 * 1024 * mb);
+        assert_eq!(check.status, CheckStatus::Pass);
+        assert!(check.details.contains("10240 MB free"), "{}", check.details);
+
+        let check = DoctorCommand::disk_space_check(home, 200 * mb);
+        assert_eq!(check.status, CheckStatus::Warn);
+        assert!(check.details.contains("200 MB"), "{}", check.details);
+
+        let check = DoctorCommand::disk_space_check(home, 20 * mb);
+        assert_eq!(check.status, CheckStatus::Fail);
+        assert!(check.details.contains("20 MB"), "{}", check.details);
+    }
+
+    #[test]
+    fn test_clock_sanity_check() {
+        let cli_args = CliArgs::default();
+        let config = Config::discover(&cli_args).unwrap();
+        let doctor = DoctorCommand::new(config);
+
+        let check = doctor.check_clock_sanity();
+        assert_eq!(check.name, "clock_sanity");
+        assert!(check.details.contains("System clock"), "{}", check.details);
+        let json = serde_json::to_value(&check).unwrap();
+        let status: CheckStatus = serde_json::from_value(json["status"].clone()).unwrap();
+        assert_eq!(status, check.status);
+    }
+
+    #[test]
+    fn test_clock_sanity_thresholds() {
+        let now = CLOCK_SANE_FLOOR_SECS + 86_400;
+
+        let check = DoctorCommand::clock_sanity_check(now, Some(now - 60));
+        assert_eq!(check.status, CheckStatus::Pass);
+        assert!(check.remediation.is_none());
+
+        // Whole-second start times may lag a fresh reading slightly
+        let check = DoctorCommand::clock_sanity_check(now, Some(now + 1));
+        assert_eq!(check.status, CheckStatus::Pass);
+
+        let check = DoctorCommand::clock_sanity_check(now, Some(now + 3600));
+        assert_eq!(check.status, CheckStatus::Warn);
+        assert!(check.details.contains("3600s before"), "{}", check.details);
+        assert!(check.remediation.is_some());
+
+        let check = DoctorCommand::clock_sanity_check(86_400, None);
+        assert_eq!(check.status, CheckStatus::Warn);
+        assert!(
+            check.details.contains("before 2024-01-01"),
+            "{}",
+            check.details
+        );
+    }
+
     #[test]
     fn test_config_parse_check() {
         let cli_args = CliArgs::default();
@@ -1023,10 +1715,203 @@ mod tests {
         assert_eq!(check.status, CheckStatus::Pass);
     }
 
+    #[test]
+    fn test_config_parse_check_warns_on_positional_extra_patterns() {
+        let cli_args = CliArgs::default();
+        let mut config = Config::discover(&cli_args).unwrap();
+        config.security.extra_secret_patterns = vec![
+            ExtraSecretPattern::Named {
+                id: "internal_token".to_string(),
+                regex: "INT_[A-Z0-9]{16}".to_string(),
+            },
+            "CORP_[0-9]{8}".into(),
+        ];
+        let doctor = DoctorCommand::new(config);
+
+        let check = doctor.check_config_parse();
+        assert_eq!(check.status, CheckStatus::Warn);
+        assert!(check.details.contains("extra_pattern_1"));
+        assert!(!check.details.contains("internal_token"));
+    }
+
+    #[test]
+    fn test_secret_patterns_check_passes_for_valid_patterns() {
+        let mut config = Config::discover(&CliArgs::default()).unwrap();
+        config.security.extra_secret_patterns = vec![
+            ExtraSecretPattern::Named {
+                id: "internal_token".to_string(),
+                regex: "INT_[A-Z0-9]{16}".to_string(),
+            },
+            "CORP_[0-9]{8}".into(),
+        ];
+        config.security.ignore_secret_patterns = vec!["aws_access_key".to_string()];
+        let doctor = DoctorCommand::new(config);
+
+        let check = doctor.check_secret_patterns();
+        assert_eq!(check.name, "secret_patterns");
+        assert_eq!(check.status, CheckStatus::Pass);
+        assert!(
+            check
+                .details
+                .contains("2 pattern(s) in [security] extra_secret_patterns")
+        );
+    }
+
+    #[test]
+    fn test_secret_patterns_check_lists_invalid_patterns() {
+        let mut config = Config::discover(&CliArgs::default()).unwrap();
+        config.security.extra_secret_patterns = vec![
+            "CORP_[0-9]{8}".into(),
+            ExtraSecretPattern::Named {
+                id: "broken_token".to_string(),
+                regex: "BROKEN_[A-Z".to_string(),
+            },
+        ];
+        let mut doctor = DoctorCommand::new(config);
+
+        let check = doctor.check_secret_patterns();
+        assert_eq!(check.status, CheckStatus::Fail);
+        assert!(check.details.contains("[security] extra_secret_patterns:"));
+        assert!(
+            check
+                .details
+                .contains("extra_secret_patterns[1] 'broken_token'")
+        );
+        assert!(check.details.contains("BROKEN_[A-Z"));
+        let remediation = check.remediation.unwrap();
+        assert!(remediation.contains("[security] extra_secret_patterns"));
+        assert!(!remediation.contains("secret_patterns_file"));
+
+        // The check is part of the JSON report, exactly once
+        let output = doctor.run_with_options().unwrap();
+        let reported: Vec<_> = output
+            .checks
+            .iter()
+            .filter(|c| c.name.contains("patterns"))
+            .collect();
+        assert_eq!(reported.len(), 1);
+        assert_eq!(reported[0].name, "secret_patterns");
+        assert_eq!(reported[0].status, CheckStatus::Fail);
+    }
+
+    #[test]
+    fn test_secret_patterns_check_names_patterns_file() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("secret-patterns.txt");
+        std::fs::write(&path, "broken_token BROKEN_[A-Z\n").unwrap();
+
+        let mut config = Config::discover(&CliArgs::default()).unwrap();
+        config.security.extra_secret_patterns = vec!["CORP_[0-9]{8}".into()];
+        config.security.secret_patterns_file = Some(path.clone());
+        let doctor = DoctorCommand::new(config);
+
+        let check = doctor.check_secret_patterns();
+        assert_eq!(check.status, CheckStatus::Fail);
+        let file_source = format!("secret_patterns_file {}", path.display());
+        assert!(check.details.contains(&file_source));
+        assert!(check.details.contains("broken_token"));
+        assert!(!check.details.contains("[security] extra_secret_patterns:"));
+        let remediation = check.remediation.unwrap();
+        assert!(remediation.contains(&file_source));
+        assert!(!remediation.contains("extra_secret_patterns"));
+
+        // A valid file is named in the pass details
+        std::fs::write(&path, "internal_token INT_[A-Z0-9]{16}\n").unwrap();
+        let check = doctor.check_secret_patterns();
+        assert_eq!(check.status, CheckStatus::Pass);
+        assert!(check.details.contains(&file_source));
+    }
+
+    #[test]
+    fn test_secret_patterns_check_warns_when_all_defaults_ignored() {
+        let mut config = Config::discover(&CliArgs::default()).unwrap();
+        config.security.ignore_secret_patterns = SecretRedactor::categories()
+            .into_iter()
+            .flat_map(|category| {
+                default_pattern_defs()
+                    .iter()
+                    .filter(move |def| def.category == category)
+                    .map(|def| def.id.to_string())
+            })
+            .collect();
+        let doctor = DoctorCommand::new(config);
+
+        let check = doctor.check_secret_patterns();
+        assert_eq!(check.status, CheckStatus::Warn);
+        assert!(check.details.contains("effectively disabled"));
+    }
+
+    #[test]
+    fn test_model_name_check_passes_for_known_model() {
+        let mut config = Config::discover(&CliArgs::default()).unwrap();
+        config.llm.provider = Some("claude-cli".to_string());
+        config.defaults.model = Some("claude-sonnet-4-5-20250929".to_string());
+        let doctor = DoctorCommand::new(config);
+
+        let check = doctor.check_model_name();
+        assert_eq!(check.name, "model_name");
+        assert_eq!(check.status, CheckStatus::Pass);
+    }
+
+    #[test]
+    fn test_model_name_check_warns_for_unknown_model() {
+        let mut config = Config::discover(&CliArgs::default()).unwrap();
+        config.llm.provider = Some("claude-cli".to_string());
+        config.defaults.model = Some("sonet".to_string());
+        let doctor = DoctorCommand::new(config);
+
+        let check = doctor.check_model_name();
+        assert_eq!(check.status, CheckStatus::Warn);
+        assert!(check.details.contains("sonet"));
+    }
+
+    #[test]
+    fn test_model_name_check_fails_on_shell_metacharacters() {
+        let mut config = Config::discover(&CliArgs::default()).unwrap();
+        config.llm.provider = Some("claude-cli".to_string());
+        config.defaults.model = Some("sonnet; rm -rf ~".to_string());
+        let mut doctor = DoctorCommand::new(config);
+
+        let check = doctor.check_model_name();
+        assert_eq!(check.status, CheckStatus::Fail);
+        assert!(check.remediation.is_some());
+
+        let output = doctor.run_with_options().unwrap();
+        assert!(
+            output
+                .checks
+                .iter()
+                .any(|c| c.name == "model_name" && c.status == CheckStatus::Fail)
+        );
+    }
+
+    #[test]
+    fn test_model_name_check_uses_provider_prefixes() {
+        for (provider, model, status) in [
+            ("anthropic", "claude-opus-4-1", CheckStatus::Pass),
+            ("anthropic", "sonnet", CheckStatus::Warn),
+            (
+                "openrouter",
+                "anthropic/claude-3.5-sonnet",
+                CheckStatus::Pass,
+            ),
+            (
+                "openrouter",
+                "meta-llama/llama-3-8b:free",
+                CheckStatus::Pass,
+            ),
+            ("openrouter", "gpt-4o", CheckStatus::Warn),
+            ("gemini-cli", "gemini-2.0-flash", CheckStatus::Pass),
+        ] {
+            let check = DoctorCommand::classify_model_name(provider, "key", model);
+            assert_eq!(check.status, status, "{provider} {model}");
+        }
+    }
+
     #[test]
     fn test_wsl_output_normalization_utf8() {
         let utf8_bytes = b"Ubuntu\n";
-        let result = DoctorCommand::normalize_wsl_output(utf8_bytes);
+        let result = decode_console_output(utf8_bytes);
         assert_eq!(result, "Ubuntu\n");
     }
 
@@ -1041,7 +1926,7 @@ mod tests {
             0x74, 0x00, // t
             0x75, 0x00, // u
         ];
-        let result = DoctorCommand::normalize_wsl_output(&utf16le_bytes);
+        let result = decode_console_output(&utf16le_bytes);
         assert_eq!(result, "Ubuntu");
     }
 
@@ -1091,6 +1976,53 @@ mod tests {
         assert!(check.details.contains("does not exist"));
     }
 
+    #[test]
+    fn test_http_provider_skips_cli_and_runner_checks() {
+        let cli_args = CliArgs::default();
+        let mut config = Config::discover(&cli_args).unwrap();
+        config.llm.provider = Some("anthropic".to_string());
+        let mut doctor = DoctorCommand::new(config);
+
+        let output = doctor.run_with_options().unwrap();
+        let names: Vec<&str> = output.checks.iter().map(|c| c.name.as_str()).collect();
+
+        for irrelevant in [
+            "claude_path",
+            "claude_version",
+            "gemini_path",
+            "gemini_help",
+            "runner_selection",
+            "wsl_availability",
+            "wsl_default_distro",
+            "wsl_distros",
+        ] {
+            assert!(
+                !names.contains(&irrelevant),
+                "{irrelevant} should not run for an HTTP provider: {names:?}"
+            );
+        }
+        for relevant in [
+            "atomic_rename",
+            "config_parse",
+            "llm_provider",
+            "write_permissions",
+        ] {
+            assert!(names.contains(&relevant), "{relevant} missing: {names:?}");
+        }
+    }
+
+    #[test]
+    fn test_cli_provider_keeps_runner_checks() {
+        let cli_args = CliArgs::default();
+        let mut config = Config::discover(&cli_args).unwrap();
+        config.llm.provider = Some("claude-cli".to_string());
+        let mut doctor = DoctorCommand::new(config);
+
+        let output = doctor.run_with_options().unwrap();
+        assert!(output.checks.iter().any(|c| c.name == "runner_selection"));
+        assert!(output.checks.iter().any(|c| c.name == "claude_path"));
+    }
+
     #[test]
     fn test_llm_provider_included_in_doctor_output() {
         let cli_args = CliArgs::default();
@@ -1116,11 +2048,13 @@ mod tests {
                 name: "zebra".to_string(),
                 status: CheckStatus::Pass,
                 details: "test".to_string(),
+                remediation: None,
             },
             DoctorCheck {
                 name: "alpha".to_string(),
                 status: CheckStatus::Pass,
                 details: "test".to_string(),
+                remediation: None,
             },
         ];
 
@@ -1129,11 +2063,13 @@ mod tests {
                 name: "alpha".to_string(),
                 status: CheckStatus::Pass,
                 details: "test".to_string(),
+                remediation: None,
             },
             DoctorCheck {
                 name: "zebra".to_string(),
                 status: CheckStatus::Pass,
                 details: "test".to_string(),
+                remediation: None,
             },
         ];
 
@@ -3,6 +3,7 @@
 //! Provides functions for detecting WSL availability, listing distributions,
 //! and validating Claude CLI availability within WSL environments.
 
+use xchecker_utils::encoding::decode_console_output;
 use xchecker_utils::error::RunnerError;
 use xchecker_utils::runner::CommandSpec;
 
@@ -76,7 +77,7 @@ pub fn is_wsl_available() -> Result<bool, RunnerError> {
 /// ```
 pub fn parse_distro_list(raw: &[u8]) -> Result<Vec<String>, RunnerError> {
     // Normalize the output (may be UTF-16LE on some Windows locales)
-    let text = normalize_wsl_output(raw);
+    let text = decode_console_output(raw);
 
     // Parse lines and filter out empty lines
     let distros: Vec<String> = text
@@ -165,45 +166,6 @@ pub fn validate_claude_in_wsl(distro: Option<&str>) -> Result<bool, RunnerError>
     }
 }
 
-/// Normalize WSL output which may be UTF-16LE on some Windows locales
-///
-/// This function detects if the input is UTF-16LE encoded and converts it to UTF-8.
-/// If the input is already UTF-8, it returns it as-is.
-///
-/// # Arguments
-/// * `raw` - Raw bytes from WSL command output
-///
-/// # Returns
-/// * `String` - Normalized UTF-8 string
-fn normalize_wsl_output(raw: &[u8]) -> String {
-    // Check if this looks like UTF-16LE (every other byte is 0x00 for ASCII)
-    let looks_like_utf16le = raw.len() >= 4
-        && raw.len().is_multiple_of(2)
-        && raw
-            .iter()
-            .skip(1)
-            .step_by(2)
-            .take(10)
-            .filter(|&&b| b == 0x00)
-            .count()
-            >= 5;
-
-    if looks_like_utf16le {
-        // Decode as UTF-16LE
-        let mut u16_vec: Vec<u16> = Vec::new();
-        let mut i = 0;
-        while i + 1 < raw.len() {
-            let u = u16::from_le_bytes([raw[i], raw[i + 1]]);
-            u16_vec.push(u);
-            i += 2;
-        }
-        return String::from_utf16_lossy(&u16_vec);
-    }
-
-    // Try UTF-8
-    String::from_utf8_lossy(raw).to_string()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -261,7 +223,7 @@ mod tests {
     #[test]
     fn test_normalize_wsl_output_utf8() {
         let input = b"Ubuntu-22.04\nDebian\n";
-        let output = normalize_wsl_output(input);
+        let output = decode_console_output(input);
         assert_eq!(output, "Ubuntu-22.04\nDebian\n");
     }
 
@@ -276,7 +238,7 @@ mod tests {
             0x74, 0x00, // t
             0x75, 0x00, // u
         ];
-        let output = normalize_wsl_output(&input);
+        let output = decode_console_output(&input);
         assert_eq!(output, "Ubuntu");
     }
 
@@ -298,7 +260,7 @@ mod tests {
             0x64, 0x00, // d
             0x0A, 0x00, // \n
         ];
-        let output = normalize_wsl_output(&input);
+        let output = decode_console_output(&input);
         assert_eq!(output, "Hello World\n");
     }
 
@@ -306,7 +268,7 @@ mod tests {
     fn test_normalize_wsl_output_short_input() {
         // Input too short to be UTF-16LE
         let input = b"Hi";
-        let output = normalize_wsl_output(input);
+        let output = decode_console_output(input);
         assert_eq!(output, "Hi");
     }
 
@@ -314,7 +276,7 @@ mod tests {
     fn test_normalize_wsl_output_odd_length() {
         // Odd length input cannot be UTF-16LE
         let input = b"Hello";
-        let output = normalize_wsl_output(input);
+        let output = decode_console_output(input);
         assert_eq!(output, "Hello");
     }
 
@@ -51,6 +51,7 @@ pub fn make_example_receipt_minimal() -> Receipt {
             files: vec![],
             max_bytes: 100000,
             max_lines: 5000,
+            preview_compressed: false,
         },
         outputs: vec![],
         exit_code: 0,
@@ -63,6 +64,12 @@ pub fn make_example_receipt_minimal() -> Receipt {
         diff_context: None,
         llm: None,
         pipeline: None,
+        validation_degraded: None,
+        validation_errors: Vec::new(),
+        input_fingerprint: None,
+        packet_hash: None,
+        duration_ms: None,
+        timings: None,
     }
 }
 
@@ -96,6 +103,7 @@ pub fn make_example_receipt_full() -> Receipt {
             blake3_pre_redaction:
                 "1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef".to_string(),
             priority: Priority::High,
+            redaction_exempt: false,
         },
         FileEvidence {
             path: "README.md".to_string(),
@@ -103,6 +111,7 @@ pub fn make_example_receipt_full() -> Receipt {
             blake3_pre_redaction:
                 "abcdef1234567890abcdef1234567890abcdef1234567890abcdef1234567890".to_string(),
             priority: Priority::Medium,
+            redaction_exempt: false,
         },
     ];
     // Sort by path for deterministic output
@@ -126,6 +135,7 @@ pub fn make_example_receipt_full() -> Receipt {
             files: packet_files,
             max_bytes: 100000,
             max_lines: 5000,
+            preview_compressed: false,
         },
         outputs,
         exit_code: 0,
@@ -150,6 +160,12 @@ pub fn make_example_receipt_full() -> Receipt {
         pipeline: Some(PipelineInfo {
             execution_strategy: Some("controlled".to_string()),
         }),
+        validation_degraded: None,
+        validation_errors: Vec::new(),
+        input_fingerprint: None,
+        packet_hash: None,
+        duration_ms: None,
+        timings: None,
     }
 }
 
@@ -249,11 +265,13 @@ pub fn make_example_doctor_minimal() -> DoctorOutput {
             name: "claude_path".to_string(),
             status: CheckStatus::Pass,
             details: "Found claude at /usr/local/bin/claude".to_string(),
+            remediation: None,
         },
         DoctorCheck {
             name: "config_parse".to_string(),
             status: CheckStatus::Pass,
             details: "Configuration parsed and validated successfully".to_string(),
+            remediation: None,
         },
     ];
     // Sort by name for deterministic output
@@ -276,41 +294,49 @@ pub fn make_example_doctor_full() -> DoctorOutput {
             name: "claude_path".to_string(),
             status: CheckStatus::Pass,
             details: "Found claude at /usr/local/bin/claude".to_string(),
+            remediation: None,
         },
         DoctorCheck {
             name: "claude_version".to_string(),
             status: CheckStatus::Pass,
             details: "0.8.1".to_string(),
+            remediation: None,
         },
         DoctorCheck {
             name: "runner_selection".to_string(),
             status: CheckStatus::Pass,
             details: "Runner mode: native (spawn claude directly)".to_string(),
+            remediation: None,
         },
         DoctorCheck {
             name: "wsl_availability".to_string(),
             status: CheckStatus::Warn,
             details: "WSL not installed or not available".to_string(),
+            remediation: None,
         },
         DoctorCheck {
             name: "wsl_default_distro".to_string(),
             status: CheckStatus::Pass,
             details: "Default WSL distro: Ubuntu-22.04".to_string(),
+            remediation: None,
         },
         DoctorCheck {
             name: "write_permissions".to_string(),
             status: CheckStatus::Pass,
             details: ".xchecker directory is writable".to_string(),
+            remediation: None,
         },
         DoctorCheck {
             name: "atomic_rename".to_string(),
             status: CheckStatus::Pass,
             details: "Atomic rename works on same volume".to_string(),
+            remediation: None,
         },
         DoctorCheck {
             name: "config_parse".to_string(),
             status: CheckStatus::Pass,
             details: "Configuration parsed and validated successfully".to_string(),
+            remediation: None,
         },
     ];
     // Sort by name for deterministic output
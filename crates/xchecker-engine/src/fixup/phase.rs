@@ -5,7 +5,7 @@
 //! from review output and applies them in preview or apply mode.
 
 use crate::phase::{NextStep, Phase, PhaseContext, PhaseMetadata, PhaseResult};
-use crate::status::artifact::{Artifact, ArtifactType};
+use crate::status::artifact::{Artifact, ArtifactBody, ArtifactType};
 use anyhow::Result;
 
 use super::FixupMode;
@@ -67,6 +67,7 @@ impl Phase for FixupPhase {
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            preview_compressed: false,
         };
 
         let budget_used = crate::packet::BudgetUsage::new(65536, 1200);
@@ -86,15 +87,32 @@ impl Phase for FixupPhase {
         match parser.parse_diffs(raw) {
             Ok(diffs) => {
                 // Create fixup.md artifact with parsed diffs
-                let fixup_content = format!(
+                let mut fixup_content = format!(
                     "# Fixup Report\n\nMode: {:?}\n\nParsed {} diff(s) from review output.\n",
                     self.mode,
                     diffs.len()
                 );
 
+                // Apply mode edits the targets in spec_dir; the orchestrator
+                // re-records upstream hashes once the phase succeeds
+                if self.mode == FixupMode::Apply {
+                    let applied = parser.apply_changes(&diffs)?;
+                    fixup_content.push_str(&format!(
+                        "\nApplied {} file(s), {} failed.\n",
+                        applied.applied_files.len(),
+                        applied.failed_files.len()
+                    ));
+                    for file in &applied.applied_files {
+                        fixup_content.push_str(&format!("- {}\n", file.path));
+                    }
+                    for warning in &applied.warnings {
+                        fixup_content.push_str(&format!("\nWarning: {warning}\n"));
+                    }
+                }
+
                 let fixup_artifact = Artifact {
                     name: "40-fixup.md".to_string(),
-                    content: fixup_content.clone(),
+                    content: ArtifactBody::Text(fixup_content.clone()),
                     artifact_type: ArtifactType::Markdown,
                     blake3_hash: blake3::hash(fixup_content.as_bytes()).to_hex().to_string(),
                 };
@@ -37,6 +37,7 @@ pub mod claude;
 pub mod example_generators;
 pub mod fixup;
 pub mod integration_tests;
+pub mod metrics;
 pub mod orchestrator;
 
 // Re-export orchestrator types for backward compatibility
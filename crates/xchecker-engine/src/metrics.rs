@@ -0,0 +1,72 @@
+//! Operational metrics for long-running embeds
+//!
+//! With the `metrics` feature enabled, the orchestrator and lock record the
+//! counters and histograms named below through the [`metrics`](::metrics)
+//! facade. No exporter is bundled: install any recorder (Prometheus, statsd,
+//! ...) in the embedding process to collect them. Without the feature every
+//! recording point compiles to nothing.
+//!
+//! | Name | Kind | Labels |
+//! |------|------|--------|
+//! | `phases_run_total` | counter | `phase`, `success` |
+//! | `phase_duration_seconds` | histogram | `phase` |
+//! | `llm_calls_total` | counter | `phase`, `dry_run` |
+//! | `packet_bytes` | histogram | `phase` |
+//! | `lock_wait_seconds` | histogram | |
+
+use std::time::Duration;
+
+use crate::types::PhaseId;
+
+pub use crate::lock::LOCK_WAIT_SECONDS;
+
+/// Counter of executed phases
+pub const PHASES_RUN_TOTAL: &str = "phases_run_total";
+
+/// Histogram of phase wall-clock time in seconds
+pub const PHASE_DURATION_SECONDS: &str = "phase_duration_seconds";
+
+/// Counter of LLM invocations, including simulated dry-run calls
+pub const LLM_CALLS_TOTAL: &str = "llm_calls_total";
+
+/// Histogram of packet body sizes in bytes
+pub const PACKET_BYTES: &str = "packet_bytes";
+
+/// Record one finished phase execution
+pub(crate) fn record_phase_run(phase: PhaseId, success: bool, duration: Duration) {
+    #[cfg(feature = "metrics")]
+    {
+        ::metrics::counter!(
+            PHASES_RUN_TOTAL,
+            "phase" => phase.as_str(),
+            "success" => if success { "true" } else { "false" },
+        )
+        .increment(1);
+        ::metrics::histogram!(PHASE_DURATION_SECONDS, "phase" => phase.as_str())
+            .record(duration.as_secs_f64());
+    }
+    #[cfg(not(feature = "metrics"))]
+    let _ = (phase, success, duration);
+}
+
+/// Record one LLM invocation
+pub(crate) fn record_llm_call(phase: PhaseId, dry_run: bool) {
+    #[cfg(feature = "metrics")]
+    ::metrics::counter!(
+        LLM_CALLS_TOTAL,
+        "phase" => phase.as_str(),
+        "dry_run" => if dry_run { "true" } else { "false" },
+    )
+    .increment(1);
+    #[cfg(not(feature = "metrics"))]
+    let _ = (phase, dry_run);
+}
+
+/// Record the size of a built packet
+pub(crate) fn record_packet_bytes(phase: PhaseId, bytes: usize) {
+    #[cfg(feature = "metrics")]
+    #[allow(clippy::cast_precision_loss)] // Packet sizes are far below 2^52
+    ::metrics::histogram!(PACKET_BYTES, "phase" => phase.as_str()).record(bytes as f64);
+    #[cfg(not(feature = "metrics"))]
+    let _ = (phase, bytes);
+}
@@ -0,0 +1,179 @@
+//! Append-only JSON Lines audit trail of phase transitions
+//!
+//! With an audit log configured on the [`OrchestratorHandle`](super::OrchestratorHandle),
+//! every phase that runs appends one canonical (JCS) JSON object to the
+//! log, whether it finished with a receipt or aborted with an error. The file is only ever opened for appending, so the
+//! trail accumulates across handles, resumes and runs.
+
+use std::fs::OpenOptions;
+use std::io::Write;
+
+use anyhow::{Context, Result};
+use camino::{Utf8Path, Utf8PathBuf};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::canonicalization::emit_jcs;
+use crate::error::XCheckerError;
+use crate::exit_codes;
+use crate::types::{ErrorKind, FileHash, PhaseId};
+
+use super::ExecutionResult;
+
+/// Schema version stamped on every audit entry
+pub const AUDIT_SCHEMA_VERSION: &str = "1";
+
+/// One phase transition, as written to the audit log
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditEntry {
+    pub schema_version: String,
+    pub spec_id: String,
+    /// Phase receipt name (e.g. `"requirements"`)
+    pub phase: String,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: DateTime<Utc>,
+    pub success: bool,
+    pub exit_code: i32,
+    /// Classified failure, from the phase receipt or the aborting error
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_kind: Option<ErrorKind>,
+    /// BLAKE3 of the packet sent to the LLM, from the phase receipt
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub packet_hash: Option<String>,
+    /// BLAKE3 hashes of the artifacts the phase wrote, from the phase receipt
+    #[serde(default)]
+    pub artifacts: Vec<FileHash>,
+}
+
+impl AuditEntry {
+    /// Build the entry for a phase of `spec_id` that started at `started_at`
+    pub(crate) fn from_result(
+        spec_id: &str,
+        phase: PhaseId,
+        started_at: DateTime<Utc>,
+        result: &ExecutionResult,
+    ) -> Self {
+        let receipt = result.receipt.as_ref();
+        Self {
+            schema_version: AUDIT_SCHEMA_VERSION.to_string(),
+            spec_id: spec_id.to_string(),
+            phase: phase.as_str().to_string(),
+            started_at,
+            finished_at: Utc::now(),
+            success: result.success,
+            exit_code: result.exit_code,
+            error_kind: receipt.and_then(|r| r.error_kind.clone()),
+            packet_hash: receipt.and_then(|r| r.packet_hash.clone()),
+            artifacts: receipt.map(|r| r.outputs.clone()).unwrap_or_default(),
+        }
+    }
+
+    /// Build the failure entry for a phase that aborted with `error`
+    pub(crate) fn from_error(
+        spec_id: &str,
+        phase: PhaseId,
+        started_at: DateTime<Utc>,
+        error: &anyhow::Error,
+    ) -> Self {
+        let (exit_code, error_kind) = error.downcast_ref::<XCheckerError>().map_or(
+            (1, ErrorKind::Unknown),
+            exit_codes::error_to_exit_code_and_kind,
+        );
+        Self {
+            schema_version: AUDIT_SCHEMA_VERSION.to_string(),
+            spec_id: spec_id.to_string(),
+            phase: phase.as_str().to_string(),
+            started_at,
+            finished_at: Utc::now(),
+            success: false,
+            exit_code,
+            error_kind: Some(error_kind),
+            packet_hash: None,
+            artifacts: Vec::new(),
+        }
+    }
+}
+
+/// Appends [`AuditEntry`] lines to a `.jsonl` file
+#[derive(Debug, Clone)]
+pub(crate) struct AuditLog {
+    path: Utf8PathBuf,
+}
+
+impl AuditLog {
+    pub(crate) const fn new(path: Utf8PathBuf) -> Self {
+        Self { path }
+    }
+
+    pub(crate) fn path(&self) -> &Utf8Path {
+        &self.path
+    }
+
+    /// Append `entry` as a single line and sync it to disk.
+    ///
+    /// The line goes out in one `O_APPEND` write, so concurrent appenders
+    /// never interleave within a line and existing entries are never
+    /// rewritten.
+    pub(crate) fn append(&self, entry: &AuditEntry) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            crate::paths::ensure_dir_all(parent)
+                .with_context(|| format!("Failed to create audit log directory: {parent}"))?;
+        }
+
+        let mut line = emit_jcs(entry).context("Failed to serialize audit entry")?;
+        line.push('\n');
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .with_context(|| format!("Failed to open audit log: {}", self.path))?;
+        file.write_all(line.as_bytes())
+            .and_then(|()| file.sync_data())
+            .with_context(|| format!("Failed to append to audit log: {}", self.path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(phase: &str) -> AuditEntry {
+        AuditEntry {
+            schema_version: AUDIT_SCHEMA_VERSION.to_string(),
+            spec_id: "spec".to_string(),
+            phase: phase.to_string(),
+            started_at: Utc::now(),
+            finished_at: Utc::now(),
+            success: true,
+            exit_code: 0,
+            error_kind: None,
+            packet_hash: Some("abc".to_string()),
+            artifacts: vec![FileHash {
+                path: "artifacts/00-requirements.md".to_string(),
+                blake3_canonicalized: "def".to_string(),
+            }],
+        }
+    }
+
+    #[test]
+    fn test_append_keeps_earlier_entries() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = Utf8PathBuf::from_path_buf(temp.path().join("logs/audit.jsonl")).unwrap();
+
+        AuditLog::new(path.clone())
+            .append(&entry("requirements"))
+            .unwrap();
+        // A second log on the same file, as after a resume, appends
+        AuditLog::new(path.clone())
+            .append(&entry("design"))
+            .unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let phases: Vec<String> = content
+            .lines()
+            .map(|line| serde_json::from_str::<AuditEntry>(line).unwrap().phase)
+            .collect();
+        assert_eq!(phases, ["requirements", "design"]);
+    }
+}
@@ -0,0 +1,148 @@
+//! NDJSON lifecycle events for streaming consumers
+//!
+//! An [`EventSink`] attached to an [`OrchestratorHandle`](super::OrchestratorHandle)
+//! receives one canonical (JCS) JSON object per line as execution progresses.
+//! Events complement the final receipt; they are not a replacement for it.
+
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::canonicalization::emit_jcs;
+use crate::redaction::SecretRedactor;
+
+/// Schema version stamped on every event line
+pub const EVENT_SCHEMA_VERSION: &str = "1";
+
+/// Lifecycle event payloads, tagged by `event`
+///
+/// Phases are recorded by their receipt name (e.g. `"requirements"`).
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub(crate) enum RunEventKind {
+    PhaseStarted {
+        phase: &'static str,
+    },
+    PacketBuilt {
+        phase: &'static str,
+        packet_hash: String,
+        bytes_used: usize,
+        lines_used: usize,
+    },
+    LlmCalled {
+        phase: &'static str,
+        exit_code: i32,
+        dry_run: bool,
+    },
+    PhaseCompleted {
+        phase: &'static str,
+        success: bool,
+        exit_code: i32,
+    },
+    RunFinished {
+        success: bool,
+        exit_code: i32,
+    },
+}
+
+#[derive(Serialize)]
+struct RunEvent<'a> {
+    schema_version: &'static str,
+    emitted_at: DateTime<Utc>,
+    spec_id: &'a str,
+    #[serde(flatten)]
+    kind: RunEventKind,
+}
+
+/// Writes lifecycle events as NDJSON to a caller-supplied writer
+///
+/// Each line is redacted before it is written. Write failures are logged and
+/// otherwise ignored so a broken consumer can never fail a run.
+pub struct EventSink {
+    writer: Mutex<Box<dyn Write + Send>>,
+    redactor: Arc<SecretRedactor>,
+}
+
+impl std::fmt::Debug for EventSink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventSink").finish_non_exhaustive()
+    }
+}
+
+impl EventSink {
+    pub(crate) fn new(writer: impl Write + Send + 'static, redactor: Arc<SecretRedactor>) -> Self {
+        Self {
+            writer: Mutex::new(Box::new(writer)),
+            redactor,
+        }
+    }
+
+    pub(crate) fn emit(&self, spec_id: &str, kind: RunEventKind) {
+        let event = RunEvent {
+            schema_version: EVENT_SCHEMA_VERSION,
+            emitted_at: Utc::now(),
+            spec_id,
+            kind,
+        };
+
+        let line = match emit_jcs(&event) {
+            Ok(json) => self.redactor.redact_string(&json),
+            Err(e) => {
+                tracing::warn!("Failed to serialize lifecycle event: {e}");
+                return;
+            }
+        };
+
+        let mut writer = self
+            .writer
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        if let Err(e) = writeln!(writer, "{line}").and_then(|()| writer.flush()) {
+            tracing::warn!("Failed to write lifecycle event: {e}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Default)]
+    struct SharedBuf(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuf {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_event_line_is_canonical_and_redacted() {
+        let buf = SharedBuf::default();
+        let sink = EventSink::new(buf.clone(), Arc::new(SecretRedactor::default()));
+        let token = format!("ghp_{}", "a".repeat(36));
+
+        sink.emit(
+            &format!("spec-{token}"),
+            RunEventKind::PhaseStarted {
+                phase: "requirements",
+            },
+        );
+
+        let output = String::from_utf8(buf.0.lock().unwrap().clone()).unwrap();
+        assert!(output.ends_with('\n'));
+        assert!(!output.contains(&token));
+
+        let value: serde_json::Value = serde_json::from_str(output.trim_end()).unwrap();
+        assert_eq!(value["event"], "phase_started");
+        assert_eq!(value["schema_version"], EVENT_SCHEMA_VERSION);
+        assert_eq!(value["phase"], "requirements");
+        assert!(value["emitted_at"].is_string());
+    }
+}
@@ -0,0 +1,96 @@
+//! Canonical fingerprint of a phase's inputs
+//!
+//! The fingerprint is a BLAKE3 hash over the JCS (RFC 8785) canonical JSON
+//! of everything that shapes a phase's LLM call, so two runs with identical
+//! inputs share a fingerprint regardless of when or where they ran.
+
+use std::collections::BTreeMap;
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+
+use crate::canonicalization::emit_jcs;
+use crate::packet::ContentSelector;
+use crate::phases::packet_limits_from_config;
+use crate::types::PhaseId;
+
+use super::llm::{resolve_model, resolve_prompt_template};
+use super::{OrchestratorConfig, PhaseOrchestrator, PhaseTimeout};
+
+/// Inputs covered by the fingerprint
+///
+/// Prompt templates ship with xchecker, so `xchecker_version` together with
+/// `prompt_template` identifies the template text.
+#[derive(Serialize)]
+struct PhaseInputs<'a> {
+    phase: &'a str,
+    problem_statement: Option<&'a str>,
+    /// BLAKE3 of each selected context file, keyed by path relative to the spec
+    context_files: BTreeMap<String, String>,
+    model: String,
+    xchecker_version: &'a str,
+    prompt_template: &'a str,
+    /// BLAKE3 of the configured `[phases.<phase>].prompt_file`, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    prompt_file: Option<String>,
+    packet_max_bytes: usize,
+    packet_max_lines: usize,
+    phase_timeout_secs: u64,
+}
+
+impl PhaseOrchestrator {
+    /// BLAKE3 over the canonical JSON of `phase_id`'s inputs under `config`.
+    ///
+    /// Context files are those the configured selectors pick up in the spec
+    /// directory right now, so the fingerprint changes when any of them do.
+    pub(crate) fn input_fingerprint(
+        &self,
+        phase_id: PhaseId,
+        config: &OrchestratorConfig,
+    ) -> Result<String> {
+        let base_path = self.artifact_manager().base_path();
+        let selector = ContentSelector::from_selectors(config.selectors.as_ref())?;
+
+        let mut context_files = BTreeMap::new();
+        for candidate in selector.select_candidates(base_path)? {
+            let bytes = std::fs::read(&candidate.path)
+                .with_context(|| format!("Failed to read context file: {}", candidate.path))?;
+            let path = candidate
+                .path
+                .strip_prefix(base_path)
+                .unwrap_or(&candidate.path)
+                .as_str()
+                .replace('\\', "/");
+            context_files.insert(path, blake3::hash(&bytes).to_hex().to_string());
+        }
+
+        let cfg = self.config_from_orchestrator_config(config);
+        let prompt_file = cfg
+            .prompt_file_for_phase(phase_id)
+            .map(|path| {
+                std::fs::read(path)
+                    .map(|bytes| blake3::hash(&bytes).to_hex().to_string())
+                    .with_context(|| format!("Failed to read prompt file: {path}"))
+            })
+            .transpose()?;
+        let phase_context = self.create_phase_context(phase_id, config)?;
+        let (packet_max_bytes, packet_max_lines) =
+            packet_limits_from_config(&phase_context, phase_id);
+
+        let inputs = PhaseInputs {
+            phase: phase_id.as_str(),
+            problem_statement: config.config.get("problem_statement").map(String::as_str),
+            context_files,
+            model: resolve_model(&cfg, phase_id),
+            xchecker_version: env!("CARGO_PKG_VERSION"),
+            prompt_template: resolve_prompt_template(&cfg).as_str(),
+            prompt_file,
+            packet_max_bytes,
+            packet_max_lines,
+            phase_timeout_secs: PhaseTimeout::from_config(config).duration.as_secs(),
+        };
+
+        let canonical = emit_jcs(&inputs)?;
+        Ok(blake3::hash(canonical.as_bytes()).to_hex().to_string())
+    }
+}
@@ -21,18 +21,82 @@
 //! }
 //! ```
 
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::path::PathBuf;
+use std::time::Instant;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
+use camino::{Utf8Path, Utf8PathBuf};
+use tokio_util::sync::CancellationToken;
 
 use crate::config::{CliArgs, Config};
 use crate::error::{ConfigError, XCheckerError};
-use crate::receipt::ReceiptManager;
+use crate::lock::{LockError, LockPolicy, RunSlot, XCheckerLock};
+use crate::packet::ContentSelector;
+use crate::phase::ProblemTransform;
+use crate::receipt::{ReceiptDiff, ReceiptManager, ReceiptSummary};
+use crate::runner::ClaudeRunner;
 use crate::spec_id::sanitize_spec_id;
-use crate::status::artifact::ArtifactManager;
-use crate::types::{PhaseId, StatusOutput};
+use crate::status::artifact::{ArtifactManager, ArtifactType};
+use crate::types::{PhaseId, Receipt, StatusOutput};
 
-use super::{ExecutionResult, OrchestratorConfig, PhaseOrchestrator};
+use super::events::{EventSink, RunEventKind};
+use super::{ExecutionResult, OrchestratorConfig, PhaseOrchestrator, PhaseTimeout};
+
+/// Phases executed by [`OrchestratorHandle::run_all`]: Requirements -> Design -> Tasks
+/// (Review, Fixup, Final are optional/advanced phases)
+const RUN_ALL_PHASES: [PhaseId; 3] = [PhaseId::Requirements, PhaseId::Design, PhaseId::Tasks];
+
+/// Take the in-memory receipt out of a phase result
+fn collect_receipt(result: ExecutionResult) -> Result<Receipt> {
+    result.receipt.ok_or_else(|| {
+        anyhow::anyhow!(
+            "Phase {} finished without writing a receipt",
+            result.phase.as_str()
+        )
+    })
+}
+
+/// Phases whose packet evidence comes from the content selectors
+const CONTEXT_PACKET_PHASES: [PhaseId; 3] =
+    [PhaseId::Requirements, PhaseId::Design, PhaseId::Tasks];
+
+/// Latest successful receipt of a phase that packed the selected context files
+fn latest_context_receipt(manager: &ReceiptManager) -> Result<Option<Receipt>> {
+    Ok(manager.list_receipts()?.into_iter().rev().find(|receipt| {
+        receipt.exit_code == 0
+            && CONTEXT_PACKET_PHASES
+                .iter()
+                .any(|phase| phase.as_str() == receipt.phase)
+    }))
+}
+
+/// Drop `.` components so `./a/b` and `a/b` compare equal
+fn without_cur_dir(path: &Utf8Path) -> Utf8PathBuf {
+    path.components()
+        .filter(|component| !matches!(component, camino::Utf8Component::CurDir))
+        .collect()
+}
+
+/// Normalize a context file path for comparison: `.` components dropped
+/// and made relative to `spec_root` when under it
+fn context_path_key(path: &Utf8Path, spec_root: &Utf8Path) -> Utf8PathBuf {
+    let path = without_cur_dir(path);
+    match path.strip_prefix(without_cur_dir(spec_root)) {
+        Ok(relative) => relative.to_owned(),
+        Err(_) => path,
+    }
+}
+
+/// Rewrite a receipt's packet file paths relative to `spec_root`
+///
+/// Paths outside the spec root are left as recorded.
+fn relative_packet_paths(mut receipt: Receipt, spec_root: &Utf8Path) -> Receipt {
+    for file in &mut receipt.packet.files {
+        file.path = context_path_key(Utf8Path::new(&file.path), spec_root).to_string();
+    }
+    receipt
+}
 
 /// The primary public API for embedding xchecker.
 ///
@@ -151,6 +215,45 @@ impl OrchestratorHandle {
         Self::from_config_internal(spec_id, config, false)
     }
 
+    /// Initialize a spec; safe to call on one that already exists.
+    ///
+    /// Creates any missing standard directories (`artifacts/`, `receipts/`,
+    /// `context/`) and never deletes or rewrites existing context files or
+    /// artifacts. Same as [`init_with_overwrite(spec_id, true)`](Self::init_with_overwrite).
+    ///
+    /// # Errors
+    ///
+    /// Returns error if handle creation fails (see [`new`](Self::new)).
+    pub fn init(spec_id: &str) -> Result<Self, XCheckerError> {
+        Self::init_with_overwrite(spec_id, true)
+    }
+
+    /// Initialize a spec, choosing what happens when it already exists.
+    ///
+    /// With `overwrite = false` an existing spec is rejected with
+    /// [`XCheckerError::SpecExists`] before anything is touched. With
+    /// `overwrite = true` this behaves like [`init`](Self::init): missing
+    /// standard directories are created and existing files are kept.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XCheckerError::SpecExists` as described above, or any error
+    /// from [`new`](Self::new).
+    pub fn init_with_overwrite(spec_id: &str, overwrite: bool) -> Result<Self, XCheckerError> {
+        let sanitized_id = sanitize_spec_id(spec_id).map_err(|e| {
+            XCheckerError::Config(ConfigError::InvalidValue {
+                key: "spec_id".to_string(),
+                value: e.to_string(),
+            })
+        })?;
+
+        if !overwrite && crate::paths::spec_root(&sanitized_id).exists() {
+            return Err(XCheckerError::SpecExists { id: sanitized_id });
+        }
+
+        Self::new(&sanitized_id)
+    }
+
     /// Create a handle using explicit configuration.
     ///
     /// This does NOT probe the global environment or filesystem for config.
@@ -201,16 +304,18 @@ impl OrchestratorHandle {
             },
         )?;
 
-        let orchestrator = if force {
-            PhaseOrchestrator::new_with_force(&sanitized_id, true)
-        } else {
-            PhaseOrchestrator::new(&sanitized_id)
-        }
-        .map_err(|e| {
-            XCheckerError::Config(crate::error::ConfigError::DiscoveryFailed {
-                reason: e.to_string(),
-            })
-        })?;
+        let lock_policy = LockPolicy {
+            ttl_seconds: None,
+            dead_process_grace_secs: config.defaults.dead_process_grace_secs,
+        };
+        let orchestrator =
+            PhaseOrchestrator::new_with_lock_policy(&sanitized_id, force, lock_policy).map_err(
+                |e| {
+                    XCheckerError::Config(crate::error::ConfigError::DiscoveryFailed {
+                        reason: e.to_string(),
+                    })
+                },
+            )?;
 
         // Convert Config to OrchestratorConfig
         let mut orch_config = OrchestratorConfig {
@@ -231,6 +336,52 @@ impl OrchestratorHandle {
                 .config
                 .insert("packet_max_lines".to_string(), packet_max_lines.to_string());
         }
+        orch_config.config.insert(
+            "prior_artifact_form".to_string(),
+            config.packet.prior_artifact_form.to_string(),
+        );
+        orch_config
+            .config
+            .insert("packet_order".to_string(), config.packet.order.to_string());
+        if let Some(preview_max_bytes) = config.packet.preview_max_bytes {
+            orch_config.config.insert(
+                "packet_preview_max_bytes".to_string(),
+                preview_max_bytes.to_string(),
+            );
+        }
+        if config.packet.compress_preview {
+            orch_config
+                .config
+                .insert("packet_compress_preview".to_string(), "true".to_string());
+        }
+        if config.packet.cache {
+            orch_config
+                .config
+                .insert("packet_cache".to_string(), "true".to_string());
+        }
+        if !config.packet.redaction_exempt.is_empty() {
+            orch_config.config.insert(
+                "packet_redaction_exempt".to_string(),
+                config.packet.redaction_exempt.join("\n"),
+            );
+        }
+        for phase in PhaseId::ALL {
+            let Some(budget) = config.packet.budget_for(phase) else {
+                continue;
+            };
+            if let Some(max_bytes) = budget.max_bytes {
+                orch_config.config.insert(
+                    format!("packet.{}.max_bytes", phase.as_str()),
+                    max_bytes.to_string(),
+                );
+            }
+            if let Some(max_lines) = budget.max_lines {
+                orch_config.config.insert(
+                    format!("packet.{}.max_lines", phase.as_str()),
+                    max_lines.to_string(),
+                );
+            }
+        }
         if let Some(max_turns) = config.defaults.max_turns {
             orch_config
                 .config
@@ -280,6 +431,27 @@ impl OrchestratorHandle {
                 .config
                 .insert("allow_links".to_string(), "true".to_string());
         }
+        if !config.normalize_line_endings() {
+            orch_config
+                .config
+                .insert("normalize_line_endings".to_string(), "false".to_string());
+        }
+        if let Some(validation_mode) = config.defaults.validation_mode {
+            orch_config
+                .config
+                .insert("validation_mode".to_string(), validation_mode.to_string());
+        }
+        if let Some(phrases) = &config.defaults.forbidden_opening_phrases {
+            orch_config
+                .config
+                .insert("forbidden_opening_phrases".to_string(), phrases.join("\n"));
+        }
+        if let Some(max_concurrent_specs) = config.defaults.max_concurrent_specs {
+            orch_config.config.insert(
+                "max_concurrent_specs".to_string(),
+                max_concurrent_specs.to_string(),
+            );
+        }
         if let Some(runner_mode) = &config.runner.mode {
             orch_config
                 .config
@@ -295,6 +467,11 @@ impl OrchestratorHandle {
                 .config
                 .insert("claude_path".to_string(), claude_path.clone());
         }
+        if let Some(fixture_dir) = &config.runner.fixture_dir {
+            orch_config
+                .config
+                .insert("fixture_dir".to_string(), fixture_dir.clone());
+        }
         if let Some(provider) = &config.llm.provider {
             orch_config
                 .config
@@ -456,14 +633,84 @@ impl OrchestratorHandle {
     /// # }
     /// ```
     pub async fn run_phase(&mut self, phase: PhaseId) -> Result<ExecutionResult> {
-        self.orchestrator
+        let result = self.run_single_phase(phase).await;
+        self.emit_run_finished(&result);
+        result
+    }
+
+    async fn run_single_phase(&mut self, phase: PhaseId) -> Result<ExecutionResult> {
+        let _slot = self.acquire_run_slot()?;
+        let result = self
+            .orchestrator
             .resume_from_phase(phase, &self.config)
-            .await
+            .await?;
+        self.snapshot_after_phase();
+        Ok(result)
+    }
+
+    /// Execute a single phase and return the receipt it wrote.
+    ///
+    /// Works like [`run_phase`](Self::run_phase); the receipt is still
+    /// persisted, but is handed back directly instead of having to be read
+    /// from [`last_receipt_path`](Self::last_receipt_path). A failed phase
+    /// returns its failure receipt; check `exit_code` to tell them apart.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if transition is invalid or execution fails before a
+    /// receipt is written.
+    pub async fn run_phase_collect(&mut self, phase: PhaseId) -> Result<Receipt> {
+        let result = self.run_phase(phase).await?;
+        collect_receipt(result)
+    }
+
+    /// Execute a single phase, passing LLM response text to `on_token` as it arrives.
+    ///
+    /// Works like [`run_phase`](Self::run_phase). Streaming-capable HTTP
+    /// providers (`anthropic`, `openrouter`) call `on_token` in order with
+    /// streamed text, buffered to whole lines so the configured secret
+    /// redactor also catches secrets split across chunks; any unterminated
+    /// last line is passed when the stream ends. Other providers call it once
+    /// with the full response. Postprocessing still sees the complete
+    /// response. Dry runs make no LLM call and never invoke `on_token`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if transition is invalid or execution fails.
+    pub async fn run_phase_streaming_tokens(
+        &mut self,
+        phase: PhaseId,
+        mut on_token: impl FnMut(&str),
+    ) -> Result<ExecutionResult> {
+        let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel::<String>();
+        self.orchestrator.set_token_sink(Some(sender));
+
+        let result = {
+            let run = self.run_phase(phase);
+            tokio::pin!(run);
+            loop {
+                tokio::select! {
+                    result = &mut run => break result,
+                    Some(chunk) = receiver.recv() => on_token(&chunk),
+                }
+            }
+        };
+
+        // Dropping the sender lets us drain whatever arrived as the run finished
+        self.orchestrator.set_token_sink(None);
+        while let Some(chunk) = receiver.recv().await {
+            on_token(&chunk);
+        }
+        result
     }
 
     /// Execute all phases in sequence.
     ///
-    /// Stops on first failure. Behavior matches the CLI `xchecker spec` command.
+    /// Stops on first failure, or successfully after a phase that returns
+    /// `NextStep::Stop` or `NextStep::Complete`; the stop reason is recorded
+    /// in that phase's receipt as the `stop_reason` flag. A phase returning
+    /// `NextStep::Repeat { max }` is re-run at most `max` more times.
+    /// Behavior matches the CLI `xchecker spec` command.
     /// Takes `&mut self` to enforce sequential use.
     ///
     /// # Errors
@@ -483,18 +730,134 @@ impl OrchestratorHandle {
     /// # }
     /// ```
     pub async fn run_all(&mut self) -> Result<ExecutionResult> {
-        // Execute phases in sequence: Requirements -> Design -> Tasks
-        // (Review, Fixup, Final are optional/advanced phases)
-        let phases = [PhaseId::Requirements, PhaseId::Design, PhaseId::Tasks];
+        let result = self.run_all_phases().await;
+        self.emit_run_finished(&result);
+        result
+    }
+
+    /// Execute all phases in sequence and return the last receipt written.
+    ///
+    /// Works like [`run_all`](Self::run_all), returning the receipt of the
+    /// final phase run (or of the phase that failed) without re-reading it
+    /// from disk. The receipt is still persisted.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if a phase fails before writing its receipt.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use xchecker_engine::orchestrator::OrchestratorHandle;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut handle = OrchestratorHandle::new("my-spec")?;
+    /// let receipt = handle.run_all_collect().await?;
+    /// if receipt.exit_code != 0 || !receipt.warnings.is_empty() {
+    ///     eprintln!("{} needs attention", receipt.phase);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn run_all_collect(&mut self) -> Result<Receipt> {
+        let result = self.run_all().await?;
+        collect_receipt(result)
+    }
+
+    async fn run_all_phases(&mut self) -> Result<ExecutionResult> {
+        self.run_phases(&RUN_ALL_PHASES).await
+    }
+
+    /// Execute a subset of the [`run_all`](Self::run_all) sequence.
+    ///
+    /// With `only` non-empty, just those phases run; with `skip` non-empty,
+    /// every phase but those runs. Either way phases run in workflow order
+    /// (Requirements → Design → Tasks) and stop on first failure, like
+    /// `run_all`. Empty `only` and `skip` run the full sequence.
+    ///
+    /// Skipped phases are not re-run, so the selected ones build on whatever
+    /// upstream artifacts are already on disk. This bypasses the
+    /// determinism guarantee of a full pipeline run: the result depends on
+    /// artifacts from an earlier run, which may have used other inputs or
+    /// configuration. Check their receipts if that matters.
+    ///
+    /// # Errors
+    ///
+    /// Returns error, before running anything, if both `only` and `skip` are
+    /// non-empty, if `only` names a phase outside the `run_all` sequence, or
+    /// with [`PhaseError::DependencyNotSatisfied`](crate::error::PhaseError::DependencyNotSatisfied)
+    /// if a selected phase depends on a skipped phase that has no
+    /// successful receipt. Otherwise the same errors as `run_all`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use xchecker_engine::orchestrator::OrchestratorHandle;
+    /// use xchecker_engine::types::PhaseId;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut handle = OrchestratorHandle::new("my-spec")?;
+    /// // Redo Design and Tasks on top of the existing requirements
+    /// handle.run_selected(&[], &[PhaseId::Requirements]).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn run_selected(
+        &mut self,
+        only: &[PhaseId],
+        skip: &[PhaseId],
+    ) -> Result<ExecutionResult> {
+        let result = match self.select_phases(only, skip) {
+            Ok(phases) => self.run_phases(&phases).await,
+            Err(e) => Err(e),
+        };
+        self.emit_run_finished(&result);
+        result
+    }
+
+    /// Resolve `only`/`skip` against the `run_all` sequence and check that
+    /// skipped dependencies have already completed
+    fn select_phases(&self, only: &[PhaseId], skip: &[PhaseId]) -> Result<Vec<PhaseId>> {
+        if !only.is_empty() && !skip.is_empty() {
+            anyhow::bail!("`only` and `skip` cannot both be set");
+        }
+        if let Some(phase) = only.iter().find(|p| !RUN_ALL_PHASES.contains(p)) {
+            anyhow::bail!(
+                "Phase '{}' is not part of the run_all sequence; use run_phase instead",
+                phase.as_str()
+            );
+        }
+
+        let selected: Vec<PhaseId> = RUN_ALL_PHASES
+            .into_iter()
+            .filter(|p| (only.is_empty() || only.contains(p)) && !skip.contains(p))
+            .collect();
+
+        // Each phase in the sequence depends on the one before it; if that
+        // one is not going to run, its output must already be there
+        for pair in RUN_ALL_PHASES.windows(2) {
+            let (dependency, phase) = (pair[0], pair[1]);
+            if selected.contains(&phase) && !selected.contains(&dependency) {
+                self.orchestrator.check_dependencies_satisfied(phase)?;
+            }
+        }
+
+        Ok(selected)
+    }
 
+    async fn run_phases(&mut self, phases: &[PhaseId]) -> Result<ExecutionResult> {
+        let _slot = self.acquire_run_slot()?;
         let mut last_result = None;
-        for phase in phases {
+        for &phase in phases {
             let result = self
                 .orchestrator
                 .resume_from_phase(phase, &self.config)
                 .await?;
+            self.snapshot_after_phase();
 
-            if !result.success {
+            if !result.success || result.next_step.is_terminal() {
                 return Ok(result);
             }
             last_result = Some(result);
@@ -504,6 +867,310 @@ impl OrchestratorHandle {
         last_result.ok_or_else(|| anyhow::anyhow!("No phases executed"))
     }
 
+    /// Execute all phases in sequence, giving up once `deadline` passes.
+    ///
+    /// Works like [`run_all`](Self::run_all), but the remaining time is
+    /// checked before each phase and each phase's timeout is capped at it.
+    /// If the deadline passes mid-phase, the phase is abandoned and its LLM
+    /// process killed. The spec lock is then released, so afterwards the
+    /// handle should only be used for reads.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`XCheckerError::DeadlineExceeded`] if the deadline passes,
+    /// otherwise the same errors as [`run_all`](Self::run_all).
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use std::time::{Duration, Instant};
+    /// use xchecker_engine::orchestrator::OrchestratorHandle;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut handle = OrchestratorHandle::new("my-spec")?;
+    /// handle
+    ///     .run_all_with_deadline(Instant::now() + Duration::from_secs(30 * 60))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn run_all_with_deadline(&mut self, deadline: Instant) -> Result<ExecutionResult> {
+        let started = Instant::now();
+        let result = match self.run_all_phases_until(deadline).await {
+            Ok(Some(result)) => Ok(result),
+            Ok(None) => {
+                if let Err(e) = self.orchestrator.release_lock() {
+                    tracing::warn!("Failed to release spec lock after deadline: {e}");
+                }
+                Err(XCheckerError::DeadlineExceeded {
+                    elapsed: started.elapsed(),
+                }
+                .into())
+            }
+            Err(e) => Err(e),
+        };
+        self.emit_run_finished(&result);
+        result
+    }
+
+    /// Execute all phases in sequence, stopping early once `token` is cancelled.
+    ///
+    /// Works like [`run_all`](Self::run_all), but the token is checked before
+    /// each phase. A phase already running when the token is cancelled is
+    /// allowed to finish; the next one is not started. Artifacts and
+    /// receipts of completed phases stay on disk. The spec lock is then
+    /// released, so afterwards the handle should only be used for reads.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`XCheckerError::Cancelled`] if the token is cancelled before
+    /// the last phase starts, otherwise the same errors as
+    /// [`run_all`](Self::run_all).
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tokio_util::sync::CancellationToken;
+    /// use xchecker_engine::orchestrator::OrchestratorHandle;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let token = CancellationToken::new();
+    /// let shutdown = token.clone();
+    /// tokio::spawn(async move {
+    ///     let _ = tokio::signal::ctrl_c().await;
+    ///     shutdown.cancel();
+    /// });
+    ///
+    /// let mut handle = OrchestratorHandle::new("my-spec")?;
+    /// handle.run_all_cancellable(token).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn run_all_cancellable(
+        &mut self,
+        token: CancellationToken,
+    ) -> Result<ExecutionResult> {
+        let result = match self.run_all_phases_unless_cancelled(&token).await {
+            Ok(Some(result)) => Ok(result),
+            Ok(None) => {
+                if let Err(e) = self.orchestrator.release_lock() {
+                    tracing::warn!("Failed to release spec lock after cancellation: {e}");
+                }
+                Err(XCheckerError::Cancelled.into())
+            }
+            Err(e) => Err(e),
+        };
+        self.emit_run_finished(&result);
+        result
+    }
+
+    /// Take a slot in the home-level run semaphore, if `max_concurrent_specs` is set.
+    ///
+    /// The slot is held for the whole run and given back when dropped.
+    fn acquire_run_slot(&self) -> Result<Option<RunSlot>, XCheckerError> {
+        let Some(limit) = self
+            .config
+            .config
+            .get("max_concurrent_specs")
+            .and_then(|value| value.parse::<usize>().ok())
+        else {
+            return Ok(None);
+        };
+
+        match RunSlot::acquire(&self.spec_id, limit) {
+            Ok(slot) => Ok(Some(slot)),
+            Err(LockError::TooManyConcurrentSpecs { limit, active }) => {
+                Err(XCheckerError::TooManyConcurrentSpecs { limit, active })
+            }
+            Err(e) => Err(XCheckerError::Lock(e)),
+        }
+    }
+
+    /// Returns `Ok(None)` when the deadline passed before the run finished.
+    async fn run_all_phases_until(&mut self, deadline: Instant) -> Result<Option<ExecutionResult>> {
+        let _slot = self.acquire_run_slot()?;
+        let configured_timeout = PhaseTimeout::from_config(&self.config).duration;
+
+        let mut last_result = None;
+        for phase in RUN_ALL_PHASES {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Ok(None);
+            }
+
+            // Let the phase's own timeout handling (partial artifact, receipt)
+            // fire before the deadline where possible
+            let mut config = self.config.clone();
+            config.config.insert(
+                "phase_timeout".to_string(),
+                configured_timeout.min(remaining).as_secs().to_string(),
+            );
+
+            let run = self.orchestrator.resume_from_phase(phase, &config);
+            let Ok(result) = tokio::time::timeout_at(deadline.into(), run).await else {
+                return Ok(None);
+            };
+            let result = result?;
+            self.snapshot_after_phase();
+
+            if !result.success {
+                if Instant::now() >= deadline {
+                    return Ok(None);
+                }
+                return Ok(Some(result));
+            }
+            if result.next_step.is_terminal() {
+                return Ok(Some(result));
+            }
+            last_result = Some(result);
+        }
+
+        last_result
+            .map(Some)
+            .ok_or_else(|| anyhow::anyhow!("No phases executed"))
+    }
+
+    /// Returns `Ok(None)` when `token` was cancelled before the run finished.
+    async fn run_all_phases_unless_cancelled(
+        &mut self,
+        token: &CancellationToken,
+    ) -> Result<Option<ExecutionResult>> {
+        let _slot = self.acquire_run_slot()?;
+        let mut last_result = None;
+        for phase in RUN_ALL_PHASES {
+            if token.is_cancelled() {
+                return Ok(None);
+            }
+
+            let result = self
+                .orchestrator
+                .resume_from_phase(phase, &self.config)
+                .await?;
+            self.snapshot_after_phase();
+
+            if !result.success || result.next_step.is_terminal() {
+                return Ok(Some(result));
+            }
+            last_result = Some(result);
+        }
+
+        last_result
+            .map(Some)
+            .ok_or_else(|| anyhow::anyhow!("No phases executed"))
+    }
+
+    /// Stream NDJSON lifecycle events to `writer` during runs.
+    ///
+    /// Each line is a canonical JSON object carrying `schema_version`,
+    /// `emitted_at`, `spec_id` and an `event` tag: `phase_started`,
+    /// `packet_built`, `llm_called`, `phase_completed` and, once per
+    /// [`run_phase`](Self::run_phase) or [`run_all`](Self::run_all) call,
+    /// `run_finished`. Lines pass through the configured secret redactor.
+    #[must_use]
+    pub fn with_event_sink(mut self, writer: impl std::io::Write + Send + 'static) -> Self {
+        let sink = EventSink::new(writer, self.config.redactor.clone());
+        self.orchestrator.set_event_sink(std::sync::Arc::new(sink));
+        self
+    }
+
+    /// Append a JSON Lines audit trail of phase transitions to `path`.
+    ///
+    /// After each phase that gets a receipt, one canonical JSON
+    /// [`AuditEntry`](super::AuditEntry) is appended: phase, RFC3339
+    /// `started_at`/`finished_at`, exit status, packet hash and the hashes
+    /// of the artifacts written. A relative `path` is resolved against the
+    /// spec directory. The file is only ever appended to, so configuring
+    /// the same path on a later handle continues the trail across resumes.
+    #[must_use]
+    pub fn with_audit_log(mut self, path: impl Into<Utf8PathBuf>) -> Self {
+        let path = path.into();
+        let path = if path.is_absolute() {
+            path
+        } else {
+            self.orchestrator.artifact_manager().base_path().join(path)
+        };
+        self.orchestrator.set_audit_log(path);
+        self
+    }
+
+    /// Path of the audit log set with [`with_audit_log`](Self::with_audit_log)
+    #[must_use]
+    pub fn audit_log_path(&self) -> Option<&Utf8Path> {
+        self.orchestrator.audit_log_path()
+    }
+
+    /// Rewrite the problem statement with `transform` before it enters the
+    /// Requirements prompt.
+    ///
+    /// Runs on the statement resolved from config, for example to strip
+    /// ticket boilerplate. The transformed text is still redacted.
+    #[must_use]
+    pub fn with_problem_transform(mut self, transform: ProblemTransform) -> Self {
+        self.orchestrator.set_problem_transform(transform);
+        self
+    }
+
+    /// Execute Claude CLI through `runner` for every phase of this handle.
+    ///
+    /// Pass a [`RecordingRunner`](crate::runner::RecordingRunner) to capture
+    /// each argv+stdin→stdout exchange, or a
+    /// [`ReplayRunner`](crate::runner::ReplayRunner) to serve them back
+    /// without a real CLI. The configured provider must be `claude-cli`.
+    #[must_use]
+    pub fn with_runner(mut self, runner: Box<dyn ClaudeRunner>) -> Self {
+        self.orchestrator
+            .set_claude_runner(std::sync::Arc::from(runner));
+        self
+    }
+
+    /// Run `phase` instead of the built-in implementation for its id
+    #[cfg(test)]
+    pub(crate) fn set_phase_override(&mut self, phase: std::sync::Arc<dyn crate::phase::Phase>) {
+        self.orchestrator.set_phase_override(phase);
+    }
+
+    /// Save the run state once a phase has finished.
+    ///
+    /// Receipts and artifacts remain the record of the run, so a failed
+    /// snapshot is logged rather than failing the phase.
+    fn snapshot_after_phase(&self) {
+        if let Err(e) = self.snapshot() {
+            tracing::warn!("Failed to save run state for spec {}: {e:#}", self.spec_id);
+        }
+    }
+
+    fn emit_run_finished(&self, result: &Result<ExecutionResult>) {
+        let (success, exit_code) = super::outcome_status(result);
+        self.orchestrator
+            .emit_event(RunEventKind::RunFinished { success, exit_code });
+    }
+
+    /// Regenerate a phase's core YAML from its markdown artifact.
+    ///
+    /// Use after hand-editing e.g. `00-requirements.md`: the markdown is
+    /// re-read, summarized with the phase's extraction, and
+    /// `00-requirements.core.yaml` is rewritten atomically. The output depends
+    /// only on the markdown, so refreshing an unedited artifact is a no-op.
+    /// No LLM call or receipt is made.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the phase has no core YAML (Fixup, Final), the
+    /// markdown artifact cannot be read, or the write fails.
+    pub fn refresh_core_yaml(&mut self, phase: PhaseId) -> Result<()> {
+        if !xchecker_phases::has_core_yaml(phase) {
+            anyhow::bail!("Phase '{}' has no core YAML artifact", phase.as_str());
+        }
+
+        let artifacts = self.orchestrator.artifact_manager();
+        let markdown = artifacts.read_phase_artifact(phase, ArtifactType::Markdown)?;
+        let core_yaml = xchecker_phases::regenerate_core_yaml(phase, &self.spec_id, &markdown)?;
+        artifacts.store_phase_artifact(phase, &core_yaml, ArtifactType::CoreYaml)?;
+        Ok(())
+    }
+
     /// Get the current spec status.
     ///
     /// Returns `StatusOutput` which is part of the stable public API.
@@ -547,6 +1214,28 @@ impl OrchestratorHandle {
         })
     }
 
+    /// Summarize the latest receipt of every phase, in workflow order.
+    ///
+    /// Phases without a receipt yet are skipped. Each summary's drift is
+    /// checked against the spec's lockfile when one exists; an unreadable
+    /// lockfile is treated as absent.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the receipts on disk cannot be read.
+    pub fn receipt_summaries(&self) -> Result<Vec<ReceiptSummary>> {
+        let lock = XCheckerLock::load(&self.spec_id).ok().flatten();
+        let receipts = self.orchestrator.receipt_manager();
+
+        let mut summaries = Vec::new();
+        for phase in PhaseId::ALL {
+            if let Some(receipt) = receipts.read_latest_receipt(phase)? {
+                summaries.push(ReceiptSummary::from_receipt(phase, &receipt, lock.as_ref()));
+            }
+        }
+        Ok(summaries)
+    }
+
     /// Get the path to the most recent receipt.
     ///
     /// Returns `None` if no receipts have been written.
@@ -593,6 +1282,207 @@ impl OrchestratorHandle {
         None
     }
 
+    /// Get the context files that changed since the last context packet.
+    ///
+    /// Re-runs the configured content selectors over the spec directory and
+    /// compares each candidate's BLAKE3 hash with the `blake3_pre_redaction`
+    /// recorded in the packet evidence of the latest successful
+    /// Requirements, Design or Tasks receipt; failed receipts and phases
+    /// that do not pack the selected files are skipped. Paths are compared
+    /// relative to the spec directory. Returns, sorted:
+    /// - files whose hash differs from the recorded one
+    /// - files that match the selectors but are not in the evidence
+    /// - files in the evidence that no longer exist or no longer match
+    ///
+    /// Files left out of the last packet for budget reasons have no evidence
+    /// and are therefore reported as added. With no such receipt, every
+    /// candidate is reported.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if receipts cannot be read, the selectors are invalid,
+    /// or a candidate file cannot be read.
+    pub fn changed_context_files(&self) -> Result<Vec<Utf8PathBuf>> {
+        let spec_root = self.orchestrator.artifact_manager().base_path();
+        let recorded: HashMap<Utf8PathBuf, String> =
+            latest_context_receipt(self.orchestrator.receipt_manager())?
+                .map(|receipt| {
+                    receipt
+                        .packet
+                        .files
+                        .into_iter()
+                        .map(|file| {
+                            (
+                                context_path_key(Utf8Path::new(&file.path), spec_root),
+                                file.blake3_pre_redaction,
+                            )
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+
+        let selector = ContentSelector::from_selectors(self.config.selectors.as_ref())?;
+        let candidates =
+            selector.select_candidates(self.orchestrator.artifact_manager().base_path())?;
+
+        let mut changed = BTreeSet::new();
+        let mut seen = HashSet::new();
+        for candidate in candidates {
+            let bytes = std::fs::read(&candidate.path)
+                .with_context(|| format!("Failed to read context file: {}", candidate.path))?;
+            let hash = blake3::hash(&bytes).to_hex().to_string();
+            let key = context_path_key(&candidate.path, spec_root);
+            if recorded.get(&key) != Some(&hash) {
+                changed.insert(key.clone());
+            }
+            seen.insert(key);
+        }
+
+        // Anything recorded but no longer selected was removed
+        changed.extend(recorded.into_keys().filter(|path| !seen.contains(path)));
+
+        Ok(changed
+            .into_iter()
+            .map(|path| {
+                if path.is_absolute() {
+                    path
+                } else {
+                    spec_root.join(path)
+                }
+            })
+            .collect())
+    }
+
+    /// Get the canonical fingerprint of `phase`'s inputs.
+    ///
+    /// A BLAKE3 hash over the canonical JSON of the problem statement, the
+    /// BLAKE3 of every context file the selectors pick up, the resolved
+    /// model, the xchecker version, the prompt template and the packet and
+    /// timeout limits. Two runs with identical inputs share a fingerprint;
+    /// each phase receipt records the fingerprint taken before the phase ran
+    /// as `input_fingerprint`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the selectors are invalid or a context file cannot
+    /// be read.
+    pub fn input_fingerprint(&self, phase: PhaseId) -> Result<String> {
+        self.orchestrator.input_fingerprint(phase, &self.config)
+    }
+
+    /// Compare this spec's most recent successful receipt with `other_spec_id`'s.
+    ///
+    /// Reports every difference in packet hash, packet file hashes, output
+    /// hashes and model name; phase durations are listed as informational
+    /// and do not make the receipts differ. Failed receipts are skipped, so
+    /// a failed retry does not hide the last good run. Packet file paths are
+    /// taken relative to each spec root so the same files under two specs
+    /// line up. Nothing is run or written.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if either spec has no successful receipt or the
+    /// receipts cannot be read.
+    pub fn diff_receipts(&self, other_spec_id: &str) -> Result<ReceiptDiff> {
+        let other_id = sanitize_spec_id(other_spec_id)
+            .with_context(|| format!("Invalid spec ID '{other_spec_id}'"))?;
+        let own_root = self.orchestrator.artifact_manager().base_path();
+        let other_root = crate::paths::spec_root(&other_id);
+
+        let latest = |manager: &ReceiptManager, spec_id: &str| -> Result<Receipt> {
+            manager
+                .list_receipts()?
+                .into_iter()
+                .rev()
+                .find(|receipt| receipt.exit_code == 0)
+                .with_context(|| format!("No successful receipts found for spec '{spec_id}'"))
+        };
+        let own = latest(self.orchestrator.receipt_manager(), &self.spec_id)?;
+        let other = latest(&ReceiptManager::new(&other_root), &other_id)?;
+
+        Ok(ReceiptDiff::compare(
+            &relative_packet_paths(own, own_root),
+            &relative_packet_paths(other, &other_root),
+        ))
+    }
+
+    /// Write the current run state to `run-state.json` in the spec root.
+    ///
+    /// Records the last completed phase, the phase to resume at, the LLM
+    /// calls made so far, the completed phase's input fingerprint, the
+    /// config overrides and a hash of every artifact. The file is replaced
+    /// atomically; phase runs call this automatically after each phase.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if receipts or artifacts cannot be read or the file
+    /// cannot be written.
+    pub fn snapshot(&self) -> Result<()> {
+        let state = self.orchestrator.capture_run_state(&self.config)?;
+        self.orchestrator.write_run_state(&state)
+    }
+
+    /// Rebuild a handle from the run state saved for `spec_id`.
+    ///
+    /// Configuration is discovered as in [`new`](Self::new), then the saved
+    /// config overrides (only [`RESUMED_CONFIG_KEYS`](super::RESUMED_CONFIG_KEYS)),
+    /// dry-run flag and LLM call count are applied. The
+    /// handle resumes at [`next_phase`](Self::next_phase).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`XCheckerError::RunStateInvalid`] if no run state was saved
+    /// or it no longer matches the spec's receipts and artifacts, plus the
+    /// errors of [`new`](Self::new).
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use xchecker_engine::orchestrator::OrchestratorHandle;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut handle = OrchestratorHandle::restore("my-spec")?;
+    /// if let Some(phase) = handle.next_phase()? {
+    ///     handle.run_phase(phase).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn restore(spec_id: &str) -> Result<Self> {
+        let mut handle = Self::new(spec_id)?;
+        let state = handle.orchestrator.read_run_state()?;
+        handle.orchestrator.validate_run_state(&state)?;
+
+        // A hand-edited or older snapshot may carry other keys; ignore them
+        handle.config.config.extend(
+            state
+                .config
+                .into_iter()
+                .filter(|(key, _)| super::run_state::is_resumed_config_key(key)),
+        );
+        handle.config.dry_run = state.dry_run;
+        handle.orchestrator.set_llm_calls(state.llm_calls);
+        Ok(handle)
+    }
+
+    /// Get the phase a run would continue with.
+    ///
+    /// The phase after the last completed one in workflow order, or `None`
+    /// once Final has completed.
+    pub fn next_phase(&self) -> Result<Option<PhaseId>> {
+        Ok(super::run_state::next_phase_after(self.current_phase()?))
+    }
+
+    /// Get the number of LLM invocations this run has attempted.
+    ///
+    /// Failed calls count too. A handle from [`restore`](Self::restore)
+    /// continues the count saved in the run state.
+    #[must_use]
+    pub fn llm_calls(&self) -> u32 {
+        self.orchestrator.llm_calls()
+    }
+
     /// Get the spec ID this handle operates on.
     #[must_use]
     pub fn spec_id(&self) -> &str {
@@ -641,6 +1531,10 @@ impl OrchestratorHandle {
     /// - `model`: LLM model to use
     /// - `phase_timeout`: Timeout in seconds
     /// - `apply_fixups`: Whether to apply fixups or preview
+    /// - `accept_modified`: Whether resume accepts upstream artifacts edited
+    ///   since their receipts (updating the recorded hashes) instead of failing
+    /// - `fixture_dir`: Read each phase's model response from
+    ///   `<fixture_dir>/<phase>.md` instead of calling an LLM
     pub fn set_config(&mut self, key: &str, value: &str) {
         self.config
             .config
@@ -5,10 +5,11 @@
 use anyhow::Result;
 use std::collections::HashMap;
 use std::fmt;
+use std::time::Duration;
 
 use crate::config::{
-    ClaudeConfig, Config, Defaults, GeminiConfig, LlmConfig, PhaseConfig, PhasesConfig,
-    PromptTemplate, RunnerConfig, SecurityConfig, Selectors,
+    ClaudeConfig, Config, Defaults, GeminiConfig, LlmConfig, PacketConfig, PhaseConfig,
+    PhasesConfig, PromptTemplate, RunnerConfig, SecurityConfig, Selectors,
 };
 use crate::error::XCheckerError;
 use crate::hooks::HooksConfig;
@@ -117,7 +118,10 @@ fn apply_overrides_from_map(config: &mut Config, overrides: &HashMap<String, Str
         .or_else(|| overrides.get("claude_cli_path"))
     {
         if config.llm.claude.is_none() {
-            config.llm.claude = Some(ClaudeConfig { binary: None });
+            config.llm.claude = Some(ClaudeConfig {
+                binary: None,
+                timeout_secs: None,
+            });
         }
         if let Some(claude_config) = config.llm.claude.as_mut() {
             claude_config.binary = Some(claude_binary_path.clone());
@@ -131,6 +135,7 @@ fn apply_overrides_from_map(config: &mut Config, overrides: &HashMap<String, Str
                 binary: None,
                 default_model: None,
                 profiles: None,
+                timeout_secs: None,
             });
         }
         if let Some(gemini_config) = config.llm.gemini.as_mut() {
@@ -144,6 +149,7 @@ fn apply_overrides_from_map(config: &mut Config, overrides: &HashMap<String, Str
                 binary: None,
                 default_model: None,
                 profiles: None,
+                timeout_secs: None,
             });
         }
         if let Some(gemini_config) = config.llm.gemini.as_mut() {
@@ -161,12 +167,23 @@ fn apply_overrides_from_map(config: &mut Config, overrides: &HashMap<String, Str
         let phase_timeout = overrides
             .get(&format!("phases.{phase_name}.phase_timeout"))
             .and_then(|s| s.parse::<u64>().ok());
+        let prompt_file = overrides
+            .get(&format!("phases.{phase_name}.prompt_file"))
+            .cloned();
 
-        if model.is_some() || max_turns.is_some() || phase_timeout.is_some() {
+        if model.is_some()
+            || max_turns.is_some()
+            || phase_timeout.is_some()
+            || prompt_file.is_some()
+        {
+            // Keep a prompt file from the config file unless the map replaces it
+            let prompt_file =
+                prompt_file.or_else(|| target.as_ref().and_then(|pc| pc.prompt_file.clone()));
             *target = Some(PhaseConfig {
                 model,
                 max_turns,
                 phase_timeout,
+                prompt_file,
             });
         }
     };
@@ -232,6 +249,16 @@ fn build_messages_from_template(
     }
 }
 
+/// Resolve the invocation timeout for `provider`.
+///
+/// Uses `[llm.<provider>].timeout_secs` when set, otherwise `fallback` (the
+/// global phase timeout). Both are subject to the `PhaseTimeout` minimum.
+fn resolve_provider_timeout(cfg: &Config, provider: &str, fallback: PhaseTimeout) -> Duration {
+    cfg.provider_timeout_secs(provider)
+        .map_or(fallback, PhaseTimeout::from_secs)
+        .duration
+}
+
 impl PhaseOrchestrator {
     /// Build a minimal Config from `OrchestratorConfig` for LLM backend construction.
     ///
@@ -257,12 +284,14 @@ impl PhaseOrchestrator {
                     gemini: None,
                     openrouter: None,
                     anthropic: None,
+                    retry: None,
                     execution_strategy: None,
                     prompt_template: None,
                 },
                 phases: PhasesConfig::default(),
                 hooks: HooksConfig::default(),
                 security: SecurityConfig::default(),
+                packet: PacketConfig::default(),
                 source_attribution: HashMap::new(),
             }
         };
@@ -286,6 +315,13 @@ impl PhaseOrchestrator {
         // Build a Config from OrchestratorConfig
         let cfg = self.config_from_orchestrator_config(orc_config);
 
+        // An injected runner replaces provider selection entirely
+        if let Some(runner) = &self.claude_runner {
+            let backend = crate::llm::from_config_with_runner(&cfg, runner.clone())
+                .map_err(XCheckerError::Llm)?;
+            return Ok((backend, None));
+        }
+
         // Use the factory function to construct the appropriate backend
         crate::llm::from_config_with_fallback(&cfg).map_err(XCheckerError::Llm)
     }
@@ -311,45 +347,14 @@ impl PhaseOrchestrator {
 
         let provider = cfg.llm.provider.as_deref().unwrap_or("claude-cli");
 
-        // Resolve model with explicit overrides when provided, otherwise defer to provider defaults.
-        // Model resolution precedence:
-        // 1. Phase-specific override ([phases.<phase>].model)
-        // 2. Global default ([defaults].model)
-        // 3. Empty string - backend handles its own default (e.g., claude-cli uses "haiku",
-        //    HTTP backends use their configured [llm.<provider>].model)
-        //
-        // Note: We don't force "haiku" for claude-cli here because:
-        // - If fallback to a different provider happens, the wrong model would be used
-        // - Each backend should handle its own default model selection
-        let phase_model = match phase_id {
-            PhaseId::Requirements => cfg.phases.requirements.as_ref(),
-            PhaseId::Design => cfg.phases.design.as_ref(),
-            PhaseId::Tasks => cfg.phases.tasks.as_ref(),
-            PhaseId::Review => cfg.phases.review.as_ref(),
-            PhaseId::Fixup => cfg.phases.fixup.as_ref(),
-            PhaseId::Final => cfg.phases.final_.as_ref(),
-        }
-        .and_then(|pc| pc.model.clone())
-        .filter(|model| !model.is_empty());
-
-        let model = phase_model.unwrap_or_else(|| {
-            cfg.defaults
-                .model
-                .clone()
-                .filter(|m| !m.is_empty())
-                .unwrap_or_default()
-        });
+        let model = resolve_model(&cfg, phase_id);
 
-        // Get timeout from config with minimum enforcement
-        let timeout = PhaseTimeout::from_config(orc_config).duration;
+        // Provider-specific timeout, falling back to the phase timeout
+        let timeout =
+            resolve_provider_timeout(&cfg, provider, PhaseTimeout::from_config(orc_config));
 
         // Build messages using the configured prompt template, including packet context.
-        let template = cfg
-            .llm
-            .prompt_template
-            .as_deref()
-            .and_then(|name| PromptTemplate::parse(name).ok())
-            .unwrap_or(PromptTemplate::Default);
+        let template = resolve_prompt_template(&cfg);
 
         let messages = build_messages_from_template(template, prompt, packet);
 
@@ -412,8 +417,31 @@ impl PhaseOrchestrator {
         let fallback_warning = fallback_info.map(|info| info.warning_message());
         let fallback_warning_for_error = fallback_warning.clone();
 
-        // Invoke LLM
-        let llm_result = backend.invoke(invocation).await.map_err(|err| {
+        // Count the attempt before invoking, so failed calls still use budget
+        self.llm_calls
+            .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+
+        // Invoke LLM, streaming redacted text to the token sink if one is attached
+        let llm_result = if let Some(sink) = &self.token_sink {
+            // Redact whole lines only, so a secret split across chunks is
+            // still matched; a closed receiver only means nobody is listening
+            let mut pending = String::new();
+            let mut on_token = |chunk: &str| {
+                pending.push_str(chunk);
+                if let Some(end) = pending.rfind('\n') {
+                    let lines: String = pending.drain(..=end).collect();
+                    let _ = sink.send(config.redactor.redact_string(&lines));
+                }
+            };
+            let result = backend.invoke_streaming(invocation, &mut on_token).await;
+            if !pending.is_empty() {
+                let _ = sink.send(config.redactor.redact_string(&pending));
+            }
+            result
+        } else {
+            backend.invoke(invocation).await
+        };
+        let llm_result = llm_result.map_err(|err| {
             anyhow::Error::new(LlmInvocationError::new(
                 XCheckerError::Llm(err),
                 fallback_warning_for_error.clone(),
@@ -487,11 +515,106 @@ impl PhaseOrchestrator {
     }
 }
 
+/// Model for `phase_id`, or empty to let the backend pick its default.
+///
+/// Model resolution precedence:
+/// 1. Phase-specific override (`[phases.<phase>].model`)
+/// 2. Global default (`[defaults].model`)
+/// 3. Empty string - backend handles its own default (e.g., claude-cli uses "haiku",
+///    HTTP backends use their configured `[llm.<provider>].model`)
+///
+/// We don't force "haiku" for claude-cli here because a fallback to a
+/// different provider would then use the wrong model; each backend handles
+/// its own default model selection.
+pub(super) fn resolve_model(cfg: &Config, phase_id: PhaseId) -> String {
+    let phase_model = match phase_id {
+        PhaseId::Requirements => cfg.phases.requirements.as_ref(),
+        PhaseId::Design => cfg.phases.design.as_ref(),
+        PhaseId::Tasks => cfg.phases.tasks.as_ref(),
+        PhaseId::Review => cfg.phases.review.as_ref(),
+        PhaseId::Fixup => cfg.phases.fixup.as_ref(),
+        PhaseId::Final => cfg.phases.final_.as_ref(),
+    }
+    .and_then(|pc| pc.model.clone())
+    .filter(|model| !model.is_empty());
+
+    phase_model.unwrap_or_else(|| {
+        cfg.defaults
+            .model
+            .clone()
+            .filter(|m| !m.is_empty())
+            .unwrap_or_default()
+    })
+}
+
+/// Configured prompt template, falling back to the universal default
+pub(super) fn resolve_prompt_template(cfg: &Config) -> PromptTemplate {
+    cfg.llm
+        .prompt_template
+        .as_deref()
+        .and_then(|name| PromptTemplate::parse(name).ok())
+        .unwrap_or(PromptTemplate::Default)
+}
+
 #[cfg(test)]
 mod tests {
-    use super::build_messages_from_template;
-    use crate::config::PromptTemplate;
+    use super::{PhaseTimeout, build_messages_from_template, resolve_provider_timeout};
+    use crate::config::{
+        AnthropicConfig, ClaudeConfig, Config, GeminiConfig, OpenRouterConfig, PromptTemplate,
+    };
     use crate::llm::Role;
+    use std::time::Duration;
+
+    #[test]
+    fn provider_timeout_overrides_phase_timeout_per_provider() {
+        let mut cfg = Config::minimal_for_testing();
+        let fallback = || PhaseTimeout::from_secs(600);
+
+        // Without provider sections every provider uses the phase timeout
+        for provider in ["claude-cli", "gemini-cli", "openrouter", "anthropic"] {
+            assert_eq!(
+                resolve_provider_timeout(&cfg, provider, fallback()),
+                Duration::from_secs(600)
+            );
+        }
+
+        cfg.llm.claude = Some(ClaudeConfig {
+            binary: None,
+            timeout_secs: Some(30),
+        });
+        cfg.llm.gemini = Some(GeminiConfig {
+            binary: None,
+            default_model: None,
+            profiles: None,
+            timeout_secs: Some(45),
+        });
+        cfg.llm.openrouter = Some(OpenRouterConfig {
+            api_key_env: None,
+            base_url: None,
+            model: None,
+            max_tokens: None,
+            temperature: None,
+            budget: None,
+            timeout_secs: Some(90),
+        });
+        cfg.llm.anthropic = Some(AnthropicConfig {
+            api_key_env: None,
+            base_url: None,
+            model: None,
+            max_tokens: None,
+            temperature: None,
+            budget: None,
+            timeout_secs: Some(2),
+        });
+
+        let resolved = |provider| resolve_provider_timeout(&cfg, provider, fallback()).as_secs();
+        assert_eq!(resolved("claude-cli"), 30);
+        assert_eq!(resolved("gemini-cli"), 45);
+        assert_eq!(resolved("openrouter"), 90);
+        // Provider overrides still respect the minimum timeout
+        assert_eq!(resolved("anthropic"), PhaseTimeout::MIN_SECS);
+        assert_eq!(resolved("unknown"), 600);
+    }
 
     #[test]
     fn build_messages_default_includes_packet() {
@@ -4,13 +4,22 @@
 //! the Phase trait, `ArtifactManager`, and Receipt system to execute
 //! phases end-to-end with proper error handling and state management.
 
+mod audit_log;
+mod events;
+mod fingerprint;
 mod handle;
 mod llm;
 mod phase_exec;
+mod run_state;
 mod workflow;
 
+pub use self::audit_log::{AUDIT_SCHEMA_VERSION, AuditEntry};
+pub use self::events::{EVENT_SCHEMA_VERSION, EventSink};
 #[allow(unused_imports)]
 pub use self::handle::OrchestratorHandle;
+pub use self::run_state::{
+    RESUMED_CONFIG_KEYS, RUN_STATE_FILE, RUN_STATE_SCHEMA_VERSION, RunState,
+};
 
 #[allow(unused_imports)]
 pub use self::phase_exec::ExecutionResult;
@@ -26,10 +35,15 @@ use std::time::Duration;
 use crate::config::Selectors;
 use crate::error::{PhaseError, XCheckerError};
 use crate::hooks::HooksConfig;
+use crate::lock::LockPolicy;
+use crate::phase::{Phase, ProblemTransform};
 use crate::receipt::ReceiptManager;
+use crate::runner::ClaudeRunner;
 use crate::status::artifact::ArtifactManager;
 use crate::types::PhaseId;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+use tokio::sync::mpsc::UnboundedSender;
 
 /// Orchestrates the execution of spec generation phases.
 ///
@@ -71,6 +85,15 @@ pub struct PhaseOrchestrator {
     spec_id: String,
     artifact_manager: ArtifactManager,
     receipt_manager: ReceiptManager,
+    event_sink: Option<Arc<EventSink>>,
+    audit_log: Option<audit_log::AuditLog>,
+    claude_runner: Option<Arc<dyn ClaudeRunner>>,
+    token_sink: Option<UnboundedSender<String>>,
+    problem_transform: Option<ProblemTransform>,
+    /// Phase implementations used instead of the built-in ones (tests only)
+    phase_overrides: HashMap<PhaseId, Arc<dyn Phase>>,
+    /// LLM invocations attempted by this orchestrator (restored from run state)
+    llm_calls: AtomicU32,
 }
 
 /// Configuration for orchestrator execution.
@@ -175,7 +198,15 @@ impl PhaseOrchestrator {
     /// # Errors
     /// Returns error if artifact manager creation fails.
     pub fn new_with_force(spec_id: &str, force: bool) -> Result<Self> {
-        let artifact_manager = ArtifactManager::new_with_force(spec_id, force)
+        Self::new_with_lock_policy(spec_id, force, LockPolicy::default())
+    }
+
+    /// Create a new orchestrator, acquiring the spec lock under `policy`.
+    ///
+    /// # Errors
+    /// Returns error if lock cannot be acquired or artifact manager creation fails.
+    pub fn new_with_lock_policy(spec_id: &str, force: bool, policy: LockPolicy) -> Result<Self> {
+        let artifact_manager = ArtifactManager::new_with_lock_policy(spec_id, force, policy)
             .with_context(|| format!("Failed to create artifact manager for spec: {spec_id}"))?;
 
         let receipt_manager = ReceiptManager::new(artifact_manager.base_path());
@@ -184,6 +215,13 @@ impl PhaseOrchestrator {
             spec_id: spec_id.to_string(),
             artifact_manager,
             receipt_manager,
+            event_sink: None,
+            audit_log: None,
+            claude_runner: None,
+            token_sink: None,
+            problem_transform: None,
+            phase_overrides: HashMap::new(),
+            llm_calls: AtomicU32::new(0),
         })
     }
 
@@ -206,9 +244,95 @@ impl PhaseOrchestrator {
             spec_id: spec_id.to_string(),
             artifact_manager,
             receipt_manager,
+            event_sink: None,
+            audit_log: None,
+            claude_runner: None,
+            token_sink: None,
+            problem_transform: None,
+            phase_overrides: HashMap::new(),
+            llm_calls: AtomicU32::new(0),
         })
     }
 
+    /// Stream lifecycle events to `sink` during execution
+    pub(crate) fn set_event_sink(&mut self, sink: Arc<EventSink>) {
+        self.event_sink = Some(sink);
+    }
+
+    /// Append an audit entry to the `.jsonl` file at `path` after each phase
+    pub(crate) fn set_audit_log(&mut self, path: camino::Utf8PathBuf) {
+        self.audit_log = Some(audit_log::AuditLog::new(path));
+    }
+
+    /// Path of the audit log, if one is configured
+    pub(crate) fn audit_log_path(&self) -> Option<&camino::Utf8Path> {
+        self.audit_log.as_ref().map(audit_log::AuditLog::path)
+    }
+
+    /// Execute Claude CLI through `runner` instead of spawning it directly
+    pub(crate) fn set_claude_runner(&mut self, runner: Arc<dyn ClaudeRunner>) {
+        self.claude_runner = Some(runner);
+    }
+
+    /// Send redacted LLM response text to `sink` as it streams in
+    pub(crate) fn set_token_sink(&mut self, sink: Option<UnboundedSender<String>>) {
+        self.token_sink = sink;
+    }
+
+    /// Rewrite the problem statement with `transform` before it enters prompts
+    pub(crate) fn set_problem_transform(&mut self, transform: ProblemTransform) {
+        self.problem_transform = Some(transform);
+    }
+
+    /// Run `phase` whenever its `PhaseId` is executed
+    #[cfg(test)]
+    pub(crate) fn set_phase_override(&mut self, phase: Arc<dyn Phase>) {
+        self.phase_overrides.insert(phase.id(), phase);
+    }
+
+    /// LLM invocations attempted so far, including any restored from run state
+    pub(crate) fn llm_calls(&self) -> u32 {
+        self.llm_calls.load(Ordering::SeqCst)
+    }
+
+    /// Continue counting LLM invocations from `calls`
+    pub(crate) fn set_llm_calls(&self, calls: u32) {
+        self.llm_calls.store(calls, Ordering::SeqCst);
+    }
+
+    /// Emit a lifecycle event if a sink is attached
+    pub(crate) fn emit_event(&self, kind: events::RunEventKind) {
+        if let Some(sink) = &self.event_sink {
+            sink.emit(&self.spec_id, kind);
+        }
+    }
+
+    /// Append the audit entry for a phase that started at `started_at`.
+    ///
+    /// A phase that aborted with an error is recorded as a failure with the
+    /// error's kind. The audit log is only useful if it is complete, so a
+    /// failed append is returned rather than swallowed.
+    pub(crate) fn record_audit_entry(
+        &self,
+        phase_id: PhaseId,
+        started_at: chrono::DateTime<chrono::Utc>,
+        outcome: &Result<ExecutionResult>,
+    ) -> Result<()> {
+        let Some(log) = &self.audit_log else {
+            return Ok(());
+        };
+        let entry = match outcome {
+            Ok(result) => {
+                audit_log::AuditEntry::from_result(&self.spec_id, phase_id, started_at, result)
+            }
+            Err(error) => {
+                audit_log::AuditEntry::from_error(&self.spec_id, phase_id, started_at, error)
+            }
+        };
+        log.append(&entry)
+            .with_context(|| format!("Failed to append audit entry for spec {}", self.spec_id))
+    }
+
     /// Check if we can resume from a specific phase
     fn can_resume_from_phase(&self, phase_id: PhaseId) -> Result<bool> {
         // Check dependencies are satisfied
@@ -326,6 +450,144 @@ impl PhaseOrchestrator {
         Ok(None) // No successful receipts found
     }
 
+    /// Reject artifacts whose names are already owned by another phase.
+    ///
+    /// Ownership comes from each other phase's latest receipt, so the check
+    /// runs before `phase_id` writes anything and an integrator's phase can
+    /// never silently overwrite a sibling's output.
+    pub(crate) fn check_artifact_name_collisions(
+        &self,
+        phase_id: PhaseId,
+        artifacts: &[crate::status::artifact::Artifact],
+    ) -> Result<(), XCheckerError> {
+        let phases = [
+            PhaseId::Requirements,
+            PhaseId::Design,
+            PhaseId::Tasks,
+            PhaseId::Review,
+            PhaseId::Fixup,
+            PhaseId::Final,
+        ];
+
+        let mut owners: Vec<(String, String)> = artifacts
+            .iter()
+            .map(|artifact| (phase_id.as_str().to_string(), artifact.name.clone()))
+            .collect();
+        for phase in phases.into_iter().filter(|phase| *phase != phase_id) {
+            // Unreadable receipts are reported elsewhere; they own nothing here
+            let Ok(Some(receipt)) = self.receipt_manager.read_latest_receipt(phase) else {
+                continue;
+            };
+            owners.extend(receipt.outputs.iter().filter_map(|output| {
+                output
+                    .path
+                    .strip_prefix("artifacts/")
+                    .map(|name| (receipt.phase.clone(), name.to_string()))
+            }));
+        }
+
+        find_artifact_name_collision(
+            owners
+                .iter()
+                .map(|(phase, name)| (phase.as_str(), name.as_str())),
+        )
+    }
+
+    /// Check upstream artifacts against the hashes recorded in their receipts.
+    ///
+    /// Every phase ordered before `phase_id` with a successful latest receipt
+    /// has its recorded artifacts re-hashed the same way the receipt did. A
+    /// mismatch fails with `ArtifactModified`, unless `accept_modified` is
+    /// set: then the receipt is rewritten in place with the new hashes and a
+    /// warning naming the accepted artifacts. Missing artifacts are left to
+    /// the dependency checks.
+    pub(crate) fn verify_upstream_artifacts(
+        &self,
+        phase_id: PhaseId,
+        accept_modified: bool,
+    ) -> Result<()> {
+        let accept = accept_modified.then_some("Accepted externally modified artifacts");
+        self.reconcile_upstream_hashes(phase_id, accept)
+    }
+
+    /// Re-record upstream hashes after an applied fixup.
+    ///
+    /// Fixup in apply mode edits earlier-phase artifacts on purpose. Upstream
+    /// artifacts were verified before it ran, so every mismatch now comes from
+    /// the fixup and is written back to its receipt; later resumes then pass
+    /// without `accept_modified`.
+    pub(crate) fn refresh_fixup_applied_hashes(&self) -> Result<()> {
+        self.reconcile_upstream_hashes(PhaseId::Fixup, Some("Refreshed hashes after applied fixup"))
+    }
+
+    /// Re-hash the artifacts of phases before `phase_id` against their receipts.
+    ///
+    /// With `accept` unset a mismatch fails with `ArtifactModified`; otherwise
+    /// the new hashes are written back and `accept` prefixes the warning that
+    /// names the changed artifacts.
+    fn reconcile_upstream_hashes(&self, phase_id: PhaseId, accept: Option<&str>) -> Result<()> {
+        let phases = [
+            PhaseId::Requirements,
+            PhaseId::Design,
+            PhaseId::Tasks,
+            PhaseId::Review,
+            PhaseId::Fixup,
+            PhaseId::Final,
+        ];
+
+        for phase in phases.into_iter().take_while(|phase| *phase != phase_id) {
+            let Some(mut receipt) = self.receipt_manager.read_latest_receipt(phase)? else {
+                continue;
+            };
+            if receipt.exit_code != 0 {
+                continue;
+            }
+
+            let mut accepted = Vec::new();
+            for output in &mut receipt.outputs {
+                let Some(name) = output.path.strip_prefix("artifacts/") else {
+                    continue;
+                };
+                let path = self.artifact_manager.artifacts_path().join(name);
+                let Ok(bytes) = std::fs::read(&path) else {
+                    continue;
+                };
+
+                let current = self
+                    .receipt_manager
+                    .rehash_output(output, bytes, phase.as_str())?;
+                if current.blake3_canonicalized == output.blake3_canonicalized {
+                    continue;
+                }
+
+                if accept.is_none() {
+                    return Err(XCheckerError::ArtifactModified {
+                        name: name.to_string(),
+                    }
+                    .into());
+                }
+                output.blake3_canonicalized = current.blake3_canonicalized;
+                accepted.push(name.to_string());
+            }
+
+            if let Some(reason) = accept
+                && !accepted.is_empty()
+            {
+                // Same emitted_at, so this overwrites the receipt it was read from
+                receipt
+                    .warnings
+                    .push(format!("{reason}: {}", accepted.join(", ")));
+                self.receipt_manager
+                    .write_receipt(&receipt)
+                    .with_context(|| {
+                        format!("Failed to update receipt for phase: {}", phase.as_str())
+                    })?;
+            }
+        }
+
+        Ok(())
+    }
+
     /// Check that all dependencies for a phase are satisfied
     fn check_dependencies_satisfied(&self, phase_id: PhaseId) -> Result<(), XCheckerError> {
         let deps = match phase_id {
@@ -385,6 +647,12 @@ impl PhaseOrchestrator {
         &self.artifact_manager
     }
 
+    /// Releases the spec lock held by the artifact manager.
+    pub(crate) fn release_lock(&mut self) -> Result<()> {
+        self.artifact_manager.release_lock()?;
+        Ok(())
+    }
+
     /// Returns a reference to the receipt manager. Used by status generation and tests.
     #[must_use]
     pub fn receipt_manager(&self) -> &ReceiptManager {
@@ -430,6 +698,43 @@ impl PhaseOrchestrator {
     }
 }
 
+/// Success flag and exit code of a phase or run outcome.
+///
+/// Errors map to the exit code their receipt would carry.
+pub(crate) fn outcome_status(outcome: &Result<ExecutionResult>) -> (bool, i32) {
+    match outcome {
+        Ok(result) => (result.success, result.exit_code),
+        Err(e) => (
+            false,
+            e.downcast_ref::<XCheckerError>().map_or(1, |err| {
+                crate::exit_codes::error_to_exit_code_and_kind(err).0
+            }),
+        ),
+    }
+}
+
+/// Find the first artifact name claimed by more than one phase.
+///
+/// Takes `(phase, artifact name)` pairs. Colliding phases are reported in
+/// sorted order so the error is stable across runs.
+pub(crate) fn find_artifact_name_collision<'a>(
+    owners: impl IntoIterator<Item = (&'a str, &'a str)>,
+) -> Result<(), XCheckerError> {
+    let mut by_name: std::collections::BTreeMap<&str, std::collections::BTreeSet<&str>> =
+        std::collections::BTreeMap::new();
+    for (phase, name) in owners {
+        by_name.entry(name).or_default().insert(phase);
+    }
+
+    match by_name.into_iter().find(|(_, phases)| phases.len() > 1) {
+        Some((name, phases)) => Err(XCheckerError::ArtifactNameCollision {
+            name: name.to_string(),
+            phases: phases.into_iter().map(str::to_string).collect(),
+        }),
+        None => Ok(()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -599,8 +904,9 @@ mod tests {
             config: HashMap::new(),
             artifacts: vec!["test-artifact.md".to_string()],
             selectors: None,
-            strict_validation: false,
+            validation_mode: crate::types::ValidationMode::Warn,
             redactor: std::sync::Arc::new(crate::redaction::SecretRedactor::default()),
+            problem_transform: None,
         };
 
         assert_eq!(context.spec_id, "test-spec");
@@ -668,6 +974,8 @@ This is a generated requirements document for spec {}. The system will provide c
             exit_code: 0,
             artifact_paths: vec![],
             receipt_path: None,
+            receipt: None,
+            next_step: NextStep::Continue,
             error: None,
         };
 
@@ -716,6 +1024,7 @@ This is a generated requirements document for spec {}. The system will provide c
                     files: vec![],
                     max_bytes: 65536,
                     max_lines: 1200,
+                    preview_compressed: false,
                 };
 
                 let mut budget = xchecker_packet::BudgetUsage::new(65536, 1200);
@@ -802,16 +1111,19 @@ This is a generated requirements document for spec {}. The system will provide c
                             range: Some("L1-L100".to_string()),
                             blake3_pre_redaction: "abc123".to_string(),
                             priority: crate::types::Priority::High,
+                            redaction_exempt: false,
                         },
                         crate::types::FileEvidence {
                             path: "Cargo.toml".to_string(),
                             range: Some("L1-L50".to_string()),
                             blake3_pre_redaction: "def456".to_string(),
                             priority: crate::types::Priority::Medium,
+                            redaction_exempt: false,
                         },
                     ],
                     max_bytes: 65536,
                     max_lines: 1200,
+                    preview_compressed: false,
                 };
 
                 let mut budget = xchecker_packet::BudgetUsage::new(65536, 1200);
@@ -833,7 +1145,8 @@ This is a generated requirements document for spec {}. The system will provide c
                 // Generate a simple artifact
                 let artifact = crate::status::artifact::Artifact {
                     name: "00-requirements.md".to_string(),
-                    content: format!("# Requirements for {}\n\nTest requirements.", ctx.spec_id),
+                    content: format!("# Requirements for {}\n\nTest requirements.", ctx.spec_id)
+                        .into(),
                     artifact_type: crate::status::artifact::ArtifactType::Markdown,
                     blake3_hash: String::new(), // Will be computed during storage
                 };
@@ -845,6 +1158,8 @@ This is a generated requirements document for spec {}. The system will provide c
                         packet_hash: None,
                         budget_used: None,
                         duration_ms: None,
+                        timings: None,
+                        validation_errors: Vec::new(),
                     },
                 })
             }
@@ -894,4 +1209,289 @@ This is a generated requirements document for spec {}. The system will provide c
         assert_eq!(second_file["range"], "L1-L50");
         assert_eq!(second_file["blake3_pre_redaction"], "def456");
     }
+
+    /// Test phase that produces its standard artifact and returns a fixed `NextStep`
+    struct ScriptedPhase {
+        id: PhaseId,
+        deps: &'static [PhaseId],
+        next_step: NextStep,
+        stem: Option<&'static str>,
+        runs: std::sync::atomic::AtomicU32,
+    }
+
+    impl ScriptedPhase {
+        fn new(id: PhaseId, deps: &'static [PhaseId], next_step: NextStep) -> Self {
+            Self {
+                id,
+                deps,
+                next_step,
+                stem: None,
+                runs: std::sync::atomic::AtomicU32::new(0),
+            }
+        }
+
+        /// Name the artifacts `<stem>.md` and `<stem>.core.yaml` instead of the standard names
+        fn with_stem(mut self, stem: &'static str) -> Self {
+            self.stem = Some(stem);
+            self
+        }
+
+        fn runs(&self) -> u32 {
+            self.runs.load(std::sync::atomic::Ordering::SeqCst)
+        }
+    }
+
+    impl Phase for ScriptedPhase {
+        fn id(&self) -> PhaseId {
+            self.id
+        }
+
+        fn deps(&self) -> &'static [PhaseId] {
+            self.deps
+        }
+
+        fn can_resume(&self) -> bool {
+            true
+        }
+
+        fn prompt(&self, _ctx: &PhaseContext) -> String {
+            format!("Run {}", self.id.as_str())
+        }
+
+        fn make_packet(&self, _ctx: &PhaseContext) -> Result<xchecker_packet::Packet> {
+            let content = "Scripted packet";
+            Ok(xchecker_packet::Packet::new(
+                content.to_string(),
+                blake3::hash(content.as_bytes()).to_hex().to_string(),
+                crate::types::PacketEvidence {
+                    files: Vec::new(),
+                    max_bytes: 65536,
+                    max_lines: 1200,
+                    preview_compressed: false,
+                },
+                xchecker_packet::BudgetUsage::new(65536, 1200),
+            ))
+        }
+
+        fn postprocess(
+            &self,
+            _raw: &str,
+            _ctx: &PhaseContext,
+        ) -> Result<crate::phase::PhaseResult> {
+            self.runs.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            // Standard names, so dependency checks see the phase as completed
+            let stem = self.stem.unwrap_or(match self.id {
+                PhaseId::Requirements => "00-requirements",
+                PhaseId::Design => "10-design",
+                other => other.as_str(),
+            });
+            Ok(crate::phase::PhaseResult {
+                artifacts: vec![
+                    crate::status::artifact::Artifact {
+                        name: format!("{stem}.md"),
+                        content: "# Scripted\n".into(),
+                        artifact_type: crate::status::artifact::ArtifactType::Markdown,
+                        blake3_hash: String::new(),
+                    },
+                    crate::status::artifact::Artifact {
+                        name: format!("{stem}.core.yaml"),
+                        content: "scripted: true\n".into(),
+                        artifact_type: crate::status::artifact::ArtifactType::CoreYaml,
+                        blake3_hash: String::new(),
+                    },
+                ],
+                next_step: self.next_step.clone(),
+                metadata: crate::phase::PhaseMetadata::default(),
+            })
+        }
+    }
+
+    /// Dry-run `OrchestratorHandle` for a fresh spec in an isolated home
+    fn setup_handle_with_guard(test_name: &str) -> (OrchestratorHandle, String, TempDirGuard) {
+        let lock = orchestrator_env_guard();
+        let home_dir = crate::paths::with_isolated_home();
+
+        let temp_dir = TempDir::new().unwrap();
+        let original_dir = env::current_dir().unwrap();
+        env::set_current_dir(temp_dir.path()).unwrap();
+
+        let spec_id = format!("test-{}-{}", test_name, std::process::id());
+        let mut handle = OrchestratorHandle::new(&spec_id).unwrap();
+        handle.set_dry_run(true);
+
+        let guard = TempDirGuard {
+            _lock: lock,
+            _temp_dir: temp_dir,
+            _home_dir: home_dir,
+            original_dir,
+        };
+
+        (handle, spec_id, guard)
+    }
+
+    fn latest_receipt(spec_id: &str, phase: PhaseId) -> Option<crate::types::Receipt> {
+        ReceiptManager::new(&crate::paths::spec_root(spec_id))
+            .read_latest_receipt(phase)
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_stop_halts_run_all_and_records_reason() {
+        let (mut handle, spec_id, _guard) = setup_handle_with_guard("handle-stop");
+
+        let requirements = Arc::new(ScriptedPhase::new(
+            PhaseId::Requirements,
+            &[],
+            NextStep::Stop {
+                reason: "requirements already satisfied".to_string(),
+            },
+        ));
+        let design = Arc::new(ScriptedPhase::new(
+            PhaseId::Design,
+            &[PhaseId::Requirements],
+            NextStep::Continue,
+        ));
+        handle.set_phase_override(requirements.clone());
+        handle.set_phase_override(design.clone());
+
+        let result = handle.run_all().await.unwrap();
+
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(result.phase, PhaseId::Requirements);
+        assert_eq!(
+            result.next_step,
+            NextStep::Stop {
+                reason: "requirements already satisfied".to_string()
+            }
+        );
+        assert_eq!(requirements.runs(), 1);
+        assert_eq!(design.runs(), 0);
+
+        let receipt = result.receipt.expect("requirements receipt");
+        assert_eq!(
+            receipt.flags.get("stop_reason").map(String::as_str),
+            Some("requirements already satisfied")
+        );
+        assert!(latest_receipt(&spec_id, PhaseId::Design).is_none());
+        assert!(latest_receipt(&spec_id, PhaseId::Tasks).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_repeat_reruns_phase_up_to_max() {
+        let (mut handle, spec_id, _guard) = setup_handle_with_guard("handle-repeat");
+
+        // Always asks to repeat, so only the bound ends the loop
+        let requirements = Arc::new(ScriptedPhase::new(
+            PhaseId::Requirements,
+            &[],
+            NextStep::Repeat { max: 2 },
+        ));
+        let design = Arc::new(ScriptedPhase::new(
+            PhaseId::Design,
+            &[PhaseId::Requirements],
+            NextStep::Continue,
+        ));
+        handle.set_phase_override(requirements.clone());
+        handle.set_phase_override(design.clone());
+
+        let result = handle
+            .run_selected(&[PhaseId::Requirements, PhaseId::Design], &[])
+            .await
+            .unwrap();
+
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(result.phase, PhaseId::Design);
+        assert_eq!(requirements.runs(), 3);
+        assert_eq!(design.runs(), 1);
+
+        let receipt =
+            latest_receipt(&spec_id, PhaseId::Requirements).expect("requirements receipt");
+        assert_eq!(receipt.flags.get("repeats").map(String::as_str), Some("2"));
+    }
+
+    #[tokio::test]
+    async fn test_colliding_artifact_names_reject_run() {
+        let (mut handle, spec_id, _guard) = setup_handle_with_guard("handle-collision");
+
+        let requirements = Arc::new(ScriptedPhase::new(
+            PhaseId::Requirements,
+            &[],
+            NextStep::Continue,
+        ));
+        // Claims the artifact names Requirements already owns
+        let design = Arc::new(
+            ScriptedPhase::new(
+                PhaseId::Design,
+                &[PhaseId::Requirements],
+                NextStep::Continue,
+            )
+            .with_stem("00-requirements"),
+        );
+        handle.set_phase_override(requirements.clone());
+        handle.set_phase_override(design.clone());
+
+        let outcome = handle
+            .run_selected(&[PhaseId::Requirements, PhaseId::Design], &[])
+            .await;
+
+        let err = outcome.expect_err("colliding artifact names must fail the run");
+        match err.downcast_ref::<XCheckerError>() {
+            Some(XCheckerError::ArtifactNameCollision { name, phases }) => {
+                assert_eq!(name, "00-requirements.core.yaml");
+                assert_eq!(phases, &["design", "requirements"]);
+            }
+            other => panic!("expected ArtifactNameCollision, got {other:?}"),
+        }
+        assert_eq!(requirements.runs(), 1);
+        assert_eq!(design.runs(), 1);
+        assert!(latest_receipt(&spec_id, PhaseId::Design).is_none());
+        assert!(latest_receipt(&spec_id, PhaseId::Requirements).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_run_phase_honors_repeat() {
+        let (mut handle, _spec_id, _guard) = setup_handle_with_guard("handle-run-phase-repeat");
+
+        let requirements = Arc::new(ScriptedPhase::new(
+            PhaseId::Requirements,
+            &[],
+            NextStep::Repeat { max: 1 },
+        ));
+        handle.set_phase_override(requirements.clone());
+
+        let result = handle.run_phase(PhaseId::Requirements).await.unwrap();
+
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(requirements.runs(), 2);
+        let receipt = result.receipt.expect("requirements receipt");
+        assert_eq!(receipt.flags.get("repeats").map(String::as_str), Some("1"));
+    }
+
+    #[test]
+    fn test_artifact_name_collision_names_both_phases() {
+        let owners = [
+            ("security-scan", "report.md"),
+            ("security-scan", "60-security.core.yaml"),
+            ("license-audit", "report.md"),
+        ];
+
+        let err = find_artifact_name_collision(owners).unwrap_err();
+        match err {
+            XCheckerError::ArtifactNameCollision { name, phases } => {
+                assert_eq!(name, "report.md");
+                assert_eq!(phases, ["license-audit", "security-scan"]);
+            }
+            other => panic!("expected ArtifactNameCollision, got {other:?}"),
+        }
+
+        // A phase re-emitting its own artifact is not a collision
+        assert!(
+            find_artifact_name_collision([
+                ("requirements", "00-requirements.md"),
+                ("requirements", "00-requirements.md"),
+                ("design", "10-design.md"),
+            ])
+            .is_ok()
+        );
+    }
 }
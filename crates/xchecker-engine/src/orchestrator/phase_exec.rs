@@ -3,7 +3,8 @@
 //! This module contains phase execution code extracted from mod.rs.
 
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 use anyhow::{Context, Result};
 
@@ -11,12 +12,19 @@ use crate::error::{PhaseError, XCheckerError};
 use crate::exit_codes;
 use crate::fixup::FixupMode;
 use crate::hooks::{HookContext, HookExecutor, HookType, execute_and_process_hook};
+use crate::metrics;
 use crate::packet::PacketBuilder;
-use crate::phase::{Phase, PhaseContext};
-use crate::phases::{DesignPhase, FixupPhase, RequirementsPhase, ReviewPhase, TasksPhase};
-use crate::status::artifact::{Artifact, ArtifactType};
-use crate::types::{ErrorKind, FileType, LlmInfo, PacketEvidence, PhaseId, PipelineInfo};
-
+use crate::phase::{NextStep, Phase, PhaseContext};
+use crate::phases::{
+    DesignPhase, FixupPhase, RequirementsPhase, ReviewPhase, TasksPhase, prompt_file,
+};
+use crate::status::artifact::{Artifact, ArtifactBody, ArtifactType, LineEndings};
+use crate::types::{
+    ErrorKind, FileType, LlmInfo, PacketEvidence, PhaseId, PipelineInfo, Receipt, TimingSpans,
+    ValidationMode,
+};
+
+use super::events::RunEventKind;
 use super::llm::{ClaudeExecutionMetadata, LlmInvocationError};
 use super::{OrchestratorConfig, PhaseOrchestrator, PhaseTimeout};
 
@@ -34,6 +42,7 @@ use super::{OrchestratorConfig, PhaseOrchestrator, PhaseTimeout};
 /// - `exit_code`: Exit code from the phase execution (0 = success, see `exit_codes` module)
 /// - `artifact_paths`: Paths to artifacts that were created (empty on failure)
 /// - `receipt_path`: Path to the receipt file (always present, even on failure)
+/// - `receipt`: The receipt written to `receipt_path`, kept in memory
 /// - `error`: Human-readable error message if execution failed
 #[derive(Debug)]
 pub struct ExecutionResult {
@@ -47,6 +56,10 @@ pub struct ExecutionResult {
     pub artifact_paths: Vec<PathBuf>,
     /// Path to the receipt file
     pub receipt_path: Option<PathBuf>,
+    /// The receipt written to `receipt_path`
+    pub receipt: Option<Receipt>,
+    /// What the phase asked to happen next; `Continue` for failed runs
+    pub next_step: NextStep,
     /// Any error that occurred during execution
     pub error: Option<String>,
 }
@@ -145,6 +158,8 @@ pub struct ExecutionResult {
 pub(crate) struct PhaseCoreOutput {
     /// Metadata about files included in the LLM packet
     pub packet_evidence: PacketEvidence,
+    /// Receipt flags describing the packet (see `packet_receipt_flags`)
+    pub packet_flags: HashMap<String, String>,
     /// Exit code from LLM execution (0 = success, non-zero = failure)
     pub claude_exit_code: i32,
     /// Optional LLM execution metadata (model, version, runner info, stderr)
@@ -155,6 +170,10 @@ pub(crate) struct PhaseCoreOutput {
     pub llm_fallback_warning: Option<String>,
     /// Postprocessed artifacts with parsed content from LLM response
     pub phase_result: xchecker_phase_api::PhaseResult,
+    /// Fingerprint of the phase's inputs, taken before execution
+    pub input_fingerprint: Option<String>,
+    /// BLAKE3 of the packet sent to the LLM
+    pub packet_hash: String,
 }
 
 /// Execute a phase with timeout enforcement
@@ -191,6 +210,76 @@ where
     }
 }
 
+/// Receipt flags describing a packet
+///
+/// Records the full and preview sizes when the preview was truncated by
+/// `[packet] preview_max_bytes`, and the comma-separated paths of files
+/// included verbatim under `[packet] redaction_exempt`. Empty otherwise.
+fn packet_receipt_flags(packet: &crate::packet::Packet) -> HashMap<String, String> {
+    let mut flags = HashMap::new();
+    if packet.is_preview_truncated() {
+        flags.insert("packet_bytes".to_string(), packet.body().len().to_string());
+        flags.insert(
+            "packet_preview_bytes".to_string(),
+            packet.preview().len().to_string(),
+        );
+    }
+    let exempt: Vec<&str> = packet
+        .evidence
+        .files
+        .iter()
+        .filter(|file| file.redaction_exempt)
+        .map(|file| file.path.as_str())
+        .collect();
+    if !exempt.is_empty() {
+        flags.insert("redaction_exempt".to_string(), exempt.join(","));
+    }
+    flags
+}
+
+/// Line ending handling for staged artifacts; normalized unless
+/// `normalize_line_endings` is set to `false`
+fn line_endings_from_config(config: &OrchestratorConfig) -> LineEndings {
+    match config
+        .config
+        .get("normalize_line_endings")
+        .map(String::as_str)
+    {
+        Some("false") => LineEndings::Preserve,
+        _ => LineEndings::Normalize,
+    }
+}
+
+/// Validation mode for phase outputs; `validation_mode` in the config map
+/// wins over the `strict_validation` flag
+fn validation_mode_from_config(config: &OrchestratorConfig) -> ValidationMode {
+    config
+        .config
+        .get("validation_mode")
+        .and_then(|value| value.parse::<ValidationMode>().ok())
+        .unwrap_or(if config.strict_validation {
+            ValidationMode::Strict
+        } else {
+            ValidationMode::Warn
+        })
+}
+
+/// Wrap a `make_packet` failure as `PacketCreationFailed`, except that a
+/// missing dependency artifact is passed through so it keeps its exit code.
+fn packet_creation_error(phase_id: PhaseId, err: anyhow::Error) -> XCheckerError {
+    match err.downcast::<XCheckerError>() {
+        Ok(err @ XCheckerError::MissingDependencyArtifact { .. }) => err,
+        Ok(err) => XCheckerError::Phase(PhaseError::PacketCreationFailed {
+            phase: phase_id.as_str().to_string(),
+            reason: err.to_string(),
+        }),
+        Err(err) => XCheckerError::Phase(PhaseError::PacketCreationFailed {
+            phase: phase_id.as_str().to_string(),
+            reason: err.to_string(),
+        }),
+    }
+}
+
 impl PhaseOrchestrator {
     /// Execute the Requirements phase end-to-end with timeout.
     ///
@@ -273,7 +362,9 @@ impl PhaseOrchestrator {
     /// Resume execution from a specific phase.
     ///
     /// Validates that all dependencies are satisfied before executing.
-    /// Use this to continue a workflow from any valid phase.
+    /// Use this to continue a workflow from any valid phase. A phase that
+    /// returns `NextStep::Repeat { max }` is re-run in place, at most `max`
+    /// more times.
     ///
     /// # Arguments
     /// * `phase_id` - The phase to resume from
@@ -289,9 +380,54 @@ impl PhaseOrchestrator {
         // Validate transition before execution (FR-ORC-001, FR-ORC-002)
         self.validate_transition(phase_id)?;
 
+        // Refuse to build on upstream artifacts edited since their receipts
+        let accept_modified = config
+            .config
+            .get("accept_modified")
+            .is_some_and(|s| s == "true");
+        self.verify_upstream_artifacts(phase_id, accept_modified)?;
+
         // Use phase factory to get the appropriate phase implementation
         let phase = self.get_phase_impl(phase_id, config)?;
-        self.execute_phase_with_resume(phase.as_ref(), config).await
+        let mut result = self
+            .execute_phase_with_resume(phase.as_ref(), config)
+            .await?;
+
+        // A phase may ask to be re-run; the first request fixes the budget
+        let mut repeats = 0;
+        let mut repeat_budget: Option<u32> = None;
+        while result.success
+            && let NextStep::Repeat { max } = result.next_step
+            && repeats < *repeat_budget.get_or_insert(max)
+        {
+            repeats += 1;
+            println!(
+                "Repeating phase: {} (repeat #{repeats}/{})",
+                phase_id.as_str(),
+                repeat_budget.unwrap_or(max)
+            );
+            // Recorded on the receipt as the `repeats` flag
+            let mut config = config.clone();
+            config
+                .config
+                .insert("repeats".to_string(), repeats.to_string());
+            result = self
+                .execute_phase_with_resume(phase.as_ref(), &config)
+                .await?;
+        }
+
+        // An applied fixup rewrote upstream artifacts; record their new hashes
+        if result.success
+            && phase_id == PhaseId::Fixup
+            && config
+                .config
+                .get("apply_fixups")
+                .is_some_and(|s| s == "true")
+        {
+            self.refresh_fixup_applied_hashes()?;
+        }
+
+        Ok(result)
     }
 
     /// Execute a phase with timeout handling
@@ -349,7 +485,7 @@ impl PhaseOrchestrator {
         // Store partial artifact
         let partial_artifact = Artifact {
             name: partial_filename,
-            content: partial_content.clone(),
+            content: ArtifactBody::Text(partial_content.clone()),
             artifact_type: ArtifactType::Partial,
             blake3_hash: blake3::hash(partial_content.as_bytes())
                 .to_hex()
@@ -364,6 +500,7 @@ impl PhaseOrchestrator {
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            preview_compressed: false,
         };
 
         let mut flags = HashMap::new();
@@ -413,6 +550,8 @@ impl PhaseOrchestrator {
             exit_code: exit_codes::codes::PHASE_TIMEOUT,
             artifact_paths: vec![partial_path.into_std_path_buf()],
             receipt_path: Some(receipt_path.into_std_path_buf()),
+            receipt: Some(receipt),
+            next_step: NextStep::Continue,
             error: Some(format!("Phase timed out after {timeout_seconds} seconds")),
         })
     }
@@ -445,7 +584,27 @@ impl PhaseOrchestrator {
         }
 
         // Execute the phase normally
-        let result = self.execute_phase(phase, config).await?;
+        let started_at = chrono::Utc::now();
+        let outcome = self.execute_phase(phase, config).await;
+        let audited = self.record_audit_entry(phase_id, started_at, &outcome);
+        // Consumers see every phase finish, including ones that abort with an error
+        let (success, exit_code) = super::outcome_status(&outcome);
+        self.emit_event(RunEventKind::PhaseCompleted {
+            phase: phase_id.as_str(),
+            success,
+            exit_code,
+        });
+        let result = match outcome {
+            Ok(result) => result,
+            Err(e) => {
+                // The phase error is the one to report; keep the audit failure visible
+                if let Err(audit_err) = audited {
+                    tracing::warn!("{audit_err:#}");
+                }
+                return Err(e);
+            }
+        };
+        audited?;
 
         // If successful and we had a partial, clean up any remaining partials
         if result.success {
@@ -498,16 +657,18 @@ impl PhaseOrchestrator {
         // Check dependencies
         self.check_phase_dependencies(phase)?;
 
+        // Fingerprint inputs before anything is written (receipt metadata only)
+        let input_fingerprint = self.input_fingerprint(phase_id, config).ok();
+
         // Step 1: Generate prompt
-        let prompt = phase.prompt(&phase_context);
+        let prompt = self.phase_prompt(phase, &phase_context, config)?;
 
         // Step 2: Build packet (FR-ORC-003)
-        let packet = phase.make_packet(&phase_context).map_err(|e| {
-            XCheckerError::Phase(PhaseError::PacketCreationFailed {
-                phase: phase_id.as_str().to_string(),
-                reason: e.to_string(),
-            })
-        })?;
+        let packet_started = std::time::Instant::now();
+        let packet = phase
+            .make_packet(&phase_context)
+            .map_err(|e| packet_creation_error(phase_id, e))?;
+        let packet_build_ms = packet_started.elapsed().as_millis() as u64;
 
         // Log packet hash and budget usage for visibility
         let budget = packet.budget_usage();
@@ -524,12 +685,14 @@ impl PhaseOrchestrator {
         );
 
         let packet_evidence = packet.evidence.clone();
+        let packet_hash = packet.hash().to_string();
 
         // Step 3: Scan for secrets (FR-ORC-003, FR-SEC)
         let redactor = config.redactor.as_ref();
 
-        // Check for secrets in the packet content - return error immediately if found
-        if redactor.has_secrets(&packet.content, "packet")? {
+        // Check for secrets in the packet content - return error immediately if found.
+        // Redaction-exempt files are left out of the scan.
+        if redactor.has_secrets(&packet.scannable_body(), "packet")? {
             return Err(XCheckerError::Phase(PhaseError::ExecutionFailed {
                 phase: phase_id.as_str().to_string(),
                 code: exit_codes::codes::SECRET_DETECTED,
@@ -537,10 +700,13 @@ impl PhaseOrchestrator {
             .into());
         }
 
-        // Store packet for debugging/preview
-        let _packet_preview_path = self
-            .artifact_manager()
-            .store_context_file(&format!("{}-packet", phase_id.as_str()), &packet.content)?;
+        // Store packet for debugging/preview; a compressed preview has
+        // already been written by the packet builder
+        if !packet.evidence.preview_compressed {
+            let _packet_preview_path = self
+                .artifact_manager()
+                .store_context_file(&format!("{}-packet", phase_id.as_str()), &packet.preview())?;
+        }
 
         // Step 4: Write full debug packet if --debug-packet flag is set (FR-PKT-006, FR-PKT-007)
         // Only write after secret scan passes; file is excluded from receipts
@@ -570,8 +736,12 @@ impl PhaseOrchestrator {
         }
 
         // Step 5: Execute LLM (or simulate in dry-run mode)
+        let llm_started = std::time::Instant::now();
         let (claude_response, claude_exit_code, claude_metadata, llm_result, llm_fallback_warning) =
-            if config.dry_run {
+            if let Some(fixture_dir) = config.config.get("fixture_dir") {
+                let (response, metadata, llm) = self.fixture_llm_response(phase_id, fixture_dir)?;
+                (response, 0, Some(metadata), Some(llm), None)
+            } else if config.dry_run {
                 let simulated_llm = self.simulate_llm_result(phase_id);
                 let simulated_metadata = super::llm::ClaudeExecutionMetadata {
                     model_alias: None,
@@ -594,9 +764,11 @@ impl PhaseOrchestrator {
                 self.run_llm_invocation(&prompt, &packet.content, phase_id, config)
                     .await?
             };
+        let llm_call_ms = llm_started.elapsed().as_millis() as u64;
 
         // Step 6: Postprocess Claude response (only if LLM succeeded)
-        let phase_result = if claude_exit_code == 0 {
+        let postprocess_started = std::time::Instant::now();
+        let mut phase_result = if claude_exit_code == 0 {
             phase
                 .postprocess(&claude_response, &phase_context)
                 .with_context(|| {
@@ -613,13 +785,20 @@ impl PhaseOrchestrator {
                 metadata: xchecker_phase_api::PhaseMetadata::default(),
             }
         };
+        phase_result.metadata.timings = Some(TimingSpans {
+            packet_build_ms,
+            llm_call_ms,
+            postprocess_ms: postprocess_started.elapsed().as_millis() as u64,
+        });
+
+        self.check_artifact_name_collisions(phase_id, &phase_result.artifacts)?;
 
         // Step 7: Write partial artifacts to .partial/ subdirectory (FR-ORC-004)
         for artifact in &phase_result.artifacts {
             // Store to .partial/ staging directory first
             let _partial_result = self
                 .artifact_manager()
-                .store_partial_staged_artifact(artifact)
+                .store_partial_staged_artifact(artifact, line_endings_from_config(config))
                 .with_context(|| format!("Failed to store partial artifact: {}", artifact.name))?;
         }
 
@@ -638,11 +817,14 @@ impl PhaseOrchestrator {
         // so we don't return phase_id, artifact_paths, output_hashes, or atomic_write_warnings
         Ok(PhaseCoreOutput {
             packet_evidence,
+            packet_flags: packet_receipt_flags(&packet),
             claude_exit_code,
             claude_metadata,
             llm_result,
             llm_fallback_warning,
             phase_result,
+            input_fingerprint,
+            packet_hash,
         })
     }
 
@@ -652,11 +834,31 @@ impl PhaseOrchestrator {
         phase: &dyn Phase,
         config: &OrchestratorConfig,
     ) -> Result<ExecutionResult> {
+        let started = std::time::Instant::now();
+        let result = self.execute_phase_steps(phase, config).await;
+        metrics::record_phase_run(
+            phase.id(),
+            result.as_ref().is_ok_and(|result| result.success),
+            started.elapsed(),
+        );
+        result
+    }
+
+    async fn execute_phase_steps(
+        &self,
+        phase: &dyn Phase,
+        config: &OrchestratorConfig,
+    ) -> Result<ExecutionResult> {
+        let started = std::time::Instant::now();
         let phase_id = phase.id();
         let pipeline_info = Some(PipelineInfo {
             execution_strategy: Some("controlled".to_string()),
         });
 
+        self.emit_event(RunEventKind::PhaseStarted {
+            phase: phase_id.as_str(),
+        });
+
         // Step 0: Remove stale .partial/ directories (FR-ORC-003, FR-ORC-007)
         self.artifact_manager()
             .remove_stale_partial_dir()
@@ -671,6 +873,10 @@ impl PhaseOrchestrator {
         // Check dependencies (Requirements phase has no deps)
         self.check_phase_dependencies(phase)?;
 
+        // Fingerprint inputs before anything is written; it is receipt metadata
+        // only, and unreadable context surfaces when the packet is built
+        let input_fingerprint = self.input_fingerprint(phase_id, config).ok();
+
         // Execute pre-phase hook if configured
         // Hooks run from invocation CWD so relative paths like ./scripts/... work
         let mut hook_warnings: Vec<String> = Vec::new();
@@ -707,6 +913,7 @@ impl PhaseOrchestrator {
                             files: vec![],
                             max_bytes: 65536,
                             max_lines: 1200,
+                            preview_compressed: false,
                         };
                         let mut flags = HashMap::new();
                         flags.insert("phase".to_string(), phase_id.as_str().to_string());
@@ -752,6 +959,8 @@ impl PhaseOrchestrator {
                             exit_code: exit_codes::codes::CLAUDE_FAILURE,
                             artifact_paths: vec![],
                             receipt_path: Some(receipt_path.into_std_path_buf()),
+                            receipt: Some(receipt),
+                            next_step: NextStep::Continue,
                             error: Some(error_reason),
                         });
                     }
@@ -765,6 +974,7 @@ impl PhaseOrchestrator {
                         files: vec![],
                         max_bytes: 65536,
                         max_lines: 1200,
+                        preview_compressed: false,
                     };
                     let mut flags = HashMap::new();
                     flags.insert("phase".to_string(), phase_id.as_str().to_string());
@@ -810,6 +1020,8 @@ impl PhaseOrchestrator {
                         exit_code: exit_codes::codes::CLAUDE_FAILURE,
                         artifact_paths: vec![],
                         receipt_path: Some(receipt_path.into_std_path_buf()),
+                        receipt: Some(receipt),
+                        next_step: NextStep::Continue,
                         error: Some(error_reason),
                     });
                 }
@@ -817,15 +1029,14 @@ impl PhaseOrchestrator {
         }
 
         // Generate prompt
-        let prompt = phase.prompt(&phase_context);
+        let prompt = self.phase_prompt(phase, &phase_context, config)?;
 
         // Step 3: Build packet (FR-ORC-003)
-        let packet = phase.make_packet(&phase_context).map_err(|e| {
-            XCheckerError::Phase(PhaseError::PacketCreationFailed {
-                phase: phase_id.as_str().to_string(),
-                reason: e.to_string(),
-            })
-        })?;
+        let packet_started = std::time::Instant::now();
+        let packet = phase
+            .make_packet(&phase_context)
+            .map_err(|e| packet_creation_error(phase_id, e))?;
+        let packet_build_ms = packet_started.elapsed().as_millis() as u64;
 
         // Log packet hash and budget usage for visibility
         let budget = packet.budget_usage();
@@ -840,18 +1051,27 @@ impl PhaseOrchestrator {
             lines_limit = budget.max_lines,
             "Built packet for phase"
         );
+        metrics::record_packet_bytes(phase_id, budget.bytes_used);
+        self.emit_event(RunEventKind::PacketBuilt {
+            phase: phase_id.as_str(),
+            packet_hash: packet.hash().to_string(),
+            bytes_used: budget.bytes_used,
+            lines_used: budget.lines_used,
+        });
 
         // Step 4: Scan for secrets (FR-ORC-003, FR-SEC)
         let redactor = config.redactor.as_ref();
 
-        // Check for secrets in the packet content
-        if redactor.has_secrets(&packet.content, "packet")? {
-            let matches = redactor.scan_for_secrets(&packet.content, "packet")?;
+        // Check for secrets in the packet content, leaving out redaction-exempt files
+        let scannable = packet.scannable_body();
+        if redactor.has_secrets(&scannable, "packet")? {
+            let matches = redactor.scan_for_secrets(&scannable, "packet")?;
 
             // Create error receipt for secret detection (FR-SEC, FR-EXIT)
             let packet_evidence = packet.evidence.clone();
             let mut flags = HashMap::new();
             flags.insert("phase".to_string(), phase_id.as_str().to_string());
+            flags.extend(packet_receipt_flags(&packet));
 
             let secret_patterns: Vec<String> =
                 matches.iter().map(|m| m.pattern_id.clone()).collect();
@@ -892,14 +1112,19 @@ impl PhaseOrchestrator {
                 exit_code: exit_codes::codes::SECRET_DETECTED,
                 artifact_paths: vec![],
                 receipt_path: Some(receipt_path.into_std_path_buf()),
+                receipt: Some(receipt),
+                next_step: NextStep::Continue,
                 error: Some(error_reason),
             });
         }
 
-        // Store packet for debugging/preview
-        let _packet_preview_path = self
-            .artifact_manager()
-            .store_context_file(&format!("{}-packet", phase_id.as_str()), &packet.content)?;
+        // Store packet for debugging/preview; a compressed preview has
+        // already been written by the packet builder
+        if !packet.evidence.preview_compressed {
+            let _packet_preview_path = self
+                .artifact_manager()
+                .store_context_file(&format!("{}-packet", phase_id.as_str()), &packet.preview())?;
+        }
 
         // Write full debug packet if --debug-packet flag is set (FR-PKT-006, FR-PKT-007)
         // Only write after secret scan passes; file is excluded from receipts
@@ -930,56 +1155,124 @@ impl PhaseOrchestrator {
         }
 
         // Execute LLM (or simulate in dry-run mode)
+        let llm_started = std::time::Instant::now();
         let mut llm_fallback_warning: Option<String> = None;
-        let (claude_response, claude_exit_code, claude_metadata, llm_result) = if config.dry_run {
-            let simulated_llm = self.simulate_llm_result(phase_id);
-            let simulated_metadata = super::llm::ClaudeExecutionMetadata {
-                model_alias: None,
-                model_full_name: "haiku".to_string(),
-                claude_cli_version: "0.8.1".to_string(),
-                fallback_used: false,
-                runner: "simulated".to_string(),
-                runner_distro: None,
-                stderr_tail: None,
-            };
-            (
-                self.simulate_claude_response(phase_id, &prompt),
-                0,
-                Some(simulated_metadata),
-                Some(simulated_llm),
-            )
-        } else {
-            // Use new LLM backend abstraction (V11: Claude CLI only)
-            match self
-                .run_llm_invocation(&prompt, &packet.content, phase_id, config)
-                .await
-            {
-                Ok((response, exit_code, metadata, result, fallback_warning)) => {
-                    llm_fallback_warning = fallback_warning;
-                    (response, exit_code, metadata, result)
-                }
-                Err(e) => {
-                    let (xchecker_err, fallback_warning) =
-                        if let Some(invocation_err) = e.downcast_ref::<LlmInvocationError>() {
-                            (
-                                invocation_err.error(),
-                                invocation_err.fallback_warning().map(|s| s.to_string()),
-                            )
-                        } else if let Some(xchecker_err) = e.downcast_ref::<XCheckerError>() {
-                            (xchecker_err, None)
-                        } else {
-                            return Err(e);
-                        };
-
-                    llm_fallback_warning = fallback_warning;
+        let (claude_response, claude_exit_code, claude_metadata, llm_result) =
+            if let Some(fixture_dir) = config.config.get("fixture_dir") {
+                let (response, metadata, llm) = self.fixture_llm_response(phase_id, fixture_dir)?;
+                (response, 0, Some(metadata), Some(llm))
+            } else if config.dry_run {
+                let simulated_llm = self.simulate_llm_result(phase_id);
+                let simulated_metadata = super::llm::ClaudeExecutionMetadata {
+                    model_alias: None,
+                    model_full_name: "haiku".to_string(),
+                    claude_cli_version: "0.8.1".to_string(),
+                    fallback_used: false,
+                    runner: "simulated".to_string(),
+                    runner_distro: None,
+                    stderr_tail: None,
+                };
+                (
+                    self.simulate_claude_response(phase_id, &prompt),
+                    0,
+                    Some(simulated_metadata),
+                    Some(simulated_llm),
+                )
+            } else {
+                // Use new LLM backend abstraction (V11: Claude CLI only)
+                match self
+                    .run_llm_invocation(&prompt, &packet.content, phase_id, config)
+                    .await
+                {
+                    Ok((response, exit_code, metadata, result, fallback_warning)) => {
+                        llm_fallback_warning = fallback_warning;
+                        (response, exit_code, metadata, result)
+                    }
+                    Err(e) => {
+                        let (xchecker_err, fallback_warning) =
+                            if let Some(invocation_err) = e.downcast_ref::<LlmInvocationError>() {
+                                (
+                                    invocation_err.error(),
+                                    invocation_err.fallback_warning().map(|s| s.to_string()),
+                                )
+                            } else if let Some(xchecker_err) = e.downcast_ref::<XCheckerError>() {
+                                (xchecker_err, None)
+                            } else {
+                                return Err(e);
+                            };
+
+                        llm_fallback_warning = fallback_warning;
+
+                        // Check if this is a budget exhaustion error by downcasting
+                        if let XCheckerError::Llm(llm_err) = xchecker_err {
+                            if matches!(llm_err, crate::llm::LlmError::BudgetExceeded { .. }) {
+                                // Handle budget exhaustion specially - create receipt with budget_exhausted flag
+                                let packet_evidence = packet.evidence.clone();
+                                let mut flags = HashMap::new();
+                                flags.insert("phase".to_string(), phase_id.as_str().to_string());
+                                flags.extend(packet_receipt_flags(&packet));
+
+                                // Use config values for truthful failure receipts (no hard-coded metadata)
+                                let configured_model =
+                                    config.config.get("model").map_or("unknown", |s| s.as_str());
+                                let configured_runner = config
+                                    .config
+                                    .get("runner_mode")
+                                    .map_or("unknown", |s| s.as_str());
+
+                                let mut warnings =
+                                    vec![format!("LLM budget exhausted: {}", llm_err)];
+                                if let Some(ref warning) = llm_fallback_warning {
+                                    warnings.push(warning.clone());
+                                }
+
+                                let mut receipt =
+                                    self.receipt_manager().create_receipt_with_redactor(
+                                        config.redactor.as_ref(),
+                                        self.spec_id(),
+                                        phase_id,
+                                        exit_codes::codes::CLAUDE_FAILURE, // Exit code 70
+                                        vec![],                            // No successful outputs
+                                        env!("CARGO_PKG_VERSION"),
+                                        "unknown", // Claude hasn't completed, so version is unknown
+                                        configured_model,
+                                        None, // No model alias
+                                        flags,
+                                        packet_evidence,
+                                        None, // No stderr_tail
+                                        None, // No stderr_redacted
+                                        warnings,
+                                        None, // No fallback
+                                        configured_runner,
+                                        None, // No runner distro
+                                        Some(ErrorKind::ClaudeFailure),
+                                        Some(llm_err.to_string()),
+                                        None, // No diff_context
+                                        pipeline_info.clone(),
+                                    );
+
+                                // Attach LlmInfo with budget_exhausted flag
+                                receipt.llm = Some(LlmInfo::for_budget_exhaustion());
+
+                                let receipt_path =
+                                    self.receipt_manager().write_receipt(&receipt)?;
+
+                                return Ok(ExecutionResult {
+                                    phase: phase_id,
+                                    success: false,
+                                    exit_code: exit_codes::codes::CLAUDE_FAILURE,
+                                    artifact_paths: vec![],
+                                    receipt_path: Some(receipt_path.into_std_path_buf()),
+                                    receipt: Some(receipt),
+                                    next_step: NextStep::Continue,
+                                    error: Some(llm_err.to_string()),
+                                });
+                            }
 
-                    // Check if this is a budget exhaustion error by downcasting
-                    if let XCheckerError::Llm(llm_err) = xchecker_err {
-                        if matches!(llm_err, crate::llm::LlmError::BudgetExceeded { .. }) {
-                            // Handle budget exhaustion specially - create receipt with budget_exhausted flag
                             let packet_evidence = packet.evidence.clone();
                             let mut flags = HashMap::new();
                             flags.insert("phase".to_string(), phase_id.as_str().to_string());
+                            flags.extend(packet_receipt_flags(&packet));
 
                             // Use config values for truthful failure receipts (no hard-coded metadata)
                             let configured_model =
@@ -989,7 +1282,48 @@ impl PhaseOrchestrator {
                                 .get("runner_mode")
                                 .map_or("unknown", |s| s.as_str());
 
-                            let mut warnings = vec![format!("LLM budget exhausted: {}", llm_err)];
+                            let (exit_code, error_kind) =
+                                exit_codes::error_to_exit_code_and_kind(xchecker_err);
+
+                            let invocation = self.build_llm_invocation(
+                                phase_id,
+                                &prompt,
+                                &packet.content,
+                                config,
+                            );
+                            let provider = self
+                                .config_from_orchestrator_config(config)
+                                .llm
+                                .provider
+                                .unwrap_or_else(|| "claude-cli".to_string());
+
+                            let mut llm_info = LlmInfo {
+                                provider: Some(provider),
+                                model_used: if invocation.model.is_empty() {
+                                    None
+                                } else {
+                                    Some(invocation.model.clone())
+                                },
+                                tokens_input: None,
+                                tokens_output: None,
+                                timed_out: None,
+                                timeout_seconds: Some(invocation.timeout.as_secs()),
+                                budget_exhausted: None,
+                            };
+
+                            let mut warnings = Vec::new();
+                            match llm_err {
+                                crate::llm::LlmError::Timeout { duration }
+                                | crate::llm::LlmError::ProviderTimeout { duration, .. } => {
+                                    llm_info.timed_out = Some(true);
+                                    llm_info.timeout_seconds = Some(duration.as_secs());
+                                    warnings.push(format!("phase_timeout:{}", duration.as_secs()));
+                                }
+                                _ => {
+                                    llm_info.timed_out = Some(false);
+                                    warnings.push(format!("llm_error:{}", llm_err));
+                                }
+                            }
                             if let Some(ref warning) = llm_fallback_warning {
                                 warnings.push(warning.clone());
                             }
@@ -998,8 +1332,8 @@ impl PhaseOrchestrator {
                                 config.redactor.as_ref(),
                                 self.spec_id(),
                                 phase_id,
-                                exit_codes::codes::CLAUDE_FAILURE, // Exit code 70
-                                vec![],                            // No successful outputs
+                                exit_code,
+                                vec![], // No successful outputs
                                 env!("CARGO_PKG_VERSION"),
                                 "unknown", // Claude hasn't completed, so version is unknown
                                 configured_model,
@@ -1012,122 +1346,39 @@ impl PhaseOrchestrator {
                                 None, // No fallback
                                 configured_runner,
                                 None, // No runner distro
-                                Some(ErrorKind::ClaudeFailure),
+                                Some(error_kind),
                                 Some(llm_err.to_string()),
                                 None, // No diff_context
                                 pipeline_info.clone(),
                             );
 
-                            // Attach LlmInfo with budget_exhausted flag
-                            receipt.llm = Some(LlmInfo::for_budget_exhaustion());
+                            receipt.llm = Some(llm_info);
 
                             let receipt_path = self.receipt_manager().write_receipt(&receipt)?;
 
                             return Ok(ExecutionResult {
                                 phase: phase_id,
                                 success: false,
-                                exit_code: exit_codes::codes::CLAUDE_FAILURE,
+                                exit_code,
                                 artifact_paths: vec![],
                                 receipt_path: Some(receipt_path.into_std_path_buf()),
+                                receipt: Some(receipt),
+                                next_step: NextStep::Continue,
                                 error: Some(llm_err.to_string()),
                             });
                         }
-
-                        let packet_evidence = packet.evidence.clone();
-                        let mut flags = HashMap::new();
-                        flags.insert("phase".to_string(), phase_id.as_str().to_string());
-
-                        // Use config values for truthful failure receipts (no hard-coded metadata)
-                        let configured_model =
-                            config.config.get("model").map_or("unknown", |s| s.as_str());
-                        let configured_runner = config
-                            .config
-                            .get("runner_mode")
-                            .map_or("unknown", |s| s.as_str());
-
-                        let (exit_code, error_kind) =
-                            exit_codes::error_to_exit_code_and_kind(xchecker_err);
-
-                        let invocation =
-                            self.build_llm_invocation(phase_id, &prompt, &packet.content, config);
-                        let provider = self
-                            .config_from_orchestrator_config(config)
-                            .llm
-                            .provider
-                            .unwrap_or_else(|| "claude-cli".to_string());
-
-                        let mut llm_info = LlmInfo {
-                            provider: Some(provider),
-                            model_used: if invocation.model.is_empty() {
-                                None
-                            } else {
-                                Some(invocation.model.clone())
-                            },
-                            tokens_input: None,
-                            tokens_output: None,
-                            timed_out: None,
-                            timeout_seconds: Some(invocation.timeout.as_secs()),
-                            budget_exhausted: None,
-                        };
-
-                        let mut warnings = Vec::new();
-                        match llm_err {
-                            crate::llm::LlmError::Timeout { duration } => {
-                                llm_info.timed_out = Some(true);
-                                llm_info.timeout_seconds = Some(duration.as_secs());
-                                warnings.push(format!("phase_timeout:{}", duration.as_secs()));
-                            }
-                            _ => {
-                                llm_info.timed_out = Some(false);
-                                warnings.push(format!("llm_error:{}", llm_err));
-                            }
-                        }
-                        if let Some(ref warning) = llm_fallback_warning {
-                            warnings.push(warning.clone());
-                        }
-
-                        let mut receipt = self.receipt_manager().create_receipt_with_redactor(
-                            config.redactor.as_ref(),
-                            self.spec_id(),
-                            phase_id,
-                            exit_code,
-                            vec![], // No successful outputs
-                            env!("CARGO_PKG_VERSION"),
-                            "unknown", // Claude hasn't completed, so version is unknown
-                            configured_model,
-                            None, // No model alias
-                            flags,
-                            packet_evidence,
-                            None, // No stderr_tail
-                            None, // No stderr_redacted
-                            warnings,
-                            None, // No fallback
-                            configured_runner,
-                            None, // No runner distro
-                            Some(error_kind),
-                            Some(llm_err.to_string()),
-                            None, // No diff_context
-                            pipeline_info.clone(),
-                        );
-
-                        receipt.llm = Some(llm_info);
-
-                        let receipt_path = self.receipt_manager().write_receipt(&receipt)?;
-
-                        return Ok(ExecutionResult {
-                            phase: phase_id,
-                            success: false,
-                            exit_code,
-                            artifact_paths: vec![],
-                            receipt_path: Some(receipt_path.into_std_path_buf()),
-                            error: Some(llm_err.to_string()),
-                        });
+                        // For other errors, propagate normally
+                        return Err(e);
                     }
-                    // For other errors, propagate normally
-                    return Err(e);
                 }
-            }
-        };
+            };
+        let llm_call_ms = llm_started.elapsed().as_millis() as u64;
+        metrics::record_llm_call(phase_id, config.dry_run);
+        self.emit_event(RunEventKind::LlmCalled {
+            phase: phase_id.as_str(),
+            exit_code: claude_exit_code,
+            dry_run: config.dry_run,
+        });
 
         // Handle Claude CLI failure (R4.3)
         if claude_exit_code != 0 {
@@ -1140,7 +1391,7 @@ impl PhaseOrchestrator {
 
             let partial_result = self.artifact_manager().store_artifact(&Artifact {
                 name: partial_filename.clone(),
-                content: claude_response.clone(),
+                content: ArtifactBody::Text(claude_response.clone()),
                 artifact_type: ArtifactType::Partial,
                 blake3_hash: blake3::hash(claude_response.as_bytes())
                     .to_hex()
@@ -1154,6 +1405,7 @@ impl PhaseOrchestrator {
 
             let mut flags = HashMap::new();
             flags.insert("phase".to_string(), phase_id.as_str().to_string());
+            flags.extend(packet_receipt_flags(&packet));
 
             let (model_alias, model_full_name) = if let Some(metadata) = &claude_metadata {
                 (
@@ -1169,6 +1421,16 @@ impl PhaseOrchestrator {
                 warnings.push(warning.clone());
             }
 
+            // Carry the CLI's own stderr into the receipt and the phase error (R4.3)
+            let stderr_excerpt = claude_metadata
+                .as_ref()
+                .and_then(|m| m.stderr_tail.as_deref())
+                .filter(|stderr| !stderr.trim().is_empty())
+                .map(|stderr| xchecker_utils::error::stderr_excerpt(stderr, &config.redactor));
+            let stderr_tail = stderr_excerpt
+                .clone()
+                .unwrap_or_else(|| "Claude CLI execution failed".to_string());
+
             let mut receipt = self.receipt_manager().create_receipt_with_redactor(
                 config.redactor.as_ref(),
                 self.spec_id(),
@@ -1183,8 +1445,8 @@ impl PhaseOrchestrator {
                 model_alias,
                 flags,
                 packet_evidence,
-                Some("Claude CLI execution failed".to_string()), // stderr_tail
-                None,                                            // stderr_redacted
+                Some(stderr_tail),
+                None, // stderr_redacted
                 warnings,
                 claude_metadata.as_ref().map(|m| m.fallback_used),
                 claude_metadata
@@ -1203,23 +1465,17 @@ impl PhaseOrchestrator {
 
             let receipt_path = self.receipt_manager().write_receipt(&receipt)?;
 
-            // Create enhanced error with stderr information (R4.3)
-            let stderr_info = claude_metadata
-                .as_ref()
-                .and_then(|m| m.stderr_tail.clone())
-                .unwrap_or_else(|| "No stderr captured".to_string());
-
-            let enhanced_error = if !stderr_info.is_empty() && stderr_info != "No stderr captured" {
-                XCheckerError::Phase(PhaseError::ExecutionFailedWithStderr {
+            // Surface the stderr excerpt when there is one (R4.3)
+            let enhanced_error = match stderr_excerpt {
+                Some(stderr_tail) => XCheckerError::Phase(PhaseError::ExecutionFailedWithStderr {
                     phase: phase_id.as_str().to_string(),
                     code: claude_exit_code,
-                    stderr_tail: stderr_info,
-                })
-            } else {
-                XCheckerError::Phase(PhaseError::PartialOutputSaved {
+                    stderr_tail,
+                }),
+                None => XCheckerError::Phase(PhaseError::PartialOutputSaved {
                     phase: phase_id.as_str().to_string(),
                     partial_path: format!("artifacts/{partial_filename}"),
-                })
+                }),
             };
 
             return Ok(ExecutionResult {
@@ -1228,12 +1484,15 @@ impl PhaseOrchestrator {
                 exit_code: claude_exit_code,
                 artifact_paths: vec![partial_path.into_std_path_buf()], // Include partial artifact
                 receipt_path: Some(receipt_path.into_std_path_buf()),
+                receipt: Some(receipt),
+                next_step: NextStep::Continue,
                 error: Some(enhanced_error.to_string()),
             });
         }
 
         // Process Claude response
-        let phase_result = phase
+        let postprocess_started = std::time::Instant::now();
+        let mut phase_result = phase
             .postprocess(&claude_response, &phase_context)
             .with_context(|| {
                 format!(
@@ -1241,6 +1500,13 @@ impl PhaseOrchestrator {
                     phase_id.as_str()
                 )
             })?;
+        phase_result.metadata.timings = Some(TimingSpans {
+            packet_build_ms,
+            llm_call_ms,
+            postprocess_ms: postprocess_started.elapsed().as_millis() as u64,
+        });
+
+        self.check_artifact_name_collisions(phase_id, &phase_result.artifacts)?;
 
         // Step 7: Write partial artifacts to .partial/ subdirectory (FR-ORC-004)
         let mut artifact_paths = Vec::new();
@@ -1251,7 +1517,7 @@ impl PhaseOrchestrator {
             // Store to .partial/ staging directory first
             let partial_result = self
                 .artifact_manager()
-                .store_partial_staged_artifact(artifact)
+                .store_partial_staged_artifact(artifact, line_endings_from_config(config))
                 .with_context(|| format!("Failed to store partial artifact: {}", artifact.name))?;
 
             // Collect atomic write warnings
@@ -1271,21 +1537,22 @@ impl PhaseOrchestrator {
                     _ => FileType::Text,
                 }
             };
-
-            let file_hash = self
-                .receipt_manager()
-                .create_file_hash(
-                    &format!("artifacts/{}", artifact.name),
-                    &artifact.content,
-                    file_type,
-                    phase_id.as_str(),
-                )
-                .map_err(|e| {
-                    XCheckerError::Phase(PhaseError::OutputValidationFailed {
-                        phase: phase_id.as_str().to_string(),
-                        reason: e.to_string(),
-                    })
-                })?;
+            let artifact_path = format!("artifacts/{}", artifact.name);
+            let file_hash = match &artifact.content {
+                ArtifactBody::Text(text) => self
+                    .receipt_manager()
+                    .create_file_hash(&artifact_path, text, file_type, phase_id.as_str())
+                    .map_err(|e| {
+                        XCheckerError::Phase(PhaseError::OutputValidationFailed {
+                            phase: phase_id.as_str().to_string(),
+                            reason: e.to_string(),
+                        })
+                    })?,
+                // Binary output has no canonical form; hash the raw bytes
+                ArtifactBody::Bytes(bytes) => self
+                    .receipt_manager()
+                    .create_bytes_hash(&artifact_path, bytes),
+            };
 
             output_hashes.push(file_hash);
         }
@@ -1308,6 +1575,13 @@ impl PhaseOrchestrator {
 
         let mut flags = HashMap::new();
         flags.insert("phase".to_string(), phase_id.as_str().to_string());
+        flags.extend(packet_receipt_flags(&packet));
+        if let NextStep::Stop { reason } = &phase_result.next_step {
+            flags.insert("stop_reason".to_string(), reason.clone());
+        }
+        if let Some(repeats) = config.config.get("repeats") {
+            flags.insert("repeats".to_string(), repeats.clone());
+        }
 
         let (model_alias, model_full_name) = if let Some(metadata) = &claude_metadata {
             (
@@ -1357,6 +1631,11 @@ impl PhaseOrchestrator {
         );
         // Set LLM info from the invocation result (V11+ multi-provider support)
         receipt.llm = llm_result.map(|r| r.into_llm_info());
+        receipt.record_validation_errors(phase_result.metadata.validation_errors.clone());
+        receipt.input_fingerprint = input_fingerprint;
+        receipt.packet_hash = Some(packet.hash().to_string());
+        receipt.duration_ms = Some(started.elapsed().as_millis() as u64);
+        receipt.timings = phase_result.metadata.timings;
 
         let receipt_path = self
             .receipt_manager()
@@ -1420,6 +1699,8 @@ impl PhaseOrchestrator {
             exit_code: 0,
             artifact_paths,
             receipt_path: Some(receipt_path.into_std_path_buf()),
+            receipt: Some(receipt),
+            next_step: phase_result.next_step,
             error: None,
         })
     }
@@ -1448,11 +1729,34 @@ impl PhaseOrchestrator {
             config: config.config.clone(),
             artifacts,
             selectors: config.selectors.clone(),
-            strict_validation: config.strict_validation,
+            validation_mode: validation_mode_from_config(config),
             redactor: config.redactor.clone(),
+            problem_transform: self.problem_transform.clone(),
         })
     }
 
+    /// Prompt for `phase`: its configured prompt file if any, else the built-in one
+    pub(crate) fn phase_prompt(
+        &self,
+        phase: &dyn Phase,
+        phase_context: &PhaseContext,
+        config: &OrchestratorConfig,
+    ) -> Result<String> {
+        let phase_id = phase.id();
+        let cfg = self.config_from_orchestrator_config(config);
+        let Some(path) = cfg.prompt_file_for_phase(phase_id) else {
+            return Ok(phase.prompt(phase_context));
+        };
+
+        let template = prompt_file::load_prompt_template(Path::new(path), phase_id)
+            .map_err(XCheckerError::Template)?;
+        Ok(prompt_file::render_prompt_template(
+            &template,
+            phase_id,
+            phase_context,
+        ))
+    }
+
     /// Check that phase dependencies are satisfied
     pub(crate) fn check_phase_dependencies(&self, phase: &dyn Phase) -> Result<()> {
         let deps = phase.deps();
@@ -1493,6 +1797,55 @@ impl PhaseOrchestrator {
         .with_extension("dry_run", serde_json::json!(true))
     }
 
+    /// Read the canned model response for `phase_id` from
+    /// `<fixture_dir>/<phase>.md` (`runner.fixture_dir`)
+    ///
+    /// The response goes through `postprocess` like a real one, so whole
+    /// pipelines run deterministically without any LLM.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PhaseError::FixtureMissing` if the fixture file does not exist.
+    pub(crate) fn fixture_llm_response(
+        &self,
+        phase_id: PhaseId,
+        fixture_dir: &str,
+    ) -> Result<(String, ClaudeExecutionMetadata, crate::llm::LlmResult)> {
+        let path = Path::new(fixture_dir).join(format!("{}.md", phase_id.as_str()));
+        let response = match std::fs::read_to_string(&path) {
+            Ok(response) => response,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Err(XCheckerError::Phase(PhaseError::FixtureMissing {
+                    phase: phase_id.as_str().to_string(),
+                    path: path.display().to_string(),
+                })
+                .into());
+            }
+            Err(e) => {
+                return Err(e)
+                    .with_context(|| format!("Failed to read fixture {}", path.display()));
+            }
+        };
+
+        let metadata = ClaudeExecutionMetadata {
+            model_alias: None,
+            model_full_name: "fixture".to_string(),
+            claude_cli_version: "fixture".to_string(),
+            fallback_used: false,
+            runner: "fixture".to_string(),
+            runner_distro: None,
+            stderr_tail: None,
+        };
+        let llm_result = crate::llm::LlmResult::new(
+            response.clone(),
+            "fixture".to_string(),
+            "fixture".to_string(),
+        )
+        .with_extension("fixture", serde_json::json!(path.display().to_string()));
+
+        Ok((response, metadata, llm_result))
+    }
+
     /// Simulate Claude CLI response for testing/dry-run
     pub(crate) fn simulate_claude_response(&self, _phase_id: PhaseId, _prompt: &str) -> String {
         match _phase_id {
@@ -1711,12 +2064,16 @@ The system implements comprehensive error handling with:
         &self,
         phase_id: PhaseId,
         config: &OrchestratorConfig,
-    ) -> Result<Box<dyn Phase>> {
+    ) -> Result<Arc<dyn Phase>> {
+        if let Some(phase) = self.phase_overrides.get(&phase_id) {
+            return Ok(Arc::clone(phase));
+        }
+
         match phase_id {
-            PhaseId::Requirements => Ok(Box::new(RequirementsPhase::new())),
-            PhaseId::Design => Ok(Box::new(DesignPhase::new())),
-            PhaseId::Tasks => Ok(Box::new(TasksPhase::new())),
-            PhaseId::Review => Ok(Box::new(ReviewPhase::new())),
+            PhaseId::Requirements => Ok(Arc::new(RequirementsPhase::new())),
+            PhaseId::Design => Ok(Arc::new(DesignPhase::new())),
+            PhaseId::Tasks => Ok(Arc::new(TasksPhase::new())),
+            PhaseId::Review => Ok(Arc::new(ReviewPhase::new())),
             PhaseId::Fixup => {
                 // Determine fixup mode from configuration (FR-FIX-004, FR-FIX-005)
                 let apply_fixups = config
@@ -1730,7 +2087,7 @@ The system implements comprehensive error handling with:
                     FixupMode::Preview
                 };
 
-                Ok(Box::new(FixupPhase::new_with_mode(fixup_mode)))
+                Ok(Arc::new(FixupPhase::new_with_mode(fixup_mode)))
             }
             PhaseId::Final => Err(anyhow::anyhow!("Final phase not yet implemented")),
         }
@@ -0,0 +1,253 @@
+//! Snapshot of an in-progress run for crash recovery
+//!
+//! After each phase the handle writes `run-state.json` to the spec root: the
+//! last completed phase, the phase to resume at, the LLM calls made so far,
+//! the completed phase's input fingerprint, the run-shaping config overrides
+//! in [`RESUMED_CONFIG_KEYS`] and a BLAKE3 of every artifact on disk. A snapshot is only restored while
+//! the receipts and artifacts still match it.
+
+use std::collections::BTreeMap;
+
+use anyhow::{Context, Result};
+use camino::Utf8PathBuf;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::atomic_write::write_file_atomic;
+use crate::canonicalization::emit_jcs;
+use crate::error::XCheckerError;
+use crate::types::PhaseId;
+
+use super::{OrchestratorConfig, PhaseOrchestrator};
+
+/// File name of the run state snapshot, relative to the spec root
+pub const RUN_STATE_FILE: &str = "run-state.json";
+
+/// Current `run-state.json` schema version
+pub const RUN_STATE_SCHEMA_VERSION: &str = "1";
+
+/// Config overrides a restored handle needs to produce the same outputs
+///
+/// Only these keys are saved and restored. Everything else is either
+/// rediscovered from the config file on restore or local to the process
+/// that was interrupted, such as binary paths, log verbosity and lock TTLs.
+pub const RESUMED_CONFIG_KEYS: &[&str] = &[
+    "apply_fixups",
+    "execution_strategy",
+    "fixture_dir",
+    "forbidden_opening_phrases",
+    "llm_provider",
+    "max_turns",
+    "model",
+    "output_format",
+    "packet_max_bytes",
+    "packet_max_lines",
+    "phase_timeout",
+    "problem_statement",
+    "prompt_template",
+    "validation_mode",
+];
+
+/// Whether `key` is one of the [`RESUMED_CONFIG_KEYS`]
+pub(crate) fn is_resumed_config_key(key: &str) -> bool {
+    RESUMED_CONFIG_KEYS.contains(&key)
+}
+
+/// State of a run between phases, as persisted in `run-state.json`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunState {
+    pub schema_version: String,
+    pub spec_id: String,
+    pub saved_at: DateTime<Utc>,
+    /// Last phase with a successful receipt when the snapshot was taken
+    pub last_completed_phase: Option<PhaseId>,
+    /// Phase a restored handle resumes at; `None` once Final has completed
+    pub next_phase: Option<PhaseId>,
+    /// LLM invocations attempted so far, failed ones included
+    pub llm_calls: u32,
+    /// `input_fingerprint` from the last completed phase's receipt
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input_fingerprint: Option<String>,
+    /// BLAKE3 of each file in `artifacts/`, keyed by file name
+    pub artifacts: BTreeMap<String, String>,
+    /// Orchestrator config overrides in effect for the run, limited to
+    /// [`RESUMED_CONFIG_KEYS`]
+    pub config: BTreeMap<String, String>,
+    pub dry_run: bool,
+}
+
+/// Phase that follows `completed` in workflow order
+///
+/// Nothing completed means the run starts at Requirements.
+#[must_use]
+pub(crate) fn next_phase_after(completed: Option<PhaseId>) -> Option<PhaseId> {
+    match completed {
+        None => Some(PhaseId::Requirements),
+        Some(phase) => PhaseId::ALL
+            .iter()
+            .position(|p| *p == phase)
+            .and_then(|idx| PhaseId::ALL.get(idx + 1))
+            .copied(),
+    }
+}
+
+impl PhaseOrchestrator {
+    /// Path of this spec's `run-state.json`
+    pub(crate) fn run_state_path(&self) -> Utf8PathBuf {
+        self.artifact_manager().base_path().join(RUN_STATE_FILE)
+    }
+
+    /// Capture the current run state under `config`
+    pub(crate) fn capture_run_state(&self, config: &OrchestratorConfig) -> Result<RunState> {
+        let last_completed_phase = self.get_current_phase_state()?;
+        let input_fingerprint = match last_completed_phase {
+            Some(phase) => self
+                .receipt_manager()
+                .read_latest_receipt(phase)?
+                .and_then(|receipt| receipt.input_fingerprint),
+            None => None,
+        };
+
+        Ok(RunState {
+            schema_version: RUN_STATE_SCHEMA_VERSION.to_string(),
+            spec_id: self.spec_id.clone(),
+            saved_at: Utc::now(),
+            last_completed_phase,
+            next_phase: next_phase_after(last_completed_phase),
+            llm_calls: self.llm_calls(),
+            input_fingerprint,
+            artifacts: self.hash_artifacts()?,
+            config: config
+                .config
+                .iter()
+                .filter(|(key, _)| is_resumed_config_key(key))
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect(),
+            dry_run: config.dry_run,
+        })
+    }
+
+    /// Write `state` to `run-state.json` atomically
+    pub(crate) fn write_run_state(&self, state: &RunState) -> Result<()> {
+        let path = self.run_state_path();
+        let json = emit_jcs(state).context("Failed to serialize run state")?;
+        write_file_atomic(&path, &json)
+            .with_context(|| format!("Failed to write run state: {path}"))?;
+        Ok(())
+    }
+
+    /// Read `run-state.json`, failing if it is missing or unreadable
+    pub(crate) fn read_run_state(&self) -> Result<RunState, XCheckerError> {
+        let path = self.run_state_path();
+        let invalid = |reason: String| XCheckerError::RunStateInvalid {
+            path: path.to_string(),
+            reason,
+        };
+
+        let json = std::fs::read_to_string(&path).map_err(|e| match e.kind() {
+            std::io::ErrorKind::NotFound => invalid("no run state has been saved".to_string()),
+            _ => invalid(e.to_string()),
+        })?;
+        serde_json::from_str(&json).map_err(|e| invalid(format!("malformed JSON: {e}")))
+    }
+
+    /// Check that `state` still describes this spec's receipts and artifacts
+    ///
+    /// The last completed phase must be unchanged, and every artifact
+    /// recorded in the snapshot must still exist with the same hash.
+    /// Artifacts written after the snapshot (e.g. partial output of the
+    /// phase that was interrupted) are allowed.
+    pub(crate) fn validate_run_state(&self, state: &RunState) -> Result<(), XCheckerError> {
+        let invalid = |reason: String| XCheckerError::RunStateInvalid {
+            path: self.run_state_path().to_string(),
+            reason,
+        };
+
+        if state.schema_version != RUN_STATE_SCHEMA_VERSION {
+            return Err(invalid(format!(
+                "unsupported schema_version '{}'",
+                state.schema_version
+            )));
+        }
+        if state.spec_id != self.spec_id {
+            return Err(invalid(format!(
+                "snapshot belongs to spec '{}'",
+                state.spec_id
+            )));
+        }
+
+        let completed = self
+            .get_current_phase_state()
+            .map_err(|e| invalid(format!("{e:#}")))?;
+        if completed != state.last_completed_phase {
+            let describe = |phase: Option<PhaseId>| phase.map_or("none", |p| p.as_str());
+            return Err(invalid(format!(
+                "snapshot was taken after phase {} but receipts show {}",
+                describe(state.last_completed_phase),
+                describe(completed)
+            )));
+        }
+
+        let artifacts = self
+            .hash_artifacts()
+            .map_err(|e| invalid(format!("{e:#}")))?;
+        for (name, hash) in &state.artifacts {
+            match artifacts.get(name) {
+                None => return Err(invalid(format!("artifact '{name}' is missing"))),
+                Some(current) if current != hash => {
+                    return Err(invalid(format!(
+                        "artifact '{name}' changed since the snapshot"
+                    )));
+                }
+                Some(_) => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// BLAKE3 of each file directly under `artifacts/`, keyed by file name
+    fn hash_artifacts(&self) -> Result<BTreeMap<String, String>> {
+        let dir = self.artifact_manager().artifacts_path();
+        let mut hashes = BTreeMap::new();
+        if !dir.exists() {
+            return Ok(hashes);
+        }
+
+        for entry in dir
+            .read_dir_utf8()
+            .with_context(|| format!("Failed to list artifacts: {dir}"))?
+        {
+            let entry = entry.with_context(|| format!("Failed to list artifacts: {dir}"))?;
+            if !entry.file_type()?.is_file() {
+                continue;
+            }
+            let bytes = std::fs::read(entry.path())
+                .with_context(|| format!("Failed to read artifact: {}", entry.path()))?;
+            hashes.insert(
+                entry.file_name().to_string(),
+                blake3::hash(&bytes).to_hex().to_string(),
+            );
+        }
+        Ok(hashes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_phase_follows_workflow_order() {
+        assert_eq!(next_phase_after(None), Some(PhaseId::Requirements));
+        assert_eq!(
+            next_phase_after(Some(PhaseId::Requirements)),
+            Some(PhaseId::Design)
+        );
+        assert_eq!(
+            next_phase_after(Some(PhaseId::Tasks)),
+            Some(PhaseId::Review)
+        );
+        assert_eq!(next_phase_after(Some(PhaseId::Final)), None);
+    }
+}
@@ -10,7 +10,9 @@ use crate::error::{PhaseError, XCheckerError};
 use crate::fixup::FixupMode;
 use crate::phase::Phase;
 use crate::phases::{DesignPhase, FixupPhase, RequirementsPhase, ReviewPhase, TasksPhase};
+use crate::status::artifact::ArtifactBody;
 use crate::types::{FileType, PhaseId, PipelineInfo};
+use xchecker_phase_api::NextStep;
 
 use super::{OrchestratorConfig, PhaseOrchestrator};
 
@@ -29,6 +31,8 @@ pub(crate) struct WorkflowResult {
     pub total_rewinds: usize,
     /// Final error if the workflow failed
     pub final_error: Option<String>,
+    /// Reason given by a phase that stopped the workflow early
+    pub stop_reason: Option<String>,
 }
 
 /// Information about a single phase execution within a workflow.
@@ -46,6 +50,10 @@ pub(crate) struct PhaseExecution {
     pub rewind_triggered: bool,
     /// The target phase for rewind (if any)
     pub rewind_target: Option<PhaseId>,
+    /// Number of times the phase was re-run at its own request
+    pub repeats: u32,
+    /// Reason given if the phase stopped the workflow
+    pub stop_reason: Option<String>,
     /// Any error that occurred during execution
     pub error: Option<String>,
 }
@@ -63,10 +71,30 @@ pub(crate) struct PhaseExecutionResult {
     pub rewind_triggered: bool,
     /// The target phase for rewind (if any)
     pub rewind_target: Option<PhaseId>,
+    /// Number of times the phase was re-run at its own request
+    pub repeats: u32,
+    /// Whether the phase ended the workflow (`Stop` or `Complete`)
+    pub terminal: bool,
+    /// Reason given if the phase stopped the workflow
+    pub stop_reason: Option<String>,
     /// Any error that occurred during execution
     pub error: Option<String>,
 }
 
+impl PhaseExecutionResult {
+    fn failed(error: String) -> Self {
+        Self {
+            success: false,
+            rewind_triggered: false,
+            rewind_target: None,
+            repeats: 0,
+            terminal: false,
+            stop_reason: None,
+            error: Some(error),
+        }
+    }
+}
+
 impl PhaseOrchestrator {
     /// Execute the complete spec generation workflow with rewind support.
     ///
@@ -79,35 +107,53 @@ impl PhaseOrchestrator {
     pub(crate) async fn execute_complete_workflow(
         &self,
         config: &OrchestratorConfig,
+    ) -> Result<WorkflowResult> {
+        // Determine fixup mode from configuration
+        let apply_fixups = config
+            .config
+            .get("apply_fixups")
+            .is_some_and(|s| s == "true");
+        let fixup_mode = if apply_fixups {
+            FixupMode::Apply
+        } else {
+            FixupMode::Preview
+        };
+
+        // Define the standard phase order
+        // (Final is not implemented yet, so the workflow ends after Fixup)
+        let requirements = RequirementsPhase::new();
+        let design = DesignPhase::new();
+        let tasks = TasksPhase::new();
+        let review = ReviewPhase::new();
+        let fixup = FixupPhase::new_with_mode(fixup_mode);
+        let standard_phases: [&dyn Phase; 5] = [&requirements, &design, &tasks, &review, &fixup];
+
+        self.execute_workflow(&standard_phases, config).await
+    }
+
+    /// Execute `phases` in order, honoring each phase's `NextStep`.
+    ///
+    /// `Rewind` jumps back (at most `MAX_REWIND_COUNT` times), `Repeat`
+    /// re-runs the phase in place, and `Stop`/`Complete` end the workflow
+    /// successfully without running later phases.
+    pub(crate) async fn execute_workflow(
+        &self,
+        phases: &[&dyn Phase],
+        config: &OrchestratorConfig,
     ) -> Result<WorkflowResult> {
         let mut rewind_count = 0;
         const MAX_REWIND_COUNT: usize = 2;
         let mut execution_history = Vec::new();
 
-        // Define the standard phase order
-        let standard_phases = [
-            PhaseId::Requirements,
-            PhaseId::Design,
-            PhaseId::Tasks,
-            PhaseId::Review,
-            PhaseId::Fixup,
-            PhaseId::Final,
-        ];
-
         let mut current_phase_index = 0;
 
-        while current_phase_index < standard_phases.len() {
-            let phase_id = standard_phases[current_phase_index];
-
-            // Skip Final phase for now (not implemented)
-            if phase_id == PhaseId::Final {
-                break;
-            }
+        while let Some(&phase) = phases.get(current_phase_index) {
+            let phase_id = phase.id();
 
             println!("Executing phase: {}", phase_id.as_str());
 
             let result = match self
-                .execute_single_phase_with_rewind_support(phase_id, config)
+                .execute_single_phase_with_rewind_support(phase, config)
                 .await
             {
                 Ok(result) => result,
@@ -117,6 +163,8 @@ impl PhaseOrchestrator {
                         success: false,
                         rewind_triggered: false,
                         rewind_target: None,
+                        repeats: 0,
+                        stop_reason: None,
                         error: Some(e.to_string()),
                     });
 
@@ -125,6 +173,7 @@ impl PhaseOrchestrator {
                         completed_phases: execution_history,
                         total_rewinds: rewind_count,
                         final_error: Some(e.to_string()),
+                        stop_reason: None,
                     });
                 }
             };
@@ -134,6 +183,8 @@ impl PhaseOrchestrator {
                 success: result.success,
                 rewind_triggered: result.rewind_triggered,
                 rewind_target: result.rewind_target,
+                repeats: result.repeats,
+                stop_reason: result.stop_reason.clone(),
                 error: result.error.clone(),
             });
 
@@ -143,6 +194,21 @@ impl PhaseOrchestrator {
                     completed_phases: execution_history,
                     total_rewinds: rewind_count,
                     final_error: result.error,
+                    stop_reason: None,
+                });
+            }
+
+            // Stop early if the phase asked to
+            if result.terminal {
+                if let Some(reason) = &result.stop_reason {
+                    println!("Stopping after phase {}: {reason}", phase_id.as_str());
+                }
+                return Ok(WorkflowResult {
+                    success: true,
+                    completed_phases: execution_history,
+                    total_rewinds: rewind_count,
+                    final_error: None,
+                    stop_reason: result.stop_reason,
                 });
             }
 
@@ -156,6 +222,7 @@ impl PhaseOrchestrator {
                         final_error: Some(format!(
                             "Maximum rewind count ({MAX_REWIND_COUNT}) exceeded"
                         )),
+                        stop_reason: None,
                     });
                 }
 
@@ -163,9 +230,7 @@ impl PhaseOrchestrator {
 
                 if let Some(target_phase) = result.rewind_target {
                     // Find the target phase index
-                    if let Some(target_index) =
-                        standard_phases.iter().position(|&p| p == target_phase)
-                    {
+                    if let Some(target_index) = phases.iter().position(|p| p.id() == target_phase) {
                         current_phase_index = target_index;
                         println!(
                             "Rewinding to phase: {} (rewind #{}/{})",
@@ -183,6 +248,7 @@ impl PhaseOrchestrator {
                             "Invalid rewind target: {}",
                             target_phase.as_str()
                         )),
+                        stop_reason: None,
                     });
                 }
             }
@@ -196,6 +262,7 @@ impl PhaseOrchestrator {
             completed_phases: execution_history,
             total_rewinds: rewind_count,
             final_error: None,
+            stop_reason: None,
         })
     }
 
@@ -205,12 +272,13 @@ impl PhaseOrchestrator {
     /// and detects rewind requests from `NextStep` results.
     ///
     /// This is not part of the public API.
-    #[allow(dead_code)] // Future-facing: used for phase execution with rewind
     async fn execute_single_phase_with_rewind_support(
         &self,
-        phase_id: PhaseId,
+        phase: &dyn Phase,
         config: &OrchestratorConfig,
     ) -> Result<PhaseExecutionResult> {
+        let phase_id = phase.id();
+
         // Check dependencies first
         if !self.can_resume_from_phase(phase_id)? {
             return Err(XCheckerError::Phase(PhaseError::DependencyNotSatisfied {
@@ -220,55 +288,8 @@ impl PhaseOrchestrator {
             .into());
         }
 
-        // Create the appropriate phase instance
-        let execution_result = match phase_id {
-            PhaseId::Requirements => {
-                let phase = RequirementsPhase::new();
-                self.execute_phase_with_next_step_handling(&phase, config)
-                    .await?
-            }
-            PhaseId::Design => {
-                let phase = DesignPhase::new();
-                self.execute_phase_with_next_step_handling(&phase, config)
-                    .await?
-            }
-            PhaseId::Tasks => {
-                let phase = TasksPhase::new();
-                self.execute_phase_with_next_step_handling(&phase, config)
-                    .await?
-            }
-            PhaseId::Review => {
-                let phase = ReviewPhase::new();
-                self.execute_phase_with_next_step_handling(&phase, config)
-                    .await?
-            }
-            PhaseId::Fixup => {
-                // Determine fixup mode from configuration
-                let apply_fixups = config
-                    .config
-                    .get("apply_fixups")
-                    .is_some_and(|s| s == "true");
-
-                let fixup_mode = if apply_fixups {
-                    FixupMode::Apply
-                } else {
-                    FixupMode::Preview
-                };
-
-                let phase = FixupPhase::new_with_mode(fixup_mode);
-                self.execute_phase_with_next_step_handling(&phase, config)
-                    .await?
-            }
-            PhaseId::Final => {
-                return Err(XCheckerError::Phase(PhaseError::InvalidTransition {
-                    from: "current state".to_string(),
-                    to: "Final phase not yet implemented".to_string(),
-                })
-                .into());
-            }
-        };
-
-        Ok(execution_result)
+        self.execute_phase_with_next_step_handling(phase, config)
+            .await
     }
 
     /// Execute a phase and handle `NextStep` results.
@@ -277,49 +298,82 @@ impl PhaseOrchestrator {
     /// from the phase result to determine if rewind is requested.
     ///
     /// This is not part of the public API.
-    #[allow(dead_code)] // Future-facing: used for phase execution with next step handling
-    async fn execute_phase_with_next_step_handling<P: Phase>(
+    async fn execute_phase_with_next_step_handling(
         &self,
-        phase: &P,
+        phase: &dyn Phase,
         config: &OrchestratorConfig,
     ) -> Result<PhaseExecutionResult> {
+        let started = std::time::Instant::now();
         let phase_id = phase.id();
 
-        // ORC-002: Route through execute_phase_core for unified execution logic
-        // Note: execute_phase_core may return an error for secret detection,
-        // but workflow needs to return Ok(PhaseExecutionResult) with success=false
-        let core = match self.execute_phase_core(phase, config).await {
-            Ok(core_output) => core_output,
-            Err(e) => {
-                // Handle secret detection error specially for workflow
-                if let Some(xchecker_err) = e.downcast_ref::<XCheckerError>()
-                    && let XCheckerError::Phase(PhaseError::ExecutionFailed { phase: _, code }) =
-                        xchecker_err
-                    && *code == crate::exit_codes::codes::SECRET_DETECTED
-                {
-                    return Ok(PhaseExecutionResult {
-                        success: false,
-                        rewind_triggered: false,
-                        rewind_target: None,
-                        error: Some("Secret detected in packet".to_string()),
-                    });
+        // A phase may ask to be re-run; the first request fixes the budget
+        let mut repeats = 0;
+        let mut repeat_budget: Option<u32> = None;
+
+        let core = loop {
+            // ORC-002: Route through execute_phase_core for unified execution logic
+            // Note: execute_phase_core may return an error for secret detection,
+            // but workflow needs to return Ok(PhaseExecutionResult) with success=false
+            let core = match self.execute_phase_core(phase, config).await {
+                Ok(core_output) => core_output,
+                Err(e) => {
+                    // Handle secret detection error specially for workflow
+                    if let Some(xchecker_err) = e.downcast_ref::<XCheckerError>()
+                        && let XCheckerError::Phase(PhaseError::ExecutionFailed { phase: _, code }) =
+                            xchecker_err
+                        && *code == crate::exit_codes::codes::SECRET_DETECTED
+                    {
+                        return Ok(PhaseExecutionResult::failed(
+                            "Secret detected in packet".to_string(),
+                        ));
+                    }
+                    // Propagate other errors
+                    return Err(e);
                 }
-                // Propagate other errors
-                return Err(e);
+            };
+
+            if core.claude_exit_code == 0
+                && let NextStep::Repeat { max } = core.phase_result.next_step
+                && repeats < *repeat_budget.get_or_insert(max)
+            {
+                repeats += 1;
+                println!(
+                    "Repeating phase: {} (repeat #{repeats}/{})",
+                    phase_id.as_str(),
+                    repeat_budget.unwrap_or(max)
+                );
+                continue;
             }
+            break core;
         };
 
         // Handle Claude CLI failure
         if core.claude_exit_code != 0 {
-            return Ok(PhaseExecutionResult {
-                success: false,
-                rewind_triggered: false,
-                rewind_target: None,
-                error: Some(format!(
-                    "Claude CLI failed with exit code: {}",
-                    core.claude_exit_code
-                )),
-            });
+            let error = core
+                .claude_metadata
+                .as_ref()
+                .and_then(|m| m.stderr_tail.as_deref())
+                .filter(|stderr| !stderr.trim().is_empty())
+                .map_or_else(
+                    || {
+                        format!(
+                            "Claude CLI failed with exit code: {}",
+                            core.claude_exit_code
+                        )
+                    },
+                    |stderr| {
+                        XCheckerError::Phase(PhaseError::ExecutionFailedWithStderr {
+                            phase: phase_id.as_str().to_string(),
+                            code: core.claude_exit_code,
+                            stderr_tail: xchecker_utils::error::stderr_excerpt(
+                                stderr,
+                                &config.redactor,
+                            ),
+                        })
+                        .to_string()
+                    },
+                );
+            return Ok(PhaseExecutionResult::failed(error));
         }
 
         // Workflow-specific behavior: Store artifacts directly (not staged)
@@ -343,21 +397,22 @@ impl PhaseOrchestrator {
                     _ => FileType::Text,
                 }
             };
-
-            let file_hash = self
-                .receipt_manager()
-                .create_file_hash(
-                    &format!("artifacts/{}", artifact.name),
-                    &artifact.content,
-                    file_type,
-                    phase_id.as_str(),
-                )
-                .map_err(|e| {
-                    XCheckerError::Phase(PhaseError::OutputValidationFailed {
-                        phase: phase_id.as_str().to_string(),
-                        reason: e.to_string(),
-                    })
-                })?;
+            let artifact_path = format!("artifacts/{}", artifact.name);
+            let file_hash = match &artifact.content {
+                ArtifactBody::Text(text) => self
+                    .receipt_manager()
+                    .create_file_hash(&artifact_path, text, file_type, phase_id.as_str())
+                    .map_err(|e| {
+                        XCheckerError::Phase(PhaseError::OutputValidationFailed {
+                            phase: phase_id.as_str().to_string(),
+                            reason: e.to_string(),
+                        })
+                    })?,
+                // Binary output has no canonical form; hash the raw bytes
+                ArtifactBody::Bytes(bytes) => self
+                    .receipt_manager()
+                    .create_bytes_hash(&artifact_path, bytes),
+            };
 
             output_hashes.push(file_hash);
         }
@@ -365,17 +420,32 @@ impl PhaseOrchestrator {
         // Prepare receipt flags
         let mut flags = std::collections::HashMap::new();
         flags.insert("phase".to_string(), phase_id.as_str().to_string());
+        flags.extend(core.packet_flags.clone());
 
         // Extract rewind information from phase_result.next_step (FR-WORKFLOW)
         let (rewind_triggered, rewind_target) = match &core.phase_result.next_step {
-            xchecker_phase_api::NextStep::Rewind { to } => {
+            NextStep::Rewind { to } => {
                 flags.insert("rewind_triggered".to_string(), "true".to_string());
                 flags.insert("rewind_target".to_string(), to.as_str().to_string());
                 (true, Some(*to))
             }
-            xchecker_phase_api::NextStep::Continue => (false, None),
-            xchecker_phase_api::NextStep::Complete => (false, None),
+            NextStep::Continue | NextStep::Complete | NextStep::Stop { .. } => (false, None),
+            // Repeat budget exhausted; carry on as normal
+            NextStep::Repeat { .. } => (false, None),
         };
+        if repeats > 0 {
+            flags.insert("repeats".to_string(), repeats.to_string());
+        }
+
+        // Stop and Complete both end the workflow; only Stop carries a reason
+        let terminal = core.phase_result.is_terminal();
+        let stop_reason = match &core.phase_result.next_step {
+            NextStep::Stop { reason } => Some(reason.clone()),
+            _ => None,
+        };
+        if let Some(reason) = &stop_reason {
+            flags.insert("stop_reason".to_string(), reason.clone());
+        }
 
         // Extract model information from claude_metadata
         let (model_alias, model_full_name) = if let Some(metadata) = &core.claude_metadata {
@@ -427,6 +497,11 @@ impl PhaseOrchestrator {
 
         // Set LLM info from invocation result (V11+ multi-provider support)
         receipt.llm = core.llm_result.map(|r| r.into_llm_info());
+        receipt.record_validation_errors(core.phase_result.metadata.validation_errors.clone());
+        receipt.input_fingerprint = core.input_fingerprint.clone();
+        receipt.packet_hash = Some(core.packet_hash.clone());
+        receipt.duration_ms = Some(started.elapsed().as_millis() as u64);
+        receipt.timings = core.phase_result.metadata.timings;
 
         let _receipt_path = self
             .receipt_manager()
@@ -437,6 +512,9 @@ impl PhaseOrchestrator {
             success: true,
             rewind_triggered,
             rewind_target,
+            repeats,
+            terminal,
+            stop_reason,
             error: None,
         })
     }
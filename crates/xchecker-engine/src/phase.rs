@@ -4,7 +4,10 @@
 //! xchecker-phase-api, providing a stable facade for engine modules.
 
 // Re-export all Phase trait types from xchecker-phase-api
-pub use xchecker_phase_api::{NextStep, Phase, PhaseContext, PhaseId, PhaseMetadata, PhaseResult};
+pub use xchecker_phase_api::{
+    NextStep, Phase, PhaseContext, PhaseId, PhaseMetadata, PhaseResult, ProblemTransform,
+    ValidationMode,
+};
 
 // Re-export packet types for backward compatibility
 pub use xchecker_packet::{BudgetUsage, Packet};
@@ -4,7 +4,9 @@
 //! re-exporting phases from the xchecker-phases crate and the fixup module.
 
 // Phases moved to xchecker-phases crate:
-pub use xchecker_phases::{DesignPhase, RequirementsPhase, ReviewPhase, TasksPhase};
+pub use xchecker_phases::{
+    DesignPhase, RequirementsPhase, ReviewPhase, TasksPhase, packet_limits_from_config, prompt_file,
+};
 
 // FixupPhase remains in the fixup module until Wave 5
 pub use crate::fixup::FixupPhase;
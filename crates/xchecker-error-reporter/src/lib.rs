@@ -382,47 +382,7 @@ pub mod utils {
                             "Review the receipt file for complete stderr output (≤2 KiB)"
                                 .to_string(),
                         ];
-
-                        // Parse stderr for common issues
-                        if stderr_tail.contains("authentication") || stderr_tail.contains("auth") {
-                            suggestions.extend(vec![
-                                "Authentication issue detected - run: claude auth login"
-                                    .to_string(),
-                                "Check your Claude API key is valid and not expired".to_string(),
-                                "Verify your Claude account has API access".to_string(),
-                            ]);
-                        } else if stderr_tail.contains("rate limit") || stderr_tail.contains("429")
-                        {
-                            suggestions.extend(vec![
-                                "Rate limit exceeded - wait a few minutes and try again"
-                                    .to_string(),
-                                "Consider upgrading your Claude subscription for higher limits"
-                                    .to_string(),
-                            ]);
-                        } else if stderr_tail.contains("network")
-                            || stderr_tail.contains("connection")
-                        {
-                            suggestions.extend(vec![
-                                "Network connectivity issue - check your internet connection"
-                                    .to_string(),
-                                "Try again in a few minutes if this is a temporary network issue"
-                                    .to_string(),
-                            ]);
-                        } else if stderr_tail.contains("model") {
-                            suggestions.extend(vec![
-                                "Model access issue - check available models with: claude models"
-                                    .to_string(),
-                                "Try using a different model with --model flag".to_string(),
-                                "Verify your subscription includes access to the requested model"
-                                    .to_string(),
-                            ]);
-                        } else {
-                            suggestions.extend(vec![
-                                "Verify Claude CLI authentication and connectivity".to_string(),
-                                "Try running with --dry-run to test configuration".to_string(),
-                            ]);
-                        }
-
+                        suggestions.extend(stderr_suggestions(stderr_tail));
                         suggestions
                     }
                     xchecker_utils::error::PhaseError::PartialOutputSaved {
@@ -740,6 +700,17 @@ pub mod utils {
                 "Review the phase output for formatting issues".to_string(),
                 "Check for malformed markdown or YAML in the output".to_string(),
             ],
+            XCheckerError::RunnerFailed {
+                exit_code,
+                stderr_excerpt,
+            } => {
+                let mut suggestions = vec![
+                    format!("Runner exited with code {exit_code}"),
+                    "Review the receipt file for the same stderr excerpt (≤2 KiB)".to_string(),
+                ];
+                suggestions.extend(stderr_suggestions(stderr_excerpt));
+                suggestions
+            }
             XCheckerError::ModelResolutionError { alias, reason, .. } => vec![
                 format!("Could not resolve model '{}': {}", alias, reason),
                 "Check that the Claude CLI is properly installed and authenticated".to_string(),
@@ -751,6 +722,38 @@ pub mod utils {
         }
     }
 
+    /// Suggestions for common problems recognisable in a runner's stderr
+    fn stderr_suggestions(stderr: &str) -> Vec<String> {
+        if stderr.contains("authentication") || stderr.contains("auth") {
+            vec![
+                "Authentication issue detected - run: claude auth login".to_string(),
+                "Check your Claude API key is valid and not expired".to_string(),
+                "Verify your Claude account has API access".to_string(),
+            ]
+        } else if stderr.contains("rate limit") || stderr.contains("429") {
+            vec![
+                "Rate limit exceeded - wait a few minutes and try again".to_string(),
+                "Consider upgrading your Claude subscription for higher limits".to_string(),
+            ]
+        } else if stderr.contains("network") || stderr.contains("connection") {
+            vec![
+                "Network connectivity issue - check your internet connection".to_string(),
+                "Try again in a few minutes if this is a temporary network issue".to_string(),
+            ]
+        } else if stderr.contains("model") {
+            vec![
+                "Model access issue - check available models with: claude models".to_string(),
+                "Try using a different model with --model flag".to_string(),
+                "Verify your subscription includes access to the requested model".to_string(),
+            ]
+        } else {
+            vec![
+                "Verify Claude CLI authentication and connectivity".to_string(),
+                "Try running with --dry-run to test configuration".to_string(),
+            ]
+        }
+    }
+
     /// Create a comprehensive error report with enhanced context (R1.3, R6.4)
     #[must_use]
     fn create_comprehensive_report_inner(error: &XCheckerError) -> String {
@@ -1038,6 +1041,22 @@ mod tests {
         assert_eq!(exit_code, 2);
     }
 
+    #[test]
+    fn test_runner_failed_error_reporting() {
+        let error = XCheckerError::RunnerFailed {
+            exit_code: 1,
+            stderr_excerpt: "Error: 429 rate limit reached".to_string(),
+        };
+
+        let enhanced_context = utils::enhance_error_context(&error);
+        assert!(enhanced_context[0].contains("code 1"));
+        assert!(
+            enhanced_context
+                .iter()
+                .any(|line| line.contains("Rate limit exceeded"))
+        );
+    }
+
     #[test]
     fn test_enhanced_phase_error_reporting() {
         use xchecker_utils::error::{PhaseError, XCheckerError};
@@ -11,8 +11,11 @@
 //! - Provide useful metrics for dashboards and gate checks
 //!
 //! Future B3.1 will add structured extraction of full requirement/design objects.
+//! [`extract_ears_criteria`] is the first piece of that: it parses acceptance
+//! criteria into trigger/system/response parts.
 
 use regex::Regex;
+use serde::Serialize;
 
 /// Summary statistics extracted from a requirements markdown document
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
@@ -55,6 +58,30 @@ pub struct TasksSummary {
     pub dependency_count: usize,
 }
 
+/// Summary statistics extracted from a review markdown document
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ReviewSummary {
+    /// Issues listed under `## Requirements Issues`
+    pub requirements_issue_count: usize,
+    /// Issues listed under `## Design Issues`
+    pub design_issue_count: usize,
+    /// Issues listed under `## Tasks Issues`
+    pub tasks_issue_count: usize,
+    /// Contradictions listed under `## Cross-Phase Issues`
+    pub cross_phase_issue_count: usize,
+}
+
+impl ReviewSummary {
+    /// Issues found across all sections
+    #[must_use]
+    pub const fn total_issue_count(&self) -> usize {
+        self.requirements_issue_count
+            + self.design_issue_count
+            + self.tasks_issue_count
+            + self.cross_phase_issue_count
+    }
+}
+
 /// Extract summary metadata from a requirements markdown document
 ///
 /// Uses simple regex patterns to count well-formed requirements elements.
@@ -101,6 +128,119 @@ pub fn summarize_requirements(markdown: &str) -> RequirementsSummary {
     summary
 }
 
+/// Keyword that opens an EARS acceptance criterion
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EarsKind {
+    /// Event-driven: `WHEN <trigger> THEN <system> SHALL <response>`
+    When,
+    /// Unwanted behaviour: `IF <trigger> THEN <system> SHALL <response>`
+    If,
+    /// State-driven: `WHILE <trigger> THEN <system> SHALL <response>`
+    While,
+}
+
+/// A parsed EARS acceptance criterion
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct EarsCriterion {
+    /// 1-based position of the enclosing `### Requirement N` heading
+    /// (0 if the criterion appears before any requirement heading)
+    pub requirement_index: usize,
+    /// Opening keyword
+    pub kind: EarsKind,
+    /// Text between the keyword and `THEN`
+    pub trigger: String,
+    /// Text between `THEN` and `SHALL`
+    pub system: String,
+    /// Text after `SHALL`, without a trailing period
+    pub response: String,
+}
+
+/// A line that opens like an EARS criterion but could not be parsed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MalformedCriterion {
+    /// 1-based position of the enclosing requirement heading (0 if none)
+    pub requirement_index: usize,
+    /// 1-based line number in the source document
+    pub line_number: usize,
+    /// The offending line, trimmed
+    pub text: String,
+}
+
+/// Acceptance criteria extracted from a requirements document
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct EarsExtraction {
+    /// Criteria that parsed cleanly, in document order
+    pub criteria: Vec<EarsCriterion>,
+    /// Lines starting with `WHEN`/`IF`/`WHILE` that lack `THEN ... SHALL ...`
+    pub malformed: Vec<MalformedCriterion>,
+}
+
+/// Parse EARS acceptance criteria from a requirements markdown document
+///
+/// Candidate lines are list items (or bare lines) whose text starts with an
+/// upper-case `WHEN`, `IF` or `WHILE`; bold markers are ignored. Each is
+/// matched against `<KEYWORD> <trigger> THEN <system> SHALL <response>`.
+/// Candidates that do not fit are reported in
+/// [`EarsExtraction::malformed`] rather than dropped.
+#[must_use]
+pub fn parse_ears_criteria(requirements_md: &str) -> EarsExtraction {
+    let req_heading_re = Regex::new(r"(?i)^\s*#{2,3}\s+Requirement\s+\d+").unwrap();
+    let candidate_re = Regex::new(r"^(?:(?:\d+[.)]|[-*+])\s+)?(WHEN|IF|WHILE)\s").unwrap();
+    let ears_re = Regex::new(
+        r"^(?:(?:\d+[.)]|[-*+])\s+)?(WHEN|IF|WHILE)\s+(.+?)\s+THEN\s+(.+?)\s+SHALL\s+(.+?)\s*$",
+    )
+    .unwrap();
+
+    let mut extraction = EarsExtraction::default();
+    let mut requirement_index = 0;
+
+    for (line_index, raw_line) in requirements_md.lines().enumerate() {
+        if req_heading_re.is_match(raw_line) {
+            requirement_index += 1;
+            continue;
+        }
+
+        let line = raw_line.trim().replace("**", "");
+        if !candidate_re.is_match(&line) {
+            continue;
+        }
+
+        match ears_re.captures(&line) {
+            Some(caps) => {
+                let kind = match &caps[1] {
+                    "WHEN" => EarsKind::When,
+                    "IF" => EarsKind::If,
+                    _ => EarsKind::While,
+                };
+                extraction.criteria.push(EarsCriterion {
+                    requirement_index,
+                    kind,
+                    trigger: caps[2].to_string(),
+                    system: caps[3].to_string(),
+                    response: caps[4].trim_end_matches('.').to_string(),
+                });
+            }
+            None => extraction.malformed.push(MalformedCriterion {
+                requirement_index,
+                line_number: line_index + 1,
+                text: raw_line.trim().to_string(),
+            }),
+        }
+    }
+
+    extraction
+}
+
+/// Extract well-formed EARS acceptance criteria from a requirements document
+///
+/// Convenience wrapper over [`parse_ears_criteria`] for callers that only
+/// need the parsed criteria; use that function to also see malformed lines.
+#[must_use]
+pub fn extract_ears_criteria(requirements_md: &str) -> Vec<EarsCriterion> {
+    parse_ears_criteria(requirements_md).criteria
+}
+
 /// Extract summary metadata from a design markdown document
 ///
 /// Uses simple regex patterns to count well-formed design elements.
@@ -185,6 +325,57 @@ pub fn summarize_tasks(markdown: &str) -> TasksSummary {
     summary
 }
 
+/// Extract summary metadata from a review markdown document
+///
+/// Counts the top-level list items under each `## <Area> Issues` heading
+/// (Requirements, Design, Tasks, Cross-Phase). Nested items are details of
+/// their parent issue and are not counted; any other heading ends the
+/// section.
+///
+/// # Examples
+///
+/// ```ignore
+/// // Note: This doctest is marked ignore due to raw string handling in doc comments.
+/// // See unit tests below for equivalent coverage.
+/// use xchecker_extraction::summarize_review;
+///
+/// let markdown = "## Design Issues\n- Missing cache eviction\n";
+/// let summary = summarize_review(markdown);
+/// assert_eq!(summary.design_issue_count, 1);
+/// ```
+#[must_use]
+pub fn summarize_review(markdown: &str) -> ReviewSummary {
+    let mut summary = ReviewSummary::default();
+
+    let heading_re = Regex::new(r"^\s*#{1,6}\s").unwrap();
+    let section_re =
+        Regex::new(r"(?i)^\s*#{2,3}\s+(Requirements|Design|Tasks|Cross[-\s]Phase)\s+Issues")
+            .unwrap();
+    // Top-level items only: no indentation before the marker
+    let item_re = Regex::new(r"^([-*+]|\d+[.)])\s+\S").unwrap();
+
+    let mut section: Option<&mut usize> = None;
+    for line in markdown.lines() {
+        if heading_re.is_match(line) {
+            section =
+                section_re
+                    .captures(line)
+                    .map(|caps| match caps[1].to_ascii_lowercase().as_str() {
+                        "requirements" => &mut summary.requirements_issue_count,
+                        "design" => &mut summary.design_issue_count,
+                        "tasks" => &mut summary.tasks_issue_count,
+                        _ => &mut summary.cross_phase_issue_count,
+                    });
+        } else if let Some(count) = section.as_deref_mut()
+            && item_re.is_match(line)
+        {
+            *count += 1;
+        }
+    }
+
+    summary
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -249,6 +440,81 @@ NFR-AVAIL-003: 99.9% uptime
         assert_eq!(summary.user_story_count, 2);
     }
 
+    const EARS_SAMPLE: &str = r#"
+# Requirements Document
+
+### Requirement 1
+
+**User Story:** As a user, I want to log in, so that I can see my data.
+
+#### Acceptance Criteria
+
+1. WHEN a user submits valid credentials THEN the system SHALL create a session.
+2. IF the password is wrong THEN the system SHALL show an error message
+
+### Requirement 2
+
+#### Acceptance Criteria
+
+1. WHEN the session expires AND the user clicks a link THEN the auth service SHALL redirect to login
+2. WHEN the user logs out the session is gone
+3. **WHILE** a sync is running THEN the system SHALL disable the save button
+"#;
+
+    #[test]
+    fn test_extract_ears_when_and_if() {
+        let criteria = extract_ears_criteria(EARS_SAMPLE);
+        assert_eq!(criteria.len(), 4);
+
+        assert_eq!(
+            criteria[0],
+            EarsCriterion {
+                requirement_index: 1,
+                kind: EarsKind::When,
+                trigger: "a user submits valid credentials".to_string(),
+                system: "the system".to_string(),
+                response: "create a session".to_string(),
+            }
+        );
+        assert_eq!(
+            criteria[1],
+            EarsCriterion {
+                requirement_index: 1,
+                kind: EarsKind::If,
+                trigger: "the password is wrong".to_string(),
+                system: "the system".to_string(),
+                response: "show an error message".to_string(),
+            }
+        );
+
+        assert_eq!(criteria[2].requirement_index, 2);
+        assert_eq!(
+            criteria[2].trigger,
+            "the session expires AND the user clicks a link"
+        );
+        assert_eq!(criteria[2].system, "the auth service");
+        assert_eq!(criteria[3].kind, EarsKind::While);
+    }
+
+    #[test]
+    fn test_parse_ears_collects_malformed_lines() {
+        let extraction = parse_ears_criteria(EARS_SAMPLE);
+        assert_eq!(
+            extraction.malformed,
+            vec![MalformedCriterion {
+                requirement_index: 2,
+                line_number: 18,
+                text: "2. WHEN the user logs out the session is gone".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_ears_ignores_prose() {
+        let md = "If you read this, nothing SHALL happen.\nWhen in doubt, ask.\n";
+        assert_eq!(parse_ears_criteria(md), EarsExtraction::default());
+    }
+
     #[test]
     fn test_summarize_design_empty() {
         let summary = summarize_design("");
@@ -315,4 +581,44 @@ Depends on: Task 1
         assert_eq!(summary.milestone_count, 1);
         assert_eq!(summary.dependency_count, 1);
     }
+
+    #[test]
+    fn test_summarize_review_empty() {
+        let summary = summarize_review("# Review\n\nNo issues found.\n");
+        assert_eq!(summary, ReviewSummary::default());
+        assert_eq!(summary.total_issue_count(), 0);
+    }
+
+    #[test]
+    fn test_summarize_review_full() {
+        let md = r#"
+# Review
+
+## Requirements Issues
+
+- Requirement 3 has no acceptance criteria
+  - Detail that is not a separate issue
+- NFR for latency is missing
+
+## Design Issues
+
+1. Cache eviction is unspecified
+
+## Tasks Issues
+
+## Cross-Phase Issues
+
+* Design uses Postgres but Task 4 migrates SQLite
+
+## Summary
+
+- Not an issue
+"#;
+        let summary = summarize_review(md);
+        assert_eq!(summary.requirements_issue_count, 2);
+        assert_eq!(summary.design_issue_count, 1);
+        assert_eq!(summary.tasks_issue_count, 0);
+        assert_eq!(summary.cross_phase_issue_count, 1);
+        assert_eq!(summary.total_issue_count(), 4);
+    }
 }
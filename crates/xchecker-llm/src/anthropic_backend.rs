@@ -4,7 +4,9 @@
 //! which offers direct access to Claude models through their native API.
 
 use crate::LlmError;
-use crate::http_client::HttpClient;
+use crate::budgeted_backend::CallBudget;
+use crate::http_client::{HttpClient, RetryPolicy};
+use crate::sse;
 use crate::types::{LlmBackend, LlmInvocation, LlmResult, Message, Role};
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
@@ -73,6 +75,21 @@ impl AnthropicBackend {
         })
     }
 
+    /// Charge every HTTP retry against `budget`, shared with the
+    /// `BudgetedBackend` wrapping this backend
+    #[must_use]
+    pub(crate) fn with_call_budget(mut self, budget: CallBudget) -> Self {
+        self.client = Arc::new((*self.client).clone().with_call_budget(budget));
+        self
+    }
+
+    /// Retry failed HTTP requests according to `policy`
+    #[must_use]
+    pub(crate) fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.client = Arc::new((*self.client).clone().with_retry_policy(policy));
+        self
+    }
+
     /// Create a new Anthropic backend from configuration
     ///
     /// # Errors
@@ -134,7 +151,8 @@ impl AnthropicBackend {
                 .unwrap_or(0.2),
         };
 
-        Self::new(api_key, base_url, default_model, default_params)
+        Ok(Self::new(api_key, base_url, default_model, default_params)?
+            .with_retry_policy(RetryPolicy::from_config(config)))
     }
 
     /// Resolve parameters for this invocation
@@ -212,11 +230,15 @@ impl AnthropicBackend {
     }
 }
 
-#[async_trait]
-impl LlmBackend for AnthropicBackend {
-    async fn invoke(&self, inv: LlmInvocation) -> Result<LlmResult, LlmError> {
+impl AnthropicBackend {
+    /// Send `inv` to the Messages API, returning the resolved model and response
+    async fn send(
+        &self,
+        inv: &LlmInvocation,
+        stream: bool,
+    ) -> Result<(String, reqwest::Response), LlmError> {
         // Resolve parameters for this invocation
-        let (model, params) = self.resolve_params(&inv);
+        let (model, params) = self.resolve_params(inv);
 
         debug!(
             provider = "anthropic",
@@ -224,6 +246,7 @@ impl LlmBackend for AnthropicBackend {
             max_tokens = params.max_tokens,
             temperature = params.temperature,
             timeout_secs = inv.timeout.as_secs(),
+            stream,
             "Invoking Anthropic backend"
         );
 
@@ -237,6 +260,7 @@ impl LlmBackend for AnthropicBackend {
             max_tokens: params.max_tokens,
             temperature: params.temperature,
             system: system_prompt,
+            stream,
         };
 
         // Build HTTP request
@@ -253,24 +277,16 @@ impl LlmBackend for AnthropicBackend {
             .execute_with_retry(request, inv.timeout, "anthropic")
             .await?;
 
-        // Parse response
-        let response_body: AnthropicResponse = response.json().await.map_err(|e| {
-            LlmError::Transport(format!("Failed to parse Anthropic response: {}", e))
-        })?;
-
-        // Extract text content from content blocks
-        let mut content_parts = Vec::new();
-        for content_block in &response_body.content {
-            if content_block.content_type == "text"
-                && let Some(text) = &content_block.text
-            {
-                content_parts.push(text.clone());
-            }
-        }
-
-        // Concatenate all text segments
-        let content = content_parts.join("");
+        Ok((model, response))
+    }
 
+    /// Build the result for a completed response
+    fn finish(
+        content: String,
+        model: String,
+        usage: Option<Usage>,
+        inv: &LlmInvocation,
+    ) -> Result<LlmResult, LlmError> {
         if content.is_empty() {
             return Err(LlmError::Transport(
                 "Anthropic response missing text content".to_string(),
@@ -281,7 +297,7 @@ impl LlmBackend for AnthropicBackend {
         let mut result = LlmResult::new(content, "anthropic", model);
 
         // Add token counts if available
-        if let Some(usage) = response_body.usage {
+        if let Some(usage) = usage {
             result.tokens_input = Some(usage.input_tokens);
             result.tokens_output = Some(usage.output_tokens);
         }
@@ -301,6 +317,77 @@ impl LlmBackend for AnthropicBackend {
     }
 }
 
+#[async_trait]
+impl LlmBackend for AnthropicBackend {
+    async fn invoke(&self, inv: LlmInvocation) -> Result<LlmResult, LlmError> {
+        let (model, response) = self.send(&inv, false).await?;
+
+        // Parse response
+        let response_body: AnthropicResponse = response.json().await.map_err(|e| {
+            LlmError::Transport(format!("Failed to parse Anthropic response: {}", e))
+        })?;
+
+        // Extract text content from content blocks
+        let mut content_parts = Vec::new();
+        for content_block in &response_body.content {
+            if content_block.content_type == "text"
+                && let Some(text) = &content_block.text
+            {
+                content_parts.push(text.clone());
+            }
+        }
+
+        // Concatenate all text segments
+        let content = content_parts.join("");
+
+        Self::finish(content, model, response_body.usage, &inv)
+    }
+
+    async fn invoke_streaming(
+        &self,
+        inv: LlmInvocation,
+        on_token: &mut (dyn for<'t> FnMut(&'t str) + Send),
+    ) -> Result<LlmResult, LlmError> {
+        let (model, response) = self.send(&inv, true).await?;
+
+        let mut content = String::new();
+        let mut usage: Option<Usage> = None;
+        sse::for_each_event(response, "anthropic", |event| {
+            let event: StreamEvent = serde_json::from_str(&event.data).map_err(|e| {
+                LlmError::Transport(format!("Failed to parse Anthropic stream event: {}", e))
+            })?;
+            match event {
+                StreamEvent::MessageStart { message } => usage = message.usage,
+                StreamEvent::ContentBlockDelta { delta } => {
+                    if let Some(text) = delta.text.filter(|text| !text.is_empty()) {
+                        on_token(&text);
+                        content.push_str(&text);
+                    }
+                }
+                StreamEvent::MessageDelta {
+                    usage: Some(delta_usage),
+                } => {
+                    // The final delta carries the cumulative output token count
+                    if let Some(usage) = usage.as_mut() {
+                        usage.output_tokens = delta_usage.output_tokens;
+                    }
+                }
+                StreamEvent::Error { error } => {
+                    return Err(LlmError::ProviderOutage(format!(
+                        "Anthropic stream error: {}",
+                        error.message
+                    )));
+                }
+                StreamEvent::MessageDelta { usage: None } | StreamEvent::Other => {}
+            }
+            Ok(())
+        })
+        .await?;
+
+        Self::finish(content, model, usage, &inv)
+    }
+}
+
 /// Anthropic message format for requests
 #[derive(Debug, Clone, Serialize)]
 struct AnthropicMessage {
@@ -317,6 +404,8 @@ struct AnthropicRequest {
     temperature: f32,
     #[serde(skip_serializing_if = "Option::is_none")]
     system: Option<String>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    stream: bool,
 }
 
 /// Anthropic response body
@@ -341,6 +430,51 @@ struct Usage {
     output_tokens: u64,
 }
 
+/// Event in a streamed Anthropic response
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum StreamEvent {
+    MessageStart {
+        message: StreamMessage,
+    },
+    ContentBlockDelta {
+        delta: ContentDelta,
+    },
+    MessageDelta {
+        usage: Option<DeltaUsage>,
+    },
+    Error {
+        error: StreamError,
+    },
+    /// Pings, block start/stop and message stop carry nothing we need
+    #[serde(other)]
+    Other,
+}
+
+/// Message skeleton sent at the start of a stream
+#[derive(Debug, Clone, Deserialize)]
+struct StreamMessage {
+    usage: Option<Usage>,
+}
+
+/// Incremental content; only text deltas carry `text`
+#[derive(Debug, Clone, Deserialize)]
+struct ContentDelta {
+    text: Option<String>,
+}
+
+/// Usage update sent near the end of a stream
+#[derive(Debug, Clone, Deserialize)]
+struct DeltaUsage {
+    output_tokens: u64,
+}
+
+/// Error reported in-band after the stream has started
+#[derive(Debug, Clone, Deserialize)]
+struct StreamError {
+    message: String,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -522,6 +656,8 @@ mod tests {
             model: Some("test-model".to_string()),
             max_tokens: None,
             temperature: None,
+            budget: None,
+            timeout_secs: None,
         });
 
         let result = AnthropicBackend::new_from_config(&config);
@@ -560,6 +696,8 @@ mod tests {
             model: None, // Missing model
             max_tokens: None,
             temperature: None,
+            budget: None,
+            timeout_secs: None,
         });
 
         let result = AnthropicBackend::new_from_config(&config);
@@ -11,12 +11,106 @@ use std::sync::Arc;
 use std::sync::atomic::{AtomicU32, Ordering};
 use tracing::{debug, warn};
 
-/// Default budget limit for OpenRouter calls per process
+/// Default budget limit for HTTP provider calls per process
 pub(crate) const DEFAULT_BUDGET_LIMIT: u32 = 20;
 
-/// Environment variable for overriding the budget limit
+/// Environment variable for overriding the OpenRouter budget limit
 pub(crate) const BUDGET_ENV_VAR: &str = "XCHECKER_OPENROUTER_BUDGET";
 
+/// Environment variable for overriding the Anthropic budget limit
+pub(crate) const ANTHROPIC_BUDGET_ENV_VAR: &str = "XCHECKER_ANTHROPIC_BUDGET";
+
+/// Shared counter of attempted LLM calls against a limit
+///
+/// Clones share the count, so the HTTP client can charge its retries to the
+/// same budget as the [`BudgetedBackend`] that wraps its backend.
+#[derive(Debug, Clone)]
+pub(crate) struct CallBudget {
+    used: Arc<AtomicU32>,
+    limit: u32,
+}
+
+impl CallBudget {
+    pub(crate) fn new(limit: u32) -> Self {
+        Self {
+            used: Arc::new(AtomicU32::new(0)),
+            limit,
+        }
+    }
+
+    /// Count an attempted call, failing once the limit is exceeded
+    ///
+    /// Returns the 1-based number of this call.
+    pub(crate) fn reserve(&self) -> Result<u32, LlmError> {
+        // Increment counter BEFORE the call is made
+        // This ensures we track attempted calls, not successful requests
+        let current = self.used.fetch_add(1, Ordering::SeqCst);
+
+        // Check if we've exceeded the limit
+        if current >= self.limit {
+            let attempted = current + 1;
+            warn!(
+                limit = self.limit,
+                attempted = attempted,
+                "Budget limit exceeded"
+            );
+            return Err(LlmError::BudgetExceeded {
+                limit: self.limit,
+                attempted,
+            });
+        }
+
+        Ok(current + 1)
+    }
+
+    #[cfg(test)]
+    pub(crate) fn used(&self) -> u32 {
+        self.used.load(Ordering::SeqCst)
+    }
+}
+
+/// Resolve the budget limit from configuration
+///
+/// Precedence (highest to lowest):
+/// 1. Environment variable (`XCHECKER_OPENROUTER_BUDGET`)
+/// 2. Config file (`[llm.openrouter] budget`)
+/// 3. Default (20 calls per process)
+pub(crate) fn resolve_budget_limit(config_budget: Option<u32>) -> u32 {
+    resolve_budget_limit_from(BUDGET_ENV_VAR, config_budget)
+}
+
+/// Resolve a provider's budget limit from `env_var`, then its config
+/// `budget`, then the default
+pub(crate) fn resolve_budget_limit_from(env_var: &str, config_budget: Option<u32>) -> u32 {
+    let limit = std::env::var(env_var)
+        .ok()
+        .and_then(|s| s.parse::<u32>().ok())
+        .or(config_budget)
+        .unwrap_or(DEFAULT_BUDGET_LIMIT);
+
+    // Log the source of the budget limit
+    if let Ok(env_val) = std::env::var(env_var) {
+        if let Ok(env_limit) = env_val.parse::<u32>() {
+            debug!(
+                limit = env_limit,
+                default = DEFAULT_BUDGET_LIMIT,
+                "Using budget limit from environment variable {}",
+                env_var
+            );
+        }
+    } else if let Some(config_limit) = config_budget {
+        debug!(
+            limit = config_limit,
+            default = DEFAULT_BUDGET_LIMIT,
+            "Using budget limit from config file"
+        );
+    } else {
+        debug!(limit = DEFAULT_BUDGET_LIMIT, "Using default budget limit");
+    }
+
+    limit
+}
+
 /// A wrapper around an `LlmBackend` that enforces a budget limit on invocations.
 ///
 /// The budget tracks attempted calls, not successful requests. This means that
@@ -28,10 +122,8 @@ pub(crate) const BUDGET_ENV_VAR: &str = "XCHECKER_OPENROUTER_BUDGET";
 pub struct BudgetedBackend {
     /// The wrapped backend
     inner: Box<dyn LlmBackend>,
-    /// Thread-safe counter for tracking calls
-    budget: Arc<AtomicU32>,
-    /// Maximum number of calls allowed
-    limit: u32,
+    /// Thread-safe counter of attempted calls and their limit
+    budget: CallBudget,
 }
 
 impl BudgetedBackend {
@@ -42,12 +134,16 @@ impl BudgetedBackend {
     /// * `inner` - The backend to wrap
     /// * `limit` - Maximum number of invocations allowed
     pub fn new(inner: Box<dyn LlmBackend>, limit: u32) -> Self {
-        debug!(limit = limit, "Creating BudgetedBackend");
-        Self {
-            inner,
-            budget: Arc::new(AtomicU32::new(0)),
-            limit,
-        }
+        Self::with_call_budget(inner, CallBudget::new(limit))
+    }
+
+    /// Create a budgeted backend that charges calls to `budget`
+    ///
+    /// Use this when `inner` charges its own retries to a clone of the same
+    /// budget.
+    pub(crate) fn with_call_budget(inner: Box<dyn LlmBackend>, budget: CallBudget) -> Self {
+        debug!(limit = budget.limit, "Creating BudgetedBackend");
+        Self { inner, budget }
     }
 
     /// Create a new budgeted backend with the default limit
@@ -138,98 +234,75 @@ impl BudgetedBackend {
     /// }
     /// ```
     pub fn with_limit_from_config(inner: Box<dyn LlmBackend>, config_budget: Option<u32>) -> Self {
-        // Precedence: env var > config file > default
-        let limit = std::env::var(BUDGET_ENV_VAR)
-            .ok()
-            .and_then(|s| s.parse::<u32>().ok())
-            .or(config_budget)
-            .unwrap_or(DEFAULT_BUDGET_LIMIT);
-
-        // Log the source of the budget limit
-        if let Ok(env_val) = std::env::var(BUDGET_ENV_VAR) {
-            if let Ok(env_limit) = env_val.parse::<u32>() {
-                debug!(
-                    limit = env_limit,
-                    default = DEFAULT_BUDGET_LIMIT,
-                    "Using budget limit from environment variable {}",
-                    BUDGET_ENV_VAR
-                );
-            }
-        } else if let Some(config_limit) = config_budget {
-            debug!(
-                limit = config_limit,
-                default = DEFAULT_BUDGET_LIMIT,
-                "Using budget limit from config file"
-            );
-        } else {
-            debug!(limit = DEFAULT_BUDGET_LIMIT, "Using default budget limit");
-        }
-
-        Self::new(inner, limit)
+        Self::new(inner, resolve_budget_limit(config_budget))
     }
 
     /// Get the current call count
     #[cfg(test)]
     pub fn call_count(&self) -> u32 {
-        self.budget.load(Ordering::SeqCst)
+        self.budget.used()
     }
 
     /// Get the budget limit
     #[cfg(test)]
     pub fn limit(&self) -> u32 {
-        self.limit
+        self.budget.limit
     }
 }
 
-#[async_trait]
-impl LlmBackend for BudgetedBackend {
-    async fn invoke(&self, inv: LlmInvocation) -> Result<LlmResult, LlmError> {
-        // Increment counter BEFORE calling inner backend
-        // This ensures we track attempted calls, not successful requests
-        let current = self.budget.fetch_add(1, Ordering::SeqCst);
-
-        // Check if we've exceeded the limit
-        if current >= self.limit {
-            let attempted = current + 1;
-            warn!(
-                limit = self.limit,
-                attempted = attempted,
-                "Budget limit exceeded"
-            );
-            return Err(LlmError::BudgetExceeded {
-                limit: self.limit,
-                attempted,
-            });
-        }
-
+impl BudgetedBackend {
+    /// Count an attempted call, failing once the limit is exceeded
+    ///
+    /// Returns the 1-based number of this call.
+    fn reserve_call(&self) -> Result<u32, LlmError> {
+        let call_count = self.budget.reserve()?;
         debug!(
-            call_count = current + 1,
-            limit = self.limit,
+            call_count,
+            limit = self.budget.limit,
             "Budget check passed, invoking inner backend"
         );
+        Ok(call_count)
+    }
 
-        // Call the inner backend
-        let result = self.inner.invoke(inv).await;
-
-        // Log the result (success or failure)
-        match &result {
+    /// Log the result (success or failure) of call number `call_count`
+    fn log_outcome(&self, call_count: u32, result: &Result<LlmResult, LlmError>) {
+        match result {
             Ok(_) => {
                 debug!(
-                    call_count = current + 1,
-                    limit = self.limit,
+                    call_count,
+                    limit = self.budget.limit,
                     "Inner backend invocation succeeded"
                 );
             }
             Err(e) => {
                 debug!(
-                    call_count = current + 1,
-                    limit = self.limit,
+                    call_count,
+                    limit = self.budget.limit,
                     error = %e,
                     "Inner backend invocation failed (budget slot still consumed)"
                 );
             }
         }
+    }
+}
+
+#[async_trait]
+impl LlmBackend for BudgetedBackend {
+    async fn invoke(&self, inv: LlmInvocation) -> Result<LlmResult, LlmError> {
+        let call_count = self.reserve_call()?;
+        let result = self.inner.invoke(inv).await;
+        self.log_outcome(call_count, &result);
+        result
+    }
 
+    async fn invoke_streaming(
+        &self,
+        inv: LlmInvocation,
+        on_token: &mut (dyn for<'t> FnMut(&'t str) + Send),
+    ) -> Result<LlmResult, LlmError> {
+        let call_count = self.reserve_call()?;
+        let result = self.inner.invoke_streaming(inv, on_token).await;
+        self.log_outcome(call_count, &result);
         result
     }
 }
@@ -6,10 +6,11 @@
 //! **NOTE:** `src/claude.rs` is legacy/test-only and will be removed in a future release (V19+).
 //! All new code should use this backend via the `LlmBackend` trait.
 
-use crate::runner::{BufferConfig, Runner, RunnerMode, WslOptions};
+use crate::runner::{BufferConfig, ClaudeRunner, Runner, RunnerMode, WslOptions};
 use crate::{LlmBackend, LlmError, LlmInvocation, LlmResult, Message, Role};
 use async_trait::async_trait;
 use std::path::PathBuf;
+use std::sync::Arc;
 use std::time::Duration;
 use xchecker_utils::types::OutputFormat;
 
@@ -19,7 +20,7 @@ pub(crate) struct ClaudeCliBackend {
     #[allow(dead_code)] // Will be used for validation and error reporting
     binary_path: PathBuf,
     /// Runner for executing Claude CLI
-    runner: Runner,
+    runner: Arc<dyn ClaudeRunner>,
     /// Output format for Claude CLI
     output_format: OutputFormat,
     /// Max turns for Claude CLI (legacy/test CLI)
@@ -56,17 +57,31 @@ impl ClaudeCliBackend {
         let buffer_config = BufferConfig::default();
         let runner = Runner::with_buffer_config(runner_mode, wsl_options, buffer_config);
 
+        Ok(Self::from_runner(
+            binary,
+            Arc::new(runner),
+            output_format,
+            max_turns,
+        ))
+    }
+
+    fn from_runner(
+        binary_path: PathBuf,
+        runner: Arc<dyn ClaudeRunner>,
+        output_format: OutputFormat,
+        max_turns: Option<u32>,
+    ) -> Self {
         let claude_cli_version = runner
-            .get_claude_version_sync()
-            .unwrap_or_else(|_| "unknown".to_string());
+            .claude_version()
+            .unwrap_or_else(|| "unknown".to_string());
 
-        Ok(Self {
-            binary_path: binary,
+        Self {
+            binary_path,
             runner,
             output_format,
             max_turns,
             claude_cli_version,
-        })
+        }
     }
 
     /// Create a new Claude CLI backend from configuration
@@ -77,11 +92,7 @@ impl ClaudeCliBackend {
     /// # Errors
     /// Returns error if binary cannot be found or configuration is invalid
     pub fn new_from_config(cfg: &crate::config::Config) -> Result<Self, LlmError> {
-        let output_format = match cfg.defaults.output_format.as_deref() {
-            Some("text") => OutputFormat::Text,
-            _ => OutputFormat::StreamJson,
-        };
-        let max_turns = cfg.defaults.max_turns;
+        let (output_format, max_turns) = Self::cli_options(cfg);
 
         // 1. Derive binary path from config or runner overrides
         let claude_binary = cfg
@@ -115,6 +126,30 @@ impl ClaudeCliBackend {
         )
     }
 
+    /// Create a Claude CLI backend that executes through `runner`
+    ///
+    /// Output format and max turns still come from `cfg`; runner mode and
+    /// binary discovery are left to `runner`, so nothing needs to be installed.
+    pub fn with_runner(cfg: &crate::config::Config, runner: Arc<dyn ClaudeRunner>) -> Self {
+        let (output_format, max_turns) = Self::cli_options(cfg);
+        let binary_path = cfg
+            .llm
+            .claude
+            .as_ref()
+            .and_then(|claude_config| claude_config.binary.clone())
+            .map_or_else(|| PathBuf::from("claude"), PathBuf::from);
+
+        Self::from_runner(binary_path, runner, output_format, max_turns)
+    }
+
+    fn cli_options(cfg: &crate::config::Config) -> (OutputFormat, Option<u32>) {
+        let output_format = match cfg.defaults.output_format.as_deref() {
+            Some("text") => OutputFormat::Text,
+            _ => OutputFormat::StreamJson,
+        };
+        (output_format, cfg.defaults.max_turns)
+    }
+
     /// Discover Claude CLI binary in PATH
     fn discover_binary() -> Result<PathBuf, LlmError> {
         which::which("claude").map_err(|e| {
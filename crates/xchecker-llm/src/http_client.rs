@@ -4,7 +4,10 @@
 //! with timeout and retry policies for reliable HTTP communication with LLM providers.
 
 use crate::LlmError;
+use crate::budgeted_backend::CallBudget;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
 use reqwest::{Client, Response, StatusCode};
+use std::hash::BuildHasher;
 use std::sync::Arc;
 use std::time::Duration;
 use tracing::{debug, warn};
@@ -15,11 +18,104 @@ const DEFAULT_MAX_HTTP_TIMEOUT: Duration = Duration::from_secs(300);
 /// Default connect timeout (30 seconds)
 const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(30);
 
-/// Maximum number of retry attempts for 5xx and network failures
-const MAX_RETRIES: u32 = 2;
+/// Default maximum number of retry attempts
+const DEFAULT_MAX_RETRIES: u32 = 2;
 
-/// Initial backoff duration for retries (1 second)
-const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+/// Default backoff before the first retry (1 second)
+const DEFAULT_BASE_DELAY: Duration = Duration::from_secs(1);
+
+/// Default cap on any single backoff (30 seconds)
+const DEFAULT_MAX_DELAY: Duration = Duration::from_secs(30);
+
+/// Status codes retried by default: rate limiting and transient server errors
+const DEFAULT_RETRY_ON: [u16; 5] = [429, 500, 502, 503, 504];
+
+/// When and how long to retry failed HTTP requests
+///
+/// Responses with a status in `retry_on`, and network failures, are retried
+/// up to `max_retries` times. Before retry `n` (1-based) the client waits
+/// `base_delay * 2^(n-1)`, capped at `max_delay`, with the upper half
+/// jittered, unless the response carries a `Retry-After` header. A
+/// `Retry-After` longer than `max_delay` is not waited out; the error is
+/// returned instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct RetryPolicy {
+    pub max_retries: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    pub retry_on: Vec<u16>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: DEFAULT_MAX_RETRIES,
+            base_delay: DEFAULT_BASE_DELAY,
+            max_delay: DEFAULT_MAX_DELAY,
+            retry_on: DEFAULT_RETRY_ON.to_vec(),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Policy from `[llm.retry]`, with the defaults for unset fields
+    pub(crate) fn from_config(config: &crate::config::Config) -> Self {
+        let mut policy = Self::default();
+        if let Some(retry) = &config.llm.retry {
+            if let Some(max_retries) = retry.max_retries {
+                policy.max_retries = max_retries;
+            }
+            if let Some(base_delay_ms) = retry.base_delay_ms {
+                policy.base_delay = Duration::from_millis(base_delay_ms);
+            }
+            if let Some(max_delay_ms) = retry.max_delay_ms {
+                policy.max_delay = Duration::from_millis(max_delay_ms);
+            }
+            if let Some(retry_on) = &retry.retry_on {
+                policy.retry_on.clone_from(retry_on);
+            }
+        }
+        policy
+    }
+
+    fn retries_status(&self, status: StatusCode) -> bool {
+        self.retry_on.contains(&status.as_u16())
+    }
+
+    /// Exponential backoff before retry number `retry` (1-based), without jitter
+    fn backoff(&self, retry: u32) -> Duration {
+        let factor = 2u32.saturating_pow(retry.saturating_sub(1));
+        self.base_delay
+            .checked_mul(factor)
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay)
+    }
+
+    /// Backoff before retry number `retry`, keeping the lower half and
+    /// picking the upper half at random so clients don't retry in lockstep
+    fn jittered_backoff(&self, retry: u32) -> Duration {
+        let backoff = self.backoff(retry);
+        let half = backoff / 2;
+        half + half.mul_f64(random_fraction())
+    }
+}
+
+/// A number in `[0, 1)`, from the per-instance random keys of `RandomState`
+fn random_fraction() -> f64 {
+    let bits = std::collections::hash_map::RandomState::new().hash_one(()) >> 11;
+    bits as f64 / (1u64 << 53) as f64
+}
+
+/// Delay requested by a `Retry-After` header, in delta-seconds or HTTP-date form
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let at = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    let wait = at.with_timezone(&chrono::Utc) - chrono::Utc::now();
+    Some(wait.to_std().unwrap_or(Duration::ZERO))
+}
 
 /// Shared HTTP client for LLM providers
 ///
@@ -27,12 +123,16 @@ const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
 /// LLM backend invocations. It provides:
 /// - Connection reuse
 /// - Configurable timeouts
-/// - Automatic retry with exponential backoff
+/// - Automatic retry with exponential backoff (see [`RetryPolicy`])
 /// - TLS support via rustls
 #[derive(Clone)]
 pub(crate) struct HttpClient {
     client: Arc<Client>,
     max_timeout: Duration,
+    retry_policy: RetryPolicy,
+    /// Budget each retry is charged to; the first attempt is charged by
+    /// the `BudgetedBackend` wrapping the provider
+    call_budget: Option<CallBudget>,
 }
 
 impl HttpClient {
@@ -64,25 +164,44 @@ impl HttpClient {
         Ok(Self {
             client: Arc::new(client),
             max_timeout,
+            retry_policy: RetryPolicy::default(),
+            call_budget: None,
         })
     }
 
+    /// Retry failed requests according to `policy`
+    #[must_use]
+    pub(crate) fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Charge every retry against `budget`, like a new call
+    #[must_use]
+    pub(crate) fn with_call_budget(mut self, budget: CallBudget) -> Self {
+        self.call_budget = Some(budget);
+        self
+    }
+
     /// Execute an HTTP request with timeout and retry policy
     ///
     /// This method implements:
     /// - Per-request timeout: `min(request_timeout, global_max_http_timeout)`
-    /// - Retry policy: up to 2 retries for 5xx and network failures
-    /// - Exponential backoff: 1s, 2s
-    /// - No retries for 4xx errors
+    /// - Retries per the [`RetryPolicy`] (by default up to 2 retries for
+    ///   429, 500, 502, 503, 504 and network failures)
+    /// - Exponential backoff with jitter, or the server's `Retry-After`
+    /// - Each retry counts against the call budget, if one is attached
     ///
     /// # Errors
     ///
-    /// Returns `LlmError` for various failure scenarios:
+    /// Returns `LlmError` for various failure scenarios, with the error of
+    /// the last attempt once retries are exhausted:
     /// - `LlmError::ProviderAuth` for 401/403 errors
     /// - `LlmError::ProviderQuota` for 429 errors
-    /// - `LlmError::ProviderOutage` for 5xx errors (after retries)
+    /// - `LlmError::ProviderOutage` for 5xx errors
     /// - `LlmError::Timeout` for timeouts
-    /// - `LlmError::Transport` for network errors (after retries)
+    /// - `LlmError::Transport` for network errors
+    /// - `LlmError::BudgetExceeded` if a retry would exceed the call budget
     pub async fn execute_with_retry(
         &self,
         request_builder: reqwest::RequestBuilder,
@@ -97,6 +216,13 @@ impl HttpClient {
         loop {
             attempt += 1;
 
+            // A retry is another paid call
+            if attempt > 1
+                && let Some(budget) = &self.call_budget
+            {
+                budget.reserve()?;
+            }
+
             // Clone the request for this attempt
             let request = request_builder
                 .try_clone()
@@ -120,35 +246,48 @@ impl HttpClient {
                     let status = response.status();
 
                     // Check for error status codes
-                    if status.is_client_error() {
-                        return Err(map_client_error(status, provider_name));
-                    }
-
-                    if status.is_server_error() {
-                        let error = LlmError::ProviderOutage(format!(
+                    let error = if status.is_client_error() {
+                        map_client_error(status, provider_name)
+                    } else if status.is_server_error() {
+                        LlmError::ProviderOutage(format!(
                             "{} returned server error: {}",
                             provider_name, status
-                        ));
+                        ))
+                    } else {
+                        // Success
+                        return Ok(response);
+                    };
+
+                    if !self.retry_policy.retries_status(status)
+                        || attempt > self.retry_policy.max_retries
+                    {
+                        return Err(error);
+                    }
 
-                        // Retry 5xx errors
-                        if attempt <= MAX_RETRIES {
+                    // Honor the server's Retry-After, unless it exceeds our cap
+                    let delay = match retry_after(response.headers()) {
+                        Some(delay) if delay > self.retry_policy.max_delay => {
                             warn!(
                                 provider = provider_name,
-                                attempt = attempt,
                                 status = status.as_u16(),
-                                "Server error, will retry"
+                                retry_after_secs = delay.as_secs(),
+                                "Retry-After exceeds maximum retry delay, giving up"
                             );
-                            // Apply exponential backoff
-                            let backoff = INITIAL_BACKOFF * attempt;
-                            tokio::time::sleep(backoff).await;
-                            continue;
+                            return Err(error);
                         }
-
-                        return Err(error);
-                    }
-
-                    // Success
-                    return Ok(response);
+                        Some(delay) => delay,
+                        None => self.retry_policy.jittered_backoff(attempt),
+                    };
+
+                    warn!(
+                        provider = provider_name,
+                        attempt = attempt,
+                        status = status.as_u16(),
+                        delay_ms = delay.as_millis() as u64,
+                        "Retryable HTTP status, will retry"
+                    );
+                    tokio::time::sleep(delay).await;
+                    continue;
                 }
                 Err(e) => {
                     // Check if it's a timeout
@@ -165,16 +304,14 @@ impl HttpClient {
                         xchecker_error_redaction::redact_error_message(&e.to_string())
                     ));
 
-                    if attempt <= MAX_RETRIES {
+                    if attempt <= self.retry_policy.max_retries {
                         warn!(
                             provider = provider_name,
                             attempt = attempt,
                             error = %e,
                             "Network error, will retry"
                         );
-                        // Apply exponential backoff
-                        let backoff = INITIAL_BACKOFF * attempt;
-                        tokio::time::sleep(backoff).await;
+                        tokio::time::sleep(self.retry_policy.jittered_backoff(attempt)).await;
                         continue;
                     }
 
@@ -437,4 +574,302 @@ mod tests {
             "Error should mention rate limit"
         );
     }
+
+    /// Answer one request per entry of `responses`, each on its own
+    /// connection, and count the requests served
+    async fn serve_responses(
+        responses: Vec<&'static str>,
+    ) -> (String, tokio::task::JoinHandle<usize>) {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let mut served = 0;
+            for response in responses {
+                let Ok((mut socket, _)) = listener.accept().await else {
+                    break;
+                };
+                let mut request = Vec::new();
+                let mut buf = [0u8; 1024];
+                while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                    let n = socket.read(&mut buf).await.unwrap();
+                    if n == 0 {
+                        break;
+                    }
+                    request.extend_from_slice(&buf[..n]);
+                }
+                socket.write_all(response.as_bytes()).await.unwrap();
+                socket.shutdown().await.ok();
+                served += 1;
+            }
+            served
+        });
+        (url, server)
+    }
+
+    const UNAVAILABLE: &str =
+        "HTTP/1.1 503 Service Unavailable\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+    const OK: &str = "HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok";
+
+    fn fast_policy() -> RetryPolicy {
+        RetryPolicy {
+            base_delay: Duration::from_millis(10),
+            max_delay: Duration::from_millis(100),
+            ..RetryPolicy::default()
+        }
+    }
+
+    async fn get(client: &HttpClient, url: &str) -> Result<Response, LlmError> {
+        let request = Client::new().get(url);
+        client
+            .execute_with_retry(request, Duration::from_secs(5), "test-provider")
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_retries_503_until_success() {
+        let (url, server) = serve_responses(vec![UNAVAILABLE, UNAVAILABLE, OK]).await;
+        let client = HttpClient::new().unwrap().with_retry_policy(fast_policy());
+
+        let response = get(&client, &url).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.text().await.unwrap(), "ok");
+        assert_eq!(server.await.unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_exhausted_retries_surface_final_error() {
+        let (url, server) = serve_responses(vec![UNAVAILABLE, UNAVAILABLE, UNAVAILABLE]).await;
+        let client = HttpClient::new().unwrap().with_retry_policy(fast_policy());
+
+        let error = get(&client, &url).await.unwrap_err();
+
+        assert!(
+            matches!(&error, LlmError::ProviderOutage(msg) if msg.contains("503")),
+            "expected ProviderOutage, got {error:?}"
+        );
+        assert_eq!(server.await.unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_retry_after_is_respected() {
+        let (url, server) = serve_responses(vec![
+            "HTTP/1.1 429 Too Many Requests\r\nRetry-After: 1\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+            OK,
+        ])
+        .await;
+        let policy = RetryPolicy {
+            max_delay: Duration::from_secs(5),
+            ..fast_policy()
+        };
+        let client = HttpClient::new().unwrap().with_retry_policy(policy);
+
+        let started = std::time::Instant::now();
+        let response = get(&client, &url).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(started.elapsed() >= Duration::from_secs(1));
+        assert_eq!(server.await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_retry_after_beyond_max_delay_is_not_waited_out() {
+        let (url, server) = serve_responses(vec![
+            "HTTP/1.1 429 Too Many Requests\r\nRetry-After: 3600\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+        ])
+        .await;
+        let client = HttpClient::new().unwrap().with_retry_policy(fast_policy());
+
+        let error = get(&client, &url).await.unwrap_err();
+
+        assert!(matches!(error, LlmError::ProviderQuota(_)));
+        assert_eq!(server.await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_retries_count_against_call_budget() {
+        let (url, server) = serve_responses(vec![UNAVAILABLE, UNAVAILABLE]).await;
+        let budget = CallBudget::new(2);
+        // The first attempt is charged by the caller, as BudgetedBackend does
+        budget.reserve().unwrap();
+        let client = HttpClient::new()
+            .unwrap()
+            .with_retry_policy(fast_policy())
+            .with_call_budget(budget.clone());
+
+        let error = get(&client, &url).await.unwrap_err();
+
+        assert!(
+            matches!(
+                error,
+                LlmError::BudgetExceeded {
+                    limit: 2,
+                    attempted: 3
+                }
+            ),
+            "expected BudgetExceeded, got {error:?}"
+        );
+        assert_eq!(budget.used(), 3);
+        assert_eq!(server.await.unwrap(), 2);
+    }
+
+    /// An anthropic backend built from `retry`, `budget` and `timeout_secs`
+    /// config, pointed at `url`
+    fn anthropic_backend(
+        url: &str,
+        retry: crate::config::LlmRetryConfig,
+        budget: Option<u32>,
+        timeout_secs: Option<u64>,
+    ) -> Box<dyn crate::LlmBackend> {
+        // SAFETY: the variable name is unique to these tests
+        unsafe {
+            std::env::set_var("XCHECKER_TEST_RETRY_ANTHROPIC_KEY", "test-key");
+        }
+        let mut config = crate::config::Config::minimal_for_testing();
+        config.llm.anthropic = Some(crate::config::AnthropicConfig {
+            api_key_env: Some("XCHECKER_TEST_RETRY_ANTHROPIC_KEY".to_string()),
+            base_url: Some(url.to_string()),
+            model: Some("haiku".to_string()),
+            max_tokens: None,
+            temperature: None,
+            budget,
+            timeout_secs,
+        });
+        config.llm.retry = Some(retry);
+        crate::construct_backend_for_provider("anthropic", &config).unwrap()
+    }
+
+    fn invocation() -> crate::LlmInvocation {
+        crate::LlmInvocation::new(
+            "spec",
+            "requirements",
+            "haiku",
+            Duration::from_secs(5),
+            vec![crate::Message::user("hello")],
+        )
+    }
+
+    #[tokio::test]
+    async fn test_retry_policy_comes_from_llm_retry_config() {
+        let (url, server) = serve_responses(vec![UNAVAILABLE]).await;
+        // 503 is not in the configured retry_on, so it is not retried
+        let backend = anthropic_backend(
+            &url,
+            crate::config::LlmRetryConfig {
+                retry_on: Some(vec![429]),
+                ..Default::default()
+            },
+            None,
+            None,
+        );
+
+        let error = backend.invoke(invocation()).await.unwrap_err();
+
+        assert!(matches!(error, LlmError::ProviderOutage(_)), "{error:?}");
+        assert_eq!(server.await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_anthropic_retries_count_against_its_budget() {
+        let (url, server) = serve_responses(vec![UNAVAILABLE, UNAVAILABLE]).await;
+        let backend = anthropic_backend(
+            &url,
+            crate::config::LlmRetryConfig {
+                max_retries: Some(5),
+                base_delay_ms: Some(10),
+                max_delay_ms: Some(100),
+                ..Default::default()
+            },
+            Some(2),
+            None,
+        );
+
+        let error = backend.invoke(invocation()).await.unwrap_err();
+
+        assert!(
+            matches!(
+                error,
+                LlmError::BudgetExceeded {
+                    limit: 2,
+                    attempted: 3
+                }
+            ),
+            "expected BudgetExceeded, got {error:?}"
+        );
+        // The first attempt and one retry; the second retry is refused
+        assert_eq!(server.await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_provider_timeout_covers_retries() {
+        let (url, server) = serve_responses(vec![
+            "HTTP/1.1 503 Service Unavailable\r\nRetry-After: 2\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+        ])
+        .await;
+        let backend = anthropic_backend(
+            &url,
+            crate::config::LlmRetryConfig {
+                max_delay_ms: Some(5000),
+                ..Default::default()
+            },
+            None,
+            Some(1),
+        );
+
+        let started = std::time::Instant::now();
+        let error = backend.invoke(invocation()).await.unwrap_err();
+
+        // The first attempt fails fast; the 1s limit expires during the
+        // 2s wait before the retry, not after it
+        assert!(
+            matches!(
+                &error,
+                LlmError::ProviderTimeout { provider, duration }
+                    if provider == "anthropic" && *duration == Duration::from_secs(1)
+            ),
+            "expected ProviderTimeout, got {error:?}"
+        );
+        assert!(started.elapsed() < Duration::from_secs(2));
+        assert_eq!(server.await.unwrap(), 1);
+    }
+
+    #[test]
+    fn test_backoff_is_exponential_and_capped() {
+        let policy = RetryPolicy {
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(5),
+            ..RetryPolicy::default()
+        };
+        assert_eq!(policy.backoff(1), Duration::from_secs(1));
+        assert_eq!(policy.backoff(2), Duration::from_secs(2));
+        assert_eq!(policy.backoff(3), Duration::from_secs(4));
+        assert_eq!(policy.backoff(4), Duration::from_secs(5));
+        assert_eq!(policy.backoff(40), Duration::from_secs(5));
+
+        for retry in 1..5 {
+            let jittered = policy.jittered_backoff(retry);
+            assert!(jittered >= policy.backoff(retry) / 2);
+            assert!(jittered <= policy.backoff(retry));
+        }
+    }
+
+    #[test]
+    fn test_retry_after_parses_seconds_and_http_dates() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(retry_after(&headers), None);
+
+        headers.insert(RETRY_AFTER, "7".parse().unwrap());
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(7)));
+
+        headers.insert(
+            RETRY_AFTER,
+            "Wed, 21 Oct 2015 07:28:00 GMT".parse().unwrap(),
+        );
+        assert_eq!(retry_after(&headers), Some(Duration::ZERO));
+
+        headers.insert(RETRY_AFTER, "soon".parse().unwrap());
+        assert_eq!(retry_after(&headers), None);
+    }
 }
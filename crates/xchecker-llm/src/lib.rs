@@ -10,6 +10,8 @@ mod claude_cli;
 mod gemini_cli;
 pub(crate) mod http_client;
 mod openrouter_backend;
+mod sse;
+mod timeout_backend;
 mod types;
 
 #[cfg(test)]
@@ -44,11 +46,16 @@ pub(crate) use gemini_cli::GeminiCliBackend;
 pub(crate) use openrouter_backend::OpenRouterBackend;
 
 use crate::config::Config;
+use budgeted_backend::{
+    ANTHROPIC_BUDGET_ENV_VAR, BUDGET_ENV_VAR, CallBudget, resolve_budget_limit_from,
+};
+use timeout_backend::TimeoutBackend;
 
 /// Construct a backend for a specific provider.
 ///
 /// This is an internal helper that attempts to construct a backend for the given provider.
-/// It does not handle fallback logic - that's done by `from_config`.
+/// It does not handle fallback logic - that's done by `from_config`. Every backend is
+/// wrapped in a `TimeoutBackend` enforcing `[llm.<provider>] timeout_secs`.
 ///
 /// # Errors
 ///
@@ -58,39 +65,51 @@ fn construct_backend_for_provider(
     provider: &str,
     config: &Config,
 ) -> Result<Box<dyn LlmBackend>, LlmError> {
-    match provider {
+    let backend: Box<dyn LlmBackend> = match provider {
         "claude-cli" => {
             let backend = ClaudeCliBackend::new_from_config(config)
                 .map_err(|e| LlmError::Misconfiguration(e.to_string()))?;
-            Ok(Box::new(backend))
+            Box::new(backend)
         }
         "gemini-cli" => {
             let backend = GeminiCliBackend::new_from_config(config)
                 .map_err(|e| LlmError::Misconfiguration(e.to_string()))?;
-            Ok(Box::new(backend))
+            Box::new(backend)
         }
         "openrouter" => {
             let backend = OpenRouterBackend::new_from_config(config)
                 .map_err(|e| LlmError::Misconfiguration(e.to_string()))?;
 
-            // Extract budget from config
+            // Wrap with BudgetedBackend for cost control; HTTP retries draw
+            // on the same budget as the calls themselves
             let config_budget = config.llm.openrouter.as_ref().and_then(|or| or.budget);
-
-            // Wrap with BudgetedBackend for cost control
-            let budgeted =
-                BudgetedBackend::with_limit_from_config(Box::new(backend), config_budget);
-            Ok(Box::new(budgeted))
+            let budget = CallBudget::new(resolve_budget_limit_from(BUDGET_ENV_VAR, config_budget));
+            let backend = backend.with_call_budget(budget.clone());
+            Box::new(BudgetedBackend::with_call_budget(Box::new(backend), budget))
         }
         "anthropic" => {
             let backend = AnthropicBackend::new_from_config(config)
                 .map_err(|e| LlmError::Misconfiguration(e.to_string()))?;
-            Ok(Box::new(backend))
+
+            let config_budget = config.llm.anthropic.as_ref().and_then(|a| a.budget);
+            let budget = CallBudget::new(resolve_budget_limit_from(
+                ANTHROPIC_BUDGET_ENV_VAR,
+                config_budget,
+            ));
+            let backend = backend.with_call_budget(budget.clone());
+            Box::new(BudgetedBackend::with_call_budget(Box::new(backend), budget))
         }
-        unknown => Err(LlmError::Unsupported(format!(
-            "Unknown LLM provider '{}'. Supported providers: claude-cli, gemini-cli, openrouter, anthropic.",
-            unknown
-        ))),
-    }
+        unknown => {
+            return Err(LlmError::Unsupported(format!(
+                "Unknown LLM provider '{}'. Supported providers: claude-cli, gemini-cli, openrouter, anthropic.",
+                unknown
+            )));
+        }
+    };
+
+    Ok(Box::new(TimeoutBackend::from_config(
+        backend, provider, config,
+    )))
 }
 
 /// Create an LLM backend from configuration, returning fallback metadata when used.
@@ -178,6 +197,35 @@ pub fn from_config_with_fallback(
     }
 }
 
+/// Create a Claude CLI backend that executes through an injected runner.
+///
+/// Used for hermetic runs, e.g. replaying recorded interactions with
+/// [`runner::ReplayRunner`]. No fallback is attempted: the runner is the
+/// only way the backend reaches Claude.
+///
+/// # Errors
+///
+/// Returns `LlmError::Misconfiguration` if the configured provider is not
+/// `claude-cli`, since an injected runner can only stand in for Claude CLI.
+pub fn from_config_with_runner(
+    config: &Config,
+    runner: std::sync::Arc<dyn runner::ClaudeRunner>,
+) -> Result<Box<dyn LlmBackend>, LlmError> {
+    let provider = config.llm.provider.as_deref().unwrap_or("claude-cli");
+    if provider != "claude-cli" {
+        return Err(LlmError::Misconfiguration(format!(
+            "An injected runner only supports the claude-cli provider, but '{provider}' is configured"
+        )));
+    }
+
+    let backend = Box::new(ClaudeCliBackend::with_runner(config, runner));
+    Ok(Box::new(TimeoutBackend::from_config(
+        backend,
+        "claude-cli",
+        config,
+    )))
+}
+
 /// Create an LLM backend from configuration.
 ///
 /// This factory function constructs the appropriate backend based on the provider
@@ -502,6 +550,7 @@ mod factory_tests {
             max_tokens: Some(2048),
             temperature: Some(0.2),
             budget: None,
+            timeout_secs: None,
         });
 
         let result = from_config(&config);
@@ -572,6 +621,7 @@ mod factory_tests {
             max_tokens: Some(2048),
             temperature: Some(0.2),
             budget: Some(0),
+            timeout_secs: None,
         });
 
         let backend = from_config(&config).expect("Failed to create backend");
@@ -688,6 +738,7 @@ mod factory_tests {
             max_tokens: Some(2048),
             temperature: Some(0.2),
             budget: None,
+            timeout_secs: None,
         });
 
         // Configure Anthropic (API key env var also not set, so fallback fails too)
@@ -697,6 +748,8 @@ mod factory_tests {
             model: Some("haiku".to_string()),
             max_tokens: Some(2048),
             temperature: Some(0.2),
+            budget: None,
+            timeout_secs: None,
         });
 
         let result = from_config(&config);
@@ -748,6 +801,7 @@ mod factory_tests {
             max_tokens: Some(256),
             temperature: Some(0.2),
             budget: None,
+            timeout_secs: None,
         });
 
         config.llm.anthropic = Some(crate::config::AnthropicConfig {
@@ -756,6 +810,8 @@ mod factory_tests {
             model: Some("haiku".to_string()),
             max_tokens: Some(256),
             temperature: Some(0.2),
+            budget: None,
+            timeout_secs: None,
         });
 
         let result = from_config_with_fallback(&config);
@@ -4,7 +4,9 @@
 //! access to multiple models through a unified OpenAI-compatible API.
 
 use crate::LlmError;
-use crate::http_client::HttpClient;
+use crate::budgeted_backend::CallBudget;
+use crate::http_client::{HttpClient, RetryPolicy};
+use crate::sse;
 use crate::types::{LlmBackend, LlmInvocation, LlmResult, Message, Role};
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
@@ -76,6 +78,21 @@ impl OpenRouterBackend {
         })
     }
 
+    /// Charge every HTTP retry against `budget`, shared with the
+    /// `BudgetedBackend` wrapping this backend
+    #[must_use]
+    pub(crate) fn with_call_budget(mut self, budget: CallBudget) -> Self {
+        self.client = Arc::new((*self.client).clone().with_call_budget(budget));
+        self
+    }
+
+    /// Retry failed HTTP requests according to `policy`
+    #[must_use]
+    pub(crate) fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.client = Arc::new((*self.client).clone().with_retry_policy(policy));
+        self
+    }
+
     /// Create a new OpenRouter backend from configuration
     ///
     /// # Errors
@@ -137,7 +154,8 @@ impl OpenRouterBackend {
                 .unwrap_or(0.2),
         };
 
-        Self::new(api_key, base_url, default_model, default_params)
+        Ok(Self::new(api_key, base_url, default_model, default_params)?
+            .with_retry_policy(RetryPolicy::from_config(config)))
     }
 
     /// Resolve parameters for this invocation
@@ -192,11 +210,15 @@ impl OpenRouterBackend {
     }
 }
 
-#[async_trait]
-impl LlmBackend for OpenRouterBackend {
-    async fn invoke(&self, inv: LlmInvocation) -> Result<LlmResult, LlmError> {
+impl OpenRouterBackend {
+    /// Send `inv` to the chat completions API, returning the resolved model and response
+    async fn send(
+        &self,
+        inv: &LlmInvocation,
+        stream: bool,
+    ) -> Result<(String, reqwest::Response), LlmError> {
         // Resolve parameters for this invocation
-        let (model, params) = self.resolve_params(&inv);
+        let (model, params) = self.resolve_params(inv);
 
         debug!(
             provider = "openrouter",
@@ -204,6 +226,7 @@ impl LlmBackend for OpenRouterBackend {
             max_tokens = params.max_tokens,
             temperature = params.temperature,
             timeout_secs = inv.timeout.as_secs(),
+            stream,
             "Invoking OpenRouter backend"
         );
 
@@ -216,7 +239,7 @@ impl LlmBackend for OpenRouterBackend {
             messages: openai_messages,
             max_tokens: params.max_tokens,
             temperature: params.temperature,
-            stream: false,
+            stream,
         };
 
         // Build HTTP request
@@ -234,6 +257,45 @@ impl LlmBackend for OpenRouterBackend {
             .execute_with_retry(request, inv.timeout, "openrouter")
             .await?;
 
+        Ok((model, response))
+    }
+
+    /// Build the result for a completed response
+    fn finish(
+        content: String,
+        model: String,
+        usage: Option<Usage>,
+        inv: &LlmInvocation,
+    ) -> LlmResult {
+        // Build result
+        let mut result = LlmResult::new(content, "openrouter", model);
+
+        // Add token counts if available
+        if let Some(usage) = usage {
+            result.tokens_input = Some(usage.prompt_tokens);
+            result.tokens_output = Some(usage.completion_tokens);
+        }
+
+        // Set timeout status (false since we got a response)
+        result.timed_out = Some(false);
+        result.timeout_seconds = Some(inv.timeout.as_secs());
+
+        debug!(
+            provider = "openrouter",
+            tokens_input = ?result.tokens_input,
+            tokens_output = ?result.tokens_output,
+            "OpenRouter invocation completed"
+        );
+
+        result
+    }
+}
+
+#[async_trait]
+impl LlmBackend for OpenRouterBackend {
+    async fn invoke(&self, inv: LlmInvocation) -> Result<LlmResult, LlmError> {
+        let (model, response) = self.send(&inv, false).await?;
+
         // Parse response
         let response_body: OpenRouterResponse = response.json().await.map_err(|e| {
             LlmError::Transport(format!("Failed to parse OpenRouter response: {}", e))
@@ -255,27 +317,56 @@ impl LlmBackend for OpenRouterBackend {
             LlmError::Transport("OpenRouter response missing content in choices[0]".to_string())
         })?;
 
-        // Build result
-        let mut result = LlmResult::new(content, "openrouter", model);
-
-        // Add token counts if available
-        if let Some(usage) = response_body.usage {
-            result.tokens_input = Some(usage.prompt_tokens);
-            result.tokens_output = Some(usage.completion_tokens);
-        }
+        Ok(Self::finish(content, model, response_body.usage, &inv))
+    }
 
-        // Set timeout status (false since we got a response)
-        result.timed_out = Some(false);
-        result.timeout_seconds = Some(inv.timeout.as_secs());
+    async fn invoke_streaming(
+        &self,
+        inv: LlmInvocation,
+        on_token: &mut (dyn for<'t> FnMut(&'t str) + Send),
+    ) -> Result<LlmResult, LlmError> {
+        let (model, response) = self.send(&inv, true).await?;
+
+        let mut content = String::new();
+        let mut usage = None;
+        sse::for_each_event(response, "openrouter", |event| {
+            if event.data == "[DONE]" {
+                return Ok(());
+            }
+            let chunk: StreamChunk = serde_json::from_str(&event.data).map_err(|e| {
+                LlmError::Transport(format!("Failed to parse OpenRouter stream chunk: {}", e))
+            })?;
+            if let Some(error) = chunk.error {
+                return Err(LlmError::ProviderOutage(format!(
+                    "OpenRouter stream error: {}",
+                    error.message
+                )));
+            }
+            let text = chunk
+                .choices
+                .into_iter()
+                .next()
+                .and_then(|choice| choice.delta.content)
+                .filter(|text| !text.is_empty());
+            if let Some(text) = text {
+                on_token(&text);
+                content.push_str(&text);
+            }
+            // Usage, when sent, arrives on the last chunk
+            if chunk.usage.is_some() {
+                usage = chunk.usage;
+            }
+            Ok(())
+        })
+        .await?;
 
-        debug!(
-            provider = "openrouter",
-            tokens_input = ?result.tokens_input,
-            tokens_output = ?result.tokens_output,
-            "OpenRouter invocation completed"
-        );
+        if content.is_empty() {
+            return Err(LlmError::Transport(
+                "OpenRouter stream carried no content".to_string(),
+            ));
+        }
 
-        Ok(result)
+        Ok(Self::finish(content, model, usage, &inv))
     }
 }
 
@@ -323,6 +414,33 @@ struct Usage {
     completion_tokens: u64,
 }
 
+/// Chunk of a streamed OpenRouter response (OpenAI-compatible)
+#[derive(Debug, Clone, Deserialize)]
+struct StreamChunk {
+    #[serde(default)]
+    choices: Vec<StreamChoice>,
+    usage: Option<Usage>,
+    error: Option<StreamError>,
+}
+
+/// Choice in a streamed chunk
+#[derive(Debug, Clone, Deserialize)]
+struct StreamChoice {
+    delta: StreamDelta,
+}
+
+/// Incremental message content
+#[derive(Debug, Clone, Deserialize)]
+struct StreamDelta {
+    content: Option<String>,
+}
+
+/// Error reported in-band after the stream has started
+#[derive(Debug, Clone, Deserialize)]
+struct StreamError {
+    message: String,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -475,6 +593,7 @@ mod tests {
             max_tokens: None,
             temperature: None,
             budget: None,
+            timeout_secs: None,
         });
 
         let result = OpenRouterBackend::new_from_config(&config);
@@ -514,6 +633,7 @@ mod tests {
             max_tokens: None,
             temperature: None,
             budget: None,
+            timeout_secs: None,
         });
 
         let result = OpenRouterBackend::new_from_config(&config);
@@ -0,0 +1,141 @@
+//! Server-sent events parsing for streaming HTTP backends
+//!
+//! Providers stream completions as `text/event-stream`: events separated by a
+//! blank line, each made of `event:` and `data:` fields. [`SseParser`] is fed
+//! raw body chunks as they arrive and yields complete events, so a chunk may
+//! end mid-line or mid-character without losing anything.
+
+use crate::LlmError;
+
+/// One complete server-sent event
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SseEvent {
+    /// `event:` field, if the server named the event
+    pub event: Option<String>,
+    /// `data:` lines joined with `\n`
+    pub data: String,
+}
+
+/// Incremental `text/event-stream` parser
+#[derive(Debug, Default)]
+pub(crate) struct SseParser {
+    buffer: Vec<u8>,
+    event: Option<String>,
+    data: Vec<String>,
+}
+
+impl SseParser {
+    /// Consume `chunk` and return the events it completes, in order
+    pub(crate) fn feed(&mut self, chunk: &[u8]) -> Vec<SseEvent> {
+        self.buffer.extend_from_slice(chunk);
+
+        let mut events = Vec::new();
+        while let Some(newline) = self.buffer.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = self.buffer.drain(..=newline).collect();
+            let line = String::from_utf8_lossy(&line);
+            if let Some(event) = self.process_line(line.trim_end_matches(['\n', '\r'])) {
+                events.push(event);
+            }
+        }
+        events
+    }
+
+    fn process_line(&mut self, line: &str) -> Option<SseEvent> {
+        if line.is_empty() {
+            // A blank line dispatches the pending event, if it carried data
+            let event = self.event.take();
+            if self.data.is_empty() {
+                return None;
+            }
+            return Some(SseEvent {
+                event,
+                data: std::mem::take(&mut self.data).join("\n"),
+            });
+        }
+
+        // Lines starting with ':' are comments (often keep-alives)
+        if line.starts_with(':') {
+            return None;
+        }
+
+        let (field, value) = line.split_once(':').unwrap_or((line, ""));
+        let value = value.strip_prefix(' ').unwrap_or(value);
+        match field {
+            "event" => self.event = Some(value.to_string()),
+            "data" => self.data.push(value.to_string()),
+            _ => {}
+        }
+        None
+    }
+}
+
+/// Read `response` to the end, passing each event to `on_event` as it completes
+///
+/// # Errors
+///
+/// Returns `LlmError::Transport` if the body cannot be read, or the first
+/// error returned by `on_event`.
+pub(crate) async fn for_each_event(
+    mut response: reqwest::Response,
+    provider: &str,
+    mut on_event: impl FnMut(SseEvent) -> Result<(), LlmError>,
+) -> Result<(), LlmError> {
+    let mut parser = SseParser::default();
+    while let Some(chunk) = response
+        .chunk()
+        .await
+        .map_err(|e| LlmError::Transport(format!("Failed to read {} stream: {}", provider, e)))?
+    {
+        for event in parser.feed(&chunk) {
+            on_event(event)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn data(event: Option<&str>, data: &str) -> SseEvent {
+        SseEvent {
+            event: event.map(str::to_string),
+            data: data.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_parses_named_and_unnamed_events() {
+        let mut parser = SseParser::default();
+        let events = parser.feed(b"event: ping\ndata: {}\n\ndata: one\ndata: two\n\n");
+        assert_eq!(
+            events,
+            vec![data(Some("ping"), "{}"), data(None, "one\ntwo")]
+        );
+    }
+
+    #[test]
+    fn test_events_split_across_chunks() {
+        let mut parser = SseParser::default();
+        assert!(parser.feed(b"data: hel").is_empty());
+        assert!(parser.feed(b"lo\r\n").is_empty());
+        assert_eq!(parser.feed(b"\r\ndata: x\n"), vec![data(None, "hello")]);
+        assert_eq!(parser.feed(b"\n"), vec![data(None, "x")]);
+    }
+
+    #[test]
+    fn test_multibyte_character_split_across_chunks() {
+        let mut parser = SseParser::default();
+        let bytes = "data: héllo\n\n".as_bytes();
+        let split = bytes.iter().position(|&b| b == 0xC3).unwrap() + 1;
+        assert!(parser.feed(&bytes[..split]).is_empty());
+        assert_eq!(parser.feed(&bytes[split..]), vec![data(None, "héllo")]);
+    }
+
+    #[test]
+    fn test_ignores_comments_and_empty_events() {
+        let mut parser = SseParser::default();
+        let events = parser.feed(b": keep-alive\n\nevent: noop\n\nretry: 10\ndata:x\n\n");
+        assert_eq!(events, vec![data(None, "x")]);
+    }
+}
@@ -0,0 +1,222 @@
+//! Provider timeout wrapper for LLM backends
+//!
+//! This module provides a wrapper around any `LlmBackend` that bounds each
+//! invocation with `tokio::time::timeout`. The limit comes from the provider's
+//! `[llm.<provider>] timeout_secs` when configured, otherwise from the
+//! invocation's own timeout (the global phase timeout).
+//!
+//! The limit is a wall-clock cap on the whole invocation: HTTP retries and
+//! the backoff between them all run inside it. Each HTTP attempt is also
+//! bounded by the same value on its own, so a single slow attempt cannot
+//! outlast it either.
+
+use crate::LlmError;
+use crate::config::Config;
+use crate::types::{LlmBackend, LlmInvocation, LlmResult};
+use async_trait::async_trait;
+use std::future::Future;
+use std::time::Duration;
+use tracing::warn;
+
+/// A wrapper around an `LlmBackend` that enforces a per-provider timeout.
+///
+/// The resolved limit is written back into the invocation so runners and
+/// HTTP clients use the same value, and the wrapped call is cut off with
+/// [`LlmError::ProviderTimeout`] if it runs past it. Timeouts reported by the
+/// inner backend are converted to the same error so callers always learn
+/// which provider timed out.
+pub(crate) struct TimeoutBackend {
+    /// The wrapped backend
+    inner: Box<dyn LlmBackend>,
+    /// Provider name reported in timeout errors
+    provider: String,
+    /// Provider-specific override of the invocation timeout
+    timeout: Option<Duration>,
+}
+
+impl TimeoutBackend {
+    /// Create a timeout wrapper for `provider`
+    ///
+    /// `timeout` overrides the invocation's timeout when set.
+    pub(crate) fn new(
+        inner: Box<dyn LlmBackend>,
+        provider: impl Into<String>,
+        timeout: Option<Duration>,
+    ) -> Self {
+        Self {
+            inner,
+            provider: provider.into(),
+            timeout,
+        }
+    }
+
+    /// Create a timeout wrapper using `[llm.<provider>] timeout_secs` from `config`
+    pub(crate) fn from_config(inner: Box<dyn LlmBackend>, provider: &str, config: &Config) -> Self {
+        let timeout = config
+            .provider_timeout_secs(provider)
+            .map(Duration::from_secs);
+        Self::new(inner, provider, timeout)
+    }
+
+    /// Timeout applied to `inv`: the provider override, else the invocation's own
+    fn resolve(&self, inv: &LlmInvocation) -> Duration {
+        self.timeout.unwrap_or(inv.timeout)
+    }
+
+    /// Run `call` under `limit`, reporting any timeout against this provider
+    async fn bounded(
+        &self,
+        limit: Duration,
+        call: impl Future<Output = Result<LlmResult, LlmError>>,
+    ) -> Result<LlmResult, LlmError> {
+        let result = match tokio::time::timeout(limit, call).await {
+            Ok(result) => result,
+            Err(_) => Err(LlmError::Timeout { duration: limit }),
+        };
+
+        result.map_err(|err| match err {
+            LlmError::Timeout { duration } => {
+                warn!(
+                    provider = %self.provider,
+                    timeout_secs = duration.as_secs(),
+                    "LLM provider timed out"
+                );
+                LlmError::ProviderTimeout {
+                    provider: self.provider.clone(),
+                    duration,
+                }
+            }
+            other => other,
+        })
+    }
+}
+
+#[async_trait]
+impl LlmBackend for TimeoutBackend {
+    async fn invoke(&self, mut inv: LlmInvocation) -> Result<LlmResult, LlmError> {
+        let limit = self.resolve(&inv);
+        inv.timeout = limit;
+        self.bounded(limit, self.inner.invoke(inv)).await
+    }
+
+    async fn invoke_streaming(
+        &self,
+        mut inv: LlmInvocation,
+        on_token: &mut (dyn for<'t> FnMut(&'t str) + Send),
+    ) -> Result<LlmResult, LlmError> {
+        let limit = self.resolve(&inv);
+        inv.timeout = limit;
+        self.bounded(limit, self.inner.invoke_streaming(inv, on_token))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Message;
+
+    /// Mock backend that sleeps before answering
+    struct SleepingBackend {
+        delay: Duration,
+    }
+
+    #[async_trait]
+    impl LlmBackend for SleepingBackend {
+        async fn invoke(&self, inv: LlmInvocation) -> Result<LlmResult, LlmError> {
+            tokio::time::sleep(self.delay).await;
+            Ok(LlmResult::new(
+                format!("timeout={}", inv.timeout.as_millis()),
+                "mock",
+                "mock-model",
+            ))
+        }
+    }
+
+    fn invocation(timeout: Duration) -> LlmInvocation {
+        LlmInvocation::new(
+            "test-spec",
+            "requirements",
+            "mock-model",
+            timeout,
+            vec![Message::user("Hello")],
+        )
+    }
+
+    #[tokio::test]
+    async fn test_sleeping_backend_exceeds_provider_timeout() {
+        let backend = TimeoutBackend::new(
+            Box::new(SleepingBackend {
+                delay: Duration::from_secs(5),
+            }),
+            "anthropic",
+            Some(Duration::from_millis(50)),
+        );
+
+        match backend.invoke(invocation(Duration::from_secs(600))).await {
+            Err(LlmError::ProviderTimeout { provider, duration }) => {
+                assert_eq!(provider, "anthropic");
+                assert_eq!(duration, Duration::from_millis(50));
+            }
+            other => panic!("Expected ProviderTimeout, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_provider_timeout_is_passed_to_inner_backend() {
+        let backend = TimeoutBackend::new(
+            Box::new(SleepingBackend {
+                delay: Duration::ZERO,
+            }),
+            "openrouter",
+            Some(Duration::from_secs(30)),
+        );
+
+        let result = backend
+            .invoke(invocation(Duration::from_secs(600)))
+            .await
+            .unwrap();
+        assert_eq!(result.raw_response, "timeout=30000");
+    }
+
+    #[tokio::test]
+    async fn test_invocation_timeout_used_without_override() {
+        let backend = TimeoutBackend::new(
+            Box::new(SleepingBackend {
+                delay: Duration::from_secs(5),
+            }),
+            "claude-cli",
+            None,
+        );
+
+        match backend.invoke(invocation(Duration::from_millis(20))).await {
+            Err(LlmError::ProviderTimeout { provider, duration }) => {
+                assert_eq!(provider, "claude-cli");
+                assert_eq!(duration, Duration::from_millis(20));
+            }
+            other => panic!("Expected ProviderTimeout, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_from_config_reads_provider_section() {
+        let mut config = Config::minimal_for_testing();
+        config.llm.gemini = Some(crate::config::GeminiConfig {
+            binary: None,
+            default_model: None,
+            profiles: None,
+            timeout_secs: Some(120),
+        });
+
+        let inner = || -> Box<dyn LlmBackend> {
+            Box::new(SleepingBackend {
+                delay: Duration::ZERO,
+            })
+        };
+        let gemini = TimeoutBackend::from_config(inner(), "gemini-cli", &config);
+        assert_eq!(gemini.timeout, Some(Duration::from_secs(120)));
+
+        let claude = TimeoutBackend::from_config(inner(), "claude-cli", &config);
+        assert_eq!(claude.timeout, None);
+    }
+}
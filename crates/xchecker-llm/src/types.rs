@@ -259,4 +259,24 @@ pub trait LlmBackend: Send + Sync {
     /// - Timeouts
     /// - Budget exhaustion
     async fn invoke(&self, inv: LlmInvocation) -> Result<LlmResult, LlmError>;
+
+    /// Invoke the LLM, passing response text to `on_token` as it arrives
+    ///
+    /// Streaming-capable backends call `on_token` once per streamed chunk, in
+    /// order; the chunks concatenate to `raw_response` of the returned result.
+    /// The default implementation does not stream and calls `on_token` once
+    /// with the full response.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LlmError` under the same conditions as [`invoke`](Self::invoke).
+    async fn invoke_streaming(
+        &self,
+        inv: LlmInvocation,
+        on_token: &mut (dyn for<'t> FnMut(&'t str) + Send),
+    ) -> Result<LlmResult, LlmError> {
+        let result = self.invoke(inv).await?;
+        on_token(&result.raw_response);
+        Ok(result)
+    }
 }
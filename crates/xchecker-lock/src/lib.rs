@@ -5,16 +5,20 @@
 //! but is not a security boundary.
 
 use anyhow::Result;
-use camino::Utf8PathBuf;
+use camino::{Utf8Path, Utf8PathBuf};
 use chrono::{DateTime, Utc};
 use fd_lock::RwLock;
 use serde::{Deserialize, Serialize};
 use std::cell::RefCell;
 use std::fs;
-use std::io::{self, Write};
+use std::io::{self, Read, Seek, SeekFrom, Write};
 use std::path::{Path, PathBuf};
 use std::process;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 // Thread-local override used only in tests to avoid process-global env races.
 thread_local! {
@@ -24,6 +28,34 @@ thread_local! {
 /// Default age threshold for considering a lock stale (in seconds)
 const DEFAULT_STALE_THRESHOLD_SECS: u64 = 3600; // 1 hour
 
+/// Histogram of seconds spent acquiring a spec lock, recorded on success
+///
+/// Only recorded when the `metrics` feature is enabled.
+pub const LOCK_WAIT_SECONDS: &str = "lock_wait_seconds";
+
+/// Longest pause between polls while [`FileLock::acquire_blocking`] waits
+const MAX_WAIT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Upper bound on `.lock` file size; real lock files are a few hundred bytes
+const MAX_LOCK_FILE_BYTES: u64 = 4 * 1024;
+
+/// Read a `.lock` file without trusting its size
+///
+/// Returns `Ok(None)` if the file is larger than [`MAX_LOCK_FILE_BYTES`]; at
+/// most one byte past the cap is ever read.
+fn read_lock_file(lock_path: &Path) -> io::Result<Option<String>> {
+    let mut buf = Vec::new();
+    fs::File::open(lock_path)?
+        .take(MAX_LOCK_FILE_BYTES + 1)
+        .read_to_end(&mut buf)?;
+    if buf.len() as u64 > MAX_LOCK_FILE_BYTES {
+        return Ok(None);
+    }
+    String::from_utf8(buf)
+        .map(Some)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
 /// Lock information stored in the lock file
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LockInfo {
@@ -37,6 +69,50 @@ pub struct LockInfo {
     pub spec_id: String,
     /// xchecker version that created the lock
     pub xchecker_version: String,
+    /// Hostname of the machine that created the lock; empty in lock files
+    /// written before it was recorded
+    #[serde(default)]
+    pub hostname: String,
+}
+
+impl LockInfo {
+    /// Whether `pid` can be checked on this machine
+    ///
+    /// False for a lock created on another host, e.g. through a shared NFS
+    /// mount, whose PID means nothing here. Locks without a hostname, and
+    /// all locks when this machine's hostname is unknown, count as local.
+    #[must_use]
+    pub fn is_local(&self) -> bool {
+        let current = current_hostname();
+        self.hostname.is_empty() || current.is_empty() || self.hostname == current
+    }
+}
+
+/// Name of this machine, or empty if it cannot be determined
+fn current_hostname() -> &'static str {
+    static HOSTNAME: OnceLock<String> = OnceLock::new();
+    HOSTNAME.get_or_init(|| {
+        #[cfg(unix)]
+        {
+            let mut buf = [0u8; 256];
+            let rc = unsafe { libc::gethostname(buf.as_mut_ptr().cast(), buf.len()) };
+            if rc != 0 {
+                return String::new();
+            }
+            let len = buf.iter().position(|&b| b == 0).unwrap_or(buf.len());
+            String::from_utf8_lossy(&buf[..len]).into_owned()
+        }
+
+        #[cfg(windows)]
+        {
+            std::env::var("COMPUTERNAME").unwrap_or_default()
+        }
+
+        #[cfg(not(any(unix, windows)))]
+        {
+            String::new()
+        }
+    })
 }
 
 /// `XChecker` lockfile for reproducibility tracking (schema v1)
@@ -81,6 +157,38 @@ pub struct LockDrift {
     pub schema_version: Option<DriftPair>,
 }
 
+impl LockDrift {
+    /// Render the drift as a stable JSON report
+    ///
+    /// The report is `{ "has_drift": bool, "fields": [...] }`, with one
+    /// `{ "field", "locked", "current" }` entry per drifted field, in the
+    /// order model, CLI version, schema version.
+    #[must_use]
+    pub fn to_report(&self) -> serde_json::Value {
+        let fields: Vec<serde_json::Value> = [
+            ("model_full_name", &self.model_full_name),
+            ("claude_cli_version", &self.claude_cli_version),
+            ("schema_version", &self.schema_version),
+        ]
+        .into_iter()
+        .filter_map(|(field, pair)| {
+            pair.as_ref().map(|pair| {
+                serde_json::json!({
+                    "field": field,
+                    "locked": pair.locked,
+                    "current": pair.current,
+                })
+            })
+        })
+        .collect();
+
+        serde_json::json!({
+            "has_drift": !fields.is_empty(),
+            "fields": fields,
+        })
+    }
+}
+
 /// Lock errors for file locking operations
 #[derive(Debug, thiserror::Error)]
 pub enum LockError {
@@ -111,10 +219,32 @@ pub enum LockError {
     #[error("Failed to release lock: {reason}")]
     ReleaseFailed { reason: String },
 
+    #[error("Too many concurrent specs: {active} runs active (limit {limit})")]
+    TooManyConcurrentSpecs { limit: usize, active: usize },
+
     #[error("IO error during lock operation: {0}")]
     Io(#[from] io::Error),
 }
 
+/// Overwrite an open lock file with `lock_info` and sync it to disk
+///
+/// Writes over the old content before truncating, so readers never see an
+/// empty file.
+fn rewrite_lock_file(mut file: &fs::File, lock_info: &LockInfo) -> io::Result<()> {
+    let json = serde_json::to_string_pretty(lock_info).map_err(io::Error::other)?;
+    file.seek(SeekFrom::Start(0))?;
+    file.write_all(json.as_bytes())?;
+    file.set_len(json.len() as u64)?;
+    file.sync_all()
+}
+
+/// Seconds since the UNIX epoch
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
 /// Write file atomically using a temporary file and atomic rename
 ///
 /// This is a simplified version of atomic_write that doesn't depend on xchecker-utils
@@ -242,6 +372,17 @@ impl XCheckerLock {
         }
     }
 
+    /// Detect drift against `current` and render it with [`LockDrift::to_report`]
+    ///
+    /// Returns `{ "has_drift": false }` when nothing drifted.
+    #[must_use]
+    pub fn check_and_report(&self, current: &RunContext) -> serde_json::Value {
+        self.detect_drift(current).map_or_else(
+            || serde_json::json!({ "has_drift": false }),
+            |drift| drift.to_report(),
+        )
+    }
+
     /// Load lockfile from spec directory
     pub fn load(spec_id: &str) -> Result<Option<Self>, io::Error> {
         let lock_path = Self::get_lock_path(spec_id);
@@ -280,6 +421,17 @@ impl XCheckerLock {
     }
 }
 
+/// Tunables for [`FileLock::acquire_with_policy`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LockPolicy {
+    /// Time-to-live for lock staleness detection (None uses default)
+    pub ttl_seconds: Option<u64>,
+    /// Age in seconds after which a lock whose process is dead can be taken
+    /// over without `force`. Fresher dead-process locks still require `force`,
+    /// guarding against quickly recycled PIDs. None always requires `force`.
+    pub dead_process_grace_secs: Option<u64>,
+}
+
 /// File lock manager for spec directories
 pub struct FileLock {
     /// Path to the lock file
@@ -288,6 +440,16 @@ pub struct FileLock {
     _fd_lock: Option<Box<RwLock<fs::File>>>,
     /// Lock information
     lock_info: LockInfo,
+    /// Set once `release()` has removed the lock file
+    released: bool,
+}
+
+/// Outcome of inspecting a lock file that blocked `create_new`
+enum ExistingLock {
+    /// The lock file disappeared before it could be read
+    Vanished,
+    /// The lock is stale or may be forcibly overridden
+    Overridable,
 }
 
 impl FileLock {
@@ -310,18 +472,115 @@ impl FileLock {
         force: bool,
         ttl_seconds: Option<u64>,
     ) -> Result<Self, LockError> {
-        let spec_root = spec_root(spec_id);
+        Self::acquire_blocking(spec_id, force, ttl_seconds, Duration::ZERO)
+    }
+
+    /// Acquire the lock for `spec_id`, waiting up to `wait_timeout` for a live holder to release it
+    ///
+    /// While another running process holds the lock, polls with exponential
+    /// backoff and the same PID-based jitter as stale lock retries. Stale
+    /// and corrupted locks fail immediately, as with [`FileLock::acquire`].
+    /// A zero `wait_timeout` behaves exactly like [`FileLock::acquire`].
+    ///
+    /// # Errors
+    /// Returns `LockError::AcquisitionFailed`, stating how long it waited, if
+    /// the lock is still held when `wait_timeout` elapses.
+    pub fn acquire_blocking(
+        spec_id: &str,
+        force: bool,
+        ttl_seconds: Option<u64>,
+        wait_timeout: Duration,
+    ) -> Result<Self, LockError> {
+        let policy = LockPolicy {
+            ttl_seconds,
+            ..LockPolicy::default()
+        };
+        Self::acquire_in_with_policy(&spec_root(spec_id), spec_id, force, policy, wait_timeout)
+    }
+
+    /// Attempt to acquire an exclusive lock for the given spec ID under `policy`
+    ///
+    /// Same as [`FileLock::acquire`], with the TTL and dead-process grace
+    /// period taken from `policy`.
+    pub fn acquire_with_policy(
+        spec_id: &str,
+        force: bool,
+        policy: LockPolicy,
+    ) -> Result<Self, LockError> {
+        Self::acquire_in_with_policy(&spec_root(spec_id), spec_id, force, policy, Duration::ZERO)
+    }
+
+    /// Attempt to acquire an exclusive lock for `spec_id` in an explicit spec directory
+    ///
+    /// Behaves exactly like [`FileLock::acquire`] but places the lock file in
+    /// `spec_root` instead of resolving it from `XCHECKER_HOME`. Useful for
+    /// callers such as benchmarks that must not touch the user's state directory.
+    pub fn acquire_in(
+        spec_root: &Utf8Path,
+        spec_id: &str,
+        force: bool,
+        ttl_seconds: Option<u64>,
+    ) -> Result<Self, LockError> {
+        let policy = LockPolicy {
+            ttl_seconds,
+            ..LockPolicy::default()
+        };
+        Self::acquire_in_with_policy(spec_root, spec_id, force, policy, Duration::ZERO)
+    }
+
+    fn acquire_in_with_policy(
+        spec_root: &Utf8Path,
+        spec_id: &str,
+        force: bool,
+        policy: LockPolicy,
+        wait_timeout: Duration,
+    ) -> Result<Self, LockError> {
+        let spec_root = spec_root.to_path_buf();
 
         // Ensure the spec directory exists (ignore benign races)
         ensure_dir_all(&spec_root).map_err(|e| LockError::AcquisitionFailed {
             reason: format!("Failed to create spec directory: {e}"),
         })?;
 
-        let lock_path = Self::get_lock_path(spec_id);
-        let ttl = ttl_seconds.unwrap_or(DEFAULT_STALE_THRESHOLD_SECS);
+        let lock_path = spec_root.as_std_path().join(".lock");
+        let ttl = policy.ttl_seconds.unwrap_or(DEFAULT_STALE_THRESHOLD_SECS);
+
+        // Attempt atomic lock acquisition with retries for stale lock handling,
+        // polling while a live holder keeps the lock and wait time remains
+        let started = Instant::now();
+        let mut attempt = 0u32;
+        let result = loop {
+            let held = match Self::acquire_with_retry(
+                spec_id,
+                &lock_path,
+                force,
+                ttl,
+                policy.dead_process_grace_secs,
+                3,
+            ) {
+                Err(err @ LockError::ConcurrentExecution { .. }) if !wait_timeout.is_zero() => err,
+                other => break other,
+            };
 
-        // Attempt atomic lock acquisition with retries for stale lock handling
-        Self::acquire_with_retry(spec_id, &lock_path, force, ttl, 3)
+            let waited = started.elapsed();
+            if waited >= wait_timeout {
+                break Err(LockError::AcquisitionFailed {
+                    reason: format!(
+                        "Timed out after {:.1}s waiting for lock on spec '{spec_id}': {held}",
+                        waited.as_secs_f64()
+                    ),
+                });
+            }
+            std::thread::sleep(
+                Self::backoff_delay(attempt, MAX_WAIT_POLL_INTERVAL).min(wait_timeout - waited),
+            );
+            attempt = attempt.saturating_add(1);
+        };
+        #[cfg(feature = "metrics")]
+        if result.is_ok() {
+            metrics::histogram!(LOCK_WAIT_SECONDS).record(started.elapsed().as_secs_f64());
+        }
+        result
     }
 
     /// Internal helper for atomic lock acquisition with retry logic
@@ -330,6 +589,7 @@ impl FileLock {
         lock_path: &Path,
         force: bool,
         ttl_seconds: u64,
+        dead_process_grace_secs: Option<u64>,
         max_retries: u32,
     ) -> Result<Self, LockError> {
         for attempt in 0..max_retries {
@@ -343,6 +603,7 @@ impl FileLock {
                     .as_secs(),
                 spec_id: spec_id.to_string(),
                 xchecker_version: env!("CARGO_PKG_VERSION").to_string(),
+                hostname: current_hostname().to_string(),
             };
 
             // Attempt atomic file creation with O_EXCL semantics (create_new)
@@ -357,8 +618,20 @@ impl FileLock {
                 }
                 Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {
                     // Lock file exists - validate it
-                    match Self::check_existing_lock(lock_path, spec_id, force, ttl_seconds) {
-                        Ok(()) => {
+                    match Self::check_existing_lock(
+                        lock_path,
+                        spec_id,
+                        force,
+                        ttl_seconds,
+                        dead_process_grace_secs,
+                    ) {
+                        Ok(ExistingLock::Vanished) => {
+                            // The holder released between create_new and read. Removing
+                            // "stale" state here could delete a lock another caller has just
+                            // created, so go straight back to create_new instead.
+                            continue;
+                        }
+                        Ok(ExistingLock::Overridable) => {
                             // Lock is stale/overridable - attempt atomic removal and retry
                             match Self::try_remove_stale_lock(lock_path, spec_id) {
                                 Ok(()) => {
@@ -378,21 +651,10 @@ impl FileLock {
                                         Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {
                                             // Another process grabbed it - apply backoff if retries remain
                                             if attempt + 1 < max_retries {
-                                                let base_delay_ms = 10u64
-                                                    .saturating_mul(2u64.saturating_pow(attempt));
-                                                // Deterministic jitter based on PID to avoid lockstep retries
-                                                // without requiring RNG (0-6ms based on attempt and PID)
-                                                let jitter_ms = ((attempt as u64)
-                                                    .wrapping_mul(3)
-                                                    .wrapping_add((process::id() as u64) % 7))
-                                                    % 7;
-                                                let delay_ms =
-                                                    base_delay_ms.saturating_add(jitter_ms);
-                                                std::thread::sleep(
-                                                    std::time::Duration::from_millis(
-                                                        delay_ms.min(100),
-                                                    ),
-                                                );
+                                                std::thread::sleep(Self::backoff_delay(
+                                                    attempt,
+                                                    Duration::from_millis(100),
+                                                ));
                                                 continue;
                                             }
                                             // Max retries reached after another process grabbed lock
@@ -444,6 +706,20 @@ impl FileLock {
         })
     }
 
+    /// Exponential backoff before retry `attempt`, capped at `max`
+    ///
+    /// Starts at 10ms and doubles per attempt. Deterministic jitter based on
+    /// PID avoids lockstep retries without requiring RNG (0-6ms based on
+    /// attempt and PID).
+    fn backoff_delay(attempt: u32, max: Duration) -> Duration {
+        let base_delay_ms = 10u64.saturating_mul(2u64.saturating_pow(attempt));
+        let jitter_ms = ((attempt as u64)
+            .wrapping_mul(3)
+            .wrapping_add((process::id() as u64) % 7))
+            % 7;
+        Duration::from_millis(base_delay_ms.saturating_add(jitter_ms)).min(max)
+    }
+
     /// Finalize lock acquisition by writing lock info and acquiring fd_lock
     fn finalize_lock(
         lock_path: PathBuf,
@@ -501,6 +777,7 @@ impl FileLock {
             lock_path,
             _fd_lock: Some(rw_lock),
             lock_info,
+            released: false,
         })
     }
 
@@ -508,17 +785,38 @@ impl FileLock {
     ///
     /// Uses rename-to-stale then delete pattern to minimize race window.
     /// Treats `NotFound` as success since another process may have already removed it.
-    /// Includes PID in stale filename to prevent collision under high parallelism.
     fn try_remove_stale_lock(lock_path: &Path, spec_id: &str) -> Result<(), LockError> {
+        Self::try_remove_stale_lock_with(lock_path, spec_id, |from, to| fs::rename(from, to))
+    }
+
+    /// Build a unique path to rename a stale lock to before deleting it
+    ///
+    /// The name combines a millisecond timestamp, the PID and a per-process
+    /// sequence number, so concurrent callers never collide: other processes
+    /// differ by PID and threads of this process by sequence number, even
+    /// within the same millisecond.
+    fn stale_lock_path(lock_path: &Path) -> PathBuf {
+        static STALE_SEQ: AtomicU64 = AtomicU64::new(0);
+
         let timestamp = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .unwrap()
             .as_millis();
         let pid = process::id();
-        let stale_path = lock_path.with_extension(format!("stale.{timestamp}.{pid}"));
+        let seq = STALE_SEQ.fetch_add(1, Ordering::Relaxed);
+        lock_path.with_extension(format!("stale.{timestamp}.{pid}.{seq}"))
+    }
+
+    /// [`Self::try_remove_stale_lock`] with an injectable rename for tests
+    fn try_remove_stale_lock_with(
+        lock_path: &Path,
+        spec_id: &str,
+        rename: impl FnOnce(&Path, &Path) -> io::Result<()>,
+    ) -> Result<(), LockError> {
+        let stale_path = Self::stale_lock_path(lock_path);
 
         // Atomic rename to mark as stale
-        match fs::rename(lock_path, &stale_path) {
+        match rename(lock_path, &stale_path) {
             Ok(()) => {
                 // Best-effort cleanup of stale file (ignore errors)
                 let _ = fs::remove_file(&stale_path);
@@ -544,15 +842,68 @@ impl FileLock {
 
     /// Get information about an existing lock (if any)
     pub fn get_lock_info(spec_id: &str) -> Result<Option<LockInfo>, LockError> {
-        let lock_path = Self::get_lock_path(spec_id);
+        Self::read_lock_info(&Self::get_lock_path(spec_id))
+    }
 
+    /// List the locks of every spec under `xchecker_home`
+    ///
+    /// Scans `specs/*/.lock` and returns `(spec_id, info, is_running)` sorted
+    /// by spec ID, where `is_running` reports whether the owning process is
+    /// alive (always `true` for a lock from another host). Lock files that
+    /// cannot be read or parsed are skipped and their spec IDs logged.
+    pub fn list_active_locks(
+        xchecker_home: &Utf8Path,
+    ) -> Result<Vec<(String, LockInfo, bool)>, LockError> {
+        let specs_dir = xchecker_home.join("specs");
+        if !specs_dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut locks = Vec::new();
+        let mut corrupted = Vec::new();
+        for entry in fs::read_dir(&specs_dir)? {
+            let entry = entry?;
+            let lock_path = entry.path().join(".lock");
+            if !lock_path.is_file() {
+                continue;
+            }
+            let spec_id = entry.file_name().to_string_lossy().into_owned();
+
+            match Self::read_lock_info(&lock_path) {
+                Ok(Some(info)) => {
+                    let running = utils::is_lock_owner_running(&info);
+                    locks.push((spec_id, info, running));
+                }
+                // Removed since the directory was read
+                Ok(None) => {}
+                Err(_) => corrupted.push(spec_id),
+            }
+        }
+
+        if !corrupted.is_empty() {
+            corrupted.sort_unstable();
+            tracing::warn!(
+                "Skipped corrupted lock files for spec(s): {}",
+                corrupted.join(", ")
+            );
+        }
+
+        locks.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+        Ok(locks)
+    }
+
+    /// Read and parse the lock file at `lock_path` (if any)
+    fn read_lock_info(lock_path: &Path) -> Result<Option<LockInfo>, LockError> {
         if !lock_path.exists() {
             return Ok(None);
         }
 
-        let lock_content =
-            fs::read_to_string(&lock_path).map_err(|e| LockError::CorruptedLock {
+        let lock_content = read_lock_file(lock_path)
+            .map_err(|e| LockError::CorruptedLock {
                 reason: format!("Failed to read lock file: {e}"),
+            })?
+            .ok_or_else(|| LockError::CorruptedLock {
+                reason: format!("lock file too large (exceeds {MAX_LOCK_FILE_BYTES} bytes)"),
             })?;
 
         let lock_info: LockInfo =
@@ -565,17 +916,28 @@ impl FileLock {
 
     /// Release the lock (called automatically on drop)
     #[allow(dead_code)] // Lock management utility
-    pub fn release(mut self) -> Result<(), LockError> {
+    pub fn release(self) -> Result<(), LockError> {
+        self.release_with(|path| fs::remove_file(path))
+    }
+
+    /// Release using `remove` to delete the lock file
+    ///
+    /// If removal fails the lock stays unreleased, so `Drop` tries again.
+    fn release_with(
+        mut self,
+        remove: impl FnOnce(&Path) -> io::Result<()>,
+    ) -> Result<(), LockError> {
         // Drop the file descriptor lock first
         self._fd_lock.take();
 
         // Remove the lock file
         if self.lock_path.exists() {
-            fs::remove_file(&self.lock_path).map_err(|e| LockError::ReleaseFailed {
+            remove(&self.lock_path).map_err(|e| LockError::ReleaseFailed {
                 reason: format!("Failed to remove lock file: {e}"),
             })?;
         }
 
+        self.released = true;
         Ok(())
     }
 
@@ -593,6 +955,95 @@ impl FileLock {
         &self.lock_info
     }
 
+    /// Mark the lock as fresh by rewriting `created_at` to now
+    ///
+    /// Staleness is judged from `created_at`, so a run that outlives the TTL
+    /// should renew its lock periodically, either directly or through
+    /// [`start_heartbeat`](Self::start_heartbeat). The file is rewritten in
+    /// place and synced to disk.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LockError::AcquisitionFailed` if the lock is no longer held or
+    /// the file cannot be written.
+    pub fn renew(&mut self) -> Result<(), LockError> {
+        let spec_id = self.lock_info.spec_id.clone();
+        let rw_lock = self
+            ._fd_lock
+            .as_mut()
+            .ok_or_else(|| LockError::AcquisitionFailed {
+                reason: format!("Cannot renew released lock for spec '{spec_id}'"),
+            })?;
+        let file = rw_lock
+            .try_write()
+            .map_err(|e| LockError::AcquisitionFailed {
+                reason: format!("Failed to lock file for renewal of spec '{spec_id}': {e}"),
+            })?;
+
+        let mut renewed = self.lock_info.clone();
+        renewed.created_at = now_secs();
+        rewrite_lock_file(&file, &renewed).map_err(|e| LockError::AcquisitionFailed {
+            reason: format!("Failed to renew lock for spec '{spec_id}': {e}"),
+        })?;
+        drop(file);
+
+        self.lock_info = renewed;
+        Ok(())
+    }
+
+    /// Renew the lock every `interval` on a background thread
+    ///
+    /// Keeps a long-running spec's lock fresh, so a crash leaves a lock that
+    /// goes stale one TTL later rather than one TTL after acquisition. Pick
+    /// an interval well below the TTL. The thread writes through its own
+    /// handle to the lock file; [`lock_info`](Self::lock_info) keeps the time
+    /// of the last [`renew`](Self::renew) call.
+    ///
+    /// Stop the heartbeat with [`LockHeartbeat::stop`] (or drop it) before
+    /// calling [`release`](Self::release).
+    ///
+    /// # Errors
+    ///
+    /// Returns `LockError::AcquisitionFailed` if the lock is no longer held or
+    /// the thread cannot be started.
+    pub fn start_heartbeat(&self, interval: Duration) -> Result<LockHeartbeat, LockError> {
+        let spec_id = &self.lock_info.spec_id;
+        let rw_lock = self
+            ._fd_lock
+            .as_ref()
+            .ok_or_else(|| LockError::AcquisitionFailed {
+                reason: format!("Cannot start heartbeat for released lock on spec '{spec_id}'"),
+            })?;
+        let file = rw_lock
+            .try_read()
+            .and_then(|file| file.try_clone())
+            .map_err(|e| LockError::AcquisitionFailed {
+                reason: format!("Failed to open lock file for heartbeat on spec '{spec_id}': {e}"),
+            })?;
+
+        let mut lock_info = self.lock_info.clone();
+        let (stop, stopped) = mpsc::channel::<()>();
+        let thread = thread::Builder::new()
+            .name(format!("lock-heartbeat-{spec_id}"))
+            .spawn(move || {
+                // Any message or a dropped sender ends the loop
+                while let Err(mpsc::RecvTimeoutError::Timeout) = stopped.recv_timeout(interval) {
+                    lock_info.created_at = now_secs();
+                    if rewrite_lock_file(&file, &lock_info).is_err() {
+                        break;
+                    }
+                }
+            })
+            .map_err(|e| LockError::AcquisitionFailed {
+                reason: format!("Failed to start heartbeat for spec '{spec_id}': {e}"),
+            })?;
+
+        Ok(LockHeartbeat {
+            stop: Some(stop),
+            thread: Some(thread),
+        })
+    }
+
     /// Get the path to the lock file for a spec ID
     fn get_lock_path(spec_id: &str) -> PathBuf {
         spec_root(spec_id).as_std_path().join(".lock")
@@ -607,18 +1058,27 @@ impl FileLock {
         spec_id: &str,
         force: bool,
         ttl_seconds: u64,
-    ) -> Result<(), LockError> {
+        dead_process_grace_secs: Option<u64>,
+    ) -> Result<ExistingLock, LockError> {
         // Retry parameters for handling concurrent initialization
         const MAX_READ_RETRIES: u32 = 3;
         const READ_RETRY_DELAY_MS: u64 = 10;
 
         for attempt in 0..MAX_READ_RETRIES {
-            let lock_content = match fs::read_to_string(lock_path) {
-                Ok(content) => content,
+            let lock_content = match read_lock_file(lock_path) {
+                Ok(Some(content)) => content,
+                Ok(None) => {
+                    // Oversized files are never mid-write lock files; don't retry
+                    return Err(LockError::CorruptedLock {
+                        reason: format!(
+                            "lock file too large for spec '{spec_id}' (exceeds {MAX_LOCK_FILE_BYTES} bytes)"
+                        ),
+                    });
+                }
                 Err(e) if e.kind() == io::ErrorKind::NotFound => {
                     // Lock was removed between create_new(AlreadyExists) and read.
                     // Treat as "no lock"; caller will retry acquisition.
-                    return Ok(());
+                    return Ok(ExistingLock::Vanished);
                 }
                 Err(e) => {
                     // IO errors during read might be transient (file being written)
@@ -655,7 +1115,9 @@ impl FileLock {
                         spec_id,
                         force,
                         ttl_seconds,
-                    );
+                        dead_process_grace_secs,
+                    )
+                    .map(|()| ExistingLock::Overridable);
                 }
                 Err(e) => {
                     // Check if this looks like a partial/incomplete JSON (EOF error)
@@ -689,6 +1151,7 @@ impl FileLock {
         spec_id: &str,
         force: bool,
         ttl_seconds: u64,
+        dead_process_grace_secs: Option<u64>,
     ) -> Result<(), LockError> {
         // Calculate lock age (handle future timestamps gracefully - clock skew)
         let now_secs = SystemTime::now()
@@ -700,6 +1163,26 @@ impl FileLock {
 
         let is_stale = lock_age > ttl_seconds;
 
+        // The PID of a lock from another host cannot be checked, so only
+        // its age can show that it is stale
+        if !existing_lock.is_local() {
+            return if force {
+                Ok(())
+            } else if is_stale {
+                Err(LockError::StaleLock {
+                    spec_id: spec_id.to_string(),
+                    pid: existing_lock.pid,
+                    age_secs: lock_age,
+                })
+            } else {
+                Err(LockError::ConcurrentExecution {
+                    spec_id: spec_id.to_string(),
+                    pid: existing_lock.pid,
+                    created_ago: Self::format_duration_since(existing_lock.created_at),
+                })
+            };
+        }
+
         // Check if the process is still running
         if Self::is_process_running(existing_lock.pid) {
             // Process is running - this is a fresh lock
@@ -715,7 +1198,13 @@ impl FileLock {
             return Ok(());
         }
 
-        // Process is not running - check staleness
+        // Process is not running - once past the grace period the lock can be
+        // taken over without force; fresher ones may belong to a recycled PID
+        if dead_process_grace_secs.is_some_and(|grace| lock_age > grace) {
+            return Ok(());
+        }
+
+        // Otherwise check staleness
         if is_stale {
             if force {
                 // Force flag allows overriding stale locks
@@ -801,14 +1290,62 @@ impl FileLock {
         }
     }
 
+    /// Check whether the process that wrote `info` is still running
+    ///
+    /// A live PID whose process started after the recorded `start_time` has
+    /// been reused by an unrelated process. Where start times cannot be read,
+    /// and for entries without a `start_time`, only the PID is checked.
+    fn is_owner_running(info: &LockInfo) -> bool {
+        if !Self::is_process_running(info.pid) {
+            return false;
+        }
+        if info.start_time == 0 {
+            return true;
+        }
+        // Start times are whole seconds; allow one second of rounding
+        Self::process_start_time(info.pid).is_none_or(|started| started <= info.start_time + 1)
+    }
+
+    /// Start time of process `pid` in seconds since the UNIX epoch, if known
+    fn process_start_time(pid: u32) -> Option<u64> {
+        #[cfg(target_os = "linux")]
+        {
+            let stat = fs::read_to_string(format!("/proc/{pid}/stat")).ok()?;
+            // The command name may contain spaces, so split after its closing paren;
+            // starttime (field 22) is then the 20th field, in clock ticks since boot
+            let fields: Vec<&str> = stat[stat.rfind(')')? + 1..].split_whitespace().collect();
+            let ticks: u64 = fields.get(19)?.parse().ok()?;
+            let boot_time: u64 = fs::read_to_string("/proc/stat")
+                .ok()?
+                .lines()
+                .find_map(|line| line.strip_prefix("btime "))?
+                .trim()
+                .parse()
+                .ok()?;
+            let ticks_per_sec = unsafe { libc::sysconf(libc::_SC_CLK_TCK) };
+            if ticks_per_sec <= 0 {
+                return None;
+            }
+            Some(boot_time + ticks / ticks_per_sec as u64)
+        }
+
+        #[cfg(not(target_os = "linux"))]
+        {
+            let _ = pid;
+            None
+        }
+    }
+
     /// Get the start time of the current process (best effort)
     fn get_process_start_time() -> Result<u64, LockError> {
-        // This is a best-effort implementation
-        // In practice, we use the current time as an approximation
-        Ok(SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs())
+        // Fall back to the current time, which is never earlier than the real
+        // start, so `is_owner_running` cannot mistake this process for a reuse
+        Ok(Self::process_start_time(process::id()).unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_secs()
+        }))
     }
 
     /// Format a duration since a timestamp in a human-readable way
@@ -845,7 +1382,11 @@ impl std::fmt::Debug for FileLock {
 impl Drop for FileLock {
     /// Automatically release the lock when the `FileLock` is dropped
     fn drop(&mut self) {
-        // Drop the file descriptor lock first
+        // Once `release()` has removed the file, the path may already belong to
+        // another holder that acquired it in the meantime.
+        if self.released {
+            return;
+        }
         self._fd_lock.take();
 
         // Remove the lock file (ignore errors in drop)
@@ -855,12 +1396,245 @@ impl Drop for FileLock {
     }
 }
 
+/// Background renewal of a [`FileLock`], started by [`FileLock::start_heartbeat`]
+///
+/// Stops when [`stop`](Self::stop) is called or the handle is dropped. A
+/// failed renewal also ends the thread.
+pub struct LockHeartbeat {
+    stop: Option<mpsc::Sender<()>>,
+    thread: Option<thread::JoinHandle<()>>,
+}
+
+impl LockHeartbeat {
+    /// Stop renewing and wait for the thread to exit
+    pub fn stop(mut self) {
+        self.shutdown();
+    }
+
+    fn shutdown(&mut self) {
+        // Dropping the sender wakes the thread immediately
+        self.stop.take();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl std::fmt::Debug for LockHeartbeat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LockHeartbeat")
+            .field("running", &self.thread.is_some())
+            .finish()
+    }
+}
+
+impl Drop for LockHeartbeat {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+/// File under the xchecker home that counts active runs for [`RunSlot`]
+const GLOBAL_LOCK_FILE: &str = ".global.lock";
+
+/// One active run recorded in the global lock file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ActiveRun {
+    /// Distinguishes runs from different threads of the same process
+    slot: String,
+    #[serde(flatten)]
+    info: LockInfo,
+}
+
+/// A counted slot in the home-level run semaphore
+///
+/// Every run against an xchecker home holds one slot, recorded in
+/// `<home>/.global.lock`, so at most `max_concurrent` runs proceed at once.
+/// The file is only touched under an exclusive file lock, and entries whose
+/// process is no longer running are pruned on every update, so a crashed
+/// holder never keeps its slot. The slot is released on drop.
+pub struct RunSlot {
+    /// Path to the global lock file
+    path: PathBuf,
+    /// This holder's entry in the file
+    slot: String,
+    /// Whether the slot is still held
+    held: bool,
+}
+
+impl RunSlot {
+    /// Take a run slot in the current xchecker home
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LockError::TooManyConcurrentSpecs`] if `max_concurrent` runs
+    /// are already active, or another `LockError` if the global lock file
+    /// cannot be read or updated.
+    pub fn acquire(spec_id: &str, max_concurrent: usize) -> Result<Self, LockError> {
+        Self::acquire_in(&xchecker_home(), spec_id, max_concurrent)
+    }
+
+    /// Take a run slot in the xchecker home at `home`
+    ///
+    /// Like [`FileLock::acquire_in`], this bypasses `XCHECKER_HOME`
+    /// resolution, which also lets callers share one home across threads.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`RunSlot::acquire`].
+    pub fn acquire_in(
+        home: &Utf8Path,
+        spec_id: &str,
+        max_concurrent: usize,
+    ) -> Result<Self, LockError> {
+        static SLOT_SEQ: AtomicU64 = AtomicU64::new(0);
+
+        ensure_dir_all(&home.to_path_buf()).map_err(|e| LockError::AcquisitionFailed {
+            reason: format!("Failed to create xchecker home: {e}"),
+        })?;
+
+        let path = home.as_std_path().join(GLOBAL_LOCK_FILE);
+        let pid = process::id();
+        let slot = format!("{pid}-{}", SLOT_SEQ.fetch_add(1, Ordering::Relaxed));
+        let info = LockInfo {
+            pid,
+            start_time: FileLock::get_process_start_time()?,
+            created_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_secs(),
+            spec_id: spec_id.to_string(),
+            xchecker_version: env!("CARGO_PKG_VERSION").to_string(),
+            hostname: current_hostname().to_string(),
+        };
+
+        Self::update_runs(&path, |runs| {
+            if runs.len() >= max_concurrent {
+                return Err(LockError::TooManyConcurrentSpecs {
+                    limit: max_concurrent,
+                    active: runs.len(),
+                });
+            }
+            runs.push(ActiveRun {
+                slot: slot.clone(),
+                info,
+            });
+            Ok(())
+        })?;
+
+        Ok(Self {
+            path,
+            slot,
+            held: true,
+        })
+    }
+
+    /// Give the slot back
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the global lock file cannot be updated.
+    pub fn release(mut self) -> Result<(), LockError> {
+        self.held = false;
+        Self::remove_slot(&self.path, &self.slot)
+    }
+
+    fn remove_slot(path: &Path, slot: &str) -> Result<(), LockError> {
+        Self::update_runs(path, |runs| {
+            runs.retain(|run| run.slot != slot);
+            Ok(())
+        })
+    }
+
+    /// Read-modify-write the active runs under an exclusive file lock
+    ///
+    /// Runs whose process has exited, or whose PID now belongs to a newer
+    /// process, are dropped before `update` sees them.
+    fn update_runs<T>(
+        path: &Path,
+        update: impl FnOnce(&mut Vec<ActiveRun>) -> Result<T, LockError>,
+    ) -> Result<T, LockError> {
+        use std::io::Seek;
+
+        let file = fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)?;
+        let mut rw_lock = RwLock::new(file);
+        let guard = rw_lock.write()?;
+        let mut file: &fs::File = &guard;
+
+        let mut content = String::new();
+        file.read_to_string(&mut content)?;
+        let mut runs: Vec<ActiveRun> = if content.trim().is_empty() {
+            Vec::new()
+        } else {
+            serde_json::from_str(&content).map_err(|e| LockError::CorruptedLock {
+                reason: format!("Failed to parse {}: {e}", path.display()),
+            })?
+        };
+        runs.retain(|run| FileLock::is_owner_running(&run.info));
+
+        let result = update(&mut runs)?;
+
+        let json =
+            serde_json::to_string_pretty(&runs).map_err(|e| LockError::AcquisitionFailed {
+                reason: format!("Failed to serialize active runs: {e}"),
+            })?;
+        file.set_len(0)?;
+        file.rewind()?;
+        file.write_all(json.as_bytes())?;
+        file.sync_all()?;
+
+        Ok(result)
+    }
+}
+
+impl std::fmt::Debug for RunSlot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RunSlot")
+            .field("path", &self.path)
+            .field("slot", &self.slot)
+            .finish()
+    }
+}
+
+impl Drop for RunSlot {
+    /// Automatically give the slot back when the `RunSlot` is dropped
+    fn drop(&mut self) {
+        if self.held {
+            let _ = Self::remove_slot(&self.path, &self.slot);
+        }
+    }
+}
+
 /// Utility functions for lock management
 pub mod utils {
     use super::{
-        DEFAULT_STALE_THRESHOLD_SECS, FileLock, LockError, Result, SystemTime, UNIX_EPOCH, fs,
+        DEFAULT_STALE_THRESHOLD_SECS, FileLock, LockError, LockInfo, Result, SystemTime,
+        UNIX_EPOCH, Utf8Path, fs,
     };
 
+    /// Outcome of [`force_remove_all_locks`]
+    #[derive(Debug, Clone, Default, PartialEq, Eq)]
+    pub struct RemovedLocks {
+        /// Spec IDs whose lock file was removed, sorted
+        pub removed: Vec<String>,
+        /// Spec IDs skipped because the lock owner is still running, sorted
+        pub running: Vec<String>,
+    }
+
+    /// Check whether the process that wrote `lock_info` is still running
+    ///
+    /// A lock from another host is assumed to be running, since its PID
+    /// cannot be checked here.
+    #[must_use]
+    pub fn is_lock_owner_running(lock_info: &LockInfo) -> bool {
+        !lock_info.is_local() || FileLock::is_process_running(lock_info.pid)
+    }
+
     /// Check if clean operation should be allowed (no active locks unless forced)
     pub fn can_clean(
         spec_id: &str,
@@ -869,7 +1643,19 @@ pub mod utils {
     ) -> Result<(), LockError> {
         let ttl = ttl_seconds.unwrap_or(DEFAULT_STALE_THRESHOLD_SECS);
         if let Some(lock_info) = FileLock::get_lock_info(spec_id)? {
-            if FileLock::is_process_running(lock_info.pid) {
+            let lock_age = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_secs()
+                .saturating_sub(lock_info.created_at);
+
+            // A lock from another host counts as active until its TTL expires
+            let owner_running = if lock_info.is_local() {
+                FileLock::is_process_running(lock_info.pid)
+            } else {
+                lock_age <= ttl
+            };
+            if owner_running {
                 if force {
                     // Force flag allows cleaning even with active locks (--hard --force overrides active locks)
                     return Ok(());
@@ -882,20 +1668,12 @@ pub mod utils {
             }
 
             // Process is dead, check if we should allow cleaning
-            if !force {
-                let lock_age = SystemTime::now()
-                    .duration_since(UNIX_EPOCH)
-                    .unwrap()
-                    .as_secs()
-                    - lock_info.created_at;
-
-                if lock_age <= ttl {
-                    return Err(LockError::StaleLock {
-                        spec_id: spec_id.to_string(),
-                        pid: lock_info.pid,
-                        age_secs: lock_age,
-                    });
-                }
+            if !force && lock_age <= ttl {
+                return Err(LockError::StaleLock {
+                    spec_id: spec_id.to_string(),
+                    pid: lock_info.pid,
+                    age_secs: lock_age,
+                });
             }
         }
 
@@ -915,6 +1693,51 @@ pub mod utils {
 
         Ok(())
     }
+
+    /// Remove the lock file of every spec under `xchecker_home` (for
+    /// cleanup after a crash)
+    ///
+    /// Scans `specs/*/.lock`. Locks whose owner is still running, including
+    /// any lock from another host, are left in place and listed in
+    /// [`RemovedLocks::running`] unless `force` is set. Unreadable lock files
+    /// are removed.
+    pub fn force_remove_all_locks(
+        xchecker_home: &Utf8Path,
+        force: bool,
+    ) -> Result<RemovedLocks, LockError> {
+        let mut report = RemovedLocks::default();
+        let specs_dir = xchecker_home.join("specs");
+        if !specs_dir.exists() {
+            return Ok(report);
+        }
+
+        for entry in fs::read_dir(&specs_dir)? {
+            let entry = entry?;
+            let lock_path = entry.path().join(".lock");
+            if !lock_path.is_file() {
+                continue;
+            }
+            let spec_id = entry.file_name().to_string_lossy().into_owned();
+
+            let running = FileLock::read_lock_info(&lock_path)
+                .ok()
+                .flatten()
+                .is_some_and(|info| is_lock_owner_running(&info));
+            if running && !force {
+                report.running.push(spec_id);
+                continue;
+            }
+
+            fs::remove_file(&lock_path).map_err(|e| LockError::ReleaseFailed {
+                reason: format!("Failed to force remove lock for spec '{spec_id}': {e}"),
+            })?;
+            report.removed.push(spec_id);
+        }
+
+        report.removed.sort_unstable();
+        report.running.sort_unstable();
+        Ok(report)
+    }
 }
 
 #[cfg(test)]
@@ -958,6 +1781,46 @@ mod tests {
         let _lock2 = FileLock::acquire(spec_id, false, None).unwrap();
     }
 
+    #[test]
+    fn test_lock_acquisition_in_explicit_spec_root() {
+        let temp_dir = TempDir::new().unwrap();
+        let spec_root = Utf8PathBuf::from_path_buf(temp_dir.path().join("explicit")).unwrap();
+        let spec_id = "test-spec-explicit-root";
+
+        let lock = FileLock::acquire_in(&spec_root, spec_id, false, None).unwrap();
+        assert!(spec_root.join(".lock").exists());
+
+        // Second acquisition in the same directory is rejected
+        let result = FileLock::acquire_in(&spec_root, spec_id, false, None);
+        assert!(matches!(result, Err(LockError::ConcurrentExecution { .. })));
+
+        lock.release().unwrap();
+        assert!(!spec_root.join(".lock").exists());
+    }
+
+    #[test]
+    fn test_stale_lock_paths_are_unique_within_a_millisecond() {
+        let temp_dir = TempDir::new().unwrap();
+        let lock_path = temp_dir.path().join(".lock");
+        let mut targets = Vec::new();
+
+        for _ in 0..2 {
+            FileLock::try_remove_stale_lock_with(&lock_path, "stale-names", |from, to| {
+                assert_eq!(from, lock_path.as_path());
+                targets.push(to.to_path_buf());
+                Ok(())
+            })
+            .unwrap();
+        }
+
+        assert_eq!(targets.len(), 2);
+        assert_ne!(targets[0], targets[1]);
+        for target in &targets {
+            let name = target.file_name().unwrap().to_str().unwrap();
+            assert!(name.starts_with(".lock.stale."), "unexpected name {name}");
+        }
+    }
+
     #[test]
     fn test_lock_info_serialization() {
         let _temp_dir = setup_test_env();
@@ -987,6 +1850,101 @@ mod tests {
         assert!(!FileLock::exists(spec_id));
     }
 
+    /// Rewrite the held lock file with `created_at` moved `secs` into the past
+    fn backdate_lock(lock: &mut FileLock, secs: u64) -> u64 {
+        lock.lock_info.created_at -= secs;
+        let file = lock._fd_lock.as_mut().unwrap().try_write().unwrap();
+        rewrite_lock_file(&file, &lock.lock_info).unwrap();
+        lock.lock_info.created_at
+    }
+
+    #[test]
+    fn test_renew_refreshes_created_at() {
+        let _temp_dir = setup_test_env();
+
+        let spec_id = "test-spec-renew";
+        let mut lock = FileLock::acquire(spec_id, false, None).unwrap();
+        let old = backdate_lock(&mut lock, 600);
+        let info = FileLock::get_lock_info(spec_id).unwrap().unwrap();
+        assert_eq!(info.created_at, old);
+
+        lock.renew().unwrap();
+
+        let info = FileLock::get_lock_info(spec_id).unwrap().unwrap();
+        assert!(info.created_at >= old + 600, "{} vs {old}", info.created_at);
+        assert_eq!(info.created_at, lock.lock_info().created_at);
+        assert_eq!(info.pid, std::process::id());
+
+        lock.release().unwrap();
+        assert!(!FileLock::exists(spec_id));
+    }
+
+    #[test]
+    fn test_heartbeat_renews_until_stopped() {
+        let _temp_dir = setup_test_env();
+
+        let spec_id = "test-spec-heartbeat";
+        let mut lock = FileLock::acquire(spec_id, false, None).unwrap();
+        let old = backdate_lock(&mut lock, 600);
+
+        let heartbeat = lock.start_heartbeat(Duration::from_millis(10)).unwrap();
+        let deadline = Instant::now() + Duration::from_secs(5);
+        let renewed = loop {
+            let info = FileLock::get_lock_info(spec_id).unwrap().unwrap();
+            if info.created_at > old || Instant::now() > deadline {
+                break info.created_at;
+            }
+            thread::sleep(Duration::from_millis(10));
+        };
+        heartbeat.stop();
+
+        assert!(renewed >= old + 600, "{renewed} vs {old}");
+        lock.release().unwrap();
+        assert!(!FileLock::exists(spec_id));
+    }
+
+    #[test]
+    fn test_release_does_not_remove_successor_lock() {
+        let _temp_dir = setup_test_env();
+
+        let spec_id = "test-spec-release-race";
+        let first = FileLock::acquire(spec_id, false, None).unwrap();
+
+        // Another holder takes the lock in the window between the file being
+        // removed and the released `FileLock` being dropped
+        let mut successor = None;
+        first
+            .release_with(|path| {
+                fs::remove_file(path)?;
+                successor = Some(FileLock::acquire(spec_id, false, None).unwrap());
+                Ok(())
+            })
+            .unwrap();
+
+        assert!(
+            FileLock::exists(spec_id),
+            "dropping a released lock must not delete the successor's lock file"
+        );
+        drop(successor);
+        assert!(!FileLock::exists(spec_id));
+    }
+
+    #[test]
+    fn test_failed_release_still_cleans_up_on_drop() {
+        let _temp_dir = setup_test_env();
+
+        let spec_id = "test-spec-release-failed";
+        let lock = FileLock::acquire(spec_id, false, None).unwrap();
+
+        let result = lock.release_with(|_| Err(io::Error::other("simulated removal failure")));
+
+        assert!(matches!(result, Err(LockError::ReleaseFailed { .. })));
+        assert!(
+            !FileLock::exists(spec_id),
+            "a lock whose release failed must be removed on drop"
+        );
+    }
+
     #[test]
     fn test_force_override_stale_lock() {
         let _temp_dir = setup_test_env();
@@ -1003,6 +1961,7 @@ mod tests {
             created_at: 0, // Very old timestamp
             spec_id: spec_id.to_string(),
             xchecker_version: "0.1.0".to_string(),
+            hostname: String::new(),
         };
 
         let lock_json = serde_json::to_string_pretty(&old_lock_info).unwrap();
@@ -1018,6 +1977,83 @@ mod tests {
         assert_eq!(lock.spec_id(), spec_id);
     }
 
+    #[test]
+    fn test_force_remove_all_locks() {
+        let temp_dir = TempDir::new().unwrap();
+        let home = Utf8Path::from_path(temp_dir.path()).unwrap();
+
+        let write_lock = |spec_id: &str, pid: u32| {
+            let spec_root = home.join("specs").join(spec_id);
+            fs::create_dir_all(&spec_root).unwrap();
+            let lock_info = LockInfo {
+                pid,
+                start_time: 0,
+                created_at: 0,
+                spec_id: spec_id.to_string(),
+                xchecker_version: "0.1.0".to_string(),
+                hostname: String::new(),
+            };
+            let lock_json = serde_json::to_string_pretty(&lock_info).unwrap();
+            fs::write(spec_root.join(".lock"), lock_json).unwrap();
+        };
+        write_lock("spec-b", 99999);
+        write_lock("spec-a", 99998);
+        write_lock("spec-live", std::process::id());
+        fs::create_dir_all(home.join("specs/spec-unlocked")).unwrap();
+
+        let report = utils::force_remove_all_locks(home, false).unwrap();
+        assert_eq!(report.removed, ["spec-a", "spec-b"]);
+        assert_eq!(report.running, ["spec-live"]);
+        assert!(!home.join("specs/spec-a/.lock").exists());
+        assert!(!home.join("specs/spec-b/.lock").exists());
+        assert!(home.join("specs/spec-live/.lock").exists());
+
+        let report = utils::force_remove_all_locks(home, true).unwrap();
+        assert_eq!(report.removed, ["spec-live"]);
+        assert!(report.running.is_empty());
+        assert!(!home.join("specs/spec-live/.lock").exists());
+    }
+
+    #[test]
+    fn test_list_active_locks_reports_running_flags() {
+        let temp_dir = TempDir::new().unwrap();
+        let home = Utf8Path::from_path(temp_dir.path()).unwrap();
+        assert!(FileLock::list_active_locks(home).unwrap().is_empty());
+
+        let write_lock = |spec_id: &str, pid: u32| {
+            let spec_root = home.join("specs").join(spec_id);
+            fs::create_dir_all(&spec_root).unwrap();
+            let lock_info = LockInfo {
+                pid,
+                start_time: 0,
+                created_at: 0,
+                spec_id: spec_id.to_string(),
+                xchecker_version: "0.1.0".to_string(),
+                hostname: String::new(),
+            };
+            let lock_json = serde_json::to_string_pretty(&lock_info).unwrap();
+            fs::write(spec_root.join(".lock"), lock_json).unwrap();
+        };
+        write_lock("spec-live", std::process::id());
+        write_lock("spec-dead", 99999);
+        fs::create_dir_all(home.join("specs/spec-corrupt")).unwrap();
+        fs::write(home.join("specs/spec-corrupt/.lock"), "not json").unwrap();
+        fs::create_dir_all(home.join("specs/spec-unlocked")).unwrap();
+
+        let locks = FileLock::list_active_locks(home).unwrap();
+        let summary: Vec<(&str, u32, bool)> = locks
+            .iter()
+            .map(|(spec_id, info, running)| (spec_id.as_str(), info.pid, *running))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("spec-dead", 99999, false),
+                ("spec-live", std::process::id(), true)
+            ]
+        );
+    }
+
     #[test]
     fn test_clean_operation_checks() {
         let _temp_dir = setup_test_env();
@@ -1184,6 +2220,46 @@ mod tests {
         assert!(drift.schema_version.is_some());
     }
 
+    #[test]
+    fn test_xchecker_lock_drift_report_lists_every_field() {
+        let lock = XCheckerLock::new("haiku".to_string(), "0.8.1".to_string());
+
+        let context = RunContext {
+            model_full_name: "sonnet".to_string(),
+            claude_cli_version: "0.9.0".to_string(),
+            schema_version: "2".to_string(),
+        };
+
+        let report = lock.check_and_report(&context);
+        assert_eq!(
+            report,
+            serde_json::json!({
+                "has_drift": true,
+                "fields": [
+                    { "field": "model_full_name", "locked": "haiku", "current": "sonnet" },
+                    { "field": "claude_cli_version", "locked": "0.8.1", "current": "0.9.0" },
+                    { "field": "schema_version", "locked": "1", "current": "2" },
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn test_xchecker_lock_drift_report_without_drift() {
+        let lock = XCheckerLock::new("haiku".to_string(), "0.8.1".to_string());
+
+        let context = RunContext {
+            model_full_name: "haiku".to_string(),
+            claude_cli_version: "0.8.1".to_string(),
+            schema_version: "1".to_string(),
+        };
+
+        assert_eq!(
+            lock.check_and_report(&context),
+            serde_json::json!({ "has_drift": false })
+        );
+    }
+
     #[test]
     fn test_xchecker_lock_save_and_load() {
         let _temp_dir = setup_test_env();
@@ -1411,6 +2487,7 @@ mod tests {
             created_at: two_minutes_ago,
             spec_id: spec_id.to_string(),
             xchecker_version: "0.1.0".to_string(),
+            hostname: String::new(),
         };
 
         let lock_json = serde_json::to_string_pretty(&old_lock_info).unwrap();
@@ -1453,6 +2530,7 @@ mod tests {
             created_at: two_hours_ago,
             spec_id: spec_id.to_string(),
             xchecker_version: "0.1.0".to_string(),
+            hostname: String::new(),
         };
 
         let lock_json = serde_json::to_string_pretty(&old_lock_info).unwrap();
@@ -1468,6 +2546,66 @@ mod tests {
         assert_eq!(lock.spec_id(), spec_id);
     }
 
+    #[test]
+    fn test_foreign_host_lock_uses_ttl_not_pid() {
+        let _temp_dir = setup_test_env();
+
+        let spec_id = "test-spec-foreign-host";
+        let lock_path = FileLock::get_lock_path(spec_id);
+        fs::create_dir_all(lock_path.parent().unwrap()).unwrap();
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let write_lock = |created_at: u64| {
+            let info = LockInfo {
+                pid: process::id(), // Live here, but it belongs to another host
+                start_time: 0,
+                created_at,
+                spec_id: spec_id.to_string(),
+                xchecker_version: "0.1.0".to_string(),
+                hostname: "other-host.invalid".to_string(),
+            };
+            assert!(!info.is_local());
+            assert!(utils::is_lock_owner_running(&info));
+            fs::write(&lock_path, serde_json::to_string_pretty(&info).unwrap()).unwrap();
+        };
+
+        // Within TTL the foreign holder is assumed alive
+        write_lock(now - 60);
+        assert!(matches!(
+            FileLock::acquire(spec_id, false, None),
+            Err(LockError::ConcurrentExecution { .. })
+        ));
+
+        // Past TTL it is stale even though its PID is running on this host
+        write_lock(now - 7200);
+        assert!(matches!(
+            FileLock::acquire(spec_id, false, None),
+            Err(LockError::StaleLock { .. })
+        ));
+        assert!(utils::can_clean(spec_id, false, None).is_ok());
+
+        let lock = FileLock::acquire(spec_id, true, None).unwrap();
+        assert!(lock.lock_info().is_local());
+        assert_eq!(lock.lock_info().hostname, current_hostname());
+    }
+
+    #[test]
+    fn test_lock_info_without_hostname_is_local() {
+        let json = r#"{
+            "pid": 12345,
+            "start_time": 0,
+            "created_at": 0,
+            "spec_id": "legacy",
+            "xchecker_version": "0.1.0"
+        }"#;
+        let info: LockInfo = serde_json::from_str(json).unwrap();
+        assert_eq!(info.hostname, "");
+        assert!(info.is_local());
+    }
+
     #[test]
     fn test_stale_lock_detection_by_dead_process() {
         let _temp_dir = setup_test_env();
@@ -1490,6 +2628,7 @@ mod tests {
             created_at: recent_time,
             spec_id: spec_id.to_string(),
             xchecker_version: "0.1.0".to_string(),
+            hostname: String::new(),
         };
 
         let lock_json = serde_json::to_string_pretty(&old_lock_info).unwrap();
@@ -1504,6 +2643,61 @@ mod tests {
         assert_eq!(lock.spec_id(), spec_id);
     }
 
+    #[test]
+    fn test_dead_process_grace_period() {
+        let _temp_dir = setup_test_env();
+
+        let write_dead_lock = |spec_id: &str, age_secs: u64| {
+            let lock_path = FileLock::get_lock_path(spec_id);
+            fs::create_dir_all(lock_path.parent().unwrap()).unwrap();
+            let created_at = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_secs()
+                - age_secs;
+            let lock_info = LockInfo {
+                pid: 99999, // Non-existent PID
+                start_time: 0,
+                created_at,
+                spec_id: spec_id.to_string(),
+                xchecker_version: "0.1.0".to_string(),
+                hostname: String::new(),
+            };
+            fs::write(
+                &lock_path,
+                serde_json::to_string_pretty(&lock_info).unwrap(),
+            )
+            .unwrap();
+        };
+        let policy = LockPolicy {
+            ttl_seconds: None,
+            dead_process_grace_secs: Some(10),
+        };
+
+        // Within the grace period the PID may have been recycled: force required
+        let spec_id = "test-spec-dead-grace-fresh";
+        write_dead_lock(spec_id, 1);
+        let result = FileLock::acquire_with_policy(spec_id, false, policy);
+        assert!(matches!(result, Err(LockError::ConcurrentExecution { .. })));
+        let lock = FileLock::acquire_with_policy(spec_id, true, policy).unwrap();
+        assert_eq!(lock.spec_id(), spec_id);
+
+        // Past the grace period the dead-process lock is taken over without force
+        let spec_id = "test-spec-dead-grace-expired";
+        write_dead_lock(spec_id, 60);
+        let lock = FileLock::acquire_with_policy(spec_id, false, policy).unwrap();
+        assert_eq!(lock.spec_id(), spec_id);
+        assert_eq!(
+            FileLock::get_lock_info(spec_id).unwrap().unwrap().pid,
+            process::id()
+        );
+
+        // Without a grace period the same lock still requires force
+        let spec_id = "test-spec-dead-grace-unset";
+        write_dead_lock(spec_id, 60);
+        assert!(FileLock::acquire_with_policy(spec_id, false, LockPolicy::default()).is_err());
+    }
+
     #[test]
     fn test_concurrent_execution_detection() {
         let _temp_dir = setup_test_env();
@@ -1526,6 +2720,72 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_acquire_blocking_waits_for_release() {
+        let _temp_dir = setup_test_env();
+
+        let spec_id = "test-spec-blocking-release";
+        let held = FileLock::acquire(spec_id, false, None).unwrap();
+
+        let releaser = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(150));
+            held.release().unwrap();
+        });
+
+        let started = Instant::now();
+        let lock = FileLock::acquire_blocking(spec_id, false, None, Duration::from_secs(10))
+            .expect("lock should be acquired once the holder releases it");
+        assert!(started.elapsed() >= Duration::from_millis(100));
+        assert_eq!(lock.spec_id(), spec_id);
+        releaser.join().unwrap();
+    }
+
+    #[test]
+    fn test_acquire_blocking_times_out() {
+        let _temp_dir = setup_test_env();
+
+        let spec_id = "test-spec-blocking-timeout";
+        let _held = FileLock::acquire(spec_id, false, None).unwrap();
+
+        let started = Instant::now();
+        let result = FileLock::acquire_blocking(spec_id, false, None, Duration::from_millis(200));
+        assert!(started.elapsed() >= Duration::from_millis(200));
+        match result {
+            Err(LockError::AcquisitionFailed { reason }) => {
+                assert!(reason.contains("Timed out after 0.2s"), "{reason}");
+                assert!(reason.contains(spec_id), "{reason}");
+            }
+            other => panic!("Expected AcquisitionFailed, got: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_acquire_blocking_zero_timeout_matches_acquire() {
+        let _temp_dir = setup_test_env();
+
+        let spec_id = "test-spec-blocking-zero";
+        let _held = FileLock::acquire(spec_id, false, None).unwrap();
+
+        let result = FileLock::acquire_blocking(spec_id, false, None, Duration::ZERO);
+        assert!(matches!(result, Err(LockError::ConcurrentExecution { .. })));
+    }
+
+    #[test]
+    fn test_backoff_delay_doubles_with_deterministic_jitter() {
+        let max = Duration::from_secs(1);
+        for attempt in 0..6 {
+            let delay = FileLock::backoff_delay(attempt, max);
+            assert_eq!(delay, FileLock::backoff_delay(attempt, max));
+            let base = Duration::from_millis(10 << attempt);
+            assert!(delay >= base && delay < base + Duration::from_millis(7));
+        }
+        assert_eq!(FileLock::backoff_delay(20, max), max);
+        assert_eq!(
+            FileLock::backoff_delay(5, Duration::from_millis(100)),
+            Duration::from_millis(100)
+        );
+    }
+
     #[test]
     fn test_lock_release_on_normal_exit() {
         let _temp_dir = setup_test_env();
@@ -1577,6 +2837,7 @@ mod tests {
             created_at: 0,
             spec_id: spec_id.to_string(),
             xchecker_version: "0.1.0".to_string(),
+            hostname: String::new(),
         };
 
         let lock_json = serde_json::to_string_pretty(&old_lock_info).unwrap();
@@ -1616,6 +2877,7 @@ mod tests {
                 .as_secs(),
             spec_id: spec_id.to_string(),
             xchecker_version: "0.1.0".to_string(),
+            hostname: String::new(),
         };
 
         let lock_json = serde_json::to_string_pretty(&invalid_lock_info).unwrap();
@@ -1772,6 +3034,7 @@ mod tests {
             created_at: 0, // Unix epoch
             spec_id: spec_id.to_string(),
             xchecker_version: "0.1.0".to_string(),
+            hostname: String::new(),
         };
 
         let lock_json = serde_json::to_string_pretty(&old_lock_info).unwrap();
@@ -1809,6 +3072,7 @@ mod tests {
             created_at: future_timestamp,
             spec_id: spec_id.to_string(),
             xchecker_version: "0.1.0".to_string(),
+            hostname: String::new(),
         };
 
         let lock_json = serde_json::to_string_pretty(&future_lock_info).unwrap();
@@ -1914,6 +3178,35 @@ mod tests {
         assert_eq!(lock.claude_cli_version, unicode_version);
     }
 
+    #[test]
+    fn test_oversized_lock_file_is_corrupted() {
+        let _temp_dir = setup_test_env();
+
+        let spec_id = "test-spec-huge-lock";
+        let lock_path = FileLock::get_lock_path(spec_id);
+        fs::create_dir_all(lock_path.parent().unwrap()).unwrap();
+
+        // Multi-megabyte lock file; only the bounded prefix may be read
+        let huge = vec![b'x'; 4 * 1024 * 1024];
+        fs::write(&lock_path, &huge).unwrap();
+        assert!(read_lock_file(&lock_path).unwrap().is_none());
+
+        match FileLock::acquire(spec_id, true, None).unwrap_err() {
+            LockError::CorruptedLock { reason } => {
+                assert!(reason.contains("lock file too large"), "{reason}");
+                assert!(reason.contains(spec_id), "{reason}");
+            }
+            other => panic!("Expected CorruptedLock error, got: {other:?}"),
+        }
+
+        match FileLock::get_lock_info(spec_id).unwrap_err() {
+            LockError::CorruptedLock { reason } => {
+                assert!(reason.contains("lock file too large"), "{reason}");
+            }
+            other => panic!("Expected CorruptedLock error, got: {other:?}"),
+        }
+    }
+
     #[test]
     fn test_empty_lockfile_error_includes_spec_id() {
         let _temp_dir = setup_test_env();
@@ -2042,6 +3335,7 @@ mod tests {
             created_at: future_timestamp,
             spec_id: spec_id.to_string(),
             xchecker_version: "0.1.0".to_string(),
+            hostname: String::new(),
         };
 
         let lock_json = serde_json::to_string_pretty(&lock_info).unwrap();
@@ -2052,4 +3346,122 @@ mod tests {
         let result = FileLock::acquire(spec_id, true, None);
         assert!(result.is_ok(), "Should handle clock skew gracefully");
     }
+
+    fn temp_home() -> (TempDir, Utf8PathBuf) {
+        let temp = TempDir::new().unwrap();
+        let home = Utf8PathBuf::from_path_buf(temp.path().to_path_buf()).unwrap();
+        (temp, home)
+    }
+
+    #[test]
+    fn test_run_slots_limited_across_threads() {
+        let (_temp, home) = temp_home();
+        let limit = 3;
+
+        let slots: Vec<RunSlot> = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..limit)
+                .map(|i| {
+                    let home = &home;
+                    scope.spawn(move || {
+                        RunSlot::acquire_in(home, &format!("spec-{i}"), limit).unwrap()
+                    })
+                })
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+
+        let rejected = std::thread::scope(|scope| {
+            scope
+                .spawn(|| RunSlot::acquire_in(&home, "spec-extra", limit))
+                .join()
+                .unwrap()
+        });
+        match rejected {
+            Err(LockError::TooManyConcurrentSpecs {
+                limit: 3,
+                active: 3,
+            }) => {}
+            other => panic!("Expected TooManyConcurrentSpecs, got: {other:?}"),
+        }
+
+        let mut slots = slots.into_iter();
+        slots.next().unwrap().release().unwrap();
+
+        let admitted = RunSlot::acquire_in(&home, "spec-extra", limit);
+        assert!(admitted.is_ok(), "a released slot should be reusable");
+
+        // Dropping the remaining slots frees them too
+        drop(slots);
+        drop(admitted);
+        let runs: Vec<ActiveRun> =
+            serde_json::from_str(&fs::read_to_string(home.join(GLOBAL_LOCK_FILE)).unwrap())
+                .unwrap();
+        assert!(runs.is_empty());
+    }
+
+    #[test]
+    fn test_run_slot_reclaims_slot_of_dead_process() {
+        let (_temp, home) = temp_home();
+
+        let crashed = vec![ActiveRun {
+            slot: "99999-0".to_string(),
+            info: LockInfo {
+                pid: 99999, // Non-existent PID
+                start_time: 0,
+                created_at: 0,
+                spec_id: "crashed-spec".to_string(),
+                xchecker_version: "0.1.0".to_string(),
+                hostname: String::new(),
+            },
+        }];
+        fs::write(
+            home.join(GLOBAL_LOCK_FILE),
+            serde_json::to_string(&crashed).unwrap(),
+        )
+        .unwrap();
+
+        let slot = RunSlot::acquire_in(&home, "live-spec", 1);
+        assert!(
+            slot.is_ok(),
+            "dead holder should not count toward the limit"
+        );
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_run_slot_reclaims_slot_of_reused_pid() {
+        let (_temp, home) = temp_home();
+
+        // A live PID recorded long before this process started belongs to a
+        // process that has since exited and had its PID reused
+        let stale = vec![ActiveRun {
+            slot: "reused-0".to_string(),
+            info: LockInfo {
+                pid: process::id(),
+                start_time: 1,
+                created_at: 1,
+                spec_id: "old-spec".to_string(),
+                xchecker_version: "0.1.0".to_string(),
+                hostname: String::new(),
+            },
+        }];
+        fs::write(
+            home.join(GLOBAL_LOCK_FILE),
+            serde_json::to_string(&stale).unwrap(),
+        )
+        .unwrap();
+
+        let slot = RunSlot::acquire_in(&home, "live-spec", 1);
+        assert!(
+            slot.is_ok(),
+            "an entry whose PID was reused should not count toward the limit"
+        );
+
+        // This process's own start time is recognised as its own
+        let own = FileLock::get_process_start_time().unwrap();
+        assert!(FileLock::is_owner_running(&LockInfo {
+            start_time: own,
+            ..stale[0].info.clone()
+        }));
+    }
 }
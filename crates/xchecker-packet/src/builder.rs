@@ -1,14 +1,21 @@
-use super::model::{CandidateFile, SelectedFile};
+use super::model::{
+    CandidateFile, CandidateSelection, ExcludedFile, FileStamp, SecretPolicy, SelectedFile,
+};
+use super::render::file_manifest;
 use super::selectors::ContentSelector;
 use crate::{BudgetUsage, Packet};
 use anyhow::{Context, Result};
 use blake3::Hasher;
 use camino::{Utf8Path, Utf8PathBuf};
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
 use std::fs;
+use std::ops::Range;
 use std::sync::{Arc, Mutex};
 use std::thread;
-use xchecker_config::Selectors;
-use xchecker_redaction::SecretRedactor;
+use xchecker_config::{PacketOrder, PriorArtifactForm, Selectors};
+use xchecker_redaction::{SecretConfigProvider, SecretRedactor};
 use xchecker_utils::cache::InsightCache;
 use xchecker_utils::error::XCheckerError;
 use xchecker_utils::logging::Logger;
@@ -26,14 +33,55 @@ pub const DEFAULT_PACKET_MAX_LINES: usize = 1200;
 pub struct PacketBuilder {
     /// Content selector for file prioritization
     selector: ContentSelector,
-    /// Secret redactor for protecting sensitive information
-    redactor: SecretRedactor,
+    /// Secret redactor for protecting sensitive information.
+    ///
+    /// Redaction is mandatory: if the redactor could not be constructed the
+    /// error is kept here and `build_packet` refuses to run.
+    redactor: Result<SecretRedactor, String>,
     /// Insight cache for performance optimization (R3.4, R3.5)
     cache: Option<InsightCache>,
     /// Maximum bytes allowed in packet
     max_bytes: usize,
     /// Maximum lines allowed in packet
     max_lines: usize,
+    /// Test seam run between candidate selection and file read
+    before_read: Option<ReadHook>,
+    /// Per-file rewrite applied after reading and before redaction
+    content_transform: Option<ContentTransform>,
+    /// Layout order of included files
+    order: PacketOrder,
+    /// Cap on the size of the human-readable packet preview
+    pub(super) preview_max_bytes: Option<usize>,
+    /// Write the packet preview gzip-compressed
+    pub(super) compress_preview: bool,
+    /// Files included verbatim, without secret scanning or redaction
+    redaction_exempt: GlobSet,
+    /// Reuse whole packets from `cache` when the file set is unchanged
+    packet_cache: bool,
+    /// Abort or redact when a selected file contains a secret
+    secret_policy: SecretPolicy,
+}
+
+/// Callback invoked with a candidate path just before its content is read
+#[derive(Clone)]
+struct ReadHook(Arc<dyn Fn(&Utf8Path) + Send + Sync>);
+
+impl std::fmt::Debug for ReadHook {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ReadHook")
+    }
+}
+
+type ContentTransformFn = dyn Fn(&Utf8Path, &str) -> String + Send + Sync;
+
+/// Callback that rewrites a file's content before redaction and inclusion
+#[derive(Clone)]
+struct ContentTransform(Arc<ContentTransformFn>);
+
+impl std::fmt::Debug for ContentTransform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ContentTransform")
+    }
 }
 
 impl PacketBuilder {
@@ -41,10 +89,18 @@ impl PacketBuilder {
     pub fn new() -> Result<Self> {
         Ok(Self {
             selector: ContentSelector::new()?.max_file_size(DEFAULT_PACKET_MAX_BYTES as u64),
-            redactor: SecretRedactor::new()?,
+            redactor: Ok(SecretRedactor::new()?),
             cache: None,
             max_bytes: DEFAULT_PACKET_MAX_BYTES,
             max_lines: DEFAULT_PACKET_MAX_LINES,
+            before_read: None,
+            content_transform: None,
+            order: PacketOrder::Priority,
+            preview_max_bytes: None,
+            compress_preview: false,
+            redaction_exempt: GlobSet::empty(),
+            packet_cache: false,
+            secret_policy: SecretPolicy::FailClosed,
         })
     }
 
@@ -53,10 +109,18 @@ impl PacketBuilder {
     pub fn with_cache(cache_dir: Utf8PathBuf) -> Result<Self> {
         Ok(Self {
             selector: ContentSelector::new()?.max_file_size(DEFAULT_PACKET_MAX_BYTES as u64),
-            redactor: SecretRedactor::new()?,
+            redactor: Ok(SecretRedactor::new()?),
             cache: Some(InsightCache::new(cache_dir)?),
             max_bytes: DEFAULT_PACKET_MAX_BYTES,
             max_lines: DEFAULT_PACKET_MAX_LINES,
+            before_read: None,
+            content_transform: None,
+            order: PacketOrder::Priority,
+            preview_max_bytes: None,
+            compress_preview: false,
+            redaction_exempt: GlobSet::empty(),
+            packet_cache: false,
+            secret_policy: SecretPolicy::FailClosed,
         })
     }
 
@@ -71,10 +135,18 @@ impl PacketBuilder {
         Ok(Self {
             selector: ContentSelector::from_selectors(selectors)?
                 .max_file_size(DEFAULT_PACKET_MAX_BYTES as u64),
-            redactor: SecretRedactor::new()?,
+            redactor: Ok(SecretRedactor::new()?),
             cache: None,
             max_bytes: DEFAULT_PACKET_MAX_BYTES,
             max_lines: DEFAULT_PACKET_MAX_LINES,
+            before_read: None,
+            content_transform: None,
+            order: PacketOrder::Priority,
+            preview_max_bytes: None,
+            compress_preview: false,
+            redaction_exempt: GlobSet::empty(),
+            packet_cache: false,
+            secret_policy: SecretPolicy::FailClosed,
         })
     }
 
@@ -93,10 +165,18 @@ impl PacketBuilder {
     ) -> Result<Self> {
         Ok(Self {
             selector: ContentSelector::from_selectors(selectors)?.max_file_size(max_bytes as u64),
-            redactor: SecretRedactor::new()?,
+            redactor: Ok(SecretRedactor::new()?),
             cache: None,
             max_bytes,
             max_lines,
+            before_read: None,
+            content_transform: None,
+            order: PacketOrder::Priority,
+            preview_max_bytes: None,
+            compress_preview: false,
+            redaction_exempt: GlobSet::empty(),
+            packet_cache: false,
+            secret_policy: SecretPolicy::FailClosed,
         })
     }
 
@@ -105,10 +185,18 @@ impl PacketBuilder {
     pub fn with_limits(max_bytes: usize, max_lines: usize) -> Result<Self> {
         Ok(Self {
             selector: ContentSelector::new()?.max_file_size(max_bytes as u64),
-            redactor: SecretRedactor::new()?,
+            redactor: Ok(SecretRedactor::new()?),
             cache: None,
             max_bytes,
             max_lines,
+            before_read: None,
+            content_transform: None,
+            order: PacketOrder::Priority,
+            preview_max_bytes: None,
+            compress_preview: false,
+            redaction_exempt: GlobSet::empty(),
+            packet_cache: false,
+            secret_policy: SecretPolicy::FailClosed,
         })
     }
 
@@ -121,10 +209,18 @@ impl PacketBuilder {
     ) -> Result<Self> {
         Ok(Self {
             selector: ContentSelector::new()?.max_file_size(max_bytes as u64),
-            redactor: SecretRedactor::new()?,
+            redactor: Ok(SecretRedactor::new()?),
             cache: Some(InsightCache::new(cache_dir)?),
             max_bytes,
             max_lines,
+            before_read: None,
+            content_transform: None,
+            order: PacketOrder::Priority,
+            preview_max_bytes: None,
+            compress_preview: false,
+            redaction_exempt: GlobSet::empty(),
+            packet_cache: false,
+            secret_policy: SecretPolicy::FailClosed,
         })
     }
 
@@ -138,10 +234,18 @@ impl PacketBuilder {
     ) -> Self {
         Self {
             selector: selector.max_file_size(max_bytes as u64),
-            redactor: SecretRedactor::new().expect("Failed to create SecretRedactor"),
+            redactor: SecretRedactor::new().map_err(|e| format!("{e:#}")),
             cache: None,
             max_bytes,
             max_lines,
+            before_read: None,
+            content_transform: None,
+            order: PacketOrder::Priority,
+            preview_max_bytes: None,
+            compress_preview: false,
+            redaction_exempt: GlobSet::empty(),
+            packet_cache: false,
+            secret_policy: SecretPolicy::FailClosed,
         }
     }
 
@@ -156,10 +260,18 @@ impl PacketBuilder {
     ) -> Self {
         Self {
             selector: selector.max_file_size(max_bytes as u64),
-            redactor,
+            redactor: Ok(redactor),
             cache: None,
             max_bytes,
             max_lines,
+            before_read: None,
+            content_transform: None,
+            order: PacketOrder::Priority,
+            preview_max_bytes: None,
+            compress_preview: false,
+            redaction_exempt: GlobSet::empty(),
+            packet_cache: false,
+            secret_policy: SecretPolicy::FailClosed,
         }
     }
 
@@ -175,24 +287,53 @@ impl PacketBuilder {
     ) -> Self {
         Self {
             selector: selector.max_file_size(max_bytes as u64),
-            redactor,
+            redactor: Ok(redactor),
             cache,
             max_bytes,
             max_lines,
+            before_read: None,
+            content_transform: None,
+            order: PacketOrder::Priority,
+            preview_max_bytes: None,
+            compress_preview: false,
+            redaction_exempt: GlobSet::empty(),
+            packet_cache: false,
+            secret_policy: SecretPolicy::FailClosed,
         }
     }
 
+    /// Replace the redactor applied to packet content
+    #[must_use]
+    pub fn with_redactor(mut self, redactor: SecretRedactor) -> Self {
+        self.redactor = Ok(redactor);
+        self
+    }
+
+    /// Replace the redactor with one built from secret pattern configuration.
+    ///
+    /// If the configuration is invalid the builder keeps no redactor and
+    /// `build_packet` fails with `XCheckerError::RedactorUnavailable`.
+    #[must_use]
+    pub fn with_redactor_config<T: SecretConfigProvider>(mut self, config: &T) -> Self {
+        self.redactor = SecretRedactor::from_config(config).map_err(|e| format!("{e:#}"));
+        self
+    }
+
     /// Get a mutable reference to the redactor for configuration
+    ///
+    /// Returns `None` if the redactor could not be constructed.
     #[allow(dead_code)] // Builder accessor for API surface
-    pub const fn redactor_mut(&mut self) -> &mut SecretRedactor {
-        &mut self.redactor
+    pub fn redactor_mut(&mut self) -> Option<&mut SecretRedactor> {
+        self.redactor.as_mut().ok()
     }
 
     /// Get a reference to the redactor
+    ///
+    /// Returns `None` if the redactor could not be constructed.
     #[must_use]
     #[allow(dead_code)] // Builder accessor for API surface
-    pub const fn redactor(&self) -> &SecretRedactor {
-        &self.redactor
+    pub fn redactor(&self) -> Option<&SecretRedactor> {
+        self.redactor.as_ref().ok()
     }
 
     /// Get a mutable reference to the cache for configuration
@@ -234,26 +375,197 @@ impl PacketBuilder {
         self
     }
 
+    /// Set the maximum size of a single file considered for the packet.
+    ///
+    /// Oversized non-upstream files are excluded before reading and reported
+    /// through [`Packet::excluded`]. Defaults to the packet byte budget.
+    #[must_use]
+    pub fn with_max_file_size(mut self, bytes: u64) -> Self {
+        self.selector = self.selector.with_max_file_size(bytes);
+        self
+    }
+
+    /// Choose which form of prior-phase artifacts packets include.
+    ///
+    /// Default is [`PriorArtifactForm::Both`].
+    #[must_use]
+    pub fn with_prior_artifact_form(mut self, form: PriorArtifactForm) -> Self {
+        self.selector = self.selector.prior_artifact_form(form);
+        self
+    }
+
+    /// Rewrite each file's content after it is read and before redaction.
+    ///
+    /// Use this to decode or strip generated content (e.g. base64 blobs).
+    /// The transformed text is what gets secret-scanned, redacted, hashed
+    /// into the packet hash and included in the packet. The evidence
+    /// `blake3_pre_redaction` hash is still computed over the original file
+    /// content, so it keeps matching the bytes on disk. Because that hash
+    /// does not identify the transformed text, the packet and insight caches
+    /// are bypassed while a transform is set.
+    #[must_use]
+    pub fn with_content_transform<F>(mut self, transform: F) -> Self
+    where
+        F: Fn(&Utf8Path, &str) -> String + Send + Sync + 'static,
+    {
+        self.content_transform = Some(ContentTransform(Arc::new(transform)));
+        self
+    }
+
+    /// Choose the order of included files within the packet.
+    ///
+    /// Files are always admitted in priority order, so the inclusion set and
+    /// budget outcome are the same either way. [`PacketOrder::Path`] only
+    /// re-sorts the admitted files (and their evidence) by path. Default is
+    /// [`PacketOrder::Priority`].
+    #[must_use]
+    pub const fn with_order(mut self, order: PacketOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Choose what happens when a selected file contains a secret.
+    ///
+    /// Under [`SecretPolicy::FailClosed`] (the default) `build_packet` fails
+    /// with `XCheckerError::SecretDetected` naming the file and the matched
+    /// pattern ids, before anything is written. [`SecretPolicy::Redact`]
+    /// replaces the secrets and includes the file. Files matching the
+    /// redaction-exempt globs are not scanned under either policy.
+    #[must_use]
+    pub const fn with_secret_policy(mut self, policy: SecretPolicy) -> Self {
+        self.secret_policy = policy;
+        self
+    }
+
+    /// Cap the size of `context/<phase>-packet.txt`.
+    ///
+    /// Only the preview is truncated (with a footer giving the full size);
+    /// the packet returned by `build_packet` always carries the full body.
+    /// `None` (the default) writes the preview in full.
+    #[must_use]
+    pub const fn with_preview_max_bytes(mut self, max_bytes: Option<usize>) -> Self {
+        self.preview_max_bytes = max_bytes;
+        self
+    }
+
+    /// Write the preview gzip-compressed as `context/<phase>-packet.txt.gz`
+    /// instead of `context/<phase>-packet.txt`.
+    ///
+    /// Only one variant is kept: writing either removes the other. The
+    /// packet evidence records which was written; read it back with
+    /// [`crate::read_packet_preview`].
+    #[must_use]
+    pub const fn with_compress_preview(mut self, compress: bool) -> Self {
+        self.compress_preview = compress;
+        self
+    }
+
+    /// Include files matching any of `globs` verbatim, without redaction.
+    ///
+    /// Meant for known-safe fixtures, such as a security doc showing token
+    /// formats, that redaction markers would corrupt. Matching files skip
+    /// secret scanning and redaction entirely, so their evidence carries
+    /// `redaction_exempt: true` and each inclusion is logged as a warning.
+    /// Globs match the same paths as selectors. Replaces any earlier set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any glob is invalid.
+    pub fn with_redaction_exempt_globs(mut self, globs: Vec<String>) -> Result<Self> {
+        let mut builder = GlobSetBuilder::new();
+        for glob in &globs {
+            builder.add(
+                Glob::new(glob)
+                    .with_context(|| format!("Invalid redaction exempt glob: {glob}"))?,
+            );
+        }
+        self.redaction_exempt = builder.build()?;
+        Ok(self)
+    }
+
+    /// Reuse the whole packet for a phase when its inputs are unchanged.
+    ///
+    /// Requires an insight cache (see [`PacketBuilder::with_cache`] and
+    /// [`PacketBuilder::set_cache`]), which stores one packet per phase keyed
+    /// by a BLAKE3 hash over the selected files' contents, the budget, the
+    /// layout order and the redactor settings. On a match, files are not
+    /// secret-scanned or redacted again; any changed file invalidates the
+    /// entry. Builders with a content transform never reuse packets. Off by
+    /// default.
+    #[must_use]
+    pub const fn with_packet_cache(mut self, enabled: bool) -> Self {
+        self.packet_cache = enabled;
+        self
+    }
+
+    /// Test seam; not part of public API stability guarantees.
+    ///
+    /// Register a callback that runs on each candidate path after selection
+    /// and before the file is read, e.g. to simulate a concurrent edit.
+    #[doc(hidden)]
+    pub fn set_before_read_hook<F>(&mut self, hook: F)
+    where
+        F: Fn(&Utf8Path) + Send + Sync + 'static,
+    {
+        self.before_read = Some(ReadHook(Arc::new(hook)));
+    }
+
     /// Build a packet from the given base path and phase context
     /// Returns a Packet with content and evidence, or fails pre-Claude if budget exceeded
+    ///
+    /// Fails with `XCheckerError::RedactorUnavailable` before selecting any
+    /// files if the builder holds no valid redactor, and with
+    /// `XCheckerError::ContextChangedDuringBuild` if a selected file's size or
+    /// mtime differs between selection and read.
     pub fn build_packet(
         &mut self,
         base_path: &Utf8Path,
         phase: &str,
         context_dir: &Utf8Path,
-        _logger: Option<&Logger>,
+        logger: Option<&Logger>,
     ) -> Result<Packet> {
+        // Never emit content without redaction
+        let redactor_ref = match &self.redactor {
+            Ok(redactor) => redactor,
+            Err(reason) => {
+                return Err(XCheckerError::RedactorUnavailable {
+                    reason: reason.clone(),
+                }
+                .into());
+            }
+        };
+
         // Select candidates using lazy selection (no content reading yet)
-        let candidates = self
+        let CandidateSelection {
+            candidates,
+            excluded,
+        } = self
             .selector
-            .select_candidates(base_path)
+            .select_candidates_with_exclusions(base_path)
             .with_context(|| format!("Failed to select files from {base_path}"))?;
 
+        // Reuse the cached packet when the selected files are unchanged
+        let file_set_hash =
+            if self.packet_cache && self.cache.is_some() && self.content_transform.is_none() {
+                self.file_set_hash(phase, &candidates, redactor_ref)
+            } else {
+                None
+            };
+        if let Some(hash) = &file_set_hash
+            && let Some(cache) = self.cache.as_mut()
+            && let Some(cached) = cache.get_packet::<CachedBuild>(phase, hash, logger)
+        {
+            return self.packet_from_cache(cached, excluded, phase, context_dir);
+        }
+
         // Prepare for parallel processing
         // Extract cache to wrap in Arc<Mutex>
         let cache_arc = self.cache.take().map(|c| Arc::new(Mutex::new(c)));
-        let redactor_ref = &self.redactor;
         let max_file_size = self.selector.get_max_file_size();
+        let before_read = self.before_read.as_ref();
+        let content_transform = self.content_transform.as_ref();
+        let redaction_exempt = &self.redaction_exempt;
+        let secret_policy = self.secret_policy;
 
         // Process files in parallel
         // We use std::thread::scope to allow sharing references (like redactor_ref)
@@ -279,7 +591,11 @@ impl PacketBuilder {
                             max_file_size,
                             phase,
                             redactor_ref,
+                            redaction_exempt.is_match(candidate.path.as_str()),
+                            secret_policy,
                             cache_clone.as_ref(),
+                            before_read,
+                            content_transform,
                         );
                         results.push(result);
                     }
@@ -315,15 +631,14 @@ impl PacketBuilder {
 
         // Build packet from results
         let mut budget = BudgetUsage::new(self.max_bytes, self.max_lines);
-        let mut packet_content = String::new();
-        let mut included_files = Vec::new();
+        let mut included = Vec::new();
 
         // Separate Upstream and Other results to apply budget logic
         // process_results corresponds 1:1 to candidates
         let mut upstream_results = Vec::new();
         let mut other_results = Vec::new();
 
-        for (candidate, result) in candidates.iter().zip(process_results.into_iter()) {
+        for (candidate, result) in candidates.iter().zip(process_results) {
             if candidate.priority == Priority::Upstream {
                 upstream_results.push((candidate, result));
             } else {
@@ -335,24 +650,23 @@ impl PacketBuilder {
         for (_candidate, result) in upstream_results {
             // Propagate errors from processing
             match result {
-                Ok(Some((file, file_content, content_size, line_count))) => {
-                    // Add file content to packet
-                    let redacted_path = self.redactor.redact_string(file.path.as_str());
-                    packet_content.push_str(&format!("=== {} ===\n", redacted_path));
-                    packet_content.push_str(&file_content);
-                    packet_content.push_str("\n\n");
-
+                Ok(Some(ProcessedFile {
+                    file,
+                    file_content,
+                    raw_file_content,
+                    redaction_exempt,
+                    content_size,
+                    line_count,
+                })) => {
                     // Update budget
                     budget.add_content(content_size, line_count);
 
-                    // Create file evidence
-                    let evidence = FileEvidence {
-                        path: file.path.to_string(),
-                        range: None, // Full file for now
-                        blake3_pre_redaction: file.blake3_pre_redaction,
-                        priority: file.priority,
-                    };
-                    included_files.push(evidence);
+                    included.push(IncludedFile {
+                        file,
+                        file_content,
+                        raw_file_content,
+                        redaction_exempt,
+                    });
                 }
                 Ok(None) => { /* Skipped file */ }
                 Err(e) => return Err(e),
@@ -361,6 +675,7 @@ impl PacketBuilder {
 
         // Check if upstream files alone exceed budget
         if budget.is_exceeded() {
+            let (packet_content, _, included_files, _) = render_included(&included, redactor_ref);
             self.write_packet_preview(&packet_content, phase, context_dir)?;
             self.write_packet_manifest(&included_files, &budget, phase, context_dir)?;
 
@@ -376,50 +691,147 @@ impl PacketBuilder {
         // Second pass: Add other files until budget is reached
         for (_candidate, result) in other_results {
             match result {
-                Ok(Some((file, file_content, content_size, line_count))) => {
+                Ok(Some(ProcessedFile {
+                    file,
+                    file_content,
+                    raw_file_content,
+                    redaction_exempt,
+                    content_size,
+                    line_count,
+                })) => {
                     // Check if this file would exceed budget
                     if budget.would_exceed(content_size, line_count) {
                         // Skip this file to stay within budget
                         continue;
                     }
 
-                    // Add file content to packet
-                    let redacted_path = self.redactor.redact_string(file.path.as_str());
-                    packet_content.push_str(&format!("=== {} ===\n", redacted_path));
-                    packet_content.push_str(&file_content);
-                    packet_content.push_str("\n\n");
-
                     // Update budget
                     budget.add_content(content_size, line_count);
 
-                    // Create file evidence
-                    let evidence = FileEvidence {
-                        path: file.path.to_string(),
-                        range: None, // Full file for now
-                        blake3_pre_redaction: file.blake3_pre_redaction,
-                        priority: file.priority,
-                    };
-                    included_files.push(evidence);
+                    included.push(IncludedFile {
+                        file,
+                        file_content,
+                        raw_file_content,
+                        redaction_exempt,
+                    });
                 }
                 Ok(None) => { /* Skipped file */ }
                 Err(e) => return Err(e),
             }
         }
 
+        // Inclusion is settled; only the layout of admitted files changes here
+        if self.order == PacketOrder::Path {
+            included.sort_by(|a, b| a.file.path.cmp(&b.file.path));
+        }
+        let (packet_content, raw_content, included_files, verbatim_lines) =
+            render_included(&included, redactor_ref);
+
         // Calculate packet hash (after redaction has been applied)
         let packet_blake3 = self.calculate_packet_hash(&packet_content);
 
+        // Write the per-file manifest before evidence takes ownership of the list
+        let manifest = file_manifest(&included, &included_files, phase);
+        self.write_packet_file_manifest(&manifest, phase, context_dir)?;
+
         // Create packet evidence
         let evidence = PacketEvidence {
             files: included_files,
             max_bytes: self.max_bytes,
             max_lines: self.max_lines,
+            preview_compressed: self.compress_preview,
         };
 
         // Always write packet preview for context (redacted content)
         self.write_packet_preview(&packet_content, phase, context_dir)?;
 
-        Ok(Packet::new(packet_content, packet_blake3, evidence, budget))
+        if let Some(hash) = &file_set_hash
+            && let Some(cache) = self.cache.as_mut()
+        {
+            let cached = CachedBuild {
+                content: packet_content.clone(),
+                blake3_hash: packet_blake3.clone(),
+                evidence: evidence.clone(),
+                bytes_used: budget.bytes_used,
+                lines_used: budget.lines_used,
+                verbatim_lines: verbatim_lines.clone(),
+                manifest,
+            };
+            if let Err(e) = cache.store_packet(phase, hash, &cached, logger) {
+                tracing::warn!("Failed to cache packet for {phase}: {e:#}");
+            }
+        }
+
+        Ok(Packet::new(packet_content, packet_blake3, evidence, budget)
+            .with_raw_body(raw_content)
+            .with_excluded(excluded)
+            .with_preview_max_bytes(self.preview_max_bytes)
+            .with_verbatim_lines(verbatim_lines))
+    }
+
+    /// Hash the selected files' contents together with every setting that
+    /// shapes the packet body, keying the packet cache.
+    ///
+    /// Returns `None` if a file cannot be read; the build then reports the
+    /// error as usual.
+    fn file_set_hash(
+        &self,
+        phase: &str,
+        candidates: &[CandidateFile],
+        redactor: &SecretRedactor,
+    ) -> Option<String> {
+        let mut hasher = Hasher::new();
+        hasher.update(
+            format!(
+                "{phase}\0{}\0{}\0{:?}\0{}\0{}\0{:?}",
+                self.max_bytes,
+                self.max_lines,
+                self.order,
+                self.selector.get_max_file_size(),
+                redactor.fingerprint(),
+                self.secret_policy
+            )
+            .as_bytes(),
+        );
+        for candidate in candidates {
+            let content = fs::read(&candidate.path).ok()?;
+            hasher.update(
+                format!(
+                    "\0{}\0{:?}\0{}\0",
+                    candidate.path,
+                    candidate.priority,
+                    self.redaction_exempt.is_match(candidate.path.as_str())
+                )
+                .as_bytes(),
+            );
+            hasher.update(blake3::hash(&content).as_bytes());
+        }
+        Some(hasher.finalize().to_hex().to_string())
+    }
+
+    /// Rebuild a packet from the packet cache, rewriting its context files
+    fn packet_from_cache(
+        &self,
+        cached: CachedBuild,
+        excluded: Vec<ExcludedFile>,
+        phase: &str,
+        context_dir: &Utf8Path,
+    ) -> Result<Packet> {
+        let mut budget = BudgetUsage::new(self.max_bytes, self.max_lines);
+        budget.add_content(cached.bytes_used, cached.lines_used);
+        let mut evidence = cached.evidence;
+        evidence.preview_compressed = self.compress_preview;
+
+        self.write_packet_file_manifest(&cached.manifest, phase, context_dir)?;
+        self.write_packet_preview(&cached.content, phase, context_dir)?;
+
+        Ok(
+            Packet::new(cached.content, cached.blake3_hash, evidence, budget)
+                .with_excluded(excluded)
+                .with_preview_max_bytes(self.preview_max_bytes)
+                .with_verbatim_lines(cached.verbatim_lines)
+                .with_from_cache(true),
+        )
     }
 
     /// Calculate BLAKE3 hash of packet content
@@ -438,15 +850,119 @@ impl PacketBuilder {
     }
 }
 
+/// A candidate file after reading, redaction and cache lookup
+struct ProcessedFile {
+    file: SelectedFile,
+    /// Redacted content as it appears in the packet body
+    file_content: String,
+    /// Same content before redaction
+    raw_file_content: String,
+    /// Content was exempt from secret scanning and redaction
+    redaction_exempt: bool,
+    content_size: usize,
+    line_count: usize,
+}
+
+/// What the packet cache keeps for a phase: the redacted packet and its
+/// manifest, never the pre-redaction body
+#[derive(Serialize, Deserialize)]
+struct CachedBuild {
+    content: String,
+    blake3_hash: String,
+    evidence: PacketEvidence,
+    bytes_used: usize,
+    lines_used: usize,
+    verbatim_lines: Vec<Range<usize>>,
+    manifest: serde_json::Value,
+}
+
+/// A file admitted to the packet, kept until layout order is applied
+pub(super) struct IncludedFile {
+    pub(super) file: SelectedFile,
+    pub(super) file_content: String,
+    pub(super) raw_file_content: String,
+    pub(super) redaction_exempt: bool,
+}
+
+/// Rendered packet body: redacted content, raw content, evidence and the
+/// 0-based content line ranges of redaction-exempt file bodies
+type RenderedPacket = (String, String, Vec<FileEvidence>, Vec<Range<usize>>);
+
+/// Render admitted files into redacted content, raw content and evidence
+fn render_included(included: &[IncludedFile], redactor: &SecretRedactor) -> RenderedPacket {
+    let mut packet_content = String::new();
+    let mut raw_content = String::new();
+    let mut evidence = Vec::with_capacity(included.len());
+    let mut verbatim_lines = Vec::new();
+    let mut line = 0;
+
+    for IncludedFile {
+        file,
+        file_content,
+        raw_file_content,
+        redaction_exempt,
+    } in included
+    {
+        let redacted_path = redactor.redact_string(file.path.as_str());
+        packet_content.push_str(&format!("=== {} ===\n", redacted_path));
+        line += 1;
+        let body_start = line;
+        packet_content.push_str(file_content);
+        line += file_content.matches('\n').count();
+        if *redaction_exempt {
+            verbatim_lines.push(body_start..line + 1);
+        }
+        packet_content.push_str("\n\n");
+        line += 2;
+        raw_content.push_str(&format!("=== {} ===\n", file.path));
+        raw_content.push_str(raw_file_content);
+        raw_content.push_str("\n\n");
+
+        evidence.push(FileEvidence {
+            path: file.path.to_string(),
+            range: None, // Full file for now
+            blake3_pre_redaction: file.blake3_pre_redaction.clone(),
+            priority: file.priority,
+            redaction_exempt: *redaction_exempt,
+        });
+    }
+
+    (packet_content, raw_content, evidence, verbatim_lines)
+}
+
+/// Fail with `ContextChangedDuringBuild` if `metadata` no longer matches the
+/// stamp captured when `candidate` was selected.
+fn ensure_unchanged(candidate: &CandidateFile, metadata: &fs::Metadata) -> Result<()> {
+    if let Some(stamp) = candidate.stamp
+        && stamp != FileStamp::from_metadata(metadata)
+    {
+        return Err(XCheckerError::ContextChangedDuringBuild {
+            path: candidate.path.to_string(),
+        }
+        .into());
+    }
+    Ok(())
+}
+
 /// Helper function to process a single candidate file in parallel.
 /// This encapsulates reading, hashing, redaction, and cache interaction.
+/// A `redaction_exempt` file skips secret scanning and redaction.
+#[allow(clippy::too_many_arguments)]
 fn process_candidate_file(
     candidate: &CandidateFile,
     max_file_size: u64,
     phase: &str,
     redactor: &SecretRedactor,
+    redaction_exempt: bool,
+    secret_policy: SecretPolicy,
     cache: Option<&Arc<Mutex<InsightCache>>>,
-) -> Result<Option<(SelectedFile, String, usize, usize)>> {
+    before_read: Option<&ReadHook>,
+    content_transform: Option<&ContentTransform>,
+) -> Result<Option<ProcessedFile>> {
+    if let Some(hook) = before_read {
+        (hook.0)(&candidate.path);
+    }
+
     // DoS protection: check file size before reading
     let metadata = fs::metadata(&candidate.path)
         .with_context(|| format!("Failed to get file metadata: {}", candidate.path))?;
@@ -455,6 +971,9 @@ fn process_candidate_file(
         return Ok(None);
     }
 
+    // The file must be unchanged since selection so evidence matches what was chosen
+    ensure_unchanged(candidate, &metadata)?;
+
     if metadata.len() > max_file_size {
         // For upstream files (critical context), fail hard if they exceed the limit
         if candidate.priority == Priority::Upstream {
@@ -480,27 +999,74 @@ fn process_candidate_file(
     let content = fs::read_to_string(&candidate.path)
         .with_context(|| format!("Failed to read file: {}", candidate.path))?;
 
-    // Scan for secrets immediately after reading
-    if redactor.has_secrets(&content, candidate.path.as_ref())? {
-        let matches = redactor.scan_for_secrets(&content, candidate.path.as_ref())?;
-        return Err(XCheckerError::SecretDetected {
-            pattern: matches
-                .first()
-                .map(|m| m.pattern_id.clone())
-                .unwrap_or_else(|| "unknown".to_string()),
-            location: matches
-                .first()
-                .map(|m| m.file_path.clone())
-                .unwrap_or_else(|| "unknown".to_string()),
+    // Re-check after reading to catch writes that raced with the read itself
+    let metadata = fs::metadata(&candidate.path)
+        .with_context(|| format!("Failed to get file metadata: {}", candidate.path))?;
+    ensure_unchanged(candidate, &metadata)?;
+    if content.len() as u64 != metadata.len() {
+        return Err(XCheckerError::ContextChangedDuringBuild {
+            path: candidate.path.to_string(),
         }
         .into());
     }
 
-    // Calculate pre-redaction hash
+    // Evidence hashes the original bytes, whatever the transform does
     let mut hasher = Hasher::new();
     hasher.update(content.as_bytes());
     let blake3_pre_redaction = hasher.finalize().to_hex().to_string();
 
+    let content = match content_transform {
+        Some(transform) => (transform.0)(&candidate.path, &content),
+        None => content,
+    };
+
+    if redaction_exempt {
+        tracing::warn!(
+            "Including {} verbatim: exempt from secret scanning and redaction",
+            candidate.path
+        );
+    }
+
+    // Scan for secrets immediately after reading
+    if !redaction_exempt && redactor.has_secrets(&content, candidate.path.as_ref())? {
+        let matches = redactor.scan_for_secrets(&content, candidate.path.as_ref())?;
+        // Pattern ids only; the matched text never leaves the redactor
+        let pattern_ids: BTreeSet<&str> = matches.iter().map(|m| m.pattern_id.as_str()).collect();
+        let pattern = if pattern_ids.is_empty() {
+            "unknown".to_string()
+        } else {
+            pattern_ids.into_iter().collect::<Vec<_>>().join(", ")
+        };
+
+        match secret_policy {
+            SecretPolicy::FailClosed => {
+                return Err(XCheckerError::SecretDetected {
+                    pattern,
+                    location: candidate.path.to_string(),
+                }
+                .into());
+            }
+            SecretPolicy::Redact => {
+                tracing::warn!("Redacting secrets ({}) in {}", pattern, candidate.path);
+            }
+        }
+    }
+
+    // Possibly truncated credentials are flagged but left in place
+    let near_misses = if redaction_exempt {
+        Vec::new()
+    } else {
+        redactor.scan_for_near_misses(&content, candidate.path.as_ref())
+    };
+    for near_miss in near_misses {
+        tracing::warn!(
+            "Possible truncated secret ({}) in {} at line {}",
+            near_miss.pattern_id,
+            near_miss.file_path,
+            near_miss.line_number
+        );
+    }
+
     let line_count_raw = content.lines().count();
     let byte_count_raw = content.len();
 
@@ -513,8 +1079,19 @@ fn process_candidate_file(
         byte_count: byte_count_raw,
     };
 
-    // Cache Logic Inlined
-    let file_content = if let Some(cache_mutex) = cache {
+    let redact = |content: &str| -> Result<String> {
+        if redaction_exempt {
+            return Ok(content.to_string());
+        }
+        Ok(redactor
+            .redact_content(content, candidate.path.as_ref())?
+            .content)
+    };
+
+    // Cache Logic Inlined. Insights are keyed on the on-disk hash, which a
+    // transform does not change, so transformed builds bypass the cache.
+    let cache = cache.filter(|_| content_transform.is_none());
+    let (file_content, raw_file_content) = if let Some(cache_mutex) = cache {
         // Try to get cached insights
         let cached_insights = {
             let mut guard = cache_mutex.lock().expect("Cache mutex poisoned");
@@ -523,18 +1100,18 @@ fn process_candidate_file(
         };
 
         if let Some(insights) = cached_insights {
-            format!(
+            let cached = format!(
                 "CACHED INSIGHTS:\n{}",
                 insights
                     .iter()
                     .map(|insight| format!("• {insight}"))
                     .collect::<Vec<_>>()
                     .join("\n")
-            )
+            );
+            (cached.clone(), cached)
         } else {
             // Cache miss
-            let redaction_result = redactor.redact_content(&content, candidate.path.as_ref())?;
-            let redacted_content = redaction_result.content;
+            let redacted_content = redact(&content)?;
 
             // Generate insights
             // Use a temporary cache instance or lock again?
@@ -567,31 +1144,32 @@ fn process_candidate_file(
                 )?;
             }
 
-            format!(
-                "INSIGHTS:\n{}\n\nORIGINAL CONTENT:\n{}",
-                insights
-                    .iter()
-                    .map(|insight| format!("• {insight}"))
-                    .collect::<Vec<_>>()
-                    .join("\n"),
-                redacted_content
+            let insights = insights
+                .iter()
+                .map(|insight| format!("• {insight}"))
+                .collect::<Vec<_>>()
+                .join("\n");
+            (
+                format!("INSIGHTS:\n{insights}\n\nORIGINAL CONTENT:\n{redacted_content}"),
+                format!("INSIGHTS:\n{insights}\n\nORIGINAL CONTENT:\n{content}"),
             )
         }
     } else {
         // No cache
-        let redaction_result = redactor.redact_content(&content, candidate.path.as_ref())?;
-        redaction_result.content
+        (redact(&content)?, content)
     };
 
     let content_size = file_content.len() + candidate.path.as_str().len() + 10;
     let line_count = file_content.lines().count() + 3;
 
-    Ok(Some((
-        selected_file,
+    Ok(Some(ProcessedFile {
+        file: selected_file,
         file_content,
+        raw_file_content,
+        redaction_exempt,
         content_size,
         line_count,
-    )))
+    }))
 }
 
 impl Default for PacketBuilder {
@@ -715,6 +1293,161 @@ mod packet_builder_tests {
         Ok(())
     }
 
+    #[test]
+    fn test_packet_order_controls_layout_not_inclusion() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let base_path = Utf8PathBuf::try_from(temp_dir.path().to_path_buf())?;
+        let context_dir = base_path.join("context");
+
+        fs::write(base_path.join("z.core.yaml"), "key: value")?;
+        fs::write(base_path.join("b.md"), "# B")?;
+        fs::write(base_path.join("a.md"), "# A")?;
+        fs::write(base_path.join("big.md"), "# Large file\n".repeat(100))?;
+
+        let build = |order| -> Result<Vec<String>> {
+            let mut builder = PacketBuilder::with_limits(200, 20)?.with_order(order);
+            let packet = builder.build_packet(&base_path, "test", &context_dir, None)?;
+            Ok(packet
+                .evidence
+                .files
+                .iter()
+                .map(|f| f.path.rsplit('/').next().unwrap().to_string())
+                .collect())
+        };
+
+        let by_priority = build(PacketOrder::Priority)?;
+        let by_path = build(PacketOrder::Path)?;
+
+        assert_eq!(by_priority[0], "z.core.yaml");
+        assert_eq!(by_path, ["a.md", "b.md", "z.core.yaml"]);
+
+        // Same files admitted under the same budget, only the layout differs
+        let mut sorted = by_priority.clone();
+        sorted.sort();
+        assert_eq!(sorted, by_path);
+        assert!(!by_priority.iter().any(|name| name == "big.md"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_compress_preview_round_trips_and_is_recorded_in_evidence() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let base_path = Utf8PathBuf::try_from(temp_dir.path().to_path_buf())?;
+        let context_dir = base_path.join("context");
+        let plain_path = context_dir.join("test-packet.txt");
+        let gz_path = context_dir.join("test-packet.txt.gz");
+
+        fs::write(base_path.join("notes.md"), "# Notes\n".repeat(200))?;
+
+        let mut builder = PacketBuilder::with_limits(65536, 1200)?;
+        let packet = builder.build_packet(&base_path, "test", &context_dir, None)?;
+        assert!(!packet.evidence.preview_compressed);
+        assert!(plain_path.exists());
+
+        let mut builder = PacketBuilder::with_limits(65536, 1200)?.with_compress_preview(true);
+        let packet = builder.build_packet(&base_path, "test", &context_dir, None)?;
+        assert!(packet.evidence.preview_compressed);
+        assert!(
+            !plain_path.exists(),
+            "stale plain preview should be removed"
+        );
+
+        let compressed = fs::read(&gz_path)?;
+        assert!(compressed.starts_with(&[0x1f, 0x8b]), "not gzip");
+        assert!(compressed.len() < packet.body().len());
+        assert_eq!(crate::read_packet_preview(&gz_path)?, packet.preview());
+
+        // Switching back writes the plain preview and drops the compressed one
+        let mut builder = PacketBuilder::with_limits(65536, 1200)?;
+        let packet = builder.build_packet(&base_path, "test", &context_dir, None)?;
+        assert!(!gz_path.exists());
+        assert_eq!(crate::read_packet_preview(&plain_path)?, packet.preview());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_file_manifest_totals_match_per_file_sizes() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let base_path = Utf8PathBuf::try_from(temp_dir.path().to_path_buf())?;
+        let context_dir = base_path.join("context");
+
+        fs::write(base_path.join("a.md"), "# A\nalpha\n")?;
+        fs::write(base_path.join("b.md"), "key: value\nother: 1\nlast: 2\n")?;
+        fs::write(base_path.join("c.txt"), "plain text")?;
+
+        let mut builder = PacketBuilder::new()?;
+        let packet = builder.build_packet(&base_path, "Requirements", &context_dir, None)?;
+
+        let manifest: serde_json::Value = serde_json::from_str(&fs::read_to_string(
+            context_dir.join("requirements-manifest.json"),
+        )?)?;
+        let files = manifest["files"].as_array().unwrap();
+        assert_eq!(files.len(), packet.evidence.files.len());
+        assert_eq!(manifest["totals"]["files"], files.len());
+
+        let sum_bytes: u64 = files.iter().map(|f| f["bytes"].as_u64().unwrap()).sum();
+        let sum_lines: u64 = files.iter().map(|f| f["lines"].as_u64().unwrap()).sum();
+        assert_eq!(manifest["totals"]["bytes"], sum_bytes);
+        assert_eq!(manifest["totals"]["lines"], sum_lines);
+
+        for (entry, evidence) in files.iter().zip(&packet.evidence.files) {
+            assert_eq!(entry["path"], evidence.path.as_str());
+            assert_eq!(
+                entry["blake3_pre_redaction"],
+                evidence.blake3_pre_redaction.as_str()
+            );
+            assert_eq!(entry["redacted"], false);
+        }
+        let b = files
+            .iter()
+            .find(|f| f["path"].as_str().unwrap().ends_with("b.md"))
+            .unwrap();
+        assert_eq!(b["bytes"], 28);
+        assert_eq!(b["lines"], 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_preview_max_bytes_truncates_preview_only() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let base_path = Utf8PathBuf::try_from(temp_dir.path().to_path_buf())?;
+        let context_dir = base_path.join("context");
+
+        fs::write(base_path.join("notes.md"), "# Notes\n".repeat(50))?;
+
+        let mut builder = PacketBuilder::with_limits(65536, 1200)?.with_preview_max_bytes(Some(64));
+        let packet = builder.build_packet(&base_path, "test", &context_dir, None)?;
+
+        let full_len = packet.body().len();
+        assert!(full_len > 64);
+        assert_eq!(packet.body().matches("# Notes").count(), 50);
+        assert!(packet.is_preview_truncated());
+
+        let preview = fs::read_to_string(context_dir.join("test-packet.txt"))?;
+        let footer = format!("[... preview truncated, full packet was {full_len} bytes ...]\n");
+        assert!(preview.ends_with(&footer));
+        assert_eq!(preview, packet.preview());
+        assert!(
+            packet
+                .body()
+                .starts_with(preview.strip_suffix(&footer).unwrap())
+        );
+
+        // Without a cap the preview is the full body
+        let mut builder = PacketBuilder::with_limits(65536, 1200)?;
+        let packet = builder.build_packet(&base_path, "test", &context_dir, None)?;
+        assert!(!packet.is_preview_truncated());
+        assert_eq!(
+            fs::read_to_string(context_dir.join("test-packet.txt"))?,
+            packet.body()
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_upstream_overflow_causes_failure() -> Result<()> {
         let temp_dir = TempDir::new()?;
@@ -788,6 +1521,43 @@ mod packet_builder_tests {
         Ok(())
     }
 
+    #[test]
+    fn test_secret_policy_fail_closed_aborts_and_redact_continues() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let base_path = Utf8PathBuf::try_from(temp_dir.path().to_path_buf())?;
+        let context_dir = base_path.join("context");
+        let token = test_support::github_pat();
+
+        fs::write(
+            base_path.join("config.yaml"),
+            format!("github_token: {token}"),
+        )?;
+
+        let mut builder = PacketBuilder::new()?.with_secret_policy(SecretPolicy::FailClosed);
+        let err = builder
+            .build_packet(&base_path, "test", &context_dir, None)
+            .unwrap_err();
+        match err.downcast_ref::<XCheckerError>() {
+            Some(XCheckerError::SecretDetected { pattern, location }) => {
+                assert!(location.ends_with("config.yaml"), "location: {location}");
+                assert!(pattern.contains("github_pat"), "pattern: {pattern}");
+            }
+            other => panic!("expected SecretDetected, got {other:?}"),
+        }
+        assert!(!err.to_string().contains(&token));
+        assert!(
+            !context_dir.exists(),
+            "fail-closed build must write nothing"
+        );
+
+        let mut builder = PacketBuilder::new()?.with_secret_policy(SecretPolicy::Redact);
+        let packet = builder.build_packet(&base_path, "test", &context_dir, None)?;
+        assert!(packet.body().contains("github_token:"));
+        assert!(!packet.body().contains(&token));
+
+        Ok(())
+    }
+
     #[test]
     fn test_redaction_applied_to_packet_content() -> Result<()> {
         let temp_dir = TempDir::new()?;
@@ -802,9 +1572,11 @@ mod packet_builder_tests {
         // Add a pattern that would match but then ignore it to test redaction
         builder
             .redactor_mut()
+            .unwrap()
             .add_extra_pattern("test_pattern".to_string(), r"safe")?;
         builder
             .redactor_mut()
+            .unwrap()
             .add_ignored_pattern("test_pattern".to_string());
 
         let packet = builder.build_packet(&base_path, "test", &context_dir, None)?;
@@ -858,6 +1630,7 @@ mod packet_builder_tests {
         let mut builder = PacketBuilder::new()?;
         builder
             .redactor_mut()
+            .unwrap()
             .add_ignored_pattern("github_pat".to_string());
 
         let packet = builder.build_packet(&base_path, "test", &context_dir, None)?;
@@ -877,23 +1650,323 @@ mod packet_builder_tests {
         // Test adding extra pattern
         builder
             .redactor_mut()
+            .unwrap()
             .add_extra_pattern("custom".to_string(), r"CUSTOM_[A-Z0-9]+")?;
 
         // Test adding ignored pattern
         builder
             .redactor_mut()
+            .unwrap()
             .add_ignored_pattern("github_pat".to_string());
 
         // Verify patterns are configured
-        let pattern_ids = builder.redactor().get_pattern_ids();
+        let pattern_ids = builder.redactor().unwrap().get_pattern_ids();
         assert!(pattern_ids.contains(&"custom".to_string()));
 
-        let ignored = builder.redactor().get_ignored_patterns();
+        let ignored = builder.redactor().unwrap().get_ignored_patterns();
         assert!(ignored.contains(&"github_pat".to_string()));
 
         Ok(())
     }
 
+    struct PatternConfig {
+        extra: Vec<xchecker_redaction::ExtraSecretPattern>,
+        ignore: Vec<String>,
+    }
+
+    impl SecretConfigProvider for PatternConfig {
+        fn extra_secret_patterns(&self) -> &[xchecker_redaction::ExtraSecretPattern] {
+            &self.extra
+        }
+
+        fn ignore_secret_patterns(&self) -> &[String] {
+            &self.ignore
+        }
+    }
+
+    #[test]
+    fn test_build_packet_applies_injected_redactor() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let base_path = Utf8PathBuf::try_from(temp_dir.path().to_path_buf())?;
+        let context_dir = base_path.join("context");
+
+        fs::write(base_path.join("notes_HOST-alpha.md"), "# Notes")?;
+
+        let mut redactor = SecretRedactor::new()?;
+        redactor.add_extra_pattern("internal_host".to_string(), r"HOST-[a-z]+")?;
+        let mut builder = PacketBuilder::new()?.with_redactor(redactor);
+        let packet = builder.build_packet(&base_path, "requirements", &context_dir, None)?;
+
+        assert!(!packet.content.contains("HOST-alpha"));
+        assert!(packet.content.contains("notes_***"));
+        let preview = fs::read_to_string(context_dir.join("requirements-packet.txt"))?;
+        assert!(!preview.contains("HOST-alpha"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_packet_excludes_oversized_file_before_reading() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let base_path = Utf8PathBuf::try_from(temp_dir.path().to_path_buf())?;
+        let context_dir = base_path.join("context");
+
+        fs::write(base_path.join("big.txt"), "line\n".repeat(512))?;
+        fs::write(base_path.join("README.md"), "# Small")?;
+
+        let visited = Arc::new(Mutex::new(Vec::new()));
+        let mut builder = PacketBuilder::new()?.with_max_file_size(1024);
+        let seen = Arc::clone(&visited);
+        builder.set_before_read_hook(move |path| seen.lock().unwrap().push(path.to_path_buf()));
+
+        let packet = builder.build_packet(&base_path, "requirements", &context_dir, None)?;
+
+        assert!(packet.content.contains("# Small"));
+        assert_eq!(packet.evidence.files.len(), 1);
+        assert_eq!(packet.excluded().len(), 1);
+        assert!(packet.excluded()[0].path.ends_with("big.txt"));
+        assert!(matches!(
+            packet.excluded()[0].reason,
+            crate::ExcludeReason::TooLarge {
+                size: 2560,
+                limit: 1024
+            }
+        ));
+        assert!(
+            !visited
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|p| p.ends_with("big.txt")),
+            "oversized file must not be opened"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_file_modified_between_selection_and_read_fails() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let base_path = Utf8PathBuf::try_from(temp_dir.path().to_path_buf())?;
+        let context_dir = base_path.join("context");
+
+        let target = base_path.join("README.md");
+        fs::write(&target, "# Test\nOriginal content.")?;
+
+        let mut builder = PacketBuilder::new()?;
+        builder.set_before_read_hook(|path| {
+            if path.file_name() == Some("README.md") {
+                fs::write(path, "# Test\nContent rewritten by a concurrent editor.").unwrap();
+            }
+        });
+
+        let err = builder
+            .build_packet(&base_path, "requirements", &context_dir, None)
+            .unwrap_err();
+        match err.downcast_ref::<XCheckerError>() {
+            Some(XCheckerError::ContextChangedDuringBuild { path }) => {
+                assert_eq!(path, target.as_str());
+            }
+            other => panic!("expected ContextChangedDuringBuild, got {other:?}"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unchanged_files_pass_read_verification() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let base_path = Utf8PathBuf::try_from(temp_dir.path().to_path_buf())?;
+        let context_dir = base_path.join("context");
+
+        fs::write(base_path.join("README.md"), "# Test\nStable content.")?;
+
+        let mut builder = PacketBuilder::new()?;
+        builder.set_before_read_hook(|_| {});
+        let packet = builder.build_packet(&base_path, "requirements", &context_dir, None)?;
+        assert_eq!(packet.evidence.files.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_content_transform_strips_lines_before_inclusion() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let base_path = Utf8PathBuf::try_from(temp_dir.path().to_path_buf())?;
+        let context_dir = base_path.join("context");
+
+        let blob = "QUJDREVGR0hJSktMTU5PUFFSU1RVVldYWVo0NTY3ODkwYWJjZGVmZ2hpams=";
+        let original = format!("# Fixture\nkept line\n{blob}\n");
+        fs::write(base_path.join("README.md"), &original)?;
+
+        let is_base64 = |line: &str| {
+            line.len() >= 40
+                && line
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '/' | '='))
+        };
+        let mut builder = PacketBuilder::new()?.with_content_transform(move |_, content| {
+            content
+                .lines()
+                .filter(|line| !is_base64(line))
+                .collect::<Vec<_>>()
+                .join("\n")
+        });
+        let packet = builder.build_packet(&base_path, "test", &context_dir, None)?;
+
+        assert!(packet.content.contains("kept line"));
+        assert!(!packet.content.contains(blob));
+
+        // Evidence still hashes the file as it is on disk
+        let expected_hash = blake3::hash(original.as_bytes()).to_hex().to_string();
+        assert_eq!(packet.evidence.files[0].blake3_pre_redaction, expected_hash);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_content_transform_bypasses_insight_cache() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let base_path = Utf8PathBuf::try_from(temp_dir.path().to_path_buf())?;
+        let work_dir = TempDir::new()?;
+        let work_path = Utf8PathBuf::try_from(work_dir.path().to_path_buf())?;
+        let context_dir = work_path.join("context");
+        let cache_dir = work_path.join("cache");
+
+        fs::write(
+            base_path.join("README.md"),
+            "# Fixture
+secret-ish line
+",
+        )?;
+
+        // Populate the insight cache from the untransformed content
+        let mut builder = PacketBuilder::with_cache(cache_dir.clone())?;
+        builder.build_packet(&base_path, "test", &context_dir, None)?;
+        assert_eq!(builder.cache().unwrap().stats().misses, 1);
+
+        // Same file on disk, but the transform changes what gets included
+        let mut builder = PacketBuilder::with_cache(cache_dir)?
+            .with_content_transform(|_, content| content.replace("secret-ish line", "stripped"));
+        let packet = builder.build_packet(&base_path, "test", &context_dir, None)?;
+        let stats = builder.cache().unwrap().stats();
+
+        assert_eq!(stats.hits + stats.misses, 0);
+        assert!(!packet.content.contains("CACHED INSIGHTS"));
+        assert!(packet.content.contains("stripped"));
+        assert!(!packet.content.contains("secret-ish line"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_redaction_exempt_file_included_verbatim() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let base_path = Utf8PathBuf::try_from(temp_dir.path().to_path_buf())?;
+        let context_dir = base_path.join("context");
+        let token = test_support::github_pat();
+
+        fs::create_dir_all(base_path.join("docs"))?;
+        let example = format!("# Token formats\n\nA GitHub PAT looks like `{token}`.\n");
+        fs::write(base_path.join("docs/token-formats.md"), &example)?;
+        fs::write(base_path.join("README.md"), "# Project\nNo secrets here.")?;
+
+        let mut builder = PacketBuilder::new()?
+            .with_redaction_exempt_globs(vec!["**/docs/token-formats.md".to_string()])?;
+        let packet = builder.build_packet(&base_path, "test", &context_dir, None)?;
+
+        assert!(packet.content.contains(&token));
+        assert!(!packet.content.contains("[REDACTED"));
+
+        // The packet-level secret scan skips the exempt body but keeps line numbering
+        let scannable = packet.scannable_body();
+        assert!(!scannable.contains(&token));
+        assert!(scannable.contains("No secrets here."));
+        assert_eq!(scannable.lines().count(), packet.content.lines().count());
+
+        let exempt: Vec<(&str, bool)> = packet
+            .evidence
+            .files
+            .iter()
+            .map(|f| (f.path.rsplit('/').next().unwrap(), f.redaction_exempt))
+            .collect();
+        assert!(exempt.contains(&("token-formats.md", true)));
+        assert!(exempt.contains(&("README.md", false)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_non_exempt_file_still_scanned_for_secrets() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let base_path = Utf8PathBuf::try_from(temp_dir.path().to_path_buf())?;
+        let context_dir = base_path.join("context");
+        let token = test_support::github_pat();
+
+        fs::create_dir_all(base_path.join("docs"))?;
+        fs::write(
+            base_path.join("docs/token-formats.md"),
+            format!("`{token}`"),
+        )?;
+        fs::write(
+            base_path.join("config.yaml"),
+            format!("github_token: {token}"),
+        )?;
+
+        let mut builder =
+            PacketBuilder::new()?.with_redaction_exempt_globs(vec!["**/docs/*.md".to_string()])?;
+        let err = builder
+            .build_packet(&base_path, "test", &context_dir, None)
+            .unwrap_err();
+
+        match err.downcast_ref::<XCheckerError>() {
+            Some(XCheckerError::SecretDetected { location, .. }) => {
+                assert!(location.ends_with("config.yaml"), "location: {location}");
+            }
+            other => panic!("expected SecretDetected, got {other:?}"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_redaction_exempt_glob_is_rejected() -> Result<()> {
+        let err = PacketBuilder::new()?
+            .with_redaction_exempt_globs(vec!["docs/[unclosed".to_string()])
+            .unwrap_err();
+        assert!(err.to_string().contains("docs/[unclosed"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_redactor_config_aborts_build() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let base_path = Utf8PathBuf::try_from(temp_dir.path().to_path_buf())?;
+        let context_dir = base_path.join("context");
+
+        fs::write(base_path.join("README.md"), "# Test\nSafe content.")?;
+
+        let config = PatternConfig {
+            extra: vec!["([unclosed".into()],
+            ignore: Vec::new(),
+        };
+        let mut builder = PacketBuilder::new()?.with_redactor_config(&config);
+        assert!(builder.redactor().is_none());
+
+        let err = builder
+            .build_packet(&base_path, "requirements", &context_dir, None)
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<XCheckerError>(),
+            Some(XCheckerError::RedactorUnavailable { .. })
+        ));
+
+        // Nothing is written when the build is refused
+        assert!(!context_dir.join("requirements-packet.txt").exists());
+
+        Ok(())
+    }
+
     #[test]
     fn test_cache_integration() -> Result<()> {
         let temp_dir = TempDir::new()?;
@@ -933,6 +2006,50 @@ mod packet_builder_tests {
         Ok(())
     }
 
+    #[test]
+    fn test_packet_cache_reuses_unchanged_file_set() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let base_path = Utf8PathBuf::try_from(temp_dir.path().to_path_buf())?;
+        let work_dir = TempDir::new()?;
+        let work_path = Utf8PathBuf::try_from(work_dir.path().to_path_buf())?;
+        let context_dir = work_path.join("context");
+        let cache_dir = work_path.join("cache");
+
+        fs::write(
+            base_path.join("README.md"),
+            "# Test Project\nOriginal content.",
+        )?;
+        fs::write(base_path.join("notes.txt"), "notes")?;
+
+        let mut builder = PacketBuilder::with_cache(cache_dir.clone())?.with_packet_cache(true);
+        let first = builder.build_packet(&base_path, "requirements", &context_dir, None)?;
+        assert!(!first.is_from_cache());
+        assert_eq!(builder.cache().unwrap().stats().packet_misses, 1);
+
+        // Unchanged files: a fresh builder reuses the packet from disk
+        let mut builder = PacketBuilder::with_cache(cache_dir.clone())?.with_packet_cache(true);
+        let second = builder.build_packet(&base_path, "requirements", &context_dir, None)?;
+        let stats = builder.cache().unwrap().stats();
+        assert!(second.is_from_cache());
+        assert_eq!(stats.packet_hits, 1);
+        // No file was looked up, so nothing was scanned or redacted again
+        assert_eq!(stats.hits + stats.misses, 0);
+        assert_eq!(second.body(), first.body());
+        assert_eq!(second.hash(), first.hash());
+        assert_eq!(second.evidence.files.len(), first.evidence.files.len());
+        assert!(context_dir.join("requirements-manifest.json").exists());
+
+        // Changing one file invalidates the cached packet
+        fs::write(base_path.join("notes.txt"), "edited notes")?;
+        let third = builder.build_packet(&base_path, "requirements", &context_dir, None)?;
+        let stats = builder.cache().unwrap().stats();
+        assert!(!third.is_from_cache());
+        assert_eq!(stats.invalidations, 1);
+        assert!(third.body().contains("edited notes"));
+
+        Ok(())
+    }
+
     #[test]
     fn test_cache_invalidation_on_content_change() -> Result<()> {
         let temp_dir = TempDir::new()?;
@@ -1274,6 +2391,7 @@ mod packet_builder_tests {
         let selectors = Selectors {
             include: vec!["**/*.rs".to_string()],
             exclude: vec!["**/test_*.rs".to_string()],
+            ignore: vec![],
         };
 
         let builder = PacketBuilder::with_selectors(Some(&selectors))?;
@@ -1290,6 +2408,7 @@ mod packet_builder_tests {
         let selectors = Selectors {
             include: vec!["docs/**".to_string()],
             exclude: vec![],
+            ignore: vec![],
         };
 
         let builder = PacketBuilder::with_selectors_and_limits(Some(&selectors), 32768, 600)?;
@@ -1318,6 +2437,7 @@ mod packet_builder_tests {
         let selectors = Selectors {
             include: vec!["**/*.rs".to_string()],
             exclude: vec![],
+            ignore: vec![],
         };
         let mut builder = PacketBuilder::with_selectors(Some(&selectors))?;
         let packet = builder.build_packet(&base_path, "requirements", &context_dir, None)?;
@@ -1388,6 +2508,10 @@ mod packet_builder_tests {
         // (evidence is internal, not sent to LLM)
         assert!(packet.evidence.files[0].path.contains(&secret_token));
 
+        // Only the explicitly unredacted accessor exposes the raw filename
+        assert!(!packet.body().contains(&secret_token));
+        assert!(packet.raw_body_unredacted().contains(&secret_token));
+
         Ok(())
     }
 
@@ -4,6 +4,10 @@
 //! content for Claude CLI invocations while respecting budget constraints and
 //! maintaining evidence for auditability.
 
+use anyhow::Result;
+use std::borrow::Cow;
+use std::ops::Range;
+use xchecker_redaction::SecretRedactor;
 use xchecker_utils::types::PacketEvidence;
 
 mod budget;
@@ -13,7 +17,12 @@ mod render;
 mod selectors;
 
 /// A packet of content prepared for Claude CLI consumption.
-#[derive(Debug, Clone)]
+///
+/// The public `content` field and [`Packet::body`] always hold the body that is
+/// safe to send, log, or persist. The pre-redaction body is kept private and is
+/// only reachable through [`Packet::raw_body_unredacted`]. The `Debug` output
+/// never includes it.
+#[derive(Clone)]
 pub struct Packet {
     /// The actual content to send to Claude.
     pub content: String,
@@ -23,25 +32,128 @@ pub struct Packet {
     pub evidence: PacketEvidence,
     /// Information about budget usage.
     pub budget_used: BudgetUsage,
+    /// Body as assembled before redaction. Never exposed through `Debug`.
+    raw_content: String,
+    /// Files that matched the selectors but were excluded before reading.
+    excluded: Vec<ExcludedFile>,
+    /// Cap on the size of the human-readable preview, if any.
+    preview_max_bytes: Option<usize>,
+    /// 0-based line ranges of `content` holding redaction-exempt file bodies.
+    verbatim_lines: Vec<Range<usize>>,
+    /// Whether the packet was reused from the packet cache.
+    from_cache: bool,
+}
+
+impl std::fmt::Debug for Packet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Packet")
+            .field("content", &self.content)
+            .field("blake3_hash", &self.blake3_hash)
+            .field("evidence", &self.evidence)
+            .field("budget_used", &self.budget_used)
+            .field("excluded", &self.excluded)
+            .field("preview_max_bytes", &self.preview_max_bytes)
+            .field("verbatim_lines", &self.verbatim_lines)
+            .field("from_cache", &self.from_cache)
+            .finish_non_exhaustive()
+    }
 }
 
 impl Packet {
     /// Create a new packet.
+    ///
+    /// `content` is used as both the body and the raw body; callers that hold
+    /// unredacted content should use [`Packet::from_unredacted`] instead.
     #[must_use]
-    pub const fn new(
+    pub fn new(
         content: String,
         blake3_hash: String,
         evidence: PacketEvidence,
         budget_used: BudgetUsage,
     ) -> Self {
         Self {
+            raw_content: content.clone(),
             content,
             blake3_hash,
             evidence,
             budget_used,
+            excluded: Vec::new(),
+            preview_max_bytes: None,
+            verbatim_lines: Vec::new(),
+            from_cache: false,
         }
     }
 
+    /// Create a packet from unredacted content, redacting it with `redactor`.
+    ///
+    /// The body and hash reflect the redacted content; the original content is
+    /// retained only for [`Packet::raw_body_unredacted`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if secret scanning fails.
+    pub fn from_unredacted(
+        raw_content: String,
+        redactor: &SecretRedactor,
+        evidence: PacketEvidence,
+        budget_used: BudgetUsage,
+    ) -> Result<Self> {
+        let content = redactor.redact_content(&raw_content, "packet")?.content;
+        let blake3_hash = blake3::hash(content.as_bytes()).to_hex().to_string();
+
+        Ok(Self {
+            content,
+            blake3_hash,
+            evidence,
+            budget_used,
+            raw_content,
+            excluded: Vec::new(),
+            preview_max_bytes: None,
+            verbatim_lines: Vec::new(),
+            from_cache: false,
+        })
+    }
+
+    /// Attach the pre-redaction body assembled alongside `content`.
+    pub(crate) fn with_raw_body(mut self, raw_content: String) -> Self {
+        self.raw_content = raw_content;
+        self
+    }
+
+    /// Record files that were excluded during selection.
+    pub(crate) fn with_excluded(mut self, excluded: Vec<ExcludedFile>) -> Self {
+        self.excluded = excluded;
+        self
+    }
+
+    /// Cap the human-readable preview at `max_bytes`.
+    pub(crate) const fn with_preview_max_bytes(mut self, max_bytes: Option<usize>) -> Self {
+        self.preview_max_bytes = max_bytes;
+        self
+    }
+
+    /// Record the line ranges of `content` included verbatim.
+    pub(crate) fn with_verbatim_lines(mut self, lines: Vec<Range<usize>>) -> Self {
+        self.verbatim_lines = lines;
+        self
+    }
+
+    /// Mark the packet as reused from the packet cache.
+    pub(crate) const fn with_from_cache(mut self, from_cache: bool) -> Self {
+        self.from_cache = from_cache;
+        self
+    }
+
+    /// Check whether the packet was reused from the packet cache instead of
+    /// being built from the files.
+    ///
+    /// The cache stores only the redacted body, so for a cached packet
+    /// [`Packet::raw_body_unredacted`] returns the redacted body.
+    #[must_use]
+    pub const fn is_from_cache(&self) -> bool {
+        self.from_cache
+    }
+
     /// Get the packet content.
     #[must_use]
     #[allow(dead_code)] // Public API for packet inspection
@@ -49,6 +161,66 @@ impl Packet {
         &self.content
     }
 
+    /// Get the redacted packet body.
+    ///
+    /// This is what gets sent to the LLM. Packet previews hold the same body,
+    /// truncated if `[packet] preview_max_bytes` is set (see [`Packet::preview`]).
+    #[must_use]
+    pub fn body(&self) -> &str {
+        &self.content
+    }
+
+    /// Get the packet body as it was before redaction.
+    ///
+    /// **Dangerous:** the returned text may contain secrets. Never log, persist,
+    /// or send it anywhere; use [`Packet::body`] for all of those.
+    #[must_use]
+    pub fn raw_body_unredacted(&self) -> &str {
+        &self.raw_content
+    }
+
+    /// Get the human-readable preview written to `context/<phase>-packet.txt`.
+    ///
+    /// This is the redacted body, truncated with a footer giving the full
+    /// size when it exceeds `[packet] preview_max_bytes`. Never send this to
+    /// the LLM; use [`Packet::body`].
+    #[must_use]
+    pub fn preview(&self) -> Cow<'_, str> {
+        render::truncate_preview(&self.content, self.preview_max_bytes)
+    }
+
+    /// Check whether the preview is shorter than the full body.
+    #[must_use]
+    pub fn is_preview_truncated(&self) -> bool {
+        self.preview_max_bytes
+            .is_some_and(|max| self.content.len() > max)
+    }
+
+    /// Get the body with redaction-exempt file contents blanked out.
+    ///
+    /// Files matching `[packet] redaction_exempt` are included verbatim, so a
+    /// secret scan of the whole body must skip them. Line numbers are kept,
+    /// so matches in the remaining content point at the right body line.
+    #[must_use]
+    pub fn scannable_body(&self) -> Cow<'_, str> {
+        if self.verbatim_lines.is_empty() {
+            return Cow::Borrowed(&self.content);
+        }
+        let lines: Vec<&str> = self
+            .content
+            .split('\n')
+            .enumerate()
+            .map(|(idx, line)| {
+                if self.verbatim_lines.iter().any(|range| range.contains(&idx)) {
+                    ""
+                } else {
+                    line
+                }
+            })
+            .collect();
+        Cow::Owned(lines.join("\n"))
+    }
+
     /// Get the packet hash.
     #[must_use]
     pub fn hash(&self) -> &str {
@@ -62,6 +234,13 @@ impl Packet {
         &self.evidence
     }
 
+    /// Get files that matched the selectors but were excluded before reading,
+    /// e.g. because they exceeded the maximum file size.
+    #[must_use]
+    pub fn excluded(&self) -> &[ExcludedFile] {
+        &self.excluded
+    }
+
     /// Get budget usage information.
     #[must_use]
     pub const fn budget_usage(&self) -> &BudgetUsage {
@@ -121,5 +300,99 @@ impl BudgetUsage {
 }
 
 pub use builder::{DEFAULT_PACKET_MAX_BYTES, DEFAULT_PACKET_MAX_LINES, PacketBuilder};
-pub use model::{PriorityRules, SelectedFile};
-pub use selectors::ContentSelector;
+pub use model::{
+    CandidateSelection, ExcludeReason, ExcludedFile, PriorityRules, SecretPolicy, SelectedFile,
+};
+pub use render::read_packet_preview;
+pub use selectors::{ContentSelector, DEFAULT_EXCLUDES};
+
+#[cfg(test)]
+mod packet_tests {
+    use super::*;
+    use xchecker_utils::test_support;
+
+    fn empty_evidence() -> PacketEvidence {
+        PacketEvidence {
+            files: Vec::new(),
+            max_bytes: 65536,
+            max_lines: 1200,
+            preview_compressed: false,
+        }
+    }
+
+    #[test]
+    fn test_body_is_redacted_and_raw_body_is_not() -> Result<()> {
+        let token = test_support::github_pat();
+        let raw = format!("token: {token}\nother line");
+
+        let packet = Packet::from_unredacted(
+            raw.clone(),
+            &SecretRedactor::new()?,
+            empty_evidence(),
+            BudgetUsage::new(65536, 1200),
+        )?;
+
+        assert!(packet.body().contains("[REDACTED:github_pat]"));
+        assert!(!packet.body().contains(&token));
+        assert!(!packet.content().contains(&token));
+        assert_eq!(packet.raw_body_unredacted(), raw);
+        assert_eq!(
+            packet.hash(),
+            blake3::hash(packet.body().as_bytes()).to_hex().as_str()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_debug_output_never_contains_raw_body() -> Result<()> {
+        let token = test_support::github_pat();
+        let packet = Packet::from_unredacted(
+            format!("token: {token}"),
+            &SecretRedactor::new()?,
+            empty_evidence(),
+            BudgetUsage::new(65536, 1200),
+        )?;
+
+        let debug = format!("{packet:?}");
+        assert!(!debug.contains(&token));
+        assert!(debug.contains("[REDACTED:github_pat]"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_preview_truncates_on_char_boundary() {
+        // 'é' is two bytes, so a 5-byte cap falls inside the third character
+        let packet = Packet::new(
+            "ééé tail".to_string(),
+            "hash".to_string(),
+            empty_evidence(),
+            BudgetUsage::new(65536, 1200),
+        )
+        .with_preview_max_bytes(Some(5));
+
+        assert_eq!(
+            packet.preview(),
+            "éé\n[... preview truncated, full packet was 11 bytes ...]\n"
+        );
+        assert_eq!(packet.body(), "ééé tail");
+
+        let uncapped = packet.with_preview_max_bytes(Some(11));
+        assert!(!uncapped.is_preview_truncated());
+        assert_eq!(uncapped.preview(), "ééé tail");
+    }
+
+    #[test]
+    fn test_new_uses_content_as_raw_body() {
+        let packet = Packet::new(
+            "plain content".to_string(),
+            "hash".to_string(),
+            empty_evidence(),
+            BudgetUsage::new(65536, 1200),
+        );
+
+        assert_eq!(packet.body(), "plain content");
+        assert_eq!(packet.raw_body_unredacted(), "plain content");
+    }
+}
@@ -1,10 +1,12 @@
-use camino::Utf8PathBuf;
+use camino::{Utf8Path, Utf8PathBuf};
 use globset::{Glob, GlobSet, GlobSetBuilder};
+use std::fs;
+use std::time::SystemTime;
 use xchecker_utils::types::Priority;
 
 /// Priority rules defining the selection order
 /// Order: *.core.yaml (non-evictable) → SPEC/ADR/REPORT → README/SCHEMA → misc
-/// LIFO within each priority class
+/// Sorted by path within each priority class
 #[derive(Debug, Clone)]
 pub struct PriorityRules {
     /// Combined globset for all priorities (High -> Medium -> Low)
@@ -54,6 +56,16 @@ impl Default for PriorityRules {
     }
 }
 
+/// What packet building does when a selected file contains a secret
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SecretPolicy {
+    /// Abort the build, naming the file and matched pattern ids (default)
+    #[default]
+    FailClosed,
+    /// Replace the secrets with redaction markers and keep building
+    Redact,
+}
+
 /// Represents a file selected for potential inclusion in a packet
 #[derive(Debug, Clone)]
 pub struct SelectedFile {
@@ -80,4 +92,62 @@ pub struct CandidateFile {
     pub path: Utf8PathBuf,
     /// Priority level
     pub priority: Priority,
+    /// Size and mtime observed at selection, `None` if metadata was unavailable
+    pub stamp: Option<FileStamp>,
+}
+
+/// Why a file matching the selectors was left out before reading
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExcludeReason {
+    /// File is larger than the selector's maximum file size
+    TooLarge {
+        /// Size observed at selection, in bytes
+        size: u64,
+        /// Configured maximum file size, in bytes
+        limit: u64,
+    },
+}
+
+/// A file that matched the selectors but was excluded from selection
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExcludedFile {
+    /// Path to the file
+    pub path: Utf8PathBuf,
+    /// Why it was excluded
+    pub reason: ExcludeReason,
+}
+
+/// Result of candidate selection: files to read and files left out
+#[derive(Debug, Clone, Default)]
+pub struct CandidateSelection {
+    /// Files to read, in priority order
+    pub candidates: Vec<CandidateFile>,
+    /// Files excluded before any content was read
+    pub excluded: Vec<ExcludedFile>,
+}
+
+/// File size and modification time used to detect edits between selection and read
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileStamp {
+    /// File length in bytes
+    pub len: u64,
+    /// Last modification time, if the platform reports one
+    pub modified: Option<SystemTime>,
+}
+
+impl FileStamp {
+    /// Build a stamp from already-fetched metadata
+    #[must_use]
+    pub fn from_metadata(metadata: &fs::Metadata) -> Self {
+        Self {
+            len: metadata.len(),
+            modified: metadata.modified().ok(),
+        }
+    }
+
+    /// Stat `path` and capture its stamp
+    #[must_use]
+    pub fn capture(path: &Utf8Path) -> Option<Self> {
+        fs::metadata(path).ok().map(|m| Self::from_metadata(&m))
+    }
 }
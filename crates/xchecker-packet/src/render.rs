@@ -1,11 +1,115 @@
-use super::builder::PacketBuilder;
+use super::builder::{IncludedFile, PacketBuilder};
 use anyhow::{Context, Result};
 use camino::Utf8Path;
-use xchecker_utils::atomic_write::write_file_atomic;
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use std::borrow::Cow;
+use std::io::{Read, Write};
+use xchecker_utils::atomic_write::{write_bytes_atomic, write_file_atomic};
+use xchecker_utils::types::FileEvidence;
+
+/// Cut `content` to at most `max_bytes` (on a char boundary) and append a
+/// footer giving the full size. Content within the limit is returned as is.
+pub(crate) fn truncate_preview(content: &str, max_bytes: Option<usize>) -> Cow<'_, str> {
+    let Some(max_bytes) = max_bytes.filter(|&max| content.len() > max) else {
+        return Cow::Borrowed(content);
+    };
+
+    let mut cut = max_bytes;
+    while !content.is_char_boundary(cut) {
+        cut -= 1;
+    }
+    let head = &content[..cut];
+    let separator = if head.is_empty() || head.ends_with('\n') {
+        ""
+    } else {
+        "\n"
+    };
+
+    Cow::Owned(format!(
+        "{head}{separator}[... preview truncated, full packet was {} bytes ...]\n",
+        content.len()
+    ))
+}
+
+/// Read a packet preview written to `context/<phase>-packet.txt`, or to
+/// `context/<phase>-packet.txt.gz` under `[packet] compress_preview`.
+///
+/// A `.gz` extension is decompressed transparently.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read, is not valid gzip, or is
+/// not UTF-8.
+pub fn read_packet_preview(path: &Utf8Path) -> Result<String> {
+    let bytes =
+        std::fs::read(path).with_context(|| format!("Failed to read packet preview: {path}"))?;
+    if path.extension() != Some("gz") {
+        return String::from_utf8(bytes)
+            .with_context(|| format!("Packet preview is not valid UTF-8: {path}"));
+    }
+
+    let mut preview = String::new();
+    GzDecoder::new(bytes.as_slice())
+        .read_to_string(&mut preview)
+        .with_context(|| format!("Failed to decompress packet preview: {path}"))?;
+    Ok(preview)
+}
+
+fn gzip(content: &str) -> std::io::Result<Vec<u8>> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(content.as_bytes())?;
+    encoder.finish()
+}
+
+/// Build the per-file packet manifest: every included file with its size,
+/// hash and whether redaction changed it, plus totals.
+/// `files` is the evidence rendered from `included`, in the same order;
+/// sizes are of the redacted content as it appears in the packet.
+pub(crate) fn file_manifest(
+    included: &[IncludedFile],
+    files: &[FileEvidence],
+    phase: &str,
+) -> serde_json::Value {
+    use serde_json::json;
+
+    let mut total_bytes = 0;
+    let mut total_lines = 0;
+    let entries = included
+        .iter()
+        .zip(files)
+        .map(|(inc, evidence)| {
+            let bytes = inc.file_content.len();
+            let lines = inc.file_content.lines().count();
+            total_bytes += bytes;
+            total_lines += lines;
+            json!({
+                "path": evidence.path,
+                "priority": format!("{:?}", evidence.priority),
+                "bytes": bytes,
+                "lines": lines,
+                "blake3_pre_redaction": evidence.blake3_pre_redaction,
+                "redacted": inc.file_content != inc.raw_file_content,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    json!({
+        "phase": phase,
+        "files": entries,
+        "totals": {
+            "files": files.len(),
+            "bytes": total_bytes,
+            "lines": total_lines,
+        },
+    })
+}
 
 impl PacketBuilder {
     /// Write packet preview to context directory
-    /// Always writes `context/<phase>-packet.txt` for auditability
+    /// Always writes `context/<phase>-packet.txt` for auditability, or
+    /// `context/<phase>-packet.txt.gz` when compression is enabled
     pub(super) fn write_packet_preview(
         &self,
         content: &str,
@@ -16,11 +120,53 @@ impl PacketBuilder {
         xchecker_utils::paths::ensure_dir_all(context_dir)
             .with_context(|| format!("Failed to create context directory: {context_dir}"))?;
 
-        let preview_path = context_dir.join(format!("{}-packet.txt", phase.to_lowercase()));
+        let plain_path = context_dir.join(format!("{}-packet.txt", phase.to_lowercase()));
+        let gz_path = context_dir.join(format!("{}-packet.txt.gz", phase.to_lowercase()));
+
+        // Write packet preview, truncated if a preview cap is configured
+        let preview = truncate_preview(content, self.preview_max_bytes);
+        let (preview_path, stale_path) = if self.compress_preview {
+            let compressed = gzip(&preview)
+                .with_context(|| format!("Failed to compress packet preview: {gz_path}"))?;
+            write_bytes_atomic(&gz_path, &compressed)
+                .with_context(|| format!("Failed to write packet preview to: {gz_path}"))?;
+            (gz_path, plain_path)
+        } else {
+            write_file_atomic(&plain_path, &preview)
+                .with_context(|| format!("Failed to write packet preview to: {plain_path}"))?;
+            (plain_path, gz_path)
+        };
+
+        // Drop the other variant left by an earlier run so readers see one preview
+        match std::fs::remove_file(&stale_path) {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => {
+                return Err(e).with_context(|| {
+                    format!("Failed to remove stale packet preview next to: {preview_path}")
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Write `context/<phase>-manifest.json` (see [`file_manifest`])
+    pub(super) fn write_packet_file_manifest(
+        &self,
+        manifest: &serde_json::Value,
+        phase: &str,
+        context_dir: &Utf8Path,
+    ) -> Result<()> {
+        xchecker_utils::paths::ensure_dir_all(context_dir)
+            .with_context(|| format!("Failed to create context directory: {context_dir}"))?;
+
+        let manifest_path = context_dir.join(format!("{}-manifest.json", phase.to_lowercase()));
 
-        // Write packet preview
-        write_file_atomic(&preview_path, content)
-            .with_context(|| format!("Failed to write packet preview to: {preview_path}"))?;
+        let manifest_json = serde_json::to_string_pretty(manifest)
+            .context("Failed to serialize packet manifest")?;
+        write_file_atomic(&manifest_path, &manifest_json)
+            .with_context(|| format!("Failed to write packet manifest to: {manifest_path}"))?;
 
         Ok(())
     }
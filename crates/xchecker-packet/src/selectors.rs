@@ -1,13 +1,17 @@
-use super::model::{CandidateFile, PriorityRules, SelectedFile};
+use super::model::{
+    CandidateFile, CandidateSelection, ExcludeReason, ExcludedFile, FileStamp, PriorityRules,
+    SelectedFile,
+};
 use anyhow::{Context, Result};
 use blake3::Hasher;
 use camino::{Utf8Path, Utf8PathBuf};
-use globset::{Glob, GlobSet, GlobSetBuilder};
+use globset::{Glob, GlobBuilder, GlobSet, GlobSetBuilder};
+use ignore::WalkBuilder;
 use std::fs;
 use std::io::Read;
 use std::thread;
 use tracing::warn;
-use xchecker_config::Selectors;
+use xchecker_config::{PriorArtifactForm, Selectors};
 use xchecker_utils::types::Priority;
 
 // Import centralized security exclusion patterns from xchecker-config
@@ -16,6 +20,48 @@ use xchecker_config::ALWAYS_EXCLUDE_PATTERNS;
 /// Default maximum file size (10MB) to prevent DoS
 const DEFAULT_MAX_FILE_SIZE: u64 = 10 * 1024 * 1024;
 
+/// Gitignore-style excludes applied by every `ContentSelector`: VCS metadata
+/// and vendored or generated trees that only waste packet budget
+pub const DEFAULT_EXCLUDES: &[&str] = &[".git", "target", "node_modules"];
+
+/// Compile [`DEFAULT_EXCLUDES`] plus `patterns` into globs over paths
+/// relative to the directory being walked.
+///
+/// Patterns follow gitignore conventions: a pattern without a `/` matches a
+/// file or directory of that name at any depth, a pattern containing a `/`
+/// (a leading `/` included) is anchored at the walk root, and a trailing
+/// `/` is accepted. Matching a directory excludes everything beneath it.
+/// Negated (`!`) patterns are not supported.
+fn build_relative_excludes(patterns: &[String]) -> Result<GlobSet> {
+    let mut builder = GlobSetBuilder::new();
+    let defaults = DEFAULT_EXCLUDES.iter().copied();
+    for pattern in defaults.chain(patterns.iter().map(String::as_str)) {
+        let trimmed = pattern.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        if trimmed.starts_with('!') {
+            anyhow::bail!("Negated exclude pattern '{trimmed}' is not supported");
+        }
+
+        let trimmed = trimmed.trim_end_matches('/');
+        let glob = match trimmed.strip_prefix('/') {
+            Some(anchored) => anchored.to_string(),
+            None if trimmed.contains('/') => trimmed.to_string(),
+            None => format!("**/{trimmed}"),
+        };
+        for glob in [glob.clone(), format!("{glob}/**")] {
+            builder.add(
+                GlobBuilder::new(&glob)
+                    .literal_separator(true)
+                    .build()
+                    .with_context(|| format!("Invalid exclude pattern '{pattern}'"))?,
+            );
+        }
+    }
+    Ok(builder.build()?)
+}
+
 /// Add mandatory security exclusions to a GlobSetBuilder.
 ///
 /// This is a defense-in-depth measure: even if user config omits these patterns,
@@ -28,13 +74,18 @@ fn add_mandatory_exclusions(builder: &mut GlobSetBuilder) -> Result<(), globset:
 }
 
 /// Content selector that implements priority-based file selection
-/// with concrete defaults and LIFO ordering within priority classes
+/// with concrete defaults and path ordering within priority classes
 #[derive(Debug, Clone)]
 pub struct ContentSelector {
     /// Include patterns for file selection
     include_patterns: GlobSet,
     /// Exclude patterns for file filtering
     exclude_patterns: GlobSet,
+    /// Gitignore-style excludes added with [`with_excludes`](Self::with_excludes)
+    extra_excludes: Vec<String>,
+    /// [`DEFAULT_EXCLUDES`] and `extra_excludes`, matched against paths
+    /// relative to the walk root
+    relative_excludes: GlobSet,
     /// Priority rules for content selection
     priority_rules: PriorityRules,
     /// Whether to follow symlinks (default: false for security)
@@ -43,8 +94,12 @@ pub struct ContentSelector {
     /// When true, symlinks are only followed if they resolve to paths within
     /// the base directory (sandbox), preventing path traversal attacks.
     allow_symlinks: bool,
+    /// Whether to skip files ignored by git (default: false)
+    respect_gitignore: bool,
     /// Maximum file size in bytes (default: 10MB)
     max_file_size: u64,
+    /// Which form of prior-phase artifacts to include (default: both)
+    prior_artifact_form: PriorArtifactForm,
 }
 
 impl ContentSelector {
@@ -85,9 +140,13 @@ impl ContentSelector {
         Ok(Self {
             include_patterns: include_builder.build()?,
             exclude_patterns: exclude_builder.build()?,
+            extra_excludes: Vec::new(),
+            relative_excludes: build_relative_excludes(&[])?,
             priority_rules: PriorityRules::default(),
             allow_symlinks: false,
+            respect_gitignore: false,
             max_file_size: DEFAULT_MAX_FILE_SIZE,
+            prior_artifact_form: PriorArtifactForm::default(),
         })
     }
 
@@ -103,6 +162,20 @@ impl ContentSelector {
         self
     }
 
+    /// Set the maximum file size limit in bytes.
+    ///
+    /// Non-upstream files whose size at selection exceeds `bytes` are excluded
+    /// from [`select_candidates`](Self::select_candidates) with
+    /// [`ExcludeReason::TooLarge`] before any content is read. Oversized
+    /// upstream files are still selected so that reading them fails loudly
+    /// instead of silently dropping critical context.
+    ///
+    /// Default is 10MB.
+    #[must_use]
+    pub const fn with_max_file_size(self, bytes: u64) -> Self {
+        self.max_file_size(bytes)
+    }
+
     /// Enable or disable symlink following during directory traversal.
     ///
     /// When enabled, symlinks are only followed if they resolve to paths
@@ -117,6 +190,52 @@ impl ContentSelector {
         self
     }
 
+    /// Skip files that git would ignore.
+    ///
+    /// When enabled, directory traversal honors `.gitignore` files (in the
+    /// scanned directory, below it, and in its parents), `.git/info/exclude`,
+    /// and the user's global gitignore, whether or not the directory is in a
+    /// git repository. Files that survive are then filtered and prioritized
+    /// exactly as without this option.
+    ///
+    /// Default is `false`.
+    #[must_use]
+    pub const fn respect_gitignore(mut self, enabled: bool) -> Self {
+        self.respect_gitignore = enabled;
+        self
+    }
+
+    /// Choose which form of prior-phase artifacts to include.
+    ///
+    /// Prior-phase artifacts are the `.md` and `.core.yaml` files directly
+    /// under an `artifacts/` directory. Other markdown and YAML files are
+    /// unaffected.
+    ///
+    /// Default is [`PriorArtifactForm::Both`].
+    #[must_use]
+    pub const fn prior_artifact_form(mut self, form: PriorArtifactForm) -> Self {
+        self.prior_artifact_form = form;
+        self
+    }
+
+    /// Skip paths matching gitignore-style `patterns` during selection.
+    ///
+    /// Patterns are matched against paths relative to the directory passed to
+    /// [`select_files`](Self::select_files), on top of [`DEFAULT_EXCLUDES`]
+    /// and any earlier `with_excludes` patterns. A bare name such as
+    /// `vendor` excludes any file or directory of that name; a pattern with a
+    /// `/` such as `/docs/generated` or `docs/generated/` is anchored at that
+    /// directory. Excluded directories are not descended into.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a pattern is not a valid glob or is negated (`!`).
+    pub fn with_excludes(mut self, patterns: Vec<String>) -> Result<Self> {
+        self.extra_excludes.extend(patterns);
+        self.relative_excludes = build_relative_excludes(&self.extra_excludes)?;
+        Ok(self)
+    }
+
     /// Get the maximum file size limit in bytes.
     #[must_use]
     pub const fn get_max_file_size(&self) -> u64 {
@@ -143,9 +262,13 @@ impl ContentSelector {
         Ok(Self {
             include_patterns: include_builder.build()?,
             exclude_patterns: exclude_builder.build()?,
+            extra_excludes: Vec::new(),
+            relative_excludes: build_relative_excludes(&[])?,
             priority_rules: PriorityRules::default(),
             allow_symlinks: false,
+            respect_gitignore: false,
             max_file_size: DEFAULT_MAX_FILE_SIZE,
+            prior_artifact_form: PriorArtifactForm::default(),
         })
     }
 
@@ -153,7 +276,8 @@ impl ContentSelector {
     ///
     /// # Precedence
     ///
-    /// - If `selectors` is `Some`: use those include/exclude patterns.
+    /// - If `selectors` is `Some`: use those include/exclude patterns, plus
+    ///   its `ignore` patterns via [`with_excludes`](Self::with_excludes).
     /// - If `None`: fall back to built-in defaults.
     ///
     /// # Errors
@@ -175,13 +299,18 @@ impl ContentSelector {
                 // Add mandatory security exclusions (defense-in-depth)
                 add_mandatory_exclusions(&mut exclude_builder)?;
 
-                Ok(Self {
+                Self {
                     include_patterns: include_builder.build()?,
                     exclude_patterns: exclude_builder.build()?,
+                    extra_excludes: Vec::new(),
+                    relative_excludes: build_relative_excludes(&[])?,
                     priority_rules: PriorityRules::default(),
                     allow_symlinks: false,
+                    respect_gitignore: false,
                     max_file_size: DEFAULT_MAX_FILE_SIZE,
-                })
+                    prior_artifact_form: PriorArtifactForm::default(),
+                }
+                .with_excludes(sel.ignore.clone())
             }
             None => Self::new(),
         }
@@ -228,13 +357,37 @@ impl ContentSelector {
         self.exclude_patterns.is_match(path.as_str())
     }
 
+    /// Check if `path` under the walk `root` matches a gitignore-style exclude
+    fn is_relative_excluded(&self, root: &Utf8Path, path: &Utf8Path) -> bool {
+        path.strip_prefix(root)
+            .is_ok_and(|relative| self.relative_excludes.is_match(relative.as_str()))
+    }
+
+    /// Check if a prior-phase artifact is filtered out by `prior_artifact_form`
+    fn is_filtered_prior_artifact(&self, path: &Utf8Path) -> bool {
+        if path.parent().and_then(Utf8Path::file_name) != Some("artifacts") {
+            return false;
+        }
+        let Some(name) = path.file_name() else {
+            return false;
+        };
+
+        if name.ends_with(".core.yaml") {
+            !self.prior_artifact_form.includes_core_yaml()
+        } else if name.ends_with(".md") {
+            !self.prior_artifact_form.includes_markdown()
+        } else {
+            false
+        }
+    }
+
     /// Check if a file should be included based on include/exclude patterns
     #[must_use]
     pub fn should_include(&self, path: &Utf8Path) -> bool {
         let path_str = path.as_str();
 
         // First check if excluded
-        if self.is_excluded(path) {
+        if self.is_excluded(path) || self.is_filtered_prior_artifact(path) {
             return false;
         }
 
@@ -243,16 +396,31 @@ impl ContentSelector {
     }
 
     /// Select candidates from a directory with priority-based ordering
-    /// Returns files grouped by priority, with LIFO ordering within each group
+    /// Returns files grouped by priority, sorted by path within each group
     /// This method is lazy: it does not read file content.
     ///
     /// For large datasets (>= 500 files), priority assignment is parallelized
     /// using `std::thread::scope` to improve performance on multi-core systems.
     pub fn select_candidates(&self, base_path: &Utf8Path) -> Result<Vec<CandidateFile>> {
+        Ok(self
+            .select_candidates_with_exclusions(base_path)?
+            .candidates)
+    }
+
+    /// Select candidates like [`select_candidates`](Self::select_candidates),
+    /// also reporting files that matched the selectors but were excluded.
+    pub fn select_candidates_with_exclusions(
+        &self,
+        base_path: &Utf8Path,
+    ) -> Result<CandidateSelection> {
         let mut paths = Vec::new();
 
         // Walk the directory tree, passing root for symlink sandbox validation
-        self.walk_directory_paths(base_path, base_path, &mut paths)?;
+        if self.respect_gitignore {
+            self.walk_gitignore_paths(base_path, &mut paths)?;
+        } else {
+            self.walk_directory_paths(base_path, base_path, &mut paths)?;
+        }
 
         let num_threads = thread::available_parallelism().map_or(1, |n| n.get());
 
@@ -262,7 +430,12 @@ impl ContentSelector {
                 .into_iter()
                 .map(|path| {
                     let priority = self.get_priority(&path);
-                    CandidateFile { path, priority }
+                    let stamp = FileStamp::capture(&path);
+                    CandidateFile {
+                        path,
+                        priority,
+                        stamp,
+                    }
                 })
                 .collect()
         } else {
@@ -279,6 +452,7 @@ impl ContentSelector {
                             chunk_candidates.push(CandidateFile {
                                 path: path.clone(),
                                 priority,
+                                stamp: FileStamp::capture(path),
                             });
                         }
                         chunk_candidates
@@ -296,23 +470,50 @@ impl ContentSelector {
             })
         };
 
-        // Sort by priority (Upstream first, then High, Medium, Low)
-        // Within each priority, maintain LIFO order (reverse chronological)
+        // Sort by priority (Upstream first, then High, Medium, Low), then by
+        // path so equal-priority files are admitted and trimmed in the same
+        // order on every run and platform, whatever order the walk found them
         candidates.sort_by(|a, b| {
-            match a.priority.cmp(&b.priority) {
-                std::cmp::Ordering::Equal => {
-                    // Within same priority, use LIFO (reverse order)
-                    b.path.cmp(&a.path)
-                }
-                other => other,
-            }
+            a.priority
+                .cmp(&b.priority)
+                .then_with(|| a.path.cmp(&b.path))
         });
 
-        Ok(candidates)
+        // DoS protection: drop oversized files using the size seen at selection
+        let (candidates, oversized): (Vec<_>, Vec<_>) =
+            candidates.into_iter().partition(|candidate| {
+                candidate.priority == Priority::Upstream
+                    || candidate
+                        .stamp
+                        .is_none_or(|stamp| stamp.len <= self.max_file_size)
+            });
+
+        let excluded = oversized
+            .into_iter()
+            .map(|candidate| {
+                let size = candidate.stamp.map_or(0, |stamp| stamp.len);
+                warn!(
+                    "Skipping large file: {} ({} bytes > limit {})",
+                    candidate.path, size, self.max_file_size
+                );
+                ExcludedFile {
+                    path: candidate.path,
+                    reason: ExcludeReason::TooLarge {
+                        size,
+                        limit: self.max_file_size,
+                    },
+                }
+            })
+            .collect();
+
+        Ok(CandidateSelection {
+            candidates,
+            excluded,
+        })
     }
 
     /// Select files from a directory with priority-based ordering
-    /// Returns files grouped by priority, with LIFO ordering within each group
+    /// Returns files grouped by priority, sorted by path within each group
     ///
     /// # Legacy Note
     /// This method is eager (reads all content). Use `select_candidates` for lazy loading.
@@ -470,20 +671,16 @@ impl ContentSelector {
                 }
 
                 // Symlinks allowed: verify target stays within sandbox (root)
-                // Fail-closed: if canonicalization fails, skip the entry
-                let is_safe = match (fs::canonicalize(&path), fs::canonicalize(root)) {
-                    (Ok(canonical_path), Ok(canonical_root)) => {
-                        canonical_path.starts_with(&canonical_root)
-                    }
-                    _ => false, // Broken symlink or resolution error - skip
-                };
-
-                if !is_safe {
+                if !symlink_within(root, &path) {
                     // Symlink points outside sandbox or is broken - skip
                     continue;
                 }
             }
 
+            if self.is_relative_excluded(root, &path) {
+                continue;
+            }
+
             // Recurse into directories (including validated symlinked directories)
             if path.is_dir() {
                 // Optimization: Check if directory itself is excluded to prune recursion
@@ -499,6 +696,65 @@ impl ContentSelector {
 
         Ok(())
     }
+
+    /// Walk like [`walk_directory_paths`](Self::walk_directory_paths), also
+    /// skipping whatever git ignores.
+    ///
+    /// Pruning of excluded directories and the symlink sandbox are applied
+    /// the same way; the walk itself is delegated to the `ignore` crate.
+    fn walk_gitignore_paths(&self, root: &Utf8Path, paths: &mut Vec<Utf8PathBuf>) -> Result<()> {
+        if !root.exists() {
+            return Ok(());
+        }
+
+        let prune = self.clone();
+        let sandbox = root.to_owned();
+        let walker = WalkBuilder::new(root)
+            .standard_filters(false)
+            .parents(true)
+            .git_ignore(true)
+            .git_exclude(true)
+            .git_global(true)
+            .require_git(false)
+            .follow_links(self.allow_symlinks)
+            .filter_entry(move |entry| {
+                if entry.depth() == 0 {
+                    return true;
+                }
+                let Some(path) = Utf8Path::from_path(entry.path()) else {
+                    return false;
+                };
+                if entry.path_is_symlink() && !symlink_within(&sandbox, path) {
+                    return false;
+                }
+                let is_dir = entry.file_type().is_some_and(|t| t.is_dir());
+                !(prune.is_relative_excluded(&sandbox, path) || is_dir && prune.is_excluded(path))
+            })
+            .build();
+
+        for entry in walker {
+            let entry = entry.context("Failed to walk directory")?;
+            if !entry.file_type().is_some_and(|t| t.is_file()) {
+                continue;
+            }
+            let path = Utf8PathBuf::try_from(entry.into_path()).context("Invalid UTF-8 path")?;
+            if self.should_include(&path) {
+                paths.push(path);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Check that symlink `path` resolves to somewhere inside `root`.
+///
+/// Fail-closed: broken symlinks and canonicalization errors count as outside.
+fn symlink_within(root: &Utf8Path, path: &Utf8Path) -> bool {
+    match (fs::canonicalize(path), fs::canonicalize(root)) {
+        (Ok(canonical_path), Ok(canonical_root)) => canonical_path.starts_with(&canonical_root),
+        _ => false, // Broken symlink or resolution error - skip
+    }
 }
 
 #[cfg(test)]
@@ -595,6 +851,50 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_equal_priority_files_sorted_by_path() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let base_path = Utf8PathBuf::try_from(temp_dir.path().to_path_buf())?;
+
+        // Create equal-priority files out of order, some in subdirectories
+        fs::create_dir_all(base_path.join("b"))?;
+        fs::create_dir_all(base_path.join("a"))?;
+        for name in [
+            "notes-2.md",
+            "b/notes.md",
+            "notes-10.md",
+            "a/notes.md",
+            "notes-1.md",
+        ] {
+            fs::write(base_path.join(name), "# Notes")?;
+        }
+
+        let selector = ContentSelector::new()?;
+        let names = |files: Vec<CandidateFile>| -> Vec<String> {
+            files
+                .iter()
+                .map(|f| f.path.strip_prefix(&base_path).unwrap().to_string())
+                .collect()
+        };
+
+        let first = names(selector.select_candidates(&base_path)?);
+        assert_eq!(
+            first,
+            [
+                "a/notes.md",
+                "b/notes.md",
+                "notes-1.md",
+                "notes-10.md",
+                "notes-2.md"
+            ]
+        );
+        for _ in 0..5 {
+            assert_eq!(names(selector.select_candidates(&base_path)?), first);
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_content_selector_from_selectors_uses_defaults_when_none() -> Result<()> {
         let selector = ContentSelector::from_selectors(None)?;
@@ -618,6 +918,7 @@ mod tests {
         let selectors = Selectors {
             include: vec!["src/**".to_string()],
             exclude: vec!["**/*.log".to_string()],
+            ignore: vec![],
         };
 
         let selector = ContentSelector::from_selectors(Some(&selectors))?;
@@ -641,6 +942,7 @@ mod tests {
         let selectors = Selectors {
             include: vec![],
             exclude: vec![],
+            ignore: vec![],
         };
 
         let selector = ContentSelector::from_selectors(Some(&selectors))?;
@@ -829,6 +1131,33 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_oversized_file_excluded_with_reason() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let base_path = Utf8PathBuf::try_from(temp_dir.path().to_path_buf())?;
+
+        fs::write(base_path.join("huge.log.md"), "x".repeat(2048))?;
+        fs::write(base_path.join("notes.md"), "small")?;
+
+        let selector = ContentSelector::new()?.with_max_file_size(1024);
+        let selection = selector.select_candidates_with_exclusions(&base_path)?;
+
+        assert_eq!(selection.candidates.len(), 1);
+        assert!(selection.candidates[0].path.ends_with("notes.md"));
+
+        assert_eq!(selection.excluded.len(), 1);
+        assert!(selection.excluded[0].path.ends_with("huge.log.md"));
+        assert_eq!(
+            selection.excluded[0].reason,
+            ExcludeReason::TooLarge {
+                size: 2048,
+                limit: 1024
+            }
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_mandatory_security_exclusions() -> Result<()> {
         let temp_dir = TempDir::new()?;
@@ -873,6 +1202,7 @@ mod tests {
         let selectors = Selectors {
             include: vec!["**/*".to_string()], // Try to include everything
             exclude: vec![],                   // No user-defined excludes
+            ignore: vec![],
         };
 
         let selector = ContentSelector::from_selectors(Some(&selectors))?;
@@ -892,6 +1222,105 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_with_excludes_skips_vendored_directories() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let base_path = Utf8PathBuf::try_from(temp_dir.path().to_path_buf())?;
+
+        fs::write(base_path.join("README.md"), "# Project")?;
+        fs::create_dir_all(base_path.join("src"))?;
+        fs::write(base_path.join("src/notes.md"), "notes")?;
+        fs::create_dir_all(base_path.join("node_modules/pkg"))?;
+        fs::write(base_path.join("node_modules/pkg/README.md"), "# Vendored")?;
+        fs::create_dir_all(base_path.join("vendor/lib"))?;
+        fs::write(base_path.join("vendor/lib/NOTES.md"), "vendored")?;
+        fs::create_dir_all(base_path.join("docs/generated"))?;
+        fs::write(base_path.join("docs/generated/api.md"), "generated")?;
+        fs::write(base_path.join("docs/guide.md"), "guide")?;
+
+        // Config-style selectors don't exclude node_modules by themselves,
+        // since their excludes are matched against full paths
+        let selectors = Selectors {
+            include: vec!["**/*.md".to_string()],
+            exclude: vec!["node_modules/**".to_string()],
+            ignore: vec![],
+        };
+        let selector = ContentSelector::from_selectors(Some(&selectors))?
+            .with_excludes(vec!["vendor".to_string(), "/docs/generated/".to_string()])?;
+        let files = selector.select_files(&base_path)?;
+
+        let mut selected: Vec<&str> = files
+            .iter()
+            .map(|f| f.path.strip_prefix(&base_path).unwrap().as_str())
+            .collect();
+        selected.sort_unstable();
+        assert_eq!(selected, ["README.md", "docs/guide.md", "src/notes.md"]);
+
+        assert!(
+            ContentSelector::new()?
+                .with_excludes(vec!["!keep.md".to_string()])
+                .is_err()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_respect_gitignore_skips_ignored_files() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let base_path = Utf8PathBuf::try_from(temp_dir.path().to_path_buf())?;
+
+        fs::write(base_path.join(".gitignore"), "*.log\nbuild/\n")?;
+        fs::write(base_path.join("README.md"), "# Project")?;
+        fs::write(base_path.join("debug.log"), "noisy")?;
+        fs::create_dir_all(base_path.join("src"))?;
+        fs::write(base_path.join("src/notes.md"), "notes")?;
+        fs::write(base_path.join("src/trace.log"), "noisy")?;
+        fs::create_dir_all(base_path.join("build"))?;
+        fs::write(base_path.join("build/out.md"), "generated")?;
+
+        let selectors = Selectors {
+            include: vec!["**/*.md".to_string(), "**/*.log".to_string()],
+            exclude: vec![],
+            ignore: vec![],
+        };
+        let selected = |selector: ContentSelector| -> Result<Vec<String>> {
+            let mut paths: Vec<String> = selector
+                .select_files(&base_path)?
+                .iter()
+                .map(|f| f.path.strip_prefix(&base_path).unwrap().to_string())
+                .collect();
+            paths.sort_unstable();
+            Ok(paths)
+        };
+
+        let selector = ContentSelector::from_selectors(Some(&selectors))?;
+        assert_eq!(
+            selected(selector.clone())?,
+            [
+                "README.md",
+                "build/out.md",
+                "debug.log",
+                "src/notes.md",
+                "src/trace.log"
+            ]
+        );
+
+        let files = selector
+            .clone()
+            .respect_gitignore(true)
+            .select_files(&base_path)?;
+        // Priority selection still applies to the surviving files
+        assert_eq!(files[0].path, base_path.join("README.md"));
+        assert_eq!(files[0].priority, Priority::Medium);
+        assert_eq!(
+            selected(selector.respect_gitignore(true))?,
+            ["README.md", "src/notes.md"]
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_mandatory_exclusions_in_with_patterns() -> Result<()> {
         let temp_dir = TempDir::new()?;
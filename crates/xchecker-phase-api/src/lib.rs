@@ -18,7 +18,7 @@ use xchecker_packet::{BudgetUsage, Packet};
 use xchecker_redaction::SecretRedactor;
 use xchecker_selectors::Selectors;
 use xchecker_status::artifact::Artifact;
-pub use xchecker_utils::types::PhaseId;
+pub use xchecker_utils::types::{PhaseId, TimingSpans, ValidationMode};
 
 /// Represents the next step to take after a phase completes
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -30,6 +30,48 @@ pub enum NextStep {
     /// Complete the entire workflow
     #[allow(dead_code)] // Reserved for workflow completion signaling
     Complete,
+    /// Halt the workflow successfully; the reason is recorded in the receipt
+    Stop { reason: String },
+    /// Re-run this phase (e.g. for self-correction), at most `max` more times
+    ///
+    /// The bound is fixed by the first `Repeat` a phase returns, so a phase
+    /// that keeps asking to repeat cannot loop forever.
+    Repeat { max: u32 },
+}
+
+impl NextStep {
+    /// Whether this step ends the workflow after the current phase
+    #[must_use]
+    pub const fn is_terminal(&self) -> bool {
+        matches!(self, Self::Complete | Self::Stop { .. })
+    }
+}
+
+/// Caller-supplied rewrite of the problem statement before it enters the prompt
+///
+/// Used to strip ticket boilerplate, expand acronyms and similar preprocessing.
+/// The transformed text is treated exactly like the original statement, so it
+/// is still subject to redaction.
+#[derive(Clone)]
+pub struct ProblemTransform(Arc<dyn Fn(&str) -> String + Send + Sync>);
+
+impl ProblemTransform {
+    /// Wrap a transformer function
+    pub fn new(transform: impl Fn(&str) -> String + Send + Sync + 'static) -> Self {
+        Self(Arc::new(transform))
+    }
+
+    /// Apply the transformer to a problem statement
+    #[must_use]
+    pub fn apply(&self, problem_statement: &str) -> String {
+        (self.0)(problem_statement)
+    }
+}
+
+impl std::fmt::Debug for ProblemTransform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ProblemTransform(..)")
+    }
 }
 
 /// Context information passed to phases during execution
@@ -49,17 +91,35 @@ pub struct PhaseContext {
     /// If `Some`, phases should use these selectors when building packets.
     /// If `None`, phases fall back to built-in selector defaults.
     pub selectors: Option<Selectors>,
-    /// Enable strict validation for phase outputs.
+    /// How validation issues in phase outputs are handled.
     ///
-    /// When `true`, validation failures (meta-summaries, too-short output,
+    /// Under `Strict`, validation failures (meta-summaries, too-short output,
     /// missing required sections) become hard errors that fail the phase.
-    /// When `false`, validation issues are logged as warnings only.
-    pub strict_validation: bool,
+    /// Under `Warn`, they are logged as warnings only. Under `LenientFlagged`,
+    /// they are logged and reported in [`PhaseMetadata::validation_errors`]
+    /// so the receipt marks the phase degraded.
+    pub validation_mode: ValidationMode,
     /// Secret redactor for any user-facing output emitted during phase execution.
     ///
     /// This is built once from the effective configuration and threaded through to ensure
     /// configured extra/ignore patterns are applied consistently.
     pub redactor: Arc<SecretRedactor>,
+    /// Optional transformer applied to the resolved problem statement.
+    ///
+    /// The Requirements phase runs it after the statement is resolved from
+    /// config and before it is placed in the prompt.
+    pub problem_transform: Option<ProblemTransform>,
+}
+
+impl PhaseContext {
+    /// Whether validation issues fail the phase.
+    ///
+    /// Replaced the `strict_validation` field, which `validation_mode` superseded.
+    #[deprecated(note = "use `validation_mode` instead")]
+    #[must_use]
+    pub fn strict_validation(&self) -> bool {
+        self.validation_mode == ValidationMode::Strict
+    }
 }
 
 /// Metadata about phase execution
@@ -72,6 +132,11 @@ pub struct PhaseMetadata {
     pub budget_used: Option<BudgetUsage>,
     /// Duration of phase execution in milliseconds
     pub duration_ms: Option<u64>,
+    /// Time spent in each sub-step, filled in by the orchestrator
+    pub timings: Option<TimingSpans>,
+    /// Redacted output validation issues the phase completed despite
+    /// (`ValidationMode::LenientFlagged` only)
+    pub validation_errors: Vec<String>,
 }
 
 /// Result of executing a phase
@@ -86,6 +151,14 @@ pub struct PhaseResult {
     pub metadata: PhaseMetadata,
 }
 
+impl PhaseResult {
+    /// Whether no further phases should run after this one
+    #[must_use]
+    pub const fn is_terminal(&self) -> bool {
+        self.next_step.is_terminal()
+    }
+}
+
 /// Core trait that all workflow phases must implement
 ///
 /// This trait separates concerns into three distinct operations:
@@ -113,6 +186,8 @@ pub trait Phase {
     ///
     /// This method builds the context packet that will be included with
     /// the prompt, selecting and organizing relevant files and information.
+    /// Phases with `deps()` fail with `XCheckerError::MissingDependencyArtifact`
+    /// rather than build a packet without their upstream artifacts.
     fn make_packet(&self, ctx: &PhaseContext) -> Result<Packet>;
 
     /// Process Claude's raw response into structured artifacts
@@ -141,6 +216,25 @@ mod tests {
         assert!(matches!(complete_step, NextStep::Complete));
     }
 
+    #[test]
+    fn test_next_step_is_terminal() {
+        assert!(NextStep::Complete.is_terminal());
+        assert!(
+            NextStep::Stop {
+                reason: "nothing to do".to_string()
+            }
+            .is_terminal()
+        );
+        assert!(!NextStep::Continue.is_terminal());
+        assert!(!NextStep::Repeat { max: 2 }.is_terminal());
+        assert!(
+            !NextStep::Rewind {
+                to: PhaseId::Requirements
+            }
+            .is_terminal()
+        );
+    }
+
     #[test]
     fn test_phase_context_creation() {
         let ctx = PhaseContext {
@@ -149,12 +243,32 @@ mod tests {
             config: HashMap::new(),
             artifacts: Vec::new(),
             selectors: None,
-            strict_validation: false,
+            validation_mode: ValidationMode::Warn,
             redactor: Arc::new(SecretRedactor::default()),
+            problem_transform: None,
         };
 
         assert_eq!(ctx.spec_id, "test-spec");
-        assert!(!ctx.strict_validation);
+        assert_eq!(ctx.validation_mode, ValidationMode::Warn);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_strict_validation_accessor_follows_validation_mode() {
+        let mut ctx = PhaseContext {
+            spec_id: "test-spec".to_string(),
+            spec_dir: PathBuf::from("/tmp/test"),
+            config: HashMap::new(),
+            artifacts: Vec::new(),
+            selectors: None,
+            validation_mode: ValidationMode::Strict,
+            redactor: Arc::new(SecretRedactor::default()),
+            problem_transform: None,
+        };
+        assert!(ctx.strict_validation());
+
+        ctx.validation_mode = ValidationMode::LenientFlagged;
+        assert!(!ctx.strict_validation());
     }
 
     #[test]
@@ -163,5 +277,7 @@ mod tests {
         assert_eq!(metadata.packet_hash, None);
         assert!(metadata.budget_used.is_none());
         assert_eq!(metadata.duration_ms, None);
+        assert!(metadata.timings.is_none());
+        assert!(metadata.validation_errors.is_empty());
     }
 }
@@ -0,0 +1,242 @@
+//! Structured `*.core.yaml` documents
+//!
+//! Each phase emits a core YAML file alongside its markdown artifact. The
+//! documents are modelled as structs so serialization order follows field
+//! declaration order: adding a field never reorders existing ones, and the same
+//! inputs always produce byte-identical output. Free-form maps use `BTreeMap`
+//! so their keys are emitted sorted. No wall-clock time is recorded; when a
+//! document was produced is part of the phase receipt.
+
+use std::collections::BTreeMap;
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use xchecker_extraction::{DesignSummary, RequirementsSummary, ReviewSummary, TasksSummary};
+
+/// Placeholder element type for structured sections that are not extracted yet (B3.1)
+pub(crate) type Pending = serde_yaml::Value;
+
+/// Render a core document with a leading comment header.
+fn render<T: Serialize>(spec_id: &str, phase: &str, document: &T) -> Result<String> {
+    let body = serde_yaml::to_string(document)
+        .with_context(|| format!("Failed to serialize {phase} core YAML"))?;
+    Ok(format!(
+        "# Core {phase} data for spec {spec_id}\n\
+         # This file contains structured data extracted from the {phase} document\n\n\
+         {body}"
+    ))
+}
+
+/// `00-requirements.core.yaml`
+#[derive(Debug, Serialize)]
+pub(crate) struct RequirementsCore {
+    pub spec_id: String,
+    pub phase: &'static str,
+    pub version: &'static str,
+    pub metadata: RequirementsCoreMetadata,
+    pub requirements: Vec<Pending>,
+    pub nfrs: Vec<Pending>,
+    pub dependencies: Vec<Pending>,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct RequirementsCoreMetadata {
+    pub total_requirements: usize,
+    pub total_user_stories: usize,
+    pub total_acceptance_criteria: usize,
+    pub total_nfrs: usize,
+    pub has_nfrs: bool,
+}
+
+impl RequirementsCore {
+    pub(crate) fn new(spec_id: &str, summary: &RequirementsSummary) -> Self {
+        Self {
+            spec_id: spec_id.to_string(),
+            phase: "requirements",
+            version: "1.0",
+            metadata: RequirementsCoreMetadata {
+                total_requirements: summary.requirement_count,
+                total_user_stories: summary.user_story_count,
+                total_acceptance_criteria: summary.acceptance_criteria_count,
+                total_nfrs: summary.nfr_count,
+                has_nfrs: summary.nfr_count > 0,
+            },
+            requirements: Vec::new(),
+            nfrs: Vec::new(),
+            dependencies: Vec::new(),
+        }
+    }
+
+    pub(crate) fn to_yaml(&self) -> Result<String> {
+        render(&self.spec_id, self.phase, self)
+    }
+}
+
+/// `10-design.core.yaml`
+#[derive(Debug, Serialize)]
+pub(crate) struct DesignCore {
+    pub spec_id: String,
+    pub phase: &'static str,
+    pub version: &'static str,
+    pub metadata: DesignCoreMetadata,
+    pub architecture: DesignArchitecture,
+    pub data_models: Vec<Pending>,
+    pub error_handling: Vec<Pending>,
+    pub testing_strategy: Vec<Pending>,
+    pub requirements_dependencies: Vec<Pending>,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct DesignCoreMetadata {
+    pub has_architecture_section: bool,
+    pub has_mermaid_diagrams: bool,
+    pub total_components: usize,
+    pub total_interfaces: usize,
+    pub total_data_models: usize,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub(crate) struct DesignArchitecture {
+    pub components: Vec<Pending>,
+    pub interfaces: Vec<Pending>,
+    pub data_flow: Vec<Pending>,
+}
+
+impl DesignCore {
+    pub(crate) fn new(spec_id: &str, summary: &DesignSummary) -> Self {
+        Self {
+            spec_id: spec_id.to_string(),
+            phase: "design",
+            version: "1.0",
+            metadata: DesignCoreMetadata {
+                has_architecture_section: summary.has_architecture,
+                has_mermaid_diagrams: summary.has_diagrams,
+                total_components: summary.component_count,
+                total_interfaces: summary.interface_count,
+                total_data_models: summary.data_model_count,
+            },
+            architecture: DesignArchitecture::default(),
+            data_models: Vec::new(),
+            error_handling: Vec::new(),
+            testing_strategy: Vec::new(),
+            requirements_dependencies: Vec::new(),
+        }
+    }
+
+    pub(crate) fn to_yaml(&self) -> Result<String> {
+        render(&self.spec_id, self.phase, self)
+    }
+}
+
+/// `20-tasks.core.yaml`
+#[derive(Debug, Serialize)]
+pub(crate) struct TasksCore {
+    pub spec_id: String,
+    pub phase: &'static str,
+    pub version: &'static str,
+    pub metadata: TasksCoreMetadata,
+    pub tasks: Vec<Pending>,
+    pub dependencies: Vec<Pending>,
+    pub requirements_coverage: Vec<Pending>,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct TasksCoreMetadata {
+    pub total_tasks: usize,
+    pub total_subtasks: usize,
+    pub total_milestones: usize,
+    pub total_dependencies: usize,
+}
+
+impl TasksCore {
+    pub(crate) fn new(spec_id: &str, summary: &TasksSummary) -> Self {
+        Self {
+            spec_id: spec_id.to_string(),
+            phase: "tasks",
+            version: "1.0",
+            metadata: TasksCoreMetadata {
+                total_tasks: summary.task_count,
+                total_subtasks: summary.subtask_count,
+                total_milestones: summary.milestone_count,
+                total_dependencies: summary.dependency_count,
+            },
+            tasks: Vec::new(),
+            dependencies: Vec::new(),
+            requirements_coverage: Vec::new(),
+        }
+    }
+
+    pub(crate) fn to_yaml(&self) -> Result<String> {
+        render(&self.spec_id, self.phase, self)
+    }
+}
+
+/// `30-review.core.yaml`
+#[derive(Debug, Serialize)]
+pub(crate) struct ReviewCore {
+    pub spec_id: String,
+    pub phase: &'static str,
+    pub version: &'static str,
+    pub metadata: ReviewCoreMetadata,
+    pub findings: ReviewFindings,
+    pub fixup_info: ReviewFixupInfo,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct ReviewCoreMetadata {
+    pub fixups_needed: bool,
+    pub has_fixup_plan: bool,
+    pub review_sections_found: Vec<Pending>,
+    pub issues_identified: usize,
+    pub issue_counts: ReviewIssueCounts,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct ReviewIssueCounts {
+    pub requirements: usize,
+    pub design: usize,
+    pub tasks: usize,
+    pub cross_phase: usize,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub(crate) struct ReviewFindings {
+    pub requirements_issues: Vec<Pending>,
+    pub design_issues: Vec<Pending>,
+    pub tasks_issues: Vec<Pending>,
+    pub cross_phase_issues: Vec<Pending>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub(crate) struct ReviewFixupInfo {
+    pub target_files: Vec<Pending>,
+    pub change_summary: BTreeMap<String, usize>,
+}
+
+impl ReviewCore {
+    pub(crate) fn new(spec_id: &str, summary: &ReviewSummary, fixups_needed: bool) -> Self {
+        Self {
+            spec_id: spec_id.to_string(),
+            phase: "review",
+            version: "1.0",
+            metadata: ReviewCoreMetadata {
+                fixups_needed,
+                has_fixup_plan: fixups_needed,
+                review_sections_found: Vec::new(),
+                issues_identified: summary.total_issue_count(),
+                issue_counts: ReviewIssueCounts {
+                    requirements: summary.requirements_issue_count,
+                    design: summary.design_issue_count,
+                    tasks: summary.tasks_issue_count,
+                    cross_phase: summary.cross_phase_issue_count,
+                },
+            },
+            findings: ReviewFindings::default(),
+            fixup_info: ReviewFixupInfo::default(),
+        }
+    }
+
+    pub(crate) fn to_yaml(&self) -> Result<String> {
+        render(&self.spec_id, self.phase, self)
+    }
+}
@@ -6,13 +6,81 @@
 use anyhow::Result;
 use camino::Utf8PathBuf;
 
-use xchecker_extraction::{summarize_design, summarize_requirements, summarize_tasks};
+use xchecker_config::{PacketOrder, PriorArtifactForm};
+use xchecker_extraction::{
+    summarize_design, summarize_requirements, summarize_review, summarize_tasks,
+};
 use xchecker_packet::{DEFAULT_PACKET_MAX_BYTES, DEFAULT_PACKET_MAX_LINES, Packet, PacketBuilder};
-use xchecker_phase_api::{NextStep, Phase, PhaseContext, PhaseMetadata, PhaseResult};
-use xchecker_status::artifact::{Artifact, ArtifactType};
+use xchecker_phase_api::{
+    NextStep, Phase, PhaseContext, PhaseMetadata, PhaseResult, ValidationMode,
+};
+use xchecker_status::artifact::{Artifact, ArtifactBody, ArtifactType, phase_artifact_filename};
+use xchecker_utils::cache::InsightCache;
+use xchecker_utils::error::XCheckerError;
+use xchecker_utils::paths;
 use xchecker_utils::types::PhaseId;
 use xchecker_utils::types::{FileEvidence, PacketEvidence};
-use xchecker_validation::OutputValidator;
+use xchecker_validation::{OutputValidator, ValidationConfig};
+
+mod core_yaml;
+pub mod prompt_file;
+
+use core_yaml::{DesignCore, RequirementsCore, ReviewCore, TasksCore};
+
+/// Problem statement from `ctx`, after any configured transformer
+///
+/// Falls back to a note pointing the model at the context packet when no
+/// statement was provided.
+fn problem_statement(ctx: &PhaseContext) -> String {
+    let problem_statement = ctx
+        .config
+        .get("problem_statement")
+        .map(String::as_str)
+        .unwrap_or("No explicit problem statement was provided. Please analyze the context packet for requirements.");
+    match &ctx.problem_transform {
+        Some(transform) => transform.apply(problem_statement),
+        None => problem_statement.to_string(),
+    }
+}
+
+/// Whether a phase writes a `*.core.yaml` companion to its markdown artifact
+#[must_use]
+pub const fn has_core_yaml(phase: PhaseId) -> bool {
+    !matches!(phase, PhaseId::Fixup | PhaseId::Final)
+}
+
+/// Rebuild a phase's core YAML from its (possibly hand-edited) markdown artifact
+///
+/// Runs the same extraction the phase's postprocess step uses, so the result
+/// matches what a fresh phase run over `markdown` would have written.
+///
+/// # Errors
+///
+/// Returns an error for phases without a core YAML artifact (Fixup, Final) or
+/// if serialization fails.
+pub fn regenerate_core_yaml(phase: PhaseId, spec_id: &str, markdown: &str) -> Result<String> {
+    match phase {
+        PhaseId::Requirements => {
+            RequirementsCore::new(spec_id, &summarize_requirements(markdown)).to_yaml()
+        }
+        PhaseId::Design => DesignCore::new(spec_id, &summarize_design(markdown)).to_yaml(),
+        PhaseId::Tasks => TasksCore::new(spec_id, &summarize_tasks(markdown)).to_yaml(),
+        PhaseId::Review => ReviewCore::new(
+            spec_id,
+            &summarize_review(markdown),
+            review_needs_fixups(markdown),
+        )
+        .to_yaml(),
+        PhaseId::Fixup | PhaseId::Final => {
+            anyhow::bail!("Phase '{}' has no core YAML artifact", phase.as_str())
+        }
+    }
+}
+
+/// Whether review output asks for fixups (simplified marker check)
+fn review_needs_fixups(review_md: &str) -> bool {
+    review_md.contains("FIXUP PLAN:") || review_md.contains("needs fixups")
+}
 
 /// Common anti-summary instructions appended to all generative phase prompts.
 /// This prevents LLM from outputting meta-commentary instead of actual content.
@@ -39,29 +107,162 @@ CORRECT (start immediately with content):
 
   This system provides...";
 
-fn packet_limits_from_config(ctx: &PhaseContext) -> (usize, usize) {
-    let max_bytes = ctx
-        .config
-        .get("packet_max_bytes")
-        .and_then(|value| value.parse::<usize>().ok())
-        .unwrap_or(DEFAULT_PACKET_MAX_BYTES);
-    let max_lines = ctx
-        .config
-        .get("packet_max_lines")
-        .and_then(|value| value.parse::<usize>().ok())
-        .unwrap_or(DEFAULT_PACKET_MAX_LINES);
+/// Packet budget `(max_bytes, max_lines)` for `phase`: `[packet.<phase>]`,
+/// then the global `packet_max_*` values, then the built-in defaults
+#[must_use]
+pub fn packet_limits_from_config(ctx: &PhaseContext, phase: PhaseId) -> (usize, usize) {
+    let limit = |field: &str, global_key: &str| {
+        ctx.config
+            .get(&format!("packet.{}.{field}", phase.as_str()))
+            .or_else(|| ctx.config.get(global_key))
+            .and_then(|value| value.parse::<usize>().ok())
+    };
+    let max_bytes = limit("max_bytes", "packet_max_bytes").unwrap_or(DEFAULT_PACKET_MAX_BYTES);
+    let max_lines = limit("max_lines", "packet_max_lines").unwrap_or(DEFAULT_PACKET_MAX_LINES);
     (max_bytes, max_lines)
 }
 
-fn build_packet_builder(ctx: &PhaseContext) -> Result<PacketBuilder> {
-    let (max_bytes, max_lines) = packet_limits_from_config(ctx);
-    let builder =
-        PacketBuilder::with_selectors_and_limits(ctx.selectors.as_ref(), max_bytes, max_lines)?;
+fn prior_artifact_form_from_config(ctx: &PhaseContext) -> PriorArtifactForm {
+    ctx.config
+        .get("prior_artifact_form")
+        .and_then(|value| value.parse::<PriorArtifactForm>().ok())
+        .unwrap_or_default()
+}
+
+fn packet_order_from_config(ctx: &PhaseContext) -> PacketOrder {
+    ctx.config
+        .get("packet_order")
+        .and_then(|value| value.parse::<PacketOrder>().ok())
+        .unwrap_or_default()
+}
+
+/// Validation rules from config: `forbidden_opening_phrases` holds one
+/// phrase per line, and an empty value disables the opening-phrase check
+fn validation_config_from_config(ctx: &PhaseContext) -> ValidationConfig {
+    match ctx.config.get("forbidden_opening_phrases") {
+        Some(phrases) => ValidationConfig {
+            forbidden_opening_phrases: phrases
+                .lines()
+                .map(str::trim)
+                .filter(|phrase| !phrase.is_empty())
+                .map(str::to_string)
+                .collect(),
+        },
+        None => ValidationConfig::default(),
+    }
+}
+
+/// Validate phase output and apply `ctx.validation_mode` to any issues
+///
+/// Issues are always logged. Returns the redacted issue summaries to record
+/// on the receipt under `LenientFlagged`, and nothing under `Warn`.
+fn check_output(content: &str, phase: PhaseId, ctx: &PhaseContext) -> Result<Vec<String>> {
+    let rules = validation_config_from_config(ctx);
+    let Err(errors) = OutputValidator::validate_with_config(content, phase, &rules) else {
+        return Ok(Vec::new());
+    };
+
+    let summaries: Vec<String> = errors
+        .iter()
+        .map(|err| ctx.redactor.redact_string(&err.to_string()))
+        .collect();
+    for summary in &summaries {
+        eprintln!(
+            "[WARN] Validation issue in {} output: {}",
+            phase.as_str(),
+            summary
+        );
+    }
+
+    match ctx.validation_mode {
+        ValidationMode::Strict => Err(anyhow::anyhow!(
+            "Validation failed for {} phase: {} issue(s)",
+            phase.as_str(),
+            errors.len()
+        )),
+        ValidationMode::LenientFlagged => Ok(summaries),
+        ValidationMode::Warn => Ok(Vec::new()),
+    }
+}
+
+fn packet_preview_max_bytes_from_config(ctx: &PhaseContext) -> Option<usize> {
+    ctx.config
+        .get("packet_preview_max_bytes")
+        .and_then(|value| value.parse::<usize>().ok())
+}
 
-    // Set redactor from context
-    // Note: PacketBuilder doesn't have a direct redactor setter yet,
-    // so we'll skip cache for now until that's available
-    Ok(builder)
+fn packet_compress_preview_from_config(ctx: &PhaseContext) -> bool {
+    ctx.config
+        .get("packet_compress_preview")
+        .is_some_and(|value| value == "true")
+}
+
+/// `[packet] redaction_exempt` globs, one per line in the config map
+fn packet_redaction_exempt_from_config(ctx: &PhaseContext) -> Vec<String> {
+    ctx.config
+        .get("packet_redaction_exempt")
+        .map(|value| value.lines().map(str::to_string).collect())
+        .unwrap_or_default()
+}
+
+fn packet_cache_from_config(ctx: &PhaseContext) -> bool {
+    ctx.config
+        .get("packet_cache")
+        .is_some_and(|value| value == "true")
+}
+
+fn build_packet_builder(ctx: &PhaseContext, phase: PhaseId) -> Result<PacketBuilder> {
+    let (max_bytes, max_lines) = packet_limits_from_config(ctx, phase);
+    let mut builder =
+        PacketBuilder::with_selectors_and_limits(ctx.selectors.as_ref(), max_bytes, max_lines)?
+            .with_prior_artifact_form(prior_artifact_form_from_config(ctx))
+            .with_order(packet_order_from_config(ctx))
+            .with_preview_max_bytes(packet_preview_max_bytes_from_config(ctx))
+            .with_compress_preview(packet_compress_preview_from_config(ctx))
+            .with_redaction_exempt_globs(packet_redaction_exempt_from_config(ctx))?;
+
+    // `[packet] cache`: keep each spec's packets apart so specs sharing a
+    // phase name don't evict each other's entries
+    if packet_cache_from_config(ctx) {
+        builder.set_cache(InsightCache::new(paths::cache_dir().join(&ctx.spec_id))?);
+        builder = builder.with_packet_cache(true);
+    }
+
+    // Use the redactor built from the effective config so extra/ignore
+    // patterns apply to packet content as well as user-facing output
+    Ok(builder.with_redactor(ctx.redactor.as_ref().clone()))
+}
+
+/// Check that every phase in `phase.deps()` has left usable artifacts
+///
+/// The markdown artifact must be non-empty and, for phases that write one,
+/// the core YAML must parse. Without this a packet would silently be built
+/// with the upstream context missing.
+fn require_dependency_artifacts(ctx: &PhaseContext, phase: &dyn Phase) -> Result<()> {
+    let artifacts_dir = ctx.spec_dir.join("artifacts");
+    for &dep in phase.deps() {
+        let markdown = std::fs::read_to_string(
+            artifacts_dir.join(phase_artifact_filename(dep, ArtifactType::Markdown)),
+        )
+        .unwrap_or_default();
+        let mut present = !markdown.trim().is_empty();
+
+        if present && has_core_yaml(dep) {
+            present = std::fs::read_to_string(
+                artifacts_dir.join(phase_artifact_filename(dep, ArtifactType::CoreYaml)),
+            )
+            .is_ok_and(|yaml| serde_yaml::from_str::<serde_yaml::Value>(&yaml).is_ok());
+        }
+
+        if !present {
+            return Err(XCheckerError::MissingDependencyArtifact {
+                phase: phase.id().as_str().to_string(),
+                dep: dep.as_str().to_string(),
+            }
+            .into());
+        }
+    }
+    Ok(())
 }
 
 /// Implementation of Requirements phase
@@ -96,11 +297,7 @@ impl Phase for RequirementsPhase {
 
     fn prompt(&self, ctx: &PhaseContext) -> String {
         // Generate requirements phase prompt with problem statement from config
-        let problem_statement = ctx
-            .config
-            .get("problem_statement")
-            .map(String::as_str)
-            .unwrap_or("No explicit problem statement was provided. Please analyze the context packet for requirements.");
+        let problem_statement = problem_statement(ctx);
 
         format!(
             r"You are a requirements analyst helping to transform a rough feature idea into structured requirements.
@@ -173,7 +370,7 @@ Please analyze the problem statement above and create structured requirements fo
         let context_dir = base_path.join("context");
 
         // Create PacketBuilder with selectors from context (if configured)
-        let mut builder = build_packet_builder(ctx)?;
+        let mut builder = build_packet_builder(ctx, self.id())?;
 
         // Build packet from base path
         // PacketBuilder will:
@@ -192,30 +389,12 @@ Please analyze the problem statement above and create structured requirements fo
         let requirements_content = raw.trim().to_string();
 
         // Validate response content
-        if let Err(errors) = OutputValidator::validate(&requirements_content, PhaseId::Requirements)
-        {
-            // Always log validation issues
-            for err in &errors {
-                let redacted_err = ctx.redactor.redact_string(&err.to_string());
-                eprintln!(
-                    "[WARN] Validation issue in requirements output: {}",
-                    redacted_err
-                );
-            }
-
-            // In strict mode, fail the phase
-            if ctx.strict_validation {
-                return Err(anyhow::anyhow!(
-                    "Validation failed for requirements phase: {} issue(s)",
-                    errors.len()
-                ));
-            }
-        }
+        let validation_errors = check_output(&requirements_content, PhaseId::Requirements, ctx)?;
 
         // Create main requirements.md artifact
         let requirements_artifact = Artifact {
             name: "00-requirements.md".to_string(),
-            content: requirements_content.clone(),
+            content: ArtifactBody::Text(requirements_content.clone()),
             artifact_type: ArtifactType::Markdown,
             blake3_hash: blake3::hash(requirements_content.as_bytes())
                 .to_hex()
@@ -226,7 +405,7 @@ Please analyze the problem statement above and create structured requirements fo
         let core_yaml_content = self.generate_core_yaml(&requirements_content, ctx)?;
         let core_yaml_artifact = Artifact {
             name: "00-requirements.core.yaml".to_string(),
-            content: core_yaml_content.clone(),
+            content: ArtifactBody::Text(core_yaml_content.clone()),
             artifact_type: ArtifactType::CoreYaml,
             blake3_hash: blake3::hash(core_yaml_content.as_bytes())
                 .to_hex()
@@ -236,7 +415,10 @@ Please analyze the problem statement above and create structured requirements fo
         let artifacts = vec![requirements_artifact, core_yaml_artifact];
 
         // Metadata will be populated by orchestrator with packet hash, budget, and duration
-        let metadata = PhaseMetadata::default();
+        let metadata = PhaseMetadata {
+            validation_errors,
+            ..PhaseMetadata::default()
+        };
 
         Ok(PhaseResult {
             artifacts,
@@ -256,45 +438,7 @@ impl RequirementsPhase {
         // B3.0: Extract summary metadata from markdown
         let summary = summarize_requirements(requirements_md);
 
-        let yaml_content = format!(
-            r#"# Core requirements data for spec {}
-# This file contains structured data extracted from the requirements document
-
-spec_id: "{}"
-phase: "requirements"
-version: "1.0"
-
-# Metadata about requirements (B3.0 extraction)
-metadata:
-  total_requirements: {}
-  total_user_stories: {}
-  total_acceptance_criteria: {}
-  total_nfrs: {}
-  has_nfrs: {}
-
-# Structured requirements data (B3.1 - future)
-requirements: []
-
-# Non-functional requirements (B3.1 - future)
-nfrs: []
-
-# Dependencies and relationships
-dependencies: []
-
-# Generated timestamp
-generated_at: "{}"
-"#,
-            ctx.spec_id,
-            ctx.spec_id,
-            summary.requirement_count,
-            summary.user_story_count,
-            summary.acceptance_criteria_count,
-            summary.nfr_count,
-            summary.nfr_count > 0,
-            chrono::Utc::now().to_rfc3339()
-        );
-
-        Ok(yaml_content)
+        RequirementsCore::new(&ctx.spec_id, &summary).to_yaml()
     }
 }
 
@@ -383,6 +527,8 @@ Please analyze the requirements and create a comprehensive design document follo
     }
 
     fn make_packet(&self, ctx: &PhaseContext) -> Result<Packet> {
+        require_dependency_artifacts(ctx, self)?;
+
         // Convert spec_dir to Utf8PathBuf for PacketBuilder
         let base_path = Utf8PathBuf::try_from(ctx.spec_dir.clone())
             .map_err(|e| anyhow::anyhow!("Invalid UTF-8 path: {e}"))?;
@@ -391,7 +537,7 @@ Please analyze the requirements and create a comprehensive design document follo
         let context_dir = base_path.join("context");
 
         // Create PacketBuilder with selectors from context (if configured)
-        let mut builder = build_packet_builder(ctx)?;
+        let mut builder = build_packet_builder(ctx, self.id())?;
 
         // Build packet from base path
         // PacketBuilder will:
@@ -411,26 +557,12 @@ Please analyze the requirements and create a comprehensive design document follo
         let design_content = raw.trim().to_string();
 
         // Validate response content
-        if let Err(errors) = OutputValidator::validate(&design_content, PhaseId::Design) {
-            // Always log validation issues
-            for err in &errors {
-                let redacted_err = ctx.redactor.redact_string(&err.to_string());
-                eprintln!("[WARN] Validation issue in design output: {}", redacted_err);
-            }
-
-            // In strict mode, fail the phase
-            if ctx.strict_validation {
-                return Err(anyhow::anyhow!(
-                    "Validation failed for design phase: {} issue(s)",
-                    errors.len()
-                ));
-            }
-        }
+        let validation_errors = check_output(&design_content, PhaseId::Design, ctx)?;
 
         // Create main design.md artifact
         let design_artifact = Artifact {
             name: "10-design.md".to_string(),
-            content: design_content.clone(),
+            content: ArtifactBody::Text(design_content.clone()),
             artifact_type: ArtifactType::Markdown,
             blake3_hash: blake3::hash(design_content.as_bytes()).to_hex().to_string(),
         };
@@ -439,7 +571,7 @@ Please analyze the requirements and create a comprehensive design document follo
         let core_yaml_content = self.generate_core_yaml(&design_content, ctx)?;
         let core_yaml_artifact = Artifact {
             name: "10-design.core.yaml".to_string(),
-            content: core_yaml_content.clone(),
+            content: ArtifactBody::Text(core_yaml_content.clone()),
             artifact_type: ArtifactType::CoreYaml,
             blake3_hash: blake3::hash(core_yaml_content.as_bytes())
                 .to_hex()
@@ -449,7 +581,10 @@ Please analyze the requirements and create a comprehensive design document follo
         let artifacts = vec![design_artifact, core_yaml_artifact];
 
         // Metadata will be populated by orchestrator with packet hash, budget, and duration
-        let metadata = PhaseMetadata::default();
+        let metadata = PhaseMetadata {
+            validation_errors,
+            ..PhaseMetadata::default()
+        };
 
         Ok(PhaseResult {
             artifacts,
@@ -467,54 +602,7 @@ impl DesignPhase {
         // B3.0: Extract summary metadata from markdown
         let summary = summarize_design(design_md);
 
-        let yaml_content = format!(
-            r#"# Core design data for spec {}
-# This file contains structured data extracted from the design document
-
-spec_id: "{}"
-phase: "design"
-version: "1.0"
-
-# Metadata about the design (B3.0 extraction)
-metadata:
-  has_architecture_section: {}
-  has_mermaid_diagrams: {}
-  total_components: {}
-  total_interfaces: {}
-  total_data_models: {}
-
-# Structured design data (B3.1 - future)
-architecture:
-  components: []
-  interfaces: []
-  data_flow: []
-
-# Data models (B3.1 - future)
-data_models: []
-
-# Error handling strategies (B3.1 - future)
-error_handling: []
-
-# Testing strategies (B3.1 - future)
-testing_strategy: []
-
-# Dependencies on requirements
-requirements_dependencies: []
-
-# Generated timestamp
-generated_at: "{}"
-"#,
-            ctx.spec_id,
-            ctx.spec_id,
-            summary.has_architecture,
-            summary.has_diagrams,
-            summary.component_count,
-            summary.interface_count,
-            summary.data_model_count,
-            chrono::Utc::now().to_rfc3339()
-        );
-
-        Ok(yaml_content)
+        DesignCore::new(&ctx.spec_id, &summary).to_yaml()
     }
 }
 
@@ -603,6 +691,8 @@ Please analyze the design and requirements to create a comprehensive implementat
     }
 
     fn make_packet(&self, ctx: &PhaseContext) -> Result<Packet> {
+        require_dependency_artifacts(ctx, self)?;
+
         // Convert spec_dir to Utf8PathBuf for PacketBuilder
         let base_path = Utf8PathBuf::try_from(ctx.spec_dir.clone())
             .map_err(|e| anyhow::anyhow!("Invalid UTF-8 path: {e}"))?;
@@ -611,7 +701,7 @@ Please analyze the design and requirements to create a comprehensive implementat
         let context_dir = base_path.join("context");
 
         // Create PacketBuilder with selectors from context (if configured)
-        let mut builder = build_packet_builder(ctx)?;
+        let mut builder = build_packet_builder(ctx, self.id())?;
 
         // Build packet from base path
         // PacketBuilder will:
@@ -631,26 +721,12 @@ Please analyze the design and requirements to create a comprehensive implementat
         let tasks_content = raw.trim().to_string();
 
         // Validate response content
-        if let Err(errors) = OutputValidator::validate(&tasks_content, PhaseId::Tasks) {
-            // Always log validation issues
-            for err in &errors {
-                let redacted_err = ctx.redactor.redact_string(&err.to_string());
-                eprintln!("[WARN] Validation issue in tasks output: {}", redacted_err);
-            }
-
-            // In strict mode, fail the phase
-            if ctx.strict_validation {
-                return Err(anyhow::anyhow!(
-                    "Validation failed for tasks phase: {} issue(s)",
-                    errors.len()
-                ));
-            }
-        }
+        let validation_errors = check_output(&tasks_content, PhaseId::Tasks, ctx)?;
 
         // Create main tasks.md artifact
         let tasks_artifact = Artifact {
             name: "20-tasks.md".to_string(),
-            content: tasks_content.clone(),
+            content: ArtifactBody::Text(tasks_content.clone()),
             artifact_type: ArtifactType::Markdown,
             blake3_hash: blake3::hash(tasks_content.as_bytes()).to_hex().to_string(),
         };
@@ -659,7 +735,7 @@ Please analyze the design and requirements to create a comprehensive implementat
         let core_yaml_content = self.generate_core_yaml(&tasks_content, ctx)?;
         let core_yaml_artifact = Artifact {
             name: "20-tasks.core.yaml".to_string(),
-            content: core_yaml_content.clone(),
+            content: ArtifactBody::Text(core_yaml_content.clone()),
             artifact_type: ArtifactType::CoreYaml,
             blake3_hash: blake3::hash(core_yaml_content.as_bytes())
                 .to_hex()
@@ -669,7 +745,10 @@ Please analyze the design and requirements to create a comprehensive implementat
         let artifacts = vec![tasks_artifact, core_yaml_artifact];
 
         // Metadata will be populated by orchestrator with packet hash, budget, and duration
-        let metadata = PhaseMetadata::default();
+        let metadata = PhaseMetadata {
+            validation_errors,
+            ..PhaseMetadata::default()
+        };
 
         Ok(PhaseResult {
             artifacts,
@@ -687,43 +766,7 @@ impl TasksPhase {
         // B3.0: Extract summary metadata from markdown
         let summary = summarize_tasks(tasks_md);
 
-        let yaml_content = format!(
-            r#"# Core tasks data for spec {}
-# This file contains structured data extracted from the tasks document
-
-spec_id: "{}"
-phase: "tasks"
-version: "1.0"
-
-# Metadata about tasks (B3.0 extraction)
-metadata:
-  total_tasks: {}
-  total_subtasks: {}
-  total_milestones: {}
-  total_dependencies: {}
-
-# Structured tasks data (B3.1 - future)
-tasks: []
-
-# Task dependencies and ordering (B3.1 - future)
-dependencies: []
-
-# Requirements coverage (B3.1 - future)
-requirements_coverage: []
-
-# Generated timestamp
-generated_at: "{}"
-"#,
-            ctx.spec_id,
-            ctx.spec_id,
-            summary.task_count,
-            summary.subtask_count,
-            summary.milestone_count,
-            summary.dependency_count,
-            chrono::Utc::now().to_rfc3339()
-        );
-
-        Ok(yaml_content)
+        TasksCore::new(&ctx.spec_id, &summary).to_yaml()
     }
 }
 
@@ -735,8 +778,9 @@ impl Default for TasksPhase {
 
 /// Implementation of Review phase
 ///
-/// This phase reviews generated tasks and identifies gaps or issues that need
-/// to be addressed through fixups to earlier phases.
+/// This phase critiques the requirements, design and tasks artifacts for gaps
+/// and contradictions, and proposes fixups to earlier phases where needed.
+/// Issues are listed per area so `30-review.core.yaml` can count them.
 #[derive(Debug, Clone)]
 pub struct ReviewPhase;
 
@@ -766,7 +810,7 @@ impl Phase for ReviewPhase {
         format!(
             r"You are a senior technical reviewer conducting a comprehensive review of the generated specification.
 
-Your task is to review the complete specification (requirements, design, and tasks) and identify any gaps, inconsistencies, or issues that need to be addressed.
+Your task is to critique the complete specification (requirements, design, and tasks) and identify any gaps, contradictions, or other issues that need to be addressed.
 
 # Review Guidelines
 
@@ -774,13 +818,20 @@ Your task is to review the complete specification (requirements, design, and tas
 1. **Requirements Completeness**: Are all user needs captured? Any missing edge cases?
 2. **Design Consistency**: Does the design address all requirements? Any architectural gaps?
 3. **Task Coverage**: Do the implementation tasks cover all design components? Any missing steps?
-4. **Cross-Phase Alignment**: Are requirements, design, and tasks consistent with each other?
+4. **Cross-Phase Alignment**: Are requirements, design, and tasks consistent with each other? Any contradictions between them?
 5. **Technical Feasibility**: Are the proposed solutions technically sound and implementable?
 6. **Quality Standards**: Do the artifacts meet professional standards for clarity and completeness?
 
 ## Review Output Format:
 
-If issues are found that require changes to earlier phases, use this format:
+Start with `# Review`, then list each issue as a top-level bullet under the matching heading. Leave a heading empty if it has no issues:
+
+## Requirements Issues
+## Design Issues
+## Tasks Issues
+## Cross-Phase Issues
+
+If issues are found that require changes to earlier phases, add a fixup plan after the issue lists:
 
 **FIXUP PLAN:**
 
@@ -800,17 +851,19 @@ For each file that needs changes, provide a unified diff in a fenced code block:
 
 ## If No Issues Found:
 
-If the specification is complete and consistent, provide a summary of what was reviewed and confirm that no fixups are needed.
+If the specification is complete and consistent, leave the issue headings empty, note what was reviewed, and confirm that no fixups are needed.
 
 Spec ID: {}
 Phase: Review
 
-Please conduct a thorough review of the specification artifacts and provide your assessment.",
-            ctx.spec_id
+Please conduct a thorough review of the specification artifacts and provide your assessment.{}",
+            ctx.spec_id, ANTI_SUMMARY_INSTRUCTIONS,
         )
     }
 
     fn make_packet(&self, ctx: &PhaseContext) -> Result<Packet> {
+        require_dependency_artifacts(ctx, self)?;
+
         let mut content = String::new();
         let mut files = Vec::new();
 
@@ -845,6 +898,7 @@ Please conduct a thorough review of the specification artifacts and provide your
                             .to_hex()
                             .to_string(),
                         priority: xchecker_utils::types::Priority::Upstream,
+                        redaction_exempt: false,
                     });
                 }
                 Err(e) => {
@@ -868,6 +922,7 @@ Please conduct a thorough review of the specification artifacts and provide your
                             .to_hex()
                             .to_string(),
                         priority: xchecker_utils::types::Priority::Upstream,
+                        redaction_exempt: false,
                     });
                 }
                 Err(e) => {
@@ -894,6 +949,7 @@ Please conduct a thorough review of the specification artifacts and provide your
                             .to_hex()
                             .to_string(),
                         priority: xchecker_utils::types::Priority::Upstream,
+                        redaction_exempt: false,
                     });
                 }
                 Err(e) => {
@@ -916,6 +972,7 @@ Please conduct a thorough review of the specification artifacts and provide your
                             .to_hex()
                             .to_string(),
                         priority: xchecker_utils::types::Priority::Upstream,
+                        redaction_exempt: false,
                     });
                 }
                 Err(e) => {
@@ -942,6 +999,7 @@ Please conduct a thorough review of the specification artifacts and provide your
                             .to_hex()
                             .to_string(),
                         priority: xchecker_utils::types::Priority::Upstream,
+                        redaction_exempt: false,
                     });
                 }
                 Err(e) => {
@@ -964,6 +1022,7 @@ Please conduct a thorough review of the specification artifacts and provide your
                             .to_hex()
                             .to_string(),
                         priority: xchecker_utils::types::Priority::Upstream,
+                        redaction_exempt: false,
                     });
                 }
                 Err(e) => {
@@ -976,12 +1035,13 @@ Please conduct a thorough review of the specification artifacts and provide your
         let blake3_hash = blake3::hash(content.as_bytes()).to_hex().to_string();
 
         // Create evidence for the packet
-        let (max_bytes, max_lines) = packet_limits_from_config(ctx);
+        let (max_bytes, max_lines) = packet_limits_from_config(ctx, self.id());
 
         let evidence = PacketEvidence {
             files,
             max_bytes,
             max_lines,
+            preview_compressed: false,
         };
 
         let mut budget_used = xchecker_packet::BudgetUsage::new(max_bytes, max_lines);
@@ -993,17 +1053,19 @@ Please conduct a thorough review of the specification artifacts and provide your
     fn postprocess(&self, raw: &str, ctx: &PhaseContext) -> Result<PhaseResult> {
         let review_content = raw.trim().to_string();
 
+        // Validate response content
+        let validation_errors = check_output(&review_content, PhaseId::Review, ctx)?;
+
         // Create the main review.md artifact
         let review_artifact = Artifact {
             name: "30-review.md".to_string(),
-            content: review_content.clone(),
+            content: ArtifactBody::Text(review_content.clone()),
             artifact_type: ArtifactType::Markdown,
             blake3_hash: blake3::hash(review_content.as_bytes()).to_hex().to_string(),
         };
 
         // Check if fixups are needed (simplified for now)
-        let has_fixup_markers =
-            review_content.contains("FIXUP PLAN:") || review_content.contains("needs fixups");
+        let has_fixup_markers = review_needs_fixups(&review_content);
         let next_step = if has_fixup_markers {
             // Fixups are needed - proceed to Fixup phase
             NextStep::Continue
@@ -1016,7 +1078,7 @@ Please conduct a thorough review of the specification artifacts and provide your
         let core_yaml_content = self.generate_core_yaml(&review_content, ctx, has_fixup_markers)?;
         let core_yaml_artifact = Artifact {
             name: "30-review.core.yaml".to_string(),
-            content: core_yaml_content.clone(),
+            content: ArtifactBody::Text(core_yaml_content.clone()),
             artifact_type: ArtifactType::CoreYaml,
             blake3_hash: blake3::hash(core_yaml_content.as_bytes())
                 .to_hex()
@@ -1026,7 +1088,10 @@ Please conduct a thorough review of the specification artifacts and provide your
         let artifacts = vec![review_artifact, core_yaml_artifact];
 
         // Metadata will be populated by orchestrator with packet hash, budget, and duration
-        let metadata = PhaseMetadata::default();
+        let metadata = PhaseMetadata {
+            validation_errors,
+            ..PhaseMetadata::default()
+        };
 
         Ok(PhaseResult {
             artifacts,
@@ -1038,50 +1103,17 @@ Please conduct a thorough review of the specification artifacts and provide your
 
 impl ReviewPhase {
     /// Generate a core YAML file with structured review data
+    ///
+    /// Uses B3.0 minimal extraction to count the issues listed per area.
     fn generate_core_yaml(
         &self,
-        _review_md: &str,
+        review_md: &str,
         ctx: &PhaseContext,
         fixups_needed: bool,
     ) -> Result<String> {
-        let yaml_content = format!(
-            r#"# Core review data for spec {}
-# This file contains structured data extracted from the review document
-
-spec_id: "{}"
-phase: "review"
-version: "1.0"
-
-# Metadata about the review
-metadata:
-  fixups_needed: {}
-  has_fixup_plan: {}
-  review_sections_found: []  # Would be parsed from markdown
-  issues_identified: 0       # Would be counted from review content
-
-# Review findings (would be extracted from markdown)
-findings:
-  requirements_issues: []
-  design_issues: []
-  tasks_issues: []
-  cross_phase_issues: []
-
-# Fixup information if needed
-fixup_info:
-  target_files: []           # Would be extracted from diff blocks
-  change_summary: {{}}       # Would be calculated from diffs
-
-# Generated timestamp
-generated_at: "{}"
-"#,
-            ctx.spec_id,
-            ctx.spec_id,
-            fixups_needed,
-            fixups_needed,
-            chrono::Utc::now().to_rfc3339()
-        );
+        let summary = summarize_review(review_md);
 
-        Ok(yaml_content)
+        ReviewCore::new(&ctx.spec_id, &summary, fixups_needed).to_yaml()
     }
 }
 
@@ -1108,13 +1140,51 @@ mod tests {
             config: HashMap::new(),
             artifacts: Vec::new(),
             selectors: None,
-            strict_validation: false,
+            validation_mode: ValidationMode::Warn,
             redactor: std::sync::Arc::new(xchecker_redaction::SecretRedactor::default()),
+            problem_transform: None,
         };
 
         (ctx, temp_dir)
     }
 
+    #[test]
+    fn test_forbidden_opening_phrases_from_config_reach_postprocess() {
+        let (mut ctx, _temp_dir) = create_test_context();
+        ctx.validation_mode = ValidationMode::LenientFlagged;
+        let phase = RequirementsPhase::new();
+        let meta_summaries = |result: PhaseResult| {
+            result
+                .metadata
+                .validation_errors
+                .into_iter()
+                .filter(|err| err.contains("meta-summary"))
+                .collect::<Vec<_>>()
+        };
+
+        // A configured phrase replaces the built-in list
+        ctx.config.insert(
+            "forbidden_opening_phrases".to_string(),
+            "Voici\nHier ist".to_string(),
+        );
+        let rejected = phase
+            .postprocess("Voici le document\n\n# Requirements Document", &ctx)
+            .unwrap();
+        assert_eq!(meta_summaries(rejected).len(), 1);
+        let allowed = phase
+            .postprocess("Sure! # Requirements Document", &ctx)
+            .unwrap();
+        assert!(meta_summaries(allowed).is_empty());
+
+        // An empty list disables the opening-phrase check
+        ctx.config
+            .insert("forbidden_opening_phrases".to_string(), String::new());
+        let allowed = phase
+            .postprocess("Voici le document\n\n# Requirements Document", &ctx)
+            .unwrap();
+        assert!(meta_summaries(allowed).is_empty());
+    }
+
     #[test]
     fn test_requirements_phase_basic_properties() {
         let phase = RequirementsPhase::new();
@@ -1138,6 +1208,22 @@ mod tests {
         assert!(prompt.contains("Acceptance Criteria"));
     }
 
+    #[test]
+    fn test_requirements_phase_prompt_applies_problem_transform() {
+        let phase = RequirementsPhase::new();
+        let (mut ctx, _temp_dir) = create_test_context();
+        ctx.config.insert(
+            "problem_statement".to_string(),
+            "add retry support to the sync job".to_string(),
+        );
+        ctx.problem_transform = Some(xchecker_phase_api::ProblemTransform::new(str::to_uppercase));
+
+        let prompt = phase.prompt(&ctx);
+
+        assert!(prompt.contains("ADD RETRY SUPPORT TO THE SYNC JOB"));
+        assert!(!prompt.contains("add retry support to the sync job"));
+    }
+
     #[test]
     fn test_requirements_phase_packet_creation() {
         let phase = RequirementsPhase::new();
@@ -1154,6 +1240,134 @@ mod tests {
         assert_eq!(packet.evidence.max_lines, 1200);
     }
 
+    /// Write the requirements artifacts the Design phase depends on
+    fn seed_requirements_artifacts(ctx: &PhaseContext) {
+        let artifacts = ctx.spec_dir.join("artifacts");
+        std::fs::create_dir_all(&artifacts).unwrap();
+        std::fs::write(
+            artifacts.join("00-requirements.md"),
+            "# Requirements Document\n",
+        )
+        .unwrap();
+        std::fs::write(
+            artifacts.join("00-requirements.core.yaml"),
+            "spec_id: test-123\nphase: requirements\n",
+        )
+        .unwrap();
+    }
+
+    fn design_packet_files(form: &str) -> Vec<String> {
+        let (mut ctx, _temp_dir) = create_test_context();
+        seed_requirements_artifacts(&ctx);
+        ctx.config
+            .insert("prior_artifact_form".to_string(), form.to_string());
+
+        let packet = DesignPhase::new().make_packet(&ctx).unwrap();
+        let mut names: Vec<String> = packet
+            .evidence
+            .files
+            .iter()
+            .filter_map(|f| f.path.rsplit(['/', '\\']).next().map(str::to_string))
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn test_design_packet_prior_artifact_form() {
+        assert_eq!(
+            design_packet_files("both"),
+            vec!["00-requirements.core.yaml", "00-requirements.md"]
+        );
+        assert_eq!(design_packet_files("markdown"), vec!["00-requirements.md"]);
+        assert_eq!(
+            design_packet_files("core_yaml"),
+            vec!["00-requirements.core.yaml"]
+        );
+    }
+
+    #[test]
+    fn test_design_packet_requires_requirements_artifacts() {
+        let (ctx, _temp_dir) = create_test_context();
+
+        let err = DesignPhase::new().make_packet(&ctx).unwrap_err();
+        match err.downcast_ref::<XCheckerError>() {
+            Some(XCheckerError::MissingDependencyArtifact { phase, dep }) => {
+                assert_eq!(phase, "design");
+                assert_eq!(dep, "requirements");
+            }
+            other => panic!("expected MissingDependencyArtifact, got {other:?}"),
+        }
+
+        // Markdown alone is not enough; the core YAML is part of the context
+        let artifacts = ctx.spec_dir.join("artifacts");
+        std::fs::create_dir_all(&artifacts).unwrap();
+        std::fs::write(artifacts.join("00-requirements.md"), "# Requirements\n").unwrap();
+        let err = DesignPhase::new().make_packet(&ctx).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<XCheckerError>(),
+            Some(XCheckerError::MissingDependencyArtifact { .. })
+        ));
+
+        seed_requirements_artifacts(&ctx);
+        let packet = DesignPhase::new().make_packet(&ctx).unwrap();
+        assert!(
+            packet
+                .evidence
+                .files
+                .iter()
+                .any(|f| f.path.ends_with("00-requirements.md"))
+        );
+    }
+
+    #[test]
+    fn test_design_packet_rejects_empty_requirements_markdown() {
+        let (ctx, _temp_dir) = create_test_context();
+        seed_requirements_artifacts(&ctx);
+        std::fs::write(ctx.spec_dir.join("artifacts/00-requirements.md"), "  \n").unwrap();
+
+        let err = DesignPhase::new().make_packet(&ctx).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<XCheckerError>(),
+            Some(XCheckerError::MissingDependencyArtifact { .. })
+        ));
+    }
+
+    #[test]
+    fn test_packet_limits_per_phase_override() {
+        let (mut ctx, _temp_dir) = create_test_context();
+        seed_requirements_artifacts(&ctx);
+        assert_eq!(
+            packet_limits_from_config(&ctx, PhaseId::Design),
+            (DEFAULT_PACKET_MAX_BYTES, DEFAULT_PACKET_MAX_LINES)
+        );
+
+        ctx.config
+            .insert("packet_max_bytes".to_string(), "32768".to_string());
+        ctx.config
+            .insert("packet_max_lines".to_string(), "600".to_string());
+        ctx.config
+            .insert("packet.design.max_bytes".to_string(), "131072".to_string());
+
+        // Design overrides bytes only; lines fall back to the global value
+        assert_eq!(
+            packet_limits_from_config(&ctx, PhaseId::Design),
+            (131_072, 600)
+        );
+        assert_eq!(
+            packet_limits_from_config(&ctx, PhaseId::Requirements),
+            (32768, 600)
+        );
+
+        let packet = DesignPhase::new().make_packet(&ctx).unwrap();
+        assert_eq!(packet.budget_usage().max_bytes, 131_072);
+        assert_eq!(packet.evidence().max_lines, 600);
+
+        let packet = RequirementsPhase::new().make_packet(&ctx).unwrap();
+        assert_eq!(packet.budget_usage().max_bytes, 32768);
+        assert_eq!(packet.evidence().max_lines, 600);
+    }
+
     #[test]
     fn test_requirements_phase_postprocessing() {
         let phase = RequirementsPhase::new();
@@ -1203,9 +1417,83 @@ This is a test requirements document.
 
         assert!(result.is_ok());
         let yaml_content = result.unwrap();
-        assert!(yaml_content.contains("spec_id: \"test-123\""));
-        assert!(yaml_content.contains("phase: \"requirements\""));
-        assert!(yaml_content.contains("version: \"1.0\""));
+        assert!(yaml_content.starts_with("# Core requirements data for spec test-123\n"));
+        assert!(yaml_content.contains("spec_id: test-123"));
+        assert!(yaml_content.contains("phase: requirements"));
+        assert!(yaml_content.contains("version: '1.0'"));
+    }
+
+    #[test]
+    fn test_core_yaml_is_byte_identical_for_same_inputs() {
+        let (ctx, _temp_dir) = create_test_context();
+
+        let requirements_md = "# Requirements\n\n### Requirement 1\n\n**User Story:** As a user";
+        let requirements = RequirementsPhase::new();
+        assert_eq!(
+            requirements
+                .generate_core_yaml(requirements_md, &ctx)
+                .unwrap(),
+            requirements
+                .generate_core_yaml(requirements_md, &ctx)
+                .unwrap()
+        );
+
+        let design = DesignPhase::new();
+        assert_eq!(
+            design
+                .generate_core_yaml("# Design\n\n## Architecture", &ctx)
+                .unwrap(),
+            design
+                .generate_core_yaml("# Design\n\n## Architecture", &ctx)
+                .unwrap()
+        );
+
+        let tasks = TasksPhase::new();
+        assert_eq!(
+            tasks
+                .generate_core_yaml("# Tasks\n\n- [ ] 1. Task", &ctx)
+                .unwrap(),
+            tasks
+                .generate_core_yaml("# Tasks\n\n- [ ] 1. Task", &ctx)
+                .unwrap()
+        );
+
+        let review = ReviewPhase::new();
+        assert_eq!(
+            review.generate_core_yaml("# Review", &ctx, true).unwrap(),
+            review.generate_core_yaml("# Review", &ctx, true).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_core_yaml_preserves_top_level_field_order() {
+        let (ctx, _temp_dir) = create_test_context();
+        let yaml = RequirementsPhase::new()
+            .generate_core_yaml("# Requirements", &ctx)
+            .unwrap();
+
+        let top_level_keys: Vec<&str> = yaml
+            .lines()
+            .filter(|line| !line.starts_with([' ', '#', '-']) && line.contains(':'))
+            .map(|line| line.split(':').next().unwrap())
+            .collect();
+        assert_eq!(
+            top_level_keys,
+            [
+                "spec_id",
+                "phase",
+                "version",
+                "metadata",
+                "requirements",
+                "nfrs",
+                "dependencies",
+            ]
+        );
+
+        // Output must still parse as YAML with the extracted metadata intact
+        let parsed: serde_yaml::Value = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(parsed["spec_id"].as_str(), Some("test-123"));
+        assert_eq!(parsed["metadata"]["has_nfrs"].as_bool(), Some(false));
     }
 
     #[test]
@@ -1273,5 +1561,64 @@ This is a test requirements document.
         assert!(prompt.contains("Review Guidelines"));
         assert!(prompt.contains("FIXUP PLAN"));
         assert!(prompt.contains("test-123"));
+        assert!(prompt.contains("contradictions"));
+        assert!(prompt.contains("## Cross-Phase Issues"));
+        assert!(prompt.contains(ANTI_SUMMARY_INSTRUCTIONS));
+    }
+
+    #[test]
+    fn test_review_phase_accepts_terse_no_issues_review_in_strict_mode() {
+        let phase = ReviewPhase::new();
+        let (mut ctx, _temp_dir) = create_test_context();
+        ctx.validation_mode = ValidationMode::Strict;
+
+        let raw_response = "# Review\n\nNo issues found; no fixups are needed.\n";
+
+        let phase_result = phase.postprocess(raw_response, &ctx).unwrap();
+        assert!(phase_result.metadata.validation_errors.is_empty());
+        let ArtifactBody::Text(core_yaml) = &phase_result.artifacts[1].content else {
+            panic!("core YAML should be text");
+        };
+        assert!(core_yaml.contains("fixups_needed: false"), "{core_yaml}");
+        assert!(core_yaml.contains("issues_identified: 0"), "{core_yaml}");
+    }
+
+    #[test]
+    fn test_review_phase_postprocessing_counts_issues() {
+        let phase = ReviewPhase::new();
+        let (ctx, _temp_dir) = create_test_context();
+
+        let raw_response = r"# Review
+
+## Requirements Issues
+
+- Requirement 2 has no acceptance criteria
+- No NFR covers startup time
+
+## Design Issues
+
+## Tasks Issues
+
+## Cross-Phase Issues
+
+- Design stores sessions in Redis but Task 3 writes them to disk
+";
+
+        let phase_result = phase.postprocess(raw_response, &ctx).unwrap();
+        let names: Vec<_> = phase_result
+            .artifacts
+            .iter()
+            .map(|a| a.name.as_str())
+            .collect();
+        assert_eq!(names, ["30-review.md", "30-review.core.yaml"]);
+
+        let ArtifactBody::Text(core_yaml) = &phase_result.artifacts[1].content else {
+            panic!("core YAML should be text");
+        };
+        assert!(core_yaml.contains("fixups_needed: false"), "{core_yaml}");
+        assert!(core_yaml.contains("issues_identified: 3"), "{core_yaml}");
+        assert!(core_yaml.contains("requirements: 2"), "{core_yaml}");
+        assert!(core_yaml.contains("design: 0"), "{core_yaml}");
+        assert!(core_yaml.contains("cross_phase: 1"), "{core_yaml}");
     }
 }
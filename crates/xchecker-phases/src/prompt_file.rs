@@ -0,0 +1,188 @@
+//! Custom prompt templates loaded from files
+//!
+//! `[phases.<phase>].prompt_file` replaces a phase's built-in prompt with a
+//! template on disk. Templates are checked up front — bounded in size, valid
+//! UTF-8, and carrying the placeholders the phase needs — so a broken template
+//! fails with a [`TemplateError`] naming the problem instead of producing a
+//! prompt that silently lost its subject.
+
+use std::io::Read;
+use std::path::Path;
+
+use xchecker_phase_api::PhaseContext;
+use xchecker_utils::error::TemplateError;
+use xchecker_utils::types::PhaseId;
+
+/// Largest prompt template accepted, in bytes
+pub const MAX_PROMPT_TEMPLATE_BYTES: u64 = 256 * 1024;
+
+/// Placeholders a template for `phase` must contain
+///
+/// `{spec_id}` is required everywhere; Requirements also needs
+/// `{problem_statement}`, since it is the only input that phase has.
+#[must_use]
+pub fn required_placeholders(phase: PhaseId) -> &'static [&'static str] {
+    match phase {
+        PhaseId::Requirements => &["{spec_id}", "{problem_statement}"],
+        _ => &["{spec_id}"],
+    }
+}
+
+/// Read and validate the prompt template for `phase` at `path`
+///
+/// # Errors
+///
+/// Returns [`TemplateError`] if the file cannot be read, exceeds
+/// [`MAX_PROMPT_TEMPLATE_BYTES`], is not UTF-8, or lacks any of
+/// [`required_placeholders`] (all missing ones are listed).
+pub fn load_prompt_template(path: &Path, phase: PhaseId) -> Result<String, TemplateError> {
+    let display = path.display().to_string();
+    let read_failed = |e: std::io::Error| TemplateError::ReadFailed {
+        path: display.clone(),
+        reason: e.to_string(),
+    };
+
+    // Read one byte past the limit so oversized files are detected without
+    // loading them whole
+    let mut bytes = Vec::new();
+    std::fs::File::open(path)
+        .map_err(read_failed)?
+        .take(MAX_PROMPT_TEMPLATE_BYTES + 1)
+        .read_to_end(&mut bytes)
+        .map_err(read_failed)?;
+    if bytes.len() as u64 > MAX_PROMPT_TEMPLATE_BYTES {
+        return Err(TemplateError::TooLarge {
+            path: display,
+            limit: MAX_PROMPT_TEMPLATE_BYTES,
+        });
+    }
+
+    let template = String::from_utf8(bytes).map_err(|e| TemplateError::InvalidUtf8 {
+        path: display.clone(),
+        offset: e.utf8_error().valid_up_to(),
+    })?;
+
+    let missing: Vec<String> = required_placeholders(phase)
+        .iter()
+        .filter(|placeholder| !template.contains(*placeholder))
+        .map(ToString::to_string)
+        .collect();
+    if !missing.is_empty() {
+        return Err(TemplateError::MissingPlaceholders {
+            path: display,
+            phase: phase.as_str().to_string(),
+            missing,
+        });
+    }
+
+    Ok(template)
+}
+
+/// Fill `template`'s placeholders from `ctx`
+///
+/// Supports `{spec_id}`, `{phase}` and `{problem_statement}`; the problem
+/// statement is resolved and transformed exactly as for the built-in
+/// Requirements prompt. Other braces are left alone.
+#[must_use]
+pub fn render_prompt_template(template: &str, phase: PhaseId, ctx: &PhaseContext) -> String {
+    template
+        .replace("{spec_id}", &ctx.spec_id)
+        .replace("{phase}", phase.as_str())
+        .replace("{problem_statement}", &crate::problem_statement(ctx))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use tempfile::TempDir;
+    use xchecker_phase_api::ValidationMode;
+
+    fn write(dir: &TempDir, bytes: &[u8]) -> std::path::PathBuf {
+        let path = dir.path().join("prompt.md");
+        std::fs::write(&path, bytes).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_valid_template_loads_and_renders() {
+        let dir = TempDir::new().unwrap();
+        let path = write(
+            &dir,
+            "Spec {spec_id} ({phase}): {problem_statement}\nKeep {braces}.".as_bytes(),
+        );
+
+        let template = load_prompt_template(&path, PhaseId::Requirements).unwrap();
+        let ctx = PhaseContext {
+            spec_id: "demo".to_string(),
+            spec_dir: dir.path().to_path_buf(),
+            config: HashMap::from([("problem_statement".to_string(), "Build a CLI".to_string())]),
+            artifacts: Vec::new(),
+            selectors: None,
+            validation_mode: ValidationMode::Warn,
+            redactor: std::sync::Arc::new(xchecker_redaction::SecretRedactor::default()),
+            problem_transform: None,
+        };
+
+        assert_eq!(
+            render_prompt_template(&template, PhaseId::Requirements, &ctx),
+            "Spec demo (requirements): Build a CLI\nKeep {braces}."
+        );
+    }
+
+    #[test]
+    fn test_non_utf8_template_is_rejected() {
+        let dir = TempDir::new().unwrap();
+        let path = write(&dir, b"{spec_id} caf\xe9");
+
+        match load_prompt_template(&path, PhaseId::Design) {
+            Err(TemplateError::InvalidUtf8 { offset, .. }) => assert_eq!(offset, 13),
+            other => panic!("expected InvalidUtf8, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_oversized_template_is_rejected() {
+        let dir = TempDir::new().unwrap();
+        let mut bytes = b"{spec_id}".to_vec();
+        bytes.resize(MAX_PROMPT_TEMPLATE_BYTES as usize + 1, b'x');
+        let path = write(&dir, &bytes);
+
+        match load_prompt_template(&path, PhaseId::Design) {
+            Err(TemplateError::TooLarge { limit, .. }) => {
+                assert_eq!(limit, MAX_PROMPT_TEMPLATE_BYTES);
+            }
+            other => panic!("expected TooLarge, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_requirements_template_without_problem_statement_is_rejected() {
+        let dir = TempDir::new().unwrap();
+        let path = write(&dir, b"Write requirements for {spec_id}.");
+
+        match load_prompt_template(&path, PhaseId::Requirements) {
+            Err(TemplateError::MissingPlaceholders { phase, missing, .. }) => {
+                assert_eq!(phase, "requirements");
+                assert_eq!(missing, vec!["{problem_statement}"]);
+            }
+            other => panic!("expected MissingPlaceholders, got {other:?}"),
+        }
+
+        // The same text is fine for a phase that only needs the spec id
+        assert!(load_prompt_template(&path, PhaseId::Design).is_ok());
+    }
+
+    #[test]
+    fn test_missing_placeholders_are_all_listed() {
+        let dir = TempDir::new().unwrap();
+        let path = write(&dir, b"No placeholders here.");
+
+        let err = load_prompt_template(&path, PhaseId::Requirements).unwrap_err();
+        let TemplateError::MissingPlaceholders { missing, .. } = &err else {
+            panic!("expected MissingPlaceholders, got {err:?}");
+        };
+        assert_eq!(missing, &["{spec_id}", "{problem_statement}"]);
+        assert!(err.to_string().contains("{spec_id}, {problem_statement}"));
+    }
+}
@@ -0,0 +1,129 @@
+//! Field-level comparison of two receipts for reproducibility audits
+//!
+//! Two runs of the same spec with the same inputs should produce receipts
+//! that agree on everything except timing. [`ReceiptDiff::compare`] lists
+//! every field that differs; timing differences are reported as
+//! informational and never make the receipts non-identical.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use xchecker_utils::types::Receipt;
+
+/// Differences between two receipts
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReceiptDiff {
+    /// True when no field other than informational ones differs
+    pub identical: bool,
+    /// Every differing field, in comparison order
+    pub mismatches: Vec<ReceiptMismatch>,
+}
+
+/// One field that differs between two receipts
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReceiptMismatch {
+    /// Field that differs, e.g. `model_full_name` or
+    /// `outputs[artifacts/00-requirements.md]`
+    pub field: String,
+    /// Value in the first receipt; `None` if absent there
+    pub left: Option<String>,
+    /// Value in the second receipt; `None` if absent there
+    pub right: Option<String>,
+    /// Expected to vary between runs (e.g. durations); does not affect `identical`
+    pub informational: bool,
+}
+
+impl ReceiptDiff {
+    /// Compare `left` against `right`
+    ///
+    /// Compares the phase, model name, packet hash, each packet file's
+    /// pre-redaction hash and each output's hash, keyed by path, and the
+    /// phase duration (informational). Packet file paths are compared as
+    /// recorded, so receipts from different spec directories should have
+    /// them made relative first.
+    #[must_use]
+    pub fn compare(left: &Receipt, right: &Receipt) -> Self {
+        let mut mismatches = Vec::new();
+        let mut check = |field: String, l: Option<String>, r: Option<String>, informational| {
+            if l != r {
+                mismatches.push(ReceiptMismatch {
+                    field,
+                    left: l,
+                    right: r,
+                    informational,
+                });
+            }
+        };
+
+        check(
+            "phase".to_string(),
+            Some(left.phase.clone()),
+            Some(right.phase.clone()),
+            false,
+        );
+        check(
+            "model_full_name".to_string(),
+            Some(left.model_full_name.clone()),
+            Some(right.model_full_name.clone()),
+            false,
+        );
+        check(
+            "packet_hash".to_string(),
+            left.packet_hash.clone(),
+            right.packet_hash.clone(),
+            false,
+        );
+
+        let packet_files = |receipt: &Receipt| -> BTreeMap<String, String> {
+            receipt
+                .packet
+                .files
+                .iter()
+                .map(|file| (file.path.clone(), file.blake3_pre_redaction.clone()))
+                .collect()
+        };
+        for (path, (l, r)) in zip_by_key(packet_files(left), packet_files(right)) {
+            check(format!("packet.files[{path}]"), l, r, false);
+        }
+
+        let outputs = |receipt: &Receipt| -> BTreeMap<String, String> {
+            receipt
+                .outputs
+                .iter()
+                .map(|output| (output.path.clone(), output.blake3_canonicalized.clone()))
+                .collect()
+        };
+        for (path, (l, r)) in zip_by_key(outputs(left), outputs(right)) {
+            check(format!("outputs[{path}]"), l, r, false);
+        }
+
+        check(
+            "duration_ms".to_string(),
+            left.duration_ms.map(|ms| ms.to_string()),
+            right.duration_ms.map(|ms| ms.to_string()),
+            true,
+        );
+
+        let identical = mismatches.iter().all(|mismatch| mismatch.informational);
+        Self {
+            identical,
+            mismatches,
+        }
+    }
+}
+
+/// Pair up the values of two maps by key, in key order
+fn zip_by_key(
+    mut left: BTreeMap<String, String>,
+    right: BTreeMap<String, String>,
+) -> BTreeMap<String, (Option<String>, Option<String>)> {
+    let mut paired: BTreeMap<String, (Option<String>, Option<String>)> = BTreeMap::new();
+    for (key, value) in right {
+        let l = left.remove(&key);
+        paired.insert(key, (l, Some(value)));
+    }
+    for (key, value) in left {
+        paired.insert(key, (Some(value), None));
+    }
+    paired
+}
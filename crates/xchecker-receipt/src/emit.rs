@@ -137,6 +137,12 @@ impl ReceiptManager {
             diff_context,
             llm: None, // Will be set by orchestrator when ClaudeResponse is available
             pipeline,
+            validation_degraded: None,
+            validation_errors: Vec::new(),
+            input_fingerprint: None,
+            packet_hash: None,
+            duration_ms: None,
+            timings: None,
         }
     }
 }
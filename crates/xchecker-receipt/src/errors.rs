@@ -22,6 +22,9 @@ pub(super) const fn error_to_exit_code_and_kind(error: &XCheckerError) -> (i32,
         // Secret detection (redaction hard stop)
         XCheckerError::SecretDetected { .. } => (8, ErrorKind::SecretDetected),
 
+        // Redactor misconfiguration is a configuration error
+        XCheckerError::RedactorUnavailable { .. } => (2, ErrorKind::CliArgs),
+
         // Concurrent execution / lock held
         XCheckerError::ConcurrentExecution { .. } => (9, ErrorKind::LockHeld),
         XCheckerError::Lock(_) => (9, ErrorKind::LockHeld),
@@ -98,6 +101,7 @@ pub fn write_error_receipt_and_exit(
             files: vec![],
             max_bytes: 0,
             max_lines: 0,
+            preview_compressed: false,
         },
         outputs: vec![],
         exit_code,
@@ -110,6 +114,12 @@ pub fn write_error_receipt_and_exit(
         diff_context: None,
         llm: None,      // No LLM info for early errors
         pipeline: None, // No pipeline info for early errors
+        validation_degraded: None,
+        validation_errors: Vec::new(),
+        input_fingerprint: None,
+        packet_hash: None,
+        duration_ms: None,
+        timings: None,
     };
 
     // Try to write the receipt, but don't fail if we can't
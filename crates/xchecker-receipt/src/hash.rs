@@ -1,5 +1,3 @@
-use anyhow::Result;
-
 use xchecker_utils::error::XCheckerError;
 use xchecker_utils::types::{FileHash, FileType};
 
@@ -23,4 +21,47 @@ impl ReceiptManager {
             blake3_canonicalized: blake3_hash,
         })
     }
+
+    /// Create a file hash for binary content, hashed over its raw bytes
+    ///
+    /// Binary content has no canonical form, so no canonicalization is applied.
+    #[must_use]
+    pub fn create_bytes_hash(&self, file_path: &str, content: &[u8]) -> FileHash {
+        FileHash {
+            path: file_path.to_string(),
+            blake3_canonicalized: blake3::hash(content).to_hex().to_string(),
+        }
+    }
+
+    /// Re-hash an artifact's current content for comparison with `recorded`
+    ///
+    /// Receipts record binary artifacts by raw-byte hash and text artifacts
+    /// by canonical hash. The raw hash is returned when it matches;
+    /// otherwise UTF-8 content is hashed canonically, as it was recorded.
+    pub fn rehash_output(
+        &self,
+        recorded: &FileHash,
+        bytes: Vec<u8>,
+        phase: &str,
+    ) -> Result<FileHash, XCheckerError> {
+        let raw = self.create_bytes_hash(&recorded.path, &bytes);
+        if raw.blake3_canonicalized == recorded.blake3_canonicalized {
+            return Ok(raw);
+        }
+        match String::from_utf8(bytes) {
+            Ok(content) => {
+                let extension = std::path::Path::new(&recorded.path)
+                    .extension()
+                    .and_then(|ext| ext.to_str())
+                    .unwrap_or("");
+                self.create_file_hash(
+                    &recorded.path,
+                    &content,
+                    FileType::from_extension(extension),
+                    phase,
+                )
+            }
+            Err(_) => Ok(raw),
+        }
+    }
 }
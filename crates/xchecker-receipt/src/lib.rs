@@ -1,11 +1,15 @@
+mod diff;
 mod emit;
 mod errors;
 mod hash;
 mod model;
+mod summary;
 mod writer;
 
+pub use diff::{ReceiptDiff, ReceiptMismatch};
 pub use errors::write_error_receipt_and_exit;
 pub use model::ReceiptManager;
+pub use summary::ReceiptSummary;
 pub use writer::add_rename_retry_warning;
 
 #[cfg(test)]
@@ -0,0 +1,51 @@
+//! Compact per-phase view of a receipt for status dashboards
+//!
+//! A [`ReceiptSummary`] keeps only the fields a dashboard needs to show one
+//! row per phase: the packet hash, how many artifacts were written, the exit
+//! code, how long the phase took and any drift from the spec's lockfile.
+
+use serde::{Deserialize, Serialize};
+use xchecker_utils::lock::{RunContext, XCheckerLock};
+use xchecker_utils::types::{LockDrift, PhaseId, Receipt};
+
+/// Summary of the latest receipt for one phase
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReceiptSummary {
+    /// Phase the receipt belongs to
+    pub phase: PhaseId,
+    /// BLAKE3 of the packet sent to the LLM; `None` for receipts that
+    /// predate packet hashing
+    pub packet_hash: Option<String>,
+    /// Number of artifacts the phase wrote
+    pub artifact_count: usize,
+    /// Exit code recorded for the phase
+    pub exit_code: i32,
+    /// Wall-clock time the phase took, in milliseconds
+    pub duration_ms: Option<u64>,
+    /// Drift between the lockfile and the model and CLI version the phase
+    /// ran with; `None` without a lockfile or when nothing drifted
+    pub drift: Option<LockDrift>,
+}
+
+impl ReceiptSummary {
+    /// Summarize `receipt`, checking it against `lock` when one exists
+    #[must_use]
+    pub fn from_receipt(phase: PhaseId, receipt: &Receipt, lock: Option<&XCheckerLock>) -> Self {
+        let drift = lock.and_then(|lock| {
+            lock.detect_drift(&RunContext {
+                model_full_name: receipt.model_full_name.clone(),
+                claude_cli_version: receipt.claude_cli_version.clone(),
+                schema_version: "1".to_string(),
+            })
+        });
+
+        Self {
+            phase,
+            packet_hash: receipt.packet_hash.clone(),
+            artifact_count: receipt.outputs.len(),
+            exit_code: receipt.exit_code,
+            duration_ms: receipt.duration_ms,
+            drift,
+        }
+    }
+}
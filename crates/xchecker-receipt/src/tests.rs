@@ -31,6 +31,7 @@ fn test_create_receipt() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let mut flags = std::collections::HashMap::new();
@@ -110,6 +111,7 @@ fn test_write_and_read_receipt() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let receipt = manager.create_receipt(
@@ -178,6 +180,7 @@ fn test_list_receipts() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let receipt = manager.create_receipt(
@@ -230,6 +233,7 @@ fn test_receipt_json_serialization() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let mut flags = std::collections::HashMap::new();
@@ -312,6 +316,7 @@ fn test_new_fields_serialization() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let mut flags = std::collections::HashMap::new();
@@ -394,6 +399,7 @@ fn test_diff_context_in_receipt() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     // Test with diff_context set to 0 (unidiff-zero enabled)
@@ -493,6 +499,7 @@ requirements:
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let receipt = manager.create_receipt(
@@ -557,6 +564,7 @@ fn test_error_receipt_creation() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let error_receipt = manager.create_error_receipt(
@@ -603,6 +611,7 @@ fn test_error_receipt_with_different_error_kinds() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     // Test SecretDetected error
@@ -676,6 +685,7 @@ fn test_optional_fields_in_receipt() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     // Test with all optional fields set
@@ -759,6 +769,7 @@ fn test_atomic_write_creates_file() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let receipt = manager.create_receipt(
@@ -818,6 +829,7 @@ fn test_jcs_emission_byte_identical() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let mut flags = std::collections::HashMap::new();
@@ -877,6 +889,7 @@ fn test_receipt_listing_chronological_order() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     // Create multiple receipts for different phases with different timestamps
@@ -937,6 +950,7 @@ fn test_read_latest_receipt_returns_most_recent() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     // Create multiple receipts for the same phase
@@ -993,6 +1007,7 @@ fn test_receipt_edge_case_empty_outputs() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     // Create receipt with no outputs (error case)
@@ -1040,6 +1055,7 @@ fn test_receipt_edge_case_large_warnings_list() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     // Create a large warnings list
@@ -1088,6 +1104,7 @@ fn test_receipt_edge_case_special_characters_in_fields() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     // Create receipt with special characters
@@ -1151,6 +1168,7 @@ fn test_receipt_with_missing_optional_fields() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let receipt = manager.create_receipt(
@@ -1201,6 +1219,7 @@ fn test_receipt_with_empty_strings() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let receipt = manager.create_receipt(
@@ -1243,6 +1262,7 @@ fn test_receipt_with_very_long_strings() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let receipt = manager.create_receipt(
@@ -1280,6 +1300,7 @@ fn test_receipt_with_unicode_content() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let receipt = manager.create_receipt(
@@ -1319,6 +1340,7 @@ fn test_receipt_with_special_characters() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let receipt = manager.create_receipt(
@@ -1365,6 +1387,7 @@ fn test_receipt_with_maximum_outputs() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let receipt = manager.create_receipt(
@@ -1416,6 +1439,7 @@ fn test_receipt_with_all_error_kinds() {
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            preview_compressed: false,
         };
 
         let error_kind_clone = error_kind.clone();
@@ -1455,6 +1479,7 @@ fn test_receipt_with_negative_exit_code() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let receipt = manager.create_receipt(
@@ -1491,6 +1516,7 @@ fn test_receipt_with_large_exit_code() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let receipt = manager.create_receipt(
@@ -1527,6 +1553,7 @@ fn test_receipt_serialization_with_null_values() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let receipt = manager.create_receipt(
@@ -1668,3 +1695,80 @@ fn test_error_kind_mapping() {
         assert_eq!(kind, expected_kind);
     }
 }
+
+// ===== Receipt schema migration =====
+
+#[test]
+fn test_migrate_v1_receipt_missing_newer_fields() {
+    // Hand-crafted v1 receipt written before `warnings`, `llm` and `pipeline` existed
+    let legacy = serde_json::json!({
+        "schema_version": "1",
+        "emitted_at": "2024-01-01T00:00:00Z",
+        "canonicalization_version": "yaml-v1,md-v1",
+        "canonicalization_backend": "jcs-rfc8785",
+        "spec_id": "legacy-spec",
+        "phase": "requirements",
+        "exit_code": 0,
+        "outputs": [],
+        "xchecker_version": "0.1.0",
+        "claude_cli_version": "0.8.1",
+        "model_full_name": "haiku",
+        "flags": {},
+        "packet": {
+            "files": [],
+            "max_bytes": 65536,
+            "max_lines": 1200
+        },
+        "runner": "native"
+    });
+
+    // Plain deserialization rejects the legacy shape
+    assert!(serde_json::from_value::<Receipt>(legacy.clone()).is_err());
+
+    let receipt = Receipt::migrate(legacy).unwrap();
+    assert_eq!(receipt.schema_version, Receipt::CURRENT_SCHEMA_VERSION);
+    assert_eq!(receipt.spec_id, "legacy-spec");
+    assert!(receipt.warnings.is_empty());
+    assert!(receipt.llm.is_none());
+    assert!(receipt.pipeline.is_none());
+}
+
+#[test]
+fn test_migrate_unversioned_receipt_applies_defaults() {
+    let legacy = serde_json::json!({
+        "emitted_at": "2024-01-01T00:00:00Z",
+        "canonicalization_version": "yaml-v1,md-v1",
+        "spec_id": "legacy-spec",
+        "phase": "design",
+        "exit_code": 0,
+        "outputs": [],
+        "xchecker_version": "0.0.1",
+        "claude_cli_version": "0.8.1",
+        "model_full_name": "haiku",
+        "packet": {
+            "files": [],
+            "max_bytes": 65536,
+            "max_lines": 1200
+        }
+    });
+
+    let receipt = Receipt::migrate(legacy).unwrap();
+    assert_eq!(receipt.schema_version, "1");
+    assert_eq!(receipt.runner, "native");
+    assert_eq!(receipt.canonicalization_backend, "jcs-rfc8785");
+    assert!(receipt.flags.is_empty());
+}
+
+#[test]
+fn test_migrate_rejects_future_schema_version() {
+    let future = serde_json::json!({ "schema_version": "2" });
+
+    let err = Receipt::migrate(future).unwrap_err();
+    assert!(err.to_string().contains("newer than supported"));
+}
+
+#[test]
+fn test_migrate_rejects_non_object() {
+    assert!(Receipt::migrate(serde_json::json!([])).is_err());
+    assert!(Receipt::migrate(serde_json::json!({ "schema_version": "v1" })).is_err());
+}
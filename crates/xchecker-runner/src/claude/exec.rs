@@ -1,8 +1,11 @@
+use async_trait::async_trait;
 use std::process::Stdio;
 use std::time::Duration;
 use tokio::io::AsyncWriteExt;
 use tokio::time::timeout;
 
+use crate::command_spec::CommandSpec;
+use crate::encoding::decode_captured;
 use crate::error::RunnerError;
 use crate::ring_buffer::RingBuffer;
 use crate::types::RunnerMode;
@@ -126,6 +129,36 @@ impl Runner {
         }
     }
 
+    /// Return the exact argv `execute_claude` would spawn for `spec`, without running it
+    ///
+    /// `spec` is the Claude invocation (`CommandSpec::new("claude").args(..)`).
+    /// As in execution, its program is replaced by the runner's configured Claude
+    /// binary, and in WSL mode the `wsl [-d <distro>] --exec` wrapper is
+    /// prepended. Each element is one discrete argument; no shell string is built.
+    /// `Auto` mode resolves like execution and falls back to native if detection fails.
+    #[must_use]
+    pub fn dry_run_command(&self, spec: &CommandSpec) -> Vec<String> {
+        let args: Vec<String> = spec
+            .args
+            .iter()
+            .map(|arg| arg.to_string_lossy().into_owned())
+            .collect();
+
+        let mode = match self.mode {
+            RunnerMode::Auto => Self::detect_auto().unwrap_or(RunnerMode::Native),
+            mode => mode,
+        };
+        let resolved = match mode {
+            RunnerMode::Native | RunnerMode::Auto => self.native_command_spec(&args),
+            RunnerMode::Wsl => self.wsl_command_spec(&args),
+        };
+
+        std::iter::once(&resolved.program)
+            .chain(&resolved.args)
+            .map(|arg| arg.to_string_lossy().into_owned())
+            .collect()
+    }
+
     /// Execute Claude CLI natively (spawn claude directly)
     async fn execute_native(
         &self,
@@ -186,7 +219,10 @@ impl Runner {
     ) -> Result<ClaudeResponse, RunnerError> {
         cmd.stdin(Stdio::piped())
             .stdout(Stdio::piped())
-            .stderr(Stdio::piped());
+            .stderr(Stdio::piped())
+            // Callers may abandon this future (e.g. a run-wide deadline);
+            // don't leave the process running when they do
+            .kill_on_drop(true);
 
         // Create Job Object on Windows for process tree termination
         #[cfg(windows)]
@@ -275,8 +311,8 @@ impl Runner {
             .map_err(|err| map_pipe_error(runner_used, err))?
         };
 
-        let stdout = stdout_buffer.to_string();
-        let stderr = stderr_buffer.to_string();
+        let stdout = decode_captured(&stdout_buffer.to_bytes());
+        let stderr = decode_captured(&stderr_buffer.to_bytes());
         let ndjson_result = Self::parse_ndjson(&stdout);
 
         Ok(ClaudeResponse {
@@ -305,6 +341,46 @@ impl Default for Runner {
     }
 }
 
+/// Injection point for Claude CLI execution
+///
+/// The Claude CLI backend drives every invocation through this trait, so a
+/// caller can swap the real [`Runner`] for a [`RecordingRunner`] or
+/// [`ReplayRunner`] to capture or serve argv+stdin→stdout interactions.
+///
+/// [`RecordingRunner`]: crate::replay::RecordingRunner
+/// [`ReplayRunner`]: crate::replay::ReplayRunner
+#[async_trait]
+pub trait ClaudeRunner: Send + Sync {
+    /// Run Claude CLI with `args`, piping `stdin_content` to it
+    async fn execute_claude(
+        &self,
+        args: &[String],
+        stdin_content: &str,
+        timeout_duration: Option<Duration>,
+    ) -> Result<ClaudeResponse, RunnerError>;
+
+    /// Claude CLI version reported by this runner, if it can tell
+    fn claude_version(&self) -> Option<String> {
+        None
+    }
+}
+
+#[async_trait]
+impl ClaudeRunner for Runner {
+    async fn execute_claude(
+        &self,
+        args: &[String],
+        stdin_content: &str,
+        timeout_duration: Option<Duration>,
+    ) -> Result<ClaudeResponse, RunnerError> {
+        Self::execute_claude(self, args, stdin_content, timeout_duration).await
+    }
+
+    fn claude_version(&self) -> Option<String> {
+        self.get_claude_version_sync().ok()
+    }
+}
+
 fn execution_failed(runner_used: RunnerMode, reason: String) -> RunnerError {
     match runner_used {
         RunnerMode::Native => RunnerError::NativeExecutionFailed { reason },
@@ -332,6 +408,7 @@ mod tests {
     use super::Runner;
     use crate::claude::BufferConfig;
     use crate::claude::WslOptions;
+    use crate::command_spec::CommandSpec;
     use crate::types::RunnerMode;
 
     #[test]
@@ -359,4 +436,60 @@ mod tests {
         assert_eq!(runner.buffer_config.stderr_cap_bytes, 512);
         assert_eq!(runner.buffer_config.stderr_receipt_cap_bytes, 256);
     }
+
+    fn claude_spec() -> CommandSpec {
+        CommandSpec::new("claude").args([
+            "--print",
+            "--append-system-prompt",
+            "a; rm -rf / && echo $(whoami)",
+        ])
+    }
+
+    #[test]
+    fn test_dry_run_command_native() {
+        let runner = Runner::new(RunnerMode::Native, WslOptions::default());
+
+        assert_eq!(
+            runner.dry_run_command(&claude_spec()),
+            vec![
+                "claude",
+                "--print",
+                "--append-system-prompt",
+                "a; rm -rf / && echo $(whoami)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dry_run_command_wsl() {
+        let runner = Runner::new(
+            RunnerMode::Wsl,
+            WslOptions {
+                distro: Some("Ubuntu-22.04".to_string()),
+                claude_path: Some("/usr/local/bin/claude".to_string()),
+            },
+        );
+
+        let argv = runner.dry_run_command(&claude_spec());
+        assert_eq!(
+            argv,
+            vec![
+                "wsl",
+                "-d",
+                "Ubuntu-22.04",
+                "--exec",
+                "/usr/local/bin/claude",
+                "--print",
+                "--append-system-prompt",
+                "a; rm -rf / && echo $(whoami)",
+            ]
+        );
+        // The wrapper never introduces a shell or shell-joined arguments
+        assert!(!argv.iter().any(|a| a == "-c" || a == "sh" || a == "bash"));
+        assert!(
+            argv[..argv.len() - 1]
+                .iter()
+                .all(|a| !a.contains(' ') && !a.contains(';'))
+        );
+    }
 }
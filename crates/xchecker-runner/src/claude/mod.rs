@@ -8,5 +8,5 @@ mod version;
 mod wsl;
 
 pub use super::ndjson::NdjsonResult;
-pub use exec::Runner;
+pub use exec::{ClaudeRunner, Runner};
 pub use types::{BufferConfig, ClaudeResponse, WslOptions};
@@ -1,5 +1,6 @@
 use std::process::Stdio;
 
+use crate::encoding::decode_captured;
 use crate::error::RunnerError;
 use crate::types::RunnerMode;
 
@@ -57,7 +58,7 @@ impl Runner {
             };
         }
 
-        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stdout = decode_captured(&output.stdout);
 
         // Extract version from output like "claude 0.8.1"
         let version = stdout
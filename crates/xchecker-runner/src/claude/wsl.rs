@@ -1,4 +1,5 @@
 use crate::command_spec::CommandSpec;
+use crate::encoding::decode_console_output;
 use std::env;
 
 use super::exec::Runner;
@@ -26,7 +27,7 @@ impl Runner {
             .output()
             && output.status.success()
         {
-            let distros = String::from_utf8_lossy(&output.stdout);
+            let distros = decode_console_output(&output.stdout);
             // Get the first non-empty line (default distro)
             for line in distros.lines() {
                 let line = line.trim();
@@ -0,0 +1,120 @@
+//! Console output decoding
+//!
+//! Most CLIs write UTF-8, but some Windows tools (notably `wsl.exe`) emit
+//! UTF-16LE depending on locale and console settings. This module turns raw
+//! captured bytes into a `String` regardless of which of the two was used.
+
+const UTF8_BOM: &[u8] = &[0xEF, 0xBB, 0xBF];
+const UTF16LE_BOM: &[u8] = &[0xFF, 0xFE];
+
+/// Decode raw console output as UTF-8 or UTF-16LE
+///
+/// A leading UTF-8 or UTF-16LE byte order mark selects the encoding and is
+/// stripped. Without a BOM, input is treated as UTF-16LE only when it has even
+/// length and at least half of the first ten high bytes are `0x00` (the
+/// signature of ASCII text in UTF-16LE); everything else decodes as UTF-8.
+/// Invalid sequences are replaced with U+FFFD.
+#[must_use]
+pub fn decode_console_output(raw: &[u8]) -> String {
+    if let Some(rest) = raw.strip_prefix(UTF8_BOM) {
+        return String::from_utf8_lossy(rest).into_owned();
+    }
+    if let Some(rest) = raw.strip_prefix(UTF16LE_BOM) {
+        return decode_utf16le(rest);
+    }
+    if looks_like_utf16le(raw) {
+        return decode_utf16le(raw);
+    }
+    String::from_utf8_lossy(raw).into_owned()
+}
+
+/// Decode captured process output using the platform's expectations
+///
+/// On Windows this goes through [`decode_console_output`] so UTF-16 CLIs are
+/// handled; elsewhere output is always UTF-8.
+pub(crate) fn decode_captured(raw: &[u8]) -> String {
+    #[cfg(windows)]
+    {
+        decode_console_output(raw)
+    }
+
+    #[cfg(not(windows))]
+    {
+        String::from_utf8_lossy(raw).into_owned()
+    }
+}
+
+/// Check if this looks like UTF-16LE (every other byte is 0x00 for ASCII)
+fn looks_like_utf16le(raw: &[u8]) -> bool {
+    raw.len() >= 4
+        && raw.len().is_multiple_of(2)
+        && raw
+            .iter()
+            .skip(1)
+            .step_by(2)
+            .take(10)
+            .filter(|&&b| b == 0x00)
+            .count()
+            >= 5
+}
+
+fn decode_utf16le(raw: &[u8]) -> String {
+    let units: Vec<u16> = raw
+        .chunks(2)
+        .map(|pair| match pair {
+            [lo, hi] => u16::from_le_bytes([*lo, *hi]),
+            // Dangling trailing byte cannot form a code unit
+            _ => char::REPLACEMENT_CHARACTER as u16,
+        })
+        .collect();
+    String::from_utf16_lossy(&units)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utf16le(text: &str) -> Vec<u8> {
+        text.encode_utf16().flat_map(u16::to_le_bytes).collect()
+    }
+
+    #[test]
+    fn test_decode_utf8() {
+        assert_eq!(
+            decode_console_output("Ubuntu-22.04\nDebian ü\n".as_bytes()),
+            "Ubuntu-22.04\nDebian ü\n"
+        );
+    }
+
+    #[test]
+    fn test_decode_utf16le_without_bom() {
+        let input = utf16le("{\"status\": \"ok\"}\n");
+        assert_eq!(decode_console_output(&input), "{\"status\": \"ok\"}\n");
+    }
+
+    #[test]
+    fn test_decode_utf16le_with_bom() {
+        let mut input = UTF16LE_BOM.to_vec();
+        input.extend(utf16le("Grüße 世界"));
+        assert_eq!(decode_console_output(&input), "Grüße 世界");
+    }
+
+    #[test]
+    fn test_decode_utf8_bom_is_stripped() {
+        let mut input = UTF8_BOM.to_vec();
+        input.extend_from_slice("# Requirements\n".as_bytes());
+        assert_eq!(decode_console_output(&input), "# Requirements\n");
+    }
+
+    #[test]
+    fn test_short_and_odd_length_input_is_utf8() {
+        assert_eq!(decode_console_output(b"Hi"), "Hi");
+        assert_eq!(decode_console_output(b"Hello"), "Hello");
+        assert_eq!(decode_console_output(b""), "");
+    }
+
+    #[test]
+    fn test_decode_captured_utf8() {
+        assert_eq!(decode_captured(b"plain output\n"), "plain output\n");
+    }
+}
@@ -25,4 +25,7 @@ pub enum RunnerError {
 
     #[error("Execution timed out after {timeout_seconds} seconds")]
     Timeout { timeout_seconds: u64 },
+
+    #[error("No recorded response for Claude CLI invocation {key}")]
+    UnrecordedInteraction { key: String },
 }
@@ -12,20 +12,24 @@
 // Declare runner submodules
 pub mod claude;
 pub mod command_spec;
+pub mod encoding;
 pub mod error;
 pub mod native;
 pub mod ndjson;
 pub mod process;
+pub mod replay;
 pub mod ring_buffer;
 pub mod types;
 pub mod wsl;
 
 // Re-export everything from xchecker-runner submodules
-pub use claude::{BufferConfig, ClaudeResponse, NdjsonResult, Runner, WslOptions};
+pub use claude::{BufferConfig, ClaudeResponse, ClaudeRunner, NdjsonResult, Runner, WslOptions};
 pub use command_spec::CommandSpec;
+pub use encoding::decode_console_output;
 pub use error::RunnerError;
 pub use native::NativeRunner;
 pub use process::{ProcessOutput, ProcessRunner};
+pub use replay::{InteractionLog, RecordedInteraction, RecordingRunner, ReplayRunner};
 pub use ring_buffer::RingBuffer;
 pub use types::RunnerMode;
 pub use wsl::WslRunner;
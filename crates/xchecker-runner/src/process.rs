@@ -1,3 +1,4 @@
+use crate::encoding::decode_captured;
 use crate::error::RunnerError;
 use std::time::Duration;
 
@@ -35,16 +36,20 @@ impl ProcessOutput {
         }
     }
 
-    /// Get stdout as a UTF-8 string, lossy conversion.
+    /// Get stdout as a string, lossy conversion.
+    ///
+    /// On Windows, UTF-16LE output is detected and decoded.
     #[must_use]
     pub fn stdout_string(&self) -> String {
-        String::from_utf8_lossy(&self.stdout).to_string()
+        decode_captured(&self.stdout)
     }
 
-    /// Get stderr as a UTF-8 string, lossy conversion.
+    /// Get stderr as a string, lossy conversion.
+    ///
+    /// On Windows, UTF-16LE output is detected and decoded.
     #[must_use]
     pub fn stderr_string(&self) -> String {
-        String::from_utf8_lossy(&self.stderr).to_string()
+        decode_captured(&self.stderr)
     }
 
     /// Check if the process exited successfully (exit code 0).
@@ -75,8 +80,8 @@ impl ProcessOutput {
 /// # Example
 ///
 /// ```rust
-/// use xchecker_utils::runner::{ProcessRunner, CommandSpec, ProcessOutput};
-/// use xchecker_utils::error::RunnerError;
+/// use xchecker_runner::{ProcessRunner, CommandSpec, ProcessOutput};
+/// use xchecker_runner::RunnerError;
 /// use std::time::Duration;
 ///
 /// struct SimpleRunner;
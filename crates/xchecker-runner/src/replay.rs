@@ -0,0 +1,296 @@
+//! Record and replay Claude CLI interactions
+//!
+//! [`RecordingRunner`] wraps another [`ClaudeRunner`] and captures each
+//! argv+stdin→stdout exchange into a shared [`InteractionLog`].
+//! [`ReplayRunner`] serves those exchanges back without spawning anything,
+//! keyed by a BLAKE3 hash of argv and prompt, so pipeline tests can run
+//! hermetically. An invocation that was never recorded fails with
+//! [`RunnerError::UnrecordedInteraction`].
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::claude::{ClaudeResponse, ClaudeRunner, Runner};
+use crate::error::RunnerError;
+use crate::types::RunnerMode;
+
+/// One captured Claude CLI invocation and its outcome
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedInteraction {
+    /// [`interaction_key`] of `argv` and `stdin`
+    pub key: String,
+    /// Arguments passed to Claude CLI (without the binary)
+    pub argv: Vec<String>,
+    /// Prompt piped to Claude CLI
+    pub stdin: String,
+    pub stdout: String,
+    pub stderr: String,
+    pub exit_code: i32,
+}
+
+impl RecordedInteraction {
+    fn to_response(&self) -> ClaudeResponse {
+        ClaudeResponse {
+            stdout: self.stdout.clone(),
+            stderr: self.stderr.clone(),
+            exit_code: self.exit_code,
+            runner_used: RunnerMode::Native,
+            runner_distro: None,
+            timed_out: false,
+            ndjson_result: Runner::parse_ndjson(&self.stdout),
+            stdout_truncated: false,
+            stderr_truncated: false,
+            stdout_total_bytes: self.stdout.len(),
+            stderr_total_bytes: self.stderr.len(),
+        }
+    }
+}
+
+/// Hash identifying an invocation by its argv and prompt
+///
+/// Each argument is NUL-terminated before the prompt is appended, so
+/// `["a", "b"]` and `["ab"]` never collide.
+#[must_use]
+pub fn interaction_key(argv: &[String], stdin: &str) -> String {
+    let mut hasher = blake3::Hasher::new();
+    for arg in argv {
+        hasher.update(arg.as_bytes());
+        hasher.update(b"\0");
+    }
+    hasher.update(stdin.as_bytes());
+    hasher.finalize().to_hex().to_string()
+}
+
+/// Shared, append-only list of recorded interactions
+///
+/// Clones share the same list, so a test can keep one handle while the
+/// [`RecordingRunner`] that fills it is owned by an orchestrator.
+#[derive(Debug, Clone, Default)]
+pub struct InteractionLog {
+    entries: Arc<Mutex<Vec<RecordedInteraction>>>,
+}
+
+impl InteractionLog {
+    /// Snapshot of the interactions recorded so far, in call order
+    ///
+    /// # Panics
+    ///
+    /// Panics if a thread panicked while holding the log.
+    #[must_use]
+    pub fn interactions(&self) -> Vec<RecordedInteraction> {
+        self.entries
+            .lock()
+            .expect("interaction log poisoned")
+            .clone()
+    }
+
+    fn push(&self, interaction: RecordedInteraction) {
+        self.entries
+            .lock()
+            .expect("interaction log poisoned")
+            .push(interaction);
+    }
+
+    /// Write the recorded interactions to `path` as pretty-printed JSON
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be written.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(&self.interactions())?;
+        std::fs::write(path, json)
+            .with_context(|| format!("Failed to write recording: {}", path.display()))
+    }
+}
+
+/// [`ClaudeRunner`] that forwards to another runner and records every exchange
+pub struct RecordingRunner {
+    inner: Box<dyn ClaudeRunner>,
+    log: InteractionLog,
+}
+
+impl RecordingRunner {
+    /// Record everything `inner` is asked to run
+    #[must_use]
+    pub fn new(inner: Box<dyn ClaudeRunner>) -> Self {
+        Self {
+            inner,
+            log: InteractionLog::default(),
+        }
+    }
+
+    /// Handle to the interactions this runner records
+    #[must_use]
+    pub fn log(&self) -> InteractionLog {
+        self.log.clone()
+    }
+}
+
+#[async_trait]
+impl ClaudeRunner for RecordingRunner {
+    async fn execute_claude(
+        &self,
+        args: &[String],
+        stdin_content: &str,
+        timeout_duration: Option<Duration>,
+    ) -> Result<ClaudeResponse, RunnerError> {
+        let response = self
+            .inner
+            .execute_claude(args, stdin_content, timeout_duration)
+            .await?;
+
+        // Timed-out runs are partial output, not something worth replaying
+        if !response.timed_out {
+            self.log.push(RecordedInteraction {
+                key: interaction_key(args, stdin_content),
+                argv: args.to_vec(),
+                stdin: stdin_content.to_string(),
+                stdout: response.stdout.clone(),
+                stderr: response.stderr.clone(),
+                exit_code: response.exit_code,
+            });
+        }
+
+        Ok(response)
+    }
+
+    fn claude_version(&self) -> Option<String> {
+        self.inner.claude_version()
+    }
+}
+
+/// [`ClaudeRunner`] that answers from recorded interactions only
+#[derive(Debug, Clone, Default)]
+pub struct ReplayRunner {
+    responses: HashMap<String, RecordedInteraction>,
+}
+
+impl ReplayRunner {
+    /// Serve `interactions`; a later entry with the same key wins
+    #[must_use]
+    pub fn new(interactions: impl IntoIterator<Item = RecordedInteraction>) -> Self {
+        let responses = interactions
+            .into_iter()
+            .map(|interaction| {
+                // Re-derive the key so hand-edited recordings still match
+                let key = interaction_key(&interaction.argv, &interaction.stdin);
+                (key, interaction)
+            })
+            .collect();
+        Self { responses }
+    }
+
+    /// Load a recording written by [`InteractionLog::save`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is not a recording.
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let json = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read recording: {}", path.display()))?;
+        let interactions: Vec<RecordedInteraction> = serde_json::from_str(&json)
+            .with_context(|| format!("Invalid recording: {}", path.display()))?;
+        Ok(Self::new(interactions))
+    }
+}
+
+#[async_trait]
+impl ClaudeRunner for ReplayRunner {
+    async fn execute_claude(
+        &self,
+        args: &[String],
+        stdin_content: &str,
+        _timeout_duration: Option<Duration>,
+    ) -> Result<ClaudeResponse, RunnerError> {
+        let key = interaction_key(args, stdin_content);
+        self.responses
+            .get(&key)
+            .map(RecordedInteraction::to_response)
+            .ok_or(RunnerError::UnrecordedInteraction { key })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Answers every prompt by echoing it back in upper case
+    struct ShoutRunner;
+
+    #[async_trait]
+    impl ClaudeRunner for ShoutRunner {
+        async fn execute_claude(
+            &self,
+            _args: &[String],
+            stdin_content: &str,
+            _timeout_duration: Option<Duration>,
+        ) -> Result<ClaudeResponse, RunnerError> {
+            Ok(RecordedInteraction {
+                key: String::new(),
+                argv: vec![],
+                stdin: String::new(),
+                stdout: stdin_content.to_uppercase(),
+                stderr: String::new(),
+                exit_code: 0,
+            }
+            .to_response())
+        }
+    }
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn test_interaction_key_separates_arguments() {
+        assert_ne!(
+            interaction_key(&args(&["a", "b"]), ""),
+            interaction_key(&args(&["ab"]), "")
+        );
+        assert_ne!(
+            interaction_key(&args(&["a"]), "one"),
+            interaction_key(&args(&["a"]), "two")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_recorded_interactions_replay_identically() {
+        let recorder = RecordingRunner::new(Box::new(ShoutRunner));
+        let log = recorder.log();
+
+        let argv = args(&["--output-format", "text"]);
+        let live = recorder.execute_claude(&argv, "hello", None).await.unwrap();
+        assert_eq!(live.stdout, "HELLO");
+
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = temp.path().join("recording.json");
+        log.save(&path).unwrap();
+
+        let replay = ReplayRunner::from_file(&path).unwrap();
+        let replayed = replay.execute_claude(&argv, "hello", None).await.unwrap();
+        assert_eq!(replayed.stdout, "HELLO");
+        assert_eq!(replayed.exit_code, 0);
+    }
+
+    #[tokio::test]
+    async fn test_replay_rejects_unrecorded_interaction() {
+        let replay = ReplayRunner::new(vec![]);
+        let argv = args(&["--model", "haiku"]);
+
+        let err = replay
+            .execute_claude(&argv, "prompt", None)
+            .await
+            .unwrap_err();
+        match err {
+            RunnerError::UnrecordedInteraction { key } => {
+                assert_eq!(key, interaction_key(&argv, "prompt"));
+            }
+            other => panic!("expected UnrecordedInteraction, got {other:?}"),
+        }
+    }
+}
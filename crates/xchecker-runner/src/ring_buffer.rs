@@ -51,6 +51,12 @@ impl RingBuffer {
         self.buffer.is_empty()
     }
 
+    /// Copy the buffered bytes out in order
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.buffer.iter().copied().collect()
+    }
+
     /// Get the total number of bytes written (including truncated bytes)
     #[must_use]
     pub const fn total_bytes_written(&self) -> usize {
@@ -66,8 +72,7 @@ impl RingBuffer {
 
 impl fmt::Display for RingBuffer {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let bytes: Vec<u8> = self.buffer.iter().copied().collect();
-        write!(f, "{}", String::from_utf8_lossy(&bytes))
+        write!(f, "{}", String::from_utf8_lossy(&self.to_bytes()))
     }
 }
 
@@ -38,8 +38,14 @@ pub const ALWAYS_EXCLUDE_PATTERNS: &[&str] = &[
 /// Content selection configuration
 #[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
 pub struct Selectors {
+    #[serde(default)]
     pub include: Vec<String>,
+    #[serde(default)]
     pub exclude: Vec<String>,
+    /// Gitignore-style patterns, relative to the directory being scanned,
+    /// excluded on top of the built-in `.git`, `target` and `node_modules`
+    #[serde(default)]
+    pub ignore: Vec<String>,
 }
 
 impl Default for Selectors {
@@ -64,6 +70,7 @@ impl Default for Selectors {
                 "**/*.core.yaml".to_string(),
             ],
             exclude,
+            ignore: Vec::new(),
         }
     }
 }
@@ -94,6 +101,23 @@ impl Selectors {
             })?;
         }
 
+        for pattern in &self.ignore {
+            let glob = pattern.trim().trim_start_matches('/').trim_end_matches('/');
+            let invalid = if pattern.trim_start().starts_with('!') {
+                Some("negated patterns are not supported".to_string())
+            } else {
+                Glob::new(glob).err().map(|e| e.to_string())
+            };
+            if let Some(reason) = invalid {
+                return Err(xchecker_utils::error::XCheckerError::Config(
+                    xchecker_utils::error::ConfigError::InvalidValue {
+                        key: "selectors.ignore".to_string(),
+                        value: format!("Invalid ignore pattern '{pattern}': {reason}"),
+                    },
+                ));
+            }
+        }
+
         Ok(())
     }
 }
@@ -1,11 +1,14 @@
 use anyhow::{Context, Result};
 use blake3::Hasher;
 use camino::Utf8PathBuf;
+use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::Path;
 
-use crate::atomic_write::{AtomicWriteResult, write_file_atomic};
-use crate::lock::{FileLock, LockError};
+use crate::atomic_write::{
+    AtomicWriteResult, has_mixed_line_endings, write_bytes_atomic, write_file_atomic,
+};
+use crate::lock::{FileLock, LockError, LockPolicy};
 use crate::paths::{SandboxConfig, SandboxRoot};
 use crate::types::PhaseId;
 
@@ -33,7 +36,7 @@ pub struct ArtifactManager {
 #[derive(Debug, Clone)]
 pub struct Artifact {
     pub name: String,
-    pub content: String,
+    pub content: ArtifactBody,
     pub artifact_type: ArtifactType,
     #[allow(dead_code)] // Hash field for future content verification
     pub blake3_hash: String,
@@ -43,10 +46,13 @@ impl Artifact {
     /// Create a new artifact with computed BLAKE3 hash
     #[must_use]
     #[allow(dead_code)] // API constructor for artifact creation
-    pub fn new(name: String, content: String, artifact_type: ArtifactType) -> Self {
-        let mut hasher = Hasher::new();
-        hasher.update(content.as_bytes());
-        let blake3_hash = hasher.finalize().to_hex().to_string();
+    pub fn new(
+        name: String,
+        content: impl Into<ArtifactBody>,
+        artifact_type: ArtifactType,
+    ) -> Self {
+        let content = content.into();
+        let blake3_hash = content.blake3_hash();
 
         Self {
             name,
@@ -57,6 +63,84 @@ impl Artifact {
     }
 }
 
+/// Content of an artifact
+///
+/// Phases produce `Text` almost exclusively; `Bytes` is for binary output such
+/// as diagrams. Hashes are taken over the raw bytes in both cases, and `Bytes`
+/// serializes as base64.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArtifactBody {
+    /// UTF-8 text, written with LF line endings
+    Text(String),
+    /// Binary content, written exactly as given
+    Bytes(#[serde(with = "base64_bytes")] Vec<u8>),
+}
+
+impl ArtifactBody {
+    /// Get the raw bytes of the body
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            Self::Text(text) => text.as_bytes(),
+            Self::Bytes(bytes) => bytes,
+        }
+    }
+
+    /// Get the body as text, or `None` for a `Bytes` body
+    #[must_use]
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            Self::Text(text) => Some(text),
+            Self::Bytes(_) => None,
+        }
+    }
+
+    /// BLAKE3 hash (hex) of the raw bytes
+    #[must_use]
+    pub fn blake3_hash(&self) -> String {
+        let mut hasher = Hasher::new();
+        hasher.update(self.as_bytes());
+        hasher.finalize().to_hex().to_string()
+    }
+}
+
+impl From<String> for ArtifactBody {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<&str> for ArtifactBody {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+impl From<Vec<u8>> for ArtifactBody {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::Bytes(bytes)
+    }
+}
+
+/// Serde adapter encoding `Vec<u8>` as a base64 string
+mod base64_bytes {
+    use base64::Engine as _;
+    use base64::engine::general_purpose::STANDARD as BASE64;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&BASE64.encode(bytes))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let encoded = String::deserialize(deserializer)?;
+        BASE64
+            .decode(encoded.as_bytes())
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 /// Result of storing an artifact with atomic write metadata
 #[derive(Debug, Clone)]
 pub struct ArtifactStoreResult {
@@ -91,6 +175,34 @@ impl ArtifactType {
     }
 }
 
+/// How line endings in text artifacts are written
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineEndings {
+    /// Rewrite CRLF and lone CR as LF, so mixed endings are unified
+    #[default]
+    Normalize,
+    /// Write text exactly as given; mixed line endings produce a warning
+    Preserve,
+}
+
+/// File name of a phase's artifact, e.g. `10-design.md`
+#[must_use]
+pub fn phase_artifact_filename(phase: PhaseId, artifact_type: ArtifactType) -> String {
+    let phase_number: u8 = match phase {
+        PhaseId::Requirements => 0,
+        PhaseId::Design => 10,
+        PhaseId::Tasks => 20,
+        PhaseId::Review => 30,
+        PhaseId::Fixup => 40,
+        PhaseId::Final => 50,
+    };
+    format!(
+        "{phase_number:02}-{}.{}",
+        phase.as_str(),
+        artifact_type.extension()
+    )
+}
+
 impl ArtifactManager {
     /// Create a new `ArtifactManager` for the given spec ID
     ///
@@ -103,12 +215,17 @@ impl ArtifactManager {
 
     /// Create a new `ArtifactManager` with optional force flag for lock override
     pub fn new_with_force(spec_id: &str, force: bool) -> Result<Self> {
+        Self::new_with_lock_policy(spec_id, force, LockPolicy::default())
+    }
+
+    /// Create a new `ArtifactManager`, acquiring the spec lock under `policy`
+    pub fn new_with_lock_policy(spec_id: &str, force: bool, policy: LockPolicy) -> Result<Self> {
         // Ensure spec directory tree exists before acquiring lock
         let base_path = crate::paths::spec_root(spec_id);
         Self::ensure_spec_dirs(&base_path)?;
 
         // Acquire exclusive lock first
-        let lock = FileLock::acquire(spec_id, force, None)
+        let lock = FileLock::acquire_with_policy(spec_id, force, policy)
             .map_err(|e| match e {
                 LockError::ConcurrentExecution { spec_id, pid, created_ago } => {
                     anyhow::anyhow!(
@@ -183,6 +300,14 @@ impl ArtifactManager {
         Ok(manager)
     }
 
+    /// Release the spec lock before the manager is dropped
+    ///
+    /// Later writes through this manager are no longer protected against
+    /// concurrent runs. A no-op for read-only managers.
+    pub fn release_lock(&mut self) -> Result<(), LockError> {
+        self._lock.take().map_or(Ok(()), FileLock::release)
+    }
+
     /// Create the required directory structure: artifacts/, receipts/, context/, .partial/
     fn ensure_directory_structure(&self) -> Result<()> {
         let directories = ["artifacts", "receipts", "context", ".partial"];
@@ -254,9 +379,14 @@ impl ArtifactManager {
 
     /// Store an artifact to the .partial/ staging directory
     /// This is used during phase execution before promoting to final location
+    ///
+    /// Text is written according to `line_endings`; with
+    /// [`LineEndings::Preserve`], mixed line endings are reported in the
+    /// result's warnings.
     pub fn store_partial_staged_artifact(
         &self,
         artifact: &Artifact,
+        line_endings: LineEndings,
     ) -> Result<ArtifactStoreResult> {
         // Validate the .partial directory path
         let partial_dir = self.validate_path(".partial")?;
@@ -273,7 +403,8 @@ impl ArtifactManager {
         let file_path_utf8 = Utf8PathBuf::from_path_buf(file_path)
             .map_err(|p| anyhow::anyhow!("Invalid UTF-8 path: {}", p.display()))?;
 
-        let atomic_result = self.write_file_atomic(&file_path_utf8, &artifact.content)?;
+        let atomic_result =
+            self.write_body_atomic(&file_path_utf8, &artifact.content, line_endings)?;
         Ok(ArtifactStoreResult {
             path: file_path_utf8,
             atomic_write_result: atomic_result,
@@ -316,7 +447,8 @@ impl ArtifactManager {
     /// Store an artifact using atomic write operations
     pub fn store_artifact(&self, artifact: &Artifact) -> Result<ArtifactStoreResult> {
         let file_path = self.get_artifact_path_validated(&artifact.name, artifact.artifact_type)?;
-        let atomic_result = self.write_file_atomic(&file_path, &artifact.content)?;
+        let atomic_result =
+            self.write_body_atomic(&file_path, &artifact.content, LineEndings::Normalize)?;
         Ok(ArtifactStoreResult {
             path: file_path,
             atomic_write_result: atomic_result,
@@ -366,6 +498,35 @@ impl ArtifactManager {
             .with_context(|| format!("Failed to atomically write file: {path}"))
     }
 
+    /// Write an artifact body atomically: text is written according to
+    /// `line_endings`, bytes are written verbatim
+    fn write_body_atomic(
+        &self,
+        path: &Utf8PathBuf,
+        content: &ArtifactBody,
+        line_endings: LineEndings,
+    ) -> Result<AtomicWriteResult> {
+        match content {
+            ArtifactBody::Text(text) if line_endings == LineEndings::Normalize => {
+                self.write_file_atomic(path, text)
+            }
+            ArtifactBody::Text(text) => {
+                let mut result = write_bytes_atomic(path, text.as_bytes())
+                    .with_context(|| format!("Failed to atomically write file: {path}"))?;
+                if has_mixed_line_endings(text) {
+                    tracing::warn!(path = %path, "Artifact has mixed line endings");
+                    result.warnings.push(
+                        "Mixed line endings left as-is (normalize_line_endings = false)"
+                            .to_string(),
+                    );
+                }
+                Ok(result)
+            }
+            ArtifactBody::Bytes(bytes) => write_bytes_atomic(path, bytes)
+                .with_context(|| format!("Failed to atomically write file: {path}")),
+        }
+    }
+
     /// Normalize line endings to \n for all content
     fn normalize_line_endings(&self, content: &str) -> String {
         content.replace("\r\n", "\n").replace('\r', "\n")
@@ -416,23 +577,7 @@ impl ArtifactManager {
 
     /// Generate filename for a phase artifact
     fn get_phase_filename(&self, phase: PhaseId, artifact_type: ArtifactType) -> String {
-        let phase_number = self.get_phase_number(phase);
-        let phase_name = phase.as_str();
-        let extension = artifact_type.extension();
-
-        format!("{phase_number:02}-{phase_name}.{extension}")
-    }
-
-    /// Get the numeric prefix for a phase
-    const fn get_phase_number(&self, phase: PhaseId) -> u8 {
-        match phase {
-            PhaseId::Requirements => 0,
-            PhaseId::Design => 10,
-            PhaseId::Tasks => 20,
-            PhaseId::Review => 30,
-            PhaseId::Fixup => 40,
-            PhaseId::Final => 50,
-        }
+        phase_artifact_filename(phase, artifact_type)
     }
 
     /// Get the base path for this spec
@@ -481,6 +626,16 @@ impl ArtifactManager {
             .with_context(|| format!("Failed to read artifact: {path}"))
     }
 
+    /// Read a phase artifact by phase and type (e.g. `00-requirements.md`)
+    pub fn read_phase_artifact(
+        &self,
+        phase: PhaseId,
+        artifact_type: ArtifactType,
+    ) -> Result<String> {
+        let name = self.get_phase_filename(phase, artifact_type);
+        self.read_artifact(&name, artifact_type)
+    }
+
     /// Check if a partial artifact exists for a phase
     #[must_use]
     pub fn has_partial_artifact(&self, phase: PhaseId) -> bool {
@@ -709,6 +864,110 @@ mod tests {
         assert_ne!(artifact1.blake3_hash, artifact3.blake3_hash);
     }
 
+    #[test]
+    fn test_bytes_body_hashes_raw_bytes() {
+        let bytes = vec![0x89, b'P', b'N', b'G', 0x00, 0xff, b'\r', b'\n'];
+        let artifact = Artifact::new(
+            "diagram.png".to_string(),
+            bytes.clone(),
+            ArtifactType::Context,
+        );
+
+        assert_eq!(artifact.content, ArtifactBody::Bytes(bytes.clone()));
+        assert_eq!(artifact.content.as_text(), None);
+        assert_eq!(
+            artifact.blake3_hash,
+            blake3::hash(&bytes).to_hex().to_string()
+        );
+
+        // Text hashes over the same bytes it would have as `Bytes`
+        let text = ArtifactBody::from("same bytes");
+        assert_eq!(
+            text.blake3_hash(),
+            ArtifactBody::Bytes(b"same bytes".to_vec()).blake3_hash()
+        );
+    }
+
+    #[test]
+    fn test_artifact_body_serde_round_trip() {
+        let bytes = ArtifactBody::Bytes(vec![0x00, 0xfe, 0xff, 0x10]);
+        let json = serde_json::to_string(&bytes).unwrap();
+        assert_eq!(json, r#"{"bytes":"AP7/EA=="}"#);
+        assert_eq!(serde_json::from_str::<ArtifactBody>(&json).unwrap(), bytes);
+
+        let text = ArtifactBody::Text("# Design\n".to_string());
+        let json = serde_json::to_string(&text).unwrap();
+        assert_eq!(json, r##"{"text":"# Design\n"}"##);
+        assert_eq!(serde_json::from_str::<ArtifactBody>(&json).unwrap(), text);
+
+        assert!(serde_json::from_str::<ArtifactBody>(r#"{"bytes":"not base64!"}"#).is_err());
+    }
+
+    #[test]
+    fn test_bytes_artifact_stored_verbatim() {
+        let (manager, _temp_dir) = create_test_manager_with_id("test-spec-bytes");
+
+        // CRLF must survive: binary bodies skip line ending normalization
+        let bytes = vec![0x00, b'\r', b'\n', 0xff];
+        let artifact = Artifact::new(
+            "diagram.bin".to_string(),
+            bytes.clone(),
+            ArtifactType::Context,
+        );
+        let result = manager.store_artifact(&artifact).unwrap();
+
+        assert_eq!(fs::read(result.path.as_std_path()).unwrap(), bytes);
+    }
+
+    #[test]
+    fn test_staged_artifact_mixed_line_endings_normalized_by_default() {
+        let (manager, _temp_dir) = create_test_manager_with_id("test-spec-mixed-normalize");
+
+        let artifact = Artifact::new(
+            "10-design.md".to_string(),
+            "# Design\r\n\nTemplated header\r\nModel output\n",
+            ArtifactType::Markdown,
+        );
+        let result = manager
+            .store_partial_staged_artifact(&artifact, LineEndings::Normalize)
+            .unwrap();
+
+        assert_eq!(
+            fs::read_to_string(result.path.as_std_path()).unwrap(),
+            "# Design\n\nTemplated header\nModel output\n"
+        );
+        assert!(result.atomic_write_result.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_staged_artifact_mixed_line_endings_warn_when_preserved() {
+        let (manager, _temp_dir) = create_test_manager_with_id("test-spec-mixed-preserve");
+
+        let content = "# Design\r\n\nTemplated header\r\nModel output\n";
+        let artifact = Artifact::new("10-design.md".to_string(), content, ArtifactType::Markdown);
+        let result = manager
+            .store_partial_staged_artifact(&artifact, LineEndings::Preserve)
+            .unwrap();
+
+        assert_eq!(
+            fs::read_to_string(result.path.as_std_path()).unwrap(),
+            content
+        );
+        assert_eq!(result.atomic_write_result.warnings.len(), 1);
+        assert!(result.atomic_write_result.warnings[0].contains("Mixed line endings"));
+
+        // Consistent CRLF is preserved without a warning
+        let artifact = Artifact::new(
+            "20-tasks.md".to_string(),
+            "# Tasks\r\n- one\r\n",
+            ArtifactType::Markdown,
+        );
+        let result = manager
+            .store_partial_staged_artifact(&artifact, LineEndings::Preserve)
+            .unwrap();
+        assert!(result.atomic_write_result.warnings.is_empty());
+    }
+
     #[test]
     fn test_context_file_storage() {
         let (manager, _temp_dir) = create_test_manager_with_id("test-spec-context");
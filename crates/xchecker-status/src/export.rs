@@ -0,0 +1,486 @@
+//! Spec export and import as deterministic tar archives.
+//!
+//! `export_spec` packs `<XCHECKER_HOME>/specs/<spec_id>` into a `.tar` whose
+//! bytes depend only on the spec's file contents: entries are sorted by path,
+//! and mtimes, owners and permissions are zeroed or fixed. Lock files are
+//! skipped since they describe a live process on the exporting machine.
+//! `import_spec` restores such an archive under a (possibly different) spec id.
+
+use anyhow::{Context, Result, bail};
+use camino::{Utf8Path, Utf8PathBuf};
+use std::fs;
+use std::io::Write;
+use xchecker_utils::lock::FileLock;
+use xchecker_utils::spec_id::sanitize_spec_id;
+
+use crate::paths;
+
+/// Mode recorded for regular files in exported archives
+const FILE_MODE: u32 = 0o644;
+
+/// Mode recorded for directories in exported archives
+const DIR_MODE: u32 = 0o755;
+
+/// Returns true for lock files that must not travel with an exported spec.
+///
+/// Covers the advisory `.lock` file and any `.lock.stale.*` leftovers from
+/// stale-lock recovery.
+fn is_lock_file(name: &str) -> bool {
+    name == ".lock" || name.starts_with(".lock.stale.")
+}
+
+/// Reject a spec id that is invalid or that sanitization would change.
+///
+/// The id becomes a path component under `<XCHECKER_HOME>/specs`, so values
+/// such as `../../x` must never reach [`paths::spec_root`].
+fn check_spec_id(spec_id: &str) -> Result<()> {
+    let sanitized =
+        sanitize_spec_id(spec_id).with_context(|| format!("Invalid spec id '{spec_id}'"))?;
+    if sanitized != spec_id {
+        bail!("Invalid spec id '{spec_id}': only [A-Za-z0-9._-] are allowed");
+    }
+    Ok(())
+}
+
+/// Recursively collect spec entries as `(relative_path, is_dir)` pairs.
+///
+/// Relative paths always use `/` separators so archives are identical
+/// across platforms.
+fn collect_entries(root: &Utf8Path, rel: &str, out: &mut Vec<(String, bool)>) -> Result<()> {
+    let dir = if rel.is_empty() {
+        root.to_path_buf()
+    } else {
+        root.join(rel)
+    };
+
+    for entry in fs::read_dir(&dir).with_context(|| format!("Failed to read directory: {dir}"))? {
+        let entry = entry?;
+        let name = entry
+            .file_name()
+            .into_string()
+            .map_err(|n| anyhow::anyhow!("Non UTF-8 path in spec directory: {n:?}"))?;
+        if is_lock_file(&name) {
+            continue;
+        }
+
+        let child = if rel.is_empty() {
+            name
+        } else {
+            format!("{rel}/{name}")
+        };
+
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            out.push((child.clone(), true));
+            collect_entries(root, &child, out)?;
+        } else if file_type.is_file() {
+            out.push((child, false));
+        }
+        // Symlinks and other special files are not part of a spec's state
+    }
+
+    Ok(())
+}
+
+/// Build a tar header with all non-content metadata fixed.
+fn deterministic_header(entry_type: tar::EntryType, mode: u32, size: u64) -> tar::Header {
+    let mut header = tar::Header::new_ustar();
+    header.set_entry_type(entry_type);
+    header.set_mode(mode);
+    header.set_size(size);
+    header.set_mtime(0);
+    header.set_uid(0);
+    header.set_gid(0);
+    header
+}
+
+/// Export a spec directory to a deterministic `.tar` archive.
+///
+/// Entries are stored relative to the spec root in sorted order with zeroed
+/// mtimes and fixed ownership/permissions, so exporting the same spec twice
+/// yields byte-identical archives. `.lock` and `.lock.stale.*` files are
+/// excluded. The archive is written atomically.
+///
+/// # Errors
+///
+/// Returns an error if `spec_id` is not a valid spec id, the spec directory
+/// does not exist or any file cannot be read, or if the archive cannot be
+/// written.
+pub fn export_spec(spec_id: &str, out: &Utf8Path) -> Result<()> {
+    check_spec_id(spec_id)?;
+    let root = paths::spec_root(spec_id);
+    if !root.is_dir() {
+        bail!("Spec '{spec_id}' not found at {root}");
+    }
+
+    let mut entries = Vec::new();
+    collect_entries(&root, "", &mut entries)?;
+    entries.sort();
+
+    let parent = match out.parent() {
+        Some(p) if !p.as_str().is_empty() => p.to_path_buf(),
+        _ => Utf8PathBuf::from("."),
+    };
+    paths::ensure_dir_all(&parent)
+        .with_context(|| format!("Failed to create output directory: {parent}"))?;
+
+    let temp = tempfile::NamedTempFile::new_in(&parent)
+        .with_context(|| format!("Failed to create temporary file in {parent}"))?;
+    let mut builder = tar::Builder::new(temp);
+
+    for (rel, is_dir) in &entries {
+        if *is_dir {
+            let mut header = deterministic_header(tar::EntryType::Directory, DIR_MODE, 0);
+            builder
+                .append_data(&mut header, format!("{rel}/"), std::io::empty())
+                .with_context(|| format!("Failed to add directory to archive: {rel}"))?;
+        } else {
+            let path = root.join(rel);
+            let content =
+                fs::read(&path).with_context(|| format!("Failed to read file: {path}"))?;
+            let mut header =
+                deterministic_header(tar::EntryType::Regular, FILE_MODE, content.len() as u64);
+            builder
+                .append_data(&mut header, rel, content.as_slice())
+                .with_context(|| format!("Failed to add file to archive: {rel}"))?;
+        }
+    }
+
+    let mut temp = builder
+        .into_inner()
+        .context("Failed to finalize spec archive")?;
+    temp.flush()?;
+    temp.as_file().sync_all()?;
+    temp.persist(out)
+        .map_err(|e| e.error)
+        .with_context(|| format!("Failed to write spec archive: {out}"))?;
+
+    Ok(())
+}
+
+/// Import a spec archive produced by [`export_spec`] as `spec_id`.
+///
+/// The target spec directory must not exist or must be empty. Only regular
+/// files and directories are restored; entries with absolute paths or `..`
+/// components are rejected, and lock files are skipped. The archive is
+/// extracted into a temporary directory inside the xchecker home, then the
+/// spec lock is taken while the target is checked and the extracted entries
+/// are moved into place, so a failed import leaves no partial spec behind and
+/// a concurrent run cannot write into the spec mid-import.
+///
+/// # Errors
+///
+/// Returns an error if `spec_id` is not a valid spec id, the spec is locked
+/// by another process, the target spec already has content, the archive is
+/// malformed or contains unsafe entries, or files cannot be written.
+pub fn import_spec(tar: &Utf8Path, spec_id: &str) -> Result<()> {
+    check_spec_id(spec_id)?;
+    let root = paths::spec_root(spec_id);
+
+    let file = fs::File::open(tar).with_context(|| format!("Failed to open archive: {tar}"))?;
+    let mut archive = tar::Archive::new(file);
+
+    let home = paths::xchecker_home();
+    paths::ensure_dir_all(&home)
+        .with_context(|| format!("Failed to create xchecker home: {home}"))?;
+    let staging = tempfile::Builder::new()
+        .prefix(".import-")
+        .tempdir_in(&home)
+        .with_context(|| format!("Failed to create temporary directory in {home}"))?;
+    let staging_root = Utf8Path::from_path(staging.path())
+        .ok_or_else(|| anyhow::anyhow!("Non UTF-8 temporary directory in {home}"))?;
+
+    for entry in archive
+        .entries()
+        .with_context(|| format!("Failed to read archive: {tar}"))?
+    {
+        let mut entry = entry.with_context(|| format!("Malformed archive entry in {tar}"))?;
+        let rel = entry.path()?.into_owned();
+        let rel = Utf8PathBuf::from_path_buf(rel)
+            .map_err(|p| anyhow::anyhow!("Non UTF-8 path in archive: {}", p.display()))?;
+
+        if rel.is_absolute()
+            || rel
+                .components()
+                .any(|c| !matches!(c, camino::Utf8Component::Normal(_)))
+        {
+            bail!("Refusing to import unsafe archive path: {rel}");
+        }
+        // The spec lock below owns `.lock`; never restore one over it
+        if rel.file_name().is_some_and(is_lock_file) {
+            continue;
+        }
+
+        let dest = staging_root.join(&rel);
+        match entry.header().entry_type() {
+            tar::EntryType::Directory => {
+                paths::ensure_dir_all(&dest)
+                    .with_context(|| format!("Failed to create directory: {dest}"))?;
+            }
+            tar::EntryType::Regular => {
+                if let Some(parent) = dest.parent() {
+                    paths::ensure_dir_all(parent)
+                        .with_context(|| format!("Failed to create directory: {parent}"))?;
+                }
+                let mut out = fs::File::create(&dest)
+                    .with_context(|| format!("Failed to create file: {dest}"))?;
+                std::io::copy(&mut entry, &mut out)
+                    .with_context(|| format!("Failed to write file: {dest}"))?;
+            }
+            other => bail!("Unsupported archive entry type {other:?} for {rel}"),
+        }
+    }
+
+    // Creates the spec directory if needed; its `.lock` is the only entry
+    // an importable spec may already have
+    let lock = FileLock::acquire(spec_id, false, None)
+        .with_context(|| format!("Failed to lock spec '{spec_id}' for import"))?;
+
+    let has_content = fs::read_dir(&root)
+        .with_context(|| format!("Failed to read directory: {root}"))?
+        .filter_map(Result::ok)
+        .any(|entry| !entry.file_name().to_str().is_some_and(is_lock_file));
+    if has_content {
+        bail!("Refusing to import into non-empty spec directory: {root}");
+    }
+
+    move_entries_into(staging_root, &root)?;
+
+    lock.release()
+        .with_context(|| format!("Failed to release lock for spec '{spec_id}'"))?;
+    Ok(())
+}
+
+/// Move every top-level entry of `from` into `to`.
+///
+/// Renaming the spec directory itself would replace its lock file, so the
+/// entries are moved one by one. If a move fails, the entries already moved
+/// are removed again so `to` is left as it was.
+fn move_entries_into(from: &Utf8Path, to: &Utf8Path) -> Result<()> {
+    let entries = fs::read_dir(from)
+        .and_then(|entries| entries.collect::<std::io::Result<Vec<_>>>())
+        .with_context(|| format!("Failed to read directory: {from}"))?;
+
+    let mut moved = Vec::with_capacity(entries.len());
+    for entry in entries {
+        let dest = to.as_std_path().join(entry.file_name());
+        if let Err(e) = fs::rename(entry.path(), &dest) {
+            for path in moved {
+                let _ = fs::remove_dir_all(&path).or_else(|_| fs::remove_file(&path));
+            }
+            return Err(e).with_context(|| {
+                format!(
+                    "Failed to move imported spec into place: {}",
+                    dest.display()
+                )
+            });
+        }
+        moved.push(dest);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    fn seed_spec(spec_id: &str) {
+        let root = paths::spec_root(spec_id);
+        fs::create_dir_all(root.join("artifacts")).unwrap();
+        fs::create_dir_all(root.join("receipts")).unwrap();
+        fs::create_dir_all(root.join("context")).unwrap();
+        fs::write(
+            root.join("artifacts/00-requirements.md"),
+            "# Requirements\n\nThe system shall export specs.\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("artifacts/00-requirements.core.yaml"),
+            "spec_id: export-spec\nphase: requirements\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("receipts/requirements-20250101_000000.json"),
+            r#"{"phase":"requirements","exit_code":0}"#,
+        )
+        .unwrap();
+        fs::write(root.join(".lock"), r#"{"pid":1234}"#).unwrap();
+        fs::write(root.join(".lock.stale.1700000000.1234"), "stale").unwrap();
+    }
+
+    fn hash_tree(root: &Utf8Path) -> BTreeMap<String, String> {
+        let mut entries = Vec::new();
+        collect_entries(root, "", &mut entries).unwrap();
+        entries
+            .into_iter()
+            .filter(|(_, is_dir)| !is_dir)
+            .map(|(rel, _)| {
+                let content = fs::read(root.join(&rel)).unwrap();
+                (rel, blake3::hash(&content).to_hex().to_string())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_export_import_roundtrip_into_fresh_home() {
+        let archive_dir = tempfile::TempDir::new().unwrap();
+        let archive = Utf8PathBuf::from_path_buf(archive_dir.path().join("spec.tar")).unwrap();
+
+        let original = {
+            let _home = paths::with_isolated_home();
+            seed_spec("export-spec");
+            export_spec("export-spec", &archive).unwrap();
+            hash_tree(&paths::spec_root("export-spec"))
+        };
+
+        let _fresh_home = paths::with_isolated_home();
+        assert!(!paths::spec_root("export-spec").exists());
+        import_spec(&archive, "export-spec").unwrap();
+
+        let root = paths::spec_root("export-spec");
+        assert_eq!(hash_tree(&root), original);
+        assert_eq!(
+            fs::read_to_string(root.join("artifacts/00-requirements.md")).unwrap(),
+            "# Requirements\n\nThe system shall export specs.\n"
+        );
+        assert!(root.join("context").is_dir());
+    }
+
+    #[test]
+    fn test_export_excludes_lock_files() {
+        let _home = paths::with_isolated_home();
+        seed_spec("lock-spec");
+
+        let archive = paths::xchecker_home().join("lock-spec.tar");
+        export_spec("lock-spec", &archive).unwrap();
+
+        let mut names = Vec::new();
+        let mut tar = tar::Archive::new(fs::File::open(&archive).unwrap());
+        for entry in tar.entries().unwrap() {
+            let entry = entry.unwrap();
+            assert_eq!(entry.header().mtime().unwrap(), 0);
+            names.push(entry.path().unwrap().to_string_lossy().into_owned());
+        }
+
+        assert!(!names.iter().any(|n| n.contains(".lock")));
+        assert!(names.contains(&"receipts/requirements-20250101_000000.json".to_string()));
+
+        let mut sorted = names.clone();
+        sorted.sort();
+        assert_eq!(names, sorted, "entries must be emitted in sorted order");
+    }
+
+    #[test]
+    fn test_export_is_byte_identical() {
+        let _home = paths::with_isolated_home();
+        seed_spec("stable-spec");
+
+        let first = paths::xchecker_home().join("first.tar");
+        let second = paths::xchecker_home().join("second.tar");
+        export_spec("stable-spec", &first).unwrap();
+
+        // Touching a file changes its mtime but must not change the archive
+        let md = paths::spec_root("stable-spec").join("artifacts/00-requirements.md");
+        let content = fs::read(&md).unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        fs::write(&md, content).unwrap();
+
+        export_spec("stable-spec", &second).unwrap();
+        assert_eq!(fs::read(&first).unwrap(), fs::read(&second).unwrap());
+    }
+
+    #[test]
+    fn test_import_refuses_non_empty_spec() {
+        let _home = paths::with_isolated_home();
+        seed_spec("busy-spec");
+
+        let archive = paths::xchecker_home().join("busy.tar");
+        export_spec("busy-spec", &archive).unwrap();
+        // The seeded lock is a placeholder that would fail to parse
+        fs::remove_file(paths::spec_root("busy-spec").join(".lock")).unwrap();
+
+        let err = import_spec(&archive, "busy-spec").unwrap_err();
+        assert!(err.to_string().contains("non-empty"));
+    }
+
+    #[test]
+    fn test_export_missing_spec_fails() {
+        let _home = paths::with_isolated_home();
+        let archive = paths::xchecker_home().join("missing.tar");
+        assert!(export_spec("does-not-exist", &archive).is_err());
+        assert!(!archive.exists());
+    }
+
+    #[test]
+    fn test_export_import_reject_traversal_spec_id() {
+        let _home = paths::with_isolated_home();
+        seed_spec("safe-spec");
+        let archive = paths::xchecker_home().join("safe.tar");
+        export_spec("safe-spec", &archive).unwrap();
+
+        let escaped = paths::xchecker_home().join("specs/../../x");
+        let err = import_spec(&archive, "../../x").unwrap_err();
+        assert!(err.to_string().contains("Invalid spec id"), "{err:#}");
+        assert!(!escaped.exists());
+
+        let err = export_spec("../../x", &archive).unwrap_err();
+        assert!(err.to_string().contains("Invalid spec id"), "{err:#}");
+    }
+
+    #[test]
+    fn test_import_refuses_locked_spec() {
+        let _home = paths::with_isolated_home();
+        seed_spec("locked-source");
+        let archive = paths::xchecker_home().join("locked.tar");
+        export_spec("locked-source", &archive).unwrap();
+
+        let lock = FileLock::acquire("locked-spec", false, None).unwrap();
+        let err = import_spec(&archive, "locked-spec").unwrap_err();
+        assert!(err.to_string().contains("Failed to lock spec"), "{err:#}");
+        assert!(!paths::spec_root("locked-spec").join("artifacts").exists());
+        drop(lock);
+
+        // The import releases its own lock once the spec is in place
+        import_spec(&archive, "locked-spec").unwrap();
+        assert!(paths::spec_root("locked-spec").join("artifacts").is_dir());
+        assert!(!paths::spec_root("locked-spec").join(".lock").exists());
+        FileLock::acquire("locked-spec", false, None).unwrap();
+    }
+
+    #[test]
+    fn test_failed_import_leaves_no_partial_spec() {
+        let _home = paths::with_isolated_home();
+        let archive = paths::xchecker_home().join("unsafe.tar");
+
+        // A valid file followed by an entry that escapes the spec root; the
+        // tar crate refuses to write `..` paths, so set the name directly
+        let mut builder = tar::Builder::new(fs::File::create(&archive).unwrap());
+        let content = b"# Requirements\n";
+        let mut header =
+            deterministic_header(tar::EntryType::Regular, FILE_MODE, content.len() as u64);
+        builder
+            .append_data(&mut header, "artifacts/00-requirements.md", &content[..])
+            .unwrap();
+        let mut header = deterministic_header(tar::EntryType::Regular, FILE_MODE, 4);
+        let name = b"../escape.txt";
+        header.as_old_mut().name[..name.len()].copy_from_slice(name);
+        header.set_cksum();
+        builder.append(&header, &b"evil"[..]).unwrap();
+        builder.into_inner().unwrap().sync_all().unwrap();
+
+        let err = import_spec(&archive, "partial-spec").unwrap_err();
+        assert!(err.to_string().contains("unsafe archive path"), "{err:#}");
+
+        assert!(!paths::spec_root("partial-spec").exists());
+        let leftovers: Vec<_> = fs::read_dir(paths::xchecker_home())
+            .unwrap()
+            .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+            .filter(|name| name.starts_with(".import-"))
+            .collect();
+        assert!(
+            leftovers.is_empty(),
+            "staging dirs left behind: {leftovers:?}"
+        );
+    }
+}
@@ -6,7 +6,9 @@
 //! # Modules
 //!
 //! - [`artifact`] - Artifact management with atomic writes and directory structure
+//! - [`export`] - Deterministic spec export/import as tar archives
 //! - [`status`] - Status output generation
+//! - [`validate`] - Spec directory health checks
 
 pub use xchecker_receipt as receipt;
 pub use xchecker_redaction as redaction;
@@ -18,4 +20,9 @@ pub use xchecker_utils::paths;
 pub use xchecker_utils::types;
 
 pub mod artifact;
+pub mod export;
 pub mod status;
+pub mod validate;
+
+pub use export::{export_spec, import_spec};
+pub use validate::{IssueSeverity, SpecIssue, SpecIssueKind, validate_spec};
@@ -0,0 +1,316 @@
+//! Spec directory health checks.
+//!
+//! `validate_spec` inspects `<XCHECKER_HOME>/specs/<spec_id>` without
+//! modifying it and reports problems that would trip up a later run: temp
+//! files left behind by interrupted atomic writes, artifacts that no longer
+//! match the receipts that recorded them, and lock files whose owning
+//! process has died.
+
+use anyhow::{Context, Result, bail};
+use camino::Utf8Path;
+use serde::Serialize;
+use std::fs;
+
+use crate::lock::{FileLock, utils as lock_utils};
+use crate::paths;
+use crate::receipt::ReceiptManager;
+use crate::types::PhaseId;
+
+/// Prefix `tempfile` gives the temporary files used by atomic writes
+const TEMP_FILE_PREFIX: &str = ".tmp";
+
+/// How serious a [`SpecIssue`] is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueSeverity {
+    /// Harmless leftovers; runs still succeed
+    Warning,
+    /// Runs will fail or build on content no receipt accounts for
+    Error,
+}
+
+/// What kind of problem a [`SpecIssue`] describes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SpecIssueKind {
+    /// Temp file from an atomic write that never completed
+    LeftoverTempFile,
+    /// Artifact recorded in a receipt is missing on disk
+    MissingArtifact,
+    /// Artifact on disk no longer matches its recorded hash
+    ArtifactMismatch,
+    /// Lock file left behind by a process that is no longer running
+    StaleLock,
+    /// Lock file that cannot be read or parsed
+    CorruptedLock,
+}
+
+/// A single problem found by [`validate_spec`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SpecIssue {
+    pub severity: IssueSeverity,
+    pub kind: SpecIssueKind,
+    /// Path relative to the spec root, when the issue concerns one file
+    pub path: Option<String>,
+    pub message: String,
+}
+
+/// Check that a spec directory is well-formed.
+///
+/// Reports, in this order:
+/// - leftover `.tmp*` files from interrupted atomic writes (warning)
+/// - artifacts listed in each phase's latest successful receipt that are
+///   missing or whose content no longer matches the recorded hash (error)
+/// - a lock file whose owning process is not running (warning), or that
+///   cannot be parsed (error)
+///
+/// An empty list means the spec is healthy. Nothing is modified.
+///
+/// # Errors
+///
+/// Returns an error if the spec directory does not exist or cannot be read.
+pub fn validate_spec(spec_id: &str) -> Result<Vec<SpecIssue>> {
+    let root = paths::spec_root(spec_id);
+    if !root.is_dir() {
+        bail!("Spec '{spec_id}' not found at {root}");
+    }
+
+    let mut issues = Vec::new();
+    collect_temp_files(&root, "", &mut issues)?;
+    check_receipt_outputs(&root, &mut issues)?;
+    check_lock(spec_id, &mut issues);
+    Ok(issues)
+}
+
+/// Recursively report temp files, with paths relative to the spec root
+fn collect_temp_files(root: &Utf8Path, rel: &str, issues: &mut Vec<SpecIssue>) -> Result<()> {
+    let dir = root.join(rel);
+    let mut entries = fs::read_dir(&dir)
+        .with_context(|| format!("Failed to read directory: {dir}"))?
+        .collect::<std::io::Result<Vec<_>>>()?;
+    entries.sort_by_key(fs::DirEntry::file_name);
+
+    for entry in entries {
+        let Ok(name) = entry.file_name().into_string() else {
+            continue;
+        };
+        let child = if rel.is_empty() {
+            name.clone()
+        } else {
+            format!("{rel}/{name}")
+        };
+
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            collect_temp_files(root, &child, issues)?;
+        } else if file_type.is_file() && name.starts_with(TEMP_FILE_PREFIX) {
+            issues.push(SpecIssue {
+                severity: IssueSeverity::Warning,
+                kind: SpecIssueKind::LeftoverTempFile,
+                message: format!("Leftover temp file from an interrupted write: {child}"),
+                path: Some(child),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Compare each phase's latest successful receipt with the artifacts on disk
+fn check_receipt_outputs(root: &Utf8Path, issues: &mut Vec<SpecIssue>) -> Result<()> {
+    let receipts = ReceiptManager::new(&root.to_path_buf());
+
+    for phase in PhaseId::ALL {
+        let Some(receipt) = receipts.read_latest_receipt(phase)? else {
+            continue;
+        };
+        if receipt.exit_code != 0 {
+            continue;
+        }
+
+        for output in &receipt.outputs {
+            let path = root.join(&output.path);
+            let Ok(bytes) = fs::read(&path) else {
+                issues.push(SpecIssue {
+                    severity: IssueSeverity::Error,
+                    kind: SpecIssueKind::MissingArtifact,
+                    path: Some(output.path.clone()),
+                    message: format!(
+                        "{} receipt records {} but the file is missing",
+                        phase.as_str(),
+                        output.path
+                    ),
+                });
+                continue;
+            };
+
+            let current = receipts.rehash_output(output, bytes, phase.as_str())?;
+            if current.blake3_canonicalized != output.blake3_canonicalized {
+                issues.push(SpecIssue {
+                    severity: IssueSeverity::Error,
+                    kind: SpecIssueKind::ArtifactMismatch,
+                    path: Some(output.path.clone()),
+                    message: format!(
+                        "{} no longer matches the hash in the {} receipt",
+                        output.path,
+                        phase.as_str()
+                    ),
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Report a lock whose owner has died, or that cannot be parsed
+fn check_lock(spec_id: &str, issues: &mut Vec<SpecIssue>) {
+    match FileLock::get_lock_info(spec_id) {
+        Ok(Some(info)) if !lock_utils::is_lock_owner_running(&info) => {
+            issues.push(SpecIssue {
+                severity: IssueSeverity::Warning,
+                kind: SpecIssueKind::StaleLock,
+                path: Some(".lock".to_string()),
+                message: format!(
+                    "Lock held by process {} which is no longer running",
+                    info.pid
+                ),
+            });
+        }
+        Ok(_) => {}
+        Err(e) => issues.push(SpecIssue {
+            severity: IssueSeverity::Error,
+            kind: SpecIssueKind::CorruptedLock,
+            path: Some(".lock".to_string()),
+            message: e.to_string(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use xchecker_utils::types::{FileType, PacketEvidence};
+
+    /// Write a requirements artifact and a successful receipt recording it
+    fn seed_spec(spec_id: &str) -> camino::Utf8PathBuf {
+        let root = paths::spec_root(spec_id);
+        fs::create_dir_all(root.join("artifacts")).unwrap();
+        fs::create_dir_all(root.join("receipts")).unwrap();
+
+        let content = "# Requirements\n\nThe system shall validate specs.\n";
+        fs::write(root.join("artifacts/00-requirements.md"), content).unwrap();
+
+        let receipts = ReceiptManager::new(&root);
+        let output = receipts
+            .create_file_hash(
+                "artifacts/00-requirements.md",
+                content,
+                FileType::Markdown,
+                "requirements",
+            )
+            .unwrap();
+        let receipt = receipts.create_receipt(
+            spec_id,
+            PhaseId::Requirements,
+            0,
+            vec![output],
+            "0.1.0",
+            "0.8.1",
+            "haiku",
+            None,
+            HashMap::new(),
+            PacketEvidence {
+                files: vec![],
+                max_bytes: 65536,
+                max_lines: 1200,
+                preview_compressed: false,
+            },
+            None,
+            None,
+            vec![],
+            None,
+            "native",
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        receipts.write_receipt(&receipt).unwrap();
+        root
+    }
+
+    #[test]
+    fn test_validate_spec_healthy_spec_has_no_issues() {
+        let _home = paths::with_isolated_home();
+        seed_spec("healthy-spec");
+
+        assert_eq!(validate_spec("healthy-spec").unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_validate_spec_reports_temp_file_and_artifact_mismatch() {
+        let _home = paths::with_isolated_home();
+        let root = seed_spec("damaged-spec");
+
+        fs::write(root.join("artifacts/.tmpA1b2C3"), "half-written").unwrap();
+        fs::write(
+            root.join("artifacts/00-requirements.md"),
+            "# Requirements\n\nEdited by hand.\n",
+        )
+        .unwrap();
+
+        let issues = validate_spec("damaged-spec").unwrap();
+        let kinds: Vec<_> = issues.iter().map(|issue| issue.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                SpecIssueKind::LeftoverTempFile,
+                SpecIssueKind::ArtifactMismatch
+            ]
+        );
+
+        assert_eq!(issues[0].severity, IssueSeverity::Warning);
+        assert_eq!(issues[0].path.as_deref(), Some("artifacts/.tmpA1b2C3"));
+        assert_eq!(issues[1].severity, IssueSeverity::Error);
+        assert_eq!(
+            issues[1].path.as_deref(),
+            Some("artifacts/00-requirements.md")
+        );
+    }
+
+    #[test]
+    fn test_validate_spec_reports_missing_artifact() {
+        let _home = paths::with_isolated_home();
+        let root = seed_spec("missing-spec");
+        fs::remove_file(root.join("artifacts/00-requirements.md")).unwrap();
+
+        let issues = validate_spec("missing-spec").unwrap();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].kind, SpecIssueKind::MissingArtifact);
+    }
+
+    #[test]
+    fn test_validate_spec_reports_lock_from_dead_process() {
+        let _home = paths::with_isolated_home();
+        let root = seed_spec("locked-spec");
+        fs::write(
+            root.join(".lock"),
+            r#"{"pid":99999,"start_time":0,"created_at":0,"spec_id":"locked-spec","xchecker_version":"0.1.0"}"#,
+        )
+        .unwrap();
+
+        let issues = validate_spec("locked-spec").unwrap();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].kind, SpecIssueKind::StaleLock);
+        assert_eq!(issues[0].severity, IssueSeverity::Warning);
+    }
+
+    #[test]
+    fn test_validate_spec_unknown_spec_errors() {
+        let _home = paths::with_isolated_home();
+        assert!(validate_spec("no-such-spec").is_err());
+    }
+}
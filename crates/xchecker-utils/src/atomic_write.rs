@@ -38,11 +38,20 @@ pub struct AtomicWriteResult {
 /// - FR-FS-004: UTF-8 encoding with LF line endings
 /// - FR-FS-005: Cross-filesystem fallback (copy→fsync→replace)
 pub fn write_file_atomic(path: &Utf8Path, content: &str) -> Result<AtomicWriteResult> {
-    let mut result = AtomicWriteResult::default();
-
     // Normalize line endings to LF (FR-FS-004)
     let normalized_content = normalize_line_endings(content);
 
+    write_bytes_atomic(path, normalized_content.as_bytes())
+}
+
+/// Atomically write raw bytes to a file using temp file + fsync + rename
+///
+/// Same guarantees as [`write_file_atomic`], but the content is written
+/// exactly as given, with no line ending normalization. Use this for binary
+/// content.
+pub fn write_bytes_atomic(path: &Utf8Path, content: &[u8]) -> Result<AtomicWriteResult> {
+    let mut result = AtomicWriteResult::default();
+
     // Ensure parent directory exists
     if let Some(parent) = path.parent() {
         fs::create_dir_all(parent)
@@ -56,7 +65,7 @@ pub fn write_file_atomic(path: &Utf8Path, content: &str) -> Result<AtomicWriteRe
 
     // Write content to temporary file
     temp_file
-        .write_all(normalized_content.as_bytes())
+        .write_all(content)
         .with_context(|| "Failed to write content to temporary file")?;
 
     // Ensure data is written to disk (FR-FS-001)
@@ -103,6 +112,30 @@ fn normalize_line_endings(content: &str) -> String {
     content.replace("\r\n", "\n").replace('\r', "\n")
 }
 
+/// Whether `content` uses more than one line ending style (CRLF, LF, lone CR)
+///
+/// Typical source: a template with CRLF endings spliced with LF model output.
+#[must_use]
+pub fn has_mixed_line_endings(content: &str) -> bool {
+    let (mut crlf, mut lf, mut cr) = (false, false, false);
+    let mut bytes = content.bytes().peekable();
+    while let Some(byte) = bytes.next() {
+        match byte {
+            b'\r' if bytes.peek() == Some(&b'\n') => {
+                bytes.next();
+                crlf = true;
+            }
+            b'\r' => cr = true,
+            b'\n' => lf = true,
+            _ => continue,
+        }
+        if u8::from(crlf) + u8::from(lf) + u8::from(cr) > 1 {
+            return true;
+        }
+    }
+    false
+}
+
 /// Attempt atomic rename with platform-specific retry logic
 ///
 /// Returns the number of retries that were needed.
@@ -256,6 +289,15 @@ mod tests {
         TempDir::new().unwrap()
     }
 
+    #[test]
+    fn test_has_mixed_line_endings() {
+        assert!(!has_mixed_line_endings("a\nb\nc"));
+        assert!(!has_mixed_line_endings("a\r\nb\r\n"));
+        assert!(!has_mixed_line_endings("no newline"));
+        assert!(has_mixed_line_endings("a\r\nb\nc"));
+        assert!(has_mixed_line_endings("a\rb\n"));
+    }
+
     #[test]
     fn test_normalize_line_endings() {
         assert_eq!(
@@ -9,6 +9,7 @@ use anyhow::{Context, Result};
 use blake3::Hasher;
 use camino::{Utf8Path, Utf8PathBuf};
 use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
@@ -21,17 +22,26 @@ pub struct InsightCache {
     cache_dir: Utf8PathBuf,
     /// In-memory cache for current session
     memory_cache: HashMap<String, CachedInsight>,
+    /// In-memory packet cache for current session, keyed by phase
+    packet_cache: HashMap<String, CachedPacket>,
     /// Cache hit/miss statistics for verbose logging
     stats: CacheStats,
 }
 
 /// Statistics for cache performance tracking
 #[derive(Debug, Default, Clone, Copy, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schemas", derive(schemars::JsonSchema))]
 pub struct CacheStats {
     pub hits: usize,
     pub misses: usize,
     pub invalidations: usize,
     pub writes: usize,
+    /// Whole-packet lookups that reused a cached packet
+    #[serde(default)]
+    pub packet_hits: usize,
+    /// Whole-packet lookups that found no usable cached packet
+    #[serde(default)]
+    pub packet_misses: usize,
 }
 
 impl CacheStats {
@@ -68,6 +78,19 @@ pub struct CachedInsight {
     pub last_modified: DateTime<Utc>,
 }
 
+/// A built packet cached for a phase
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedPacket {
+    /// BLAKE3 hash over the selected files and build settings
+    pub file_set_hash: String,
+    /// Phase the packet was built for
+    pub phase: String,
+    /// Timestamp when the packet was cached
+    pub cached_at: DateTime<Utc>,
+    /// The packet itself, opaque to the cache
+    pub packet: serde_json::Value,
+}
+
 impl InsightCache {
     /// Create a new insight cache with the specified cache directory
     pub fn new(cache_dir: Utf8PathBuf) -> Result<Self> {
@@ -78,6 +101,7 @@ impl InsightCache {
         Ok(Self {
             cache_dir,
             memory_cache: HashMap::new(),
+            packet_cache: HashMap::new(),
             stats: CacheStats::default(),
         })
     }
@@ -273,6 +297,113 @@ impl InsightCache {
         Ok(())
     }
 
+    /// Get the path of the cached packet for a phase
+    fn packet_file_path(&self, phase: &str) -> Utf8PathBuf {
+        self.cache_dir
+            .join(format!("packet_{}.json", phase.to_lowercase()))
+    }
+
+    /// Get the packet cached for `phase`, or None if none was cached or it
+    /// was built from a different file set
+    ///
+    /// A cached packet whose `file_set_hash` differs is stale and is removed.
+    pub fn get_packet<T: DeserializeOwned>(
+        &mut self,
+        phase: &str,
+        file_set_hash: &str,
+        logger: Option<&Logger>,
+    ) -> Option<T> {
+        let key = phase.to_lowercase();
+        let cache_file = self.packet_file_path(phase);
+
+        let cached = match self.packet_cache.get(&key) {
+            Some(cached) => Some(cached.clone()),
+            None if cache_file.exists() => {
+                let loaded = fs::read_to_string(&cache_file)
+                    .ok()
+                    .and_then(|content| serde_json::from_str::<CachedPacket>(&content).ok());
+                if loaded.is_none() {
+                    // Corrupted cache file, remove it
+                    let _ = fs::remove_file(&cache_file);
+                    if let Some(logger) = logger {
+                        logger.verbose(&format!("Cache file corrupted, removed: {cache_file}"));
+                    }
+                }
+                loaded
+            }
+            None => None,
+        };
+
+        if let Some(cached) = cached {
+            let packet = (cached.file_set_hash == file_set_hash)
+                .then(|| serde_json::from_value(cached.packet.clone()).ok())
+                .flatten();
+            if let Some(packet) = packet {
+                self.packet_cache.insert(key, cached);
+                self.stats.packet_hits += 1;
+                if let Some(logger) = logger {
+                    logger.verbose(&format!(
+                        "Packet cache hit: {phase} [{}]",
+                        &file_set_hash[..8]
+                    ));
+                }
+                return Some(packet);
+            }
+
+            // File set changed (or the entry no longer parses), drop it
+            self.packet_cache.remove(&key);
+            let _ = fs::remove_file(&cache_file);
+            self.stats.invalidations += 1;
+            if let Some(logger) = logger {
+                logger.verbose(&format!(
+                    "Packet cache invalidated (file set changed): {phase} [{}]",
+                    &file_set_hash[..8]
+                ));
+            }
+        }
+
+        self.stats.packet_misses += 1;
+        if let Some(logger) = logger {
+            logger.verbose(&format!(
+                "Packet cache miss: {phase} [{}]",
+                &file_set_hash[..8]
+            ));
+        }
+        None
+    }
+
+    /// Store the packet built for `phase` from the file set `file_set_hash`
+    ///
+    /// Replaces any packet previously cached for the phase.
+    pub fn store_packet<T: Serialize>(
+        &mut self,
+        phase: &str,
+        file_set_hash: &str,
+        packet: &T,
+        logger: Option<&Logger>,
+    ) -> Result<()> {
+        let cached = CachedPacket {
+            file_set_hash: file_set_hash.to_string(),
+            phase: phase.to_string(),
+            cached_at: Utc::now(),
+            packet: serde_json::to_value(packet).context("Failed to serialize cached packet")?,
+        };
+
+        let cache_file = self.packet_file_path(phase);
+        let content =
+            serde_json::to_string_pretty(&cached).context("Failed to serialize cached packet")?;
+        fs::write(&cache_file, content)
+            .with_context(|| format!("Failed to write cache file: {cache_file}"))?;
+        self.packet_cache.insert(phase.to_lowercase(), cached);
+
+        self.stats.writes += 1;
+        if let Some(logger) = logger {
+            logger.verbose(&format!("Cached packet: {phase} [{}]", &file_set_hash[..8]));
+        }
+
+        Ok(())
+    }
+
     /// Generate core insights for a file (R3.5: 10-25 bullet points per phase)
     #[must_use]
     pub fn generate_insights(
@@ -573,6 +704,7 @@ impl InsightCache {
     #[allow(dead_code)] // Cache management utility
     pub fn clear(&mut self) -> Result<()> {
         self.memory_cache.clear();
+        self.packet_cache.clear();
 
         // Remove all cache files
         if self.cache_dir.exists() {
@@ -678,6 +810,33 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_packet_cache_hit_and_invalidation() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let cache_dir = Utf8PathBuf::try_from(temp_dir.path().to_path_buf())?;
+        let mut cache = InsightCache::new(cache_dir.clone())?;
+
+        let hash1 = calculate_content_hash("file set 1");
+        let hash2 = calculate_content_hash("file set 2");
+        assert!(cache.get_packet::<String>("design", &hash1, None).is_none());
+        assert_eq!(cache.stats().packet_misses, 1);
+
+        cache.store_packet("design", &hash1, &"packet body".to_string(), None)?;
+
+        // Served from disk by a fresh instance
+        let mut cache = InsightCache::new(cache_dir)?;
+        let packet: Option<String> = cache.get_packet("design", &hash1, None);
+        assert_eq!(packet.as_deref(), Some("packet body"));
+        assert_eq!(cache.stats().packet_hits, 1);
+
+        // A different file set invalidates the entry
+        assert!(cache.get_packet::<String>("design", &hash2, None).is_none());
+        assert_eq!(cache.stats().invalidations, 1);
+        assert!(cache.get_packet::<String>("design", &hash1, None).is_none());
+
+        Ok(())
+    }
+
     #[test]
     fn test_cache_invalidation_on_file_change() -> Result<()> {
         let temp_dir = TempDir::new()?;
@@ -88,6 +88,9 @@ pub enum XCheckerError {
     #[error("Secret detected: {pattern} in {location}")]
     SecretDetected { pattern: String, location: String },
 
+    #[error("Secret redactor unavailable: {reason}")]
+    RedactorUnavailable { reason: String },
+
     #[error(
         "Packet overflow: {used_bytes} bytes, {used_lines} lines > limits {limit_bytes} bytes, {limit_lines} lines"
     )]
@@ -101,6 +104,9 @@ pub enum XCheckerError {
     #[error("Concurrent execution detected for spec {id}")]
     ConcurrentExecution { id: String },
 
+    #[error("Context file changed during packet build: {path}")]
+    ContextChangedDuringBuild { path: String },
+
     #[error("Packet preview too large: {size} bytes")]
     PacketPreviewTooLarge { size: usize },
 
@@ -120,6 +126,9 @@ pub enum XCheckerError {
     #[error("Source resolution error: {0}")]
     Source(#[from] SourceError),
 
+    #[error("Prompt template error: {0}")]
+    Template(#[from] TemplateError),
+
     #[error("Fixup error: {0}")]
     Fixup(#[from] FixupError),
 
@@ -138,6 +147,36 @@ pub enum XCheckerError {
         issues: Vec<ValidationError>,
         issue_count: usize,
     },
+
+    #[error("Artifact name collision: '{name}' is produced by phases {}", phases.join(", "))]
+    ArtifactNameCollision { name: String, phases: Vec<String> },
+
+    #[error("Runner process exited with code {exit_code}: {stderr_excerpt}")]
+    RunnerFailed {
+        exit_code: i32,
+        stderr_excerpt: String,
+    },
+
+    #[error("Spec '{id}' already exists")]
+    SpecExists { id: String },
+
+    #[error("Artifact '{name}' was modified outside xchecker")]
+    ArtifactModified { name: String },
+
+    #[error("Run deadline exceeded after {:.1}s", elapsed.as_secs_f64())]
+    DeadlineExceeded { elapsed: std::time::Duration },
+
+    #[error("Run cancelled")]
+    Cancelled,
+
+    #[error("Phase {phase} requires the {dep} artifacts, which are missing or empty")]
+    MissingDependencyArtifact { phase: String, dep: String },
+
+    #[error("Too many concurrent specs: {active} runs active (limit {limit})")]
+    TooManyConcurrentSpecs { limit: usize, active: usize },
+
+    #[error("Run state at {path} cannot be restored: {reason}")]
+    RunStateInvalid { path: String, reason: String },
 }
 
 /// Trait for providing user-friendly error reporting with context and suggestions
@@ -396,6 +435,9 @@ pub enum PhaseError {
 
     #[error("Phase {phase} produced partial output due to failure")]
     PartialOutputSaved { phase: String, partial_path: String },
+
+    #[error("Phase {phase} fixture not found: {path}")]
+    FixtureMissing { phase: String, path: String },
 }
 
 impl UserFriendlyError for PhaseError {
@@ -452,6 +494,9 @@ impl UserFriendlyError for PhaseError {
             } => {
                 format!("The {phase} phase failed and partial output was saved to: {partial_path}")
             }
+            Self::FixtureMissing { phase, path } => {
+                format!("No fixture response for the {phase} phase at: {path}")
+            }
         }
     }
 
@@ -490,6 +535,9 @@ impl UserFriendlyError for PhaseError {
             Self::PartialOutputSaved { phase: _, partial_path: _ } => {
                 Some("Partial outputs are saved when phases fail to help with debugging and recovery.".to_string())
             }
+            Self::FixtureMissing { phase: _, path: _ } => {
+                Some("With runner.fixture_dir set, each phase reads its model response from <fixture_dir>/<phase>.md instead of calling an LLM.".to_string())
+            }
         }
     }
 
@@ -631,6 +679,10 @@ impl UserFriendlyError for PhaseError {
                 "Use the partial output to understand where the phase failed".to_string(),
                 "Try resuming the phase after addressing any issues".to_string(),
             ],
+            Self::FixtureMissing { phase, path } => vec![
+                format!("Create {path} with the raw model response for the {phase} phase"),
+                "Unset runner.fixture_dir to call the configured LLM instead".to_string(),
+            ],
         }
     }
 
@@ -835,6 +887,94 @@ impl UserFriendlyError for SourceError {
     }
 }
 
+/// Custom prompt template errors
+#[derive(Error, Debug)]
+pub enum TemplateError {
+    #[error("Prompt template could not be read: {path}: {reason}")]
+    ReadFailed { path: String, reason: String },
+
+    #[error("Prompt template too large: {path} exceeds {limit} bytes")]
+    TooLarge { path: String, limit: u64 },
+
+    #[error("Prompt template is not valid UTF-8: {path} (invalid byte at offset {offset})")]
+    InvalidUtf8 { path: String, offset: usize },
+
+    #[error("Prompt template for {phase} is missing placeholders {}: {path}", missing.join(", "))]
+    MissingPlaceholders {
+        path: String,
+        phase: String,
+        missing: Vec<String>,
+    },
+}
+
+impl UserFriendlyError for TemplateError {
+    fn user_message(&self) -> String {
+        match self {
+            Self::ReadFailed { path, reason } => {
+                format!("Could not read prompt template '{path}': {reason}")
+            }
+            Self::TooLarge { path, limit } => {
+                format!("Prompt template '{path}' is larger than {limit} bytes")
+            }
+            Self::InvalidUtf8 { path, offset } => {
+                format!("Prompt template '{path}' is not valid UTF-8 (byte offset {offset})")
+            }
+            Self::MissingPlaceholders {
+                path,
+                phase,
+                missing,
+            } => format!(
+                "Prompt template '{path}' for the {phase} phase is missing {}",
+                missing.join(", ")
+            ),
+        }
+    }
+
+    fn context(&self) -> Option<String> {
+        match self {
+            Self::ReadFailed { .. } => Some(
+                "Prompt templates are read from [phases.<phase>].prompt_file, relative to the working directory."
+                    .to_string(),
+            ),
+            Self::TooLarge { .. } => Some(
+                "Prompt templates are size-bounded so a wrong path cannot pull a large file into every LLM call."
+                    .to_string(),
+            ),
+            Self::InvalidUtf8 { .. } => {
+                Some("Prompt templates are sent to the LLM as text and must be UTF-8.".to_string())
+            }
+            Self::MissingPlaceholders { .. } => Some(
+                "Placeholders are replaced with run values; without them the prompt would lose the spec it is about."
+                    .to_string(),
+            ),
+        }
+    }
+
+    fn suggestions(&self) -> Vec<String> {
+        match self {
+            Self::ReadFailed { .. } => vec![
+                "Check the prompt_file path in your configuration".to_string(),
+                "Use an absolute path if xchecker runs from another directory".to_string(),
+            ],
+            Self::TooLarge { .. } => vec![
+                "Move large reference material into context files selected for the packet"
+                    .to_string(),
+            ],
+            Self::InvalidUtf8 { .. } => {
+                vec!["Re-save the template with UTF-8 encoding".to_string()]
+            }
+            Self::MissingPlaceholders { missing, .. } => missing
+                .iter()
+                .map(|placeholder| format!("Add {placeholder} to the template"))
+                .collect(),
+        }
+    }
+
+    fn category(&self) -> ErrorCategory {
+        ErrorCategory::Configuration
+    }
+}
+
 /// Claude CLI integration errors
 #[derive(Error, Debug)]
 pub enum ClaudeError {
@@ -991,6 +1131,26 @@ pub enum RunnerError {
 
     #[error("Execution timed out after {timeout_seconds} seconds")]
     Timeout { timeout_seconds: u64 },
+
+    #[error("No recorded response for Claude CLI invocation {key}")]
+    UnrecordedInteraction { key: String },
+}
+
+impl From<xchecker_runner::RunnerError> for RunnerError {
+    fn from(err: xchecker_runner::RunnerError) -> Self {
+        use xchecker_runner::RunnerError as Source;
+
+        match err {
+            Source::DetectionFailed { reason } => Self::DetectionFailed { reason },
+            Source::WslNotAvailable { reason } => Self::WslNotAvailable { reason },
+            Source::WslExecutionFailed { reason } => Self::WslExecutionFailed { reason },
+            Source::NativeExecutionFailed { reason } => Self::NativeExecutionFailed { reason },
+            Source::ConfigurationInvalid { reason } => Self::ConfigurationInvalid { reason },
+            Source::ClaudeNotFoundInRunner { runner } => Self::ClaudeNotFoundInRunner { runner },
+            Source::Timeout { timeout_seconds } => Self::Timeout { timeout_seconds },
+            Source::UnrecordedInteraction { key } => Self::UnrecordedInteraction { key },
+        }
+    }
 }
 
 impl UserFriendlyError for RunnerError {
@@ -1017,6 +1177,9 @@ impl UserFriendlyError for RunnerError {
             Self::Timeout { timeout_seconds } => {
                 format!("Claude CLI execution timed out after {timeout_seconds} seconds")
             }
+            Self::UnrecordedInteraction { key } => {
+                format!("No recorded Claude CLI response matches invocation {key}")
+            }
         }
     }
 
@@ -1043,6 +1206,9 @@ impl UserFriendlyError for RunnerError {
             Self::Timeout { .. } => {
                 Some("Phase execution has configurable timeouts to prevent hanging operations.".to_string())
             }
+            Self::UnrecordedInteraction { .. } => {
+                Some("Replayed runs only serve Claude CLI responses captured by a previous recording.".to_string())
+            }
         }
     }
 
@@ -1098,6 +1264,10 @@ impl UserFriendlyError for RunnerError {
                 "Try running with --verbose to see where it's hanging".to_string(),
                 "Consider breaking down complex requests into smaller parts".to_string(),
             ],
+            Self::UnrecordedInteraction { .. } => vec![
+                "Re-record the run: packets or arguments changed since the recording".to_string(),
+                "Check that the replay uses the same spec id, config and inputs".to_string(),
+            ],
         }
     }
 
@@ -1426,6 +1596,11 @@ impl UserFriendlyError for LockError {
             Self::ReleaseFailed { reason } => {
                 format!("Failed to release lock: {reason}")
             }
+            Self::TooManyConcurrentSpecs { limit, active } => {
+                format!(
+                    "{active} xchecker runs are already active (max_concurrent_specs = {limit})"
+                )
+            }
             Self::Io(e) => {
                 format!("File system error during lock operation: {e}")
             }
@@ -1449,6 +1624,9 @@ impl UserFriendlyError for LockError {
             Self::ReleaseFailed { .. } => {
                 Some("Lock release cleans up the lock file when operations complete. Failure to release may leave stale locks.".to_string())
             }
+            Self::TooManyConcurrentSpecs { .. } => {
+                Some("Runs sharing one xchecker home are counted in .xchecker/.global.lock to limit disk and LLM load.".to_string())
+            }
             Self::Io(_) => {
                 Some("File system operations are required for lock management. Check permissions and disk space.".to_string())
             }
@@ -1485,6 +1663,10 @@ impl UserFriendlyError for LockError {
                 "Ensure the lock file exists and is writable".to_string(),
                 "The lock will be automatically cleaned up when the process exits".to_string(),
             ],
+            Self::TooManyConcurrentSpecs { .. } => vec![
+                "Wait for another xchecker run to finish and try again".to_string(),
+                "Raise max_concurrent_specs under [defaults] in .xchecker/config.toml".to_string(),
+            ],
             Self::Io(e) => {
                 match e.kind() {
                     io::ErrorKind::PermissionDenied => vec![
@@ -1512,7 +1694,9 @@ impl UserFriendlyError for LockError {
 
     fn category(&self) -> ErrorCategory {
         match self {
-            Self::ConcurrentExecution { .. } | Self::StaleLock { .. } => ErrorCategory::Concurrency,
+            Self::ConcurrentExecution { .. }
+            | Self::StaleLock { .. }
+            | Self::TooManyConcurrentSpecs { .. } => ErrorCategory::Concurrency,
             Self::CorruptedLock { .. } => ErrorCategory::Validation,
             Self::AcquisitionFailed { .. } | Self::ReleaseFailed { .. } => {
                 ErrorCategory::FileSystem
@@ -1579,6 +1763,8 @@ pub enum ValidationError {
     TooShort { actual: usize, minimum: usize },
     /// Required section header is missing
     MissingSectionHeader { header: String },
+    /// Acceptance criterion doesn't follow an EARS pattern
+    NonEarsCriterion { line: usize, criterion: String },
 }
 
 impl std::fmt::Display for ValidationError {
@@ -1597,6 +1783,14 @@ impl std::fmt::Display for ValidationError {
             Self::MissingSectionHeader { header } => {
                 write!(f, "Missing required section: '{}'", header)
             }
+            Self::NonEarsCriterion { line, criterion } => {
+                write!(
+                    f,
+                    "Acceptance criterion on line {} is not in EARS form \
+                     (WHEN/IF ... THEN ... SHALL, WHILE, WHERE): '{}'",
+                    line, criterion
+                )
+            }
         }
     }
 }
@@ -1626,6 +1820,13 @@ pub enum LlmError {
     #[error("Timeout after {duration:?}")]
     Timeout { duration: Duration },
 
+    /// Invocation exceeded the timeout resolved for a specific provider
+    #[error("Provider '{provider}' timed out after {duration:?}")]
+    ProviderTimeout {
+        provider: String,
+        duration: Duration,
+    },
+
     /// Budget limit exceeded
     #[error("Budget exceeded: attempted {attempted} calls, limit is {limit}")]
     BudgetExceeded { limit: u32, attempted: u32 },
@@ -1649,6 +1850,12 @@ impl UserFriendlyError for LlmError {
             Self::Timeout { duration } => {
                 format!("LLM invocation timed out after {:?}", duration)
             }
+            Self::ProviderTimeout { provider, duration } => {
+                format!(
+                    "LLM provider '{}' did not respond within {:?}",
+                    provider, duration
+                )
+            }
             Self::BudgetExceeded { limit, attempted } => {
                 format!(
                     "LLM budget exceeded: attempted {} calls, limit is {}",
@@ -1680,6 +1887,10 @@ impl UserFriendlyError for LlmError {
                 "Timeouts occur when LLM invocations take longer than the configured limit."
                     .to_string(),
             ),
+            Self::ProviderTimeout { .. } => Some(
+                "Each provider waits up to its [llm.<provider>] timeout_secs, falling back to the phase timeout."
+                    .to_string(),
+            ),
             Self::BudgetExceeded { .. } => {
                 Some("Budget limits prevent excessive LLM API calls and costs.".to_string())
             }
@@ -1720,6 +1931,11 @@ impl UserFriendlyError for LlmError {
                 "Check your internet connection".to_string(),
                 "Try breaking down complex requests into smaller parts".to_string(),
             ],
+            Self::ProviderTimeout { .. } => vec![
+                "Increase timeout_secs in the provider's [llm.<provider>] config section".to_string(),
+                "Check the provider's status page for slow responses".to_string(),
+                "Consider using a fallback provider if configured".to_string(),
+            ],
             Self::BudgetExceeded { .. } => vec![
                 "Increase the budget limit via environment variable (e.g., XCHECKER_OPENROUTER_BUDGET)".to_string(),
                 "Review which phases are consuming budget".to_string(),
@@ -1745,6 +1961,7 @@ impl UserFriendlyError for LlmError {
             Self::ProviderQuota(_) => ErrorCategory::ResourceLimits,
             Self::ProviderOutage(_) => ErrorCategory::ClaudeIntegration,
             Self::Timeout { .. } => ErrorCategory::PhaseExecution,
+            Self::ProviderTimeout { .. } => ErrorCategory::PhaseExecution,
             Self::BudgetExceeded { .. } => ErrorCategory::ResourceLimits,
             Self::Misconfiguration(_) => ErrorCategory::Configuration,
             Self::Unsupported(_) => ErrorCategory::Configuration,
@@ -1769,6 +1986,9 @@ impl UserFriendlyError for XCheckerError {
             } => {
                 format!("Security issue: Detected potential secret in {location}")
             }
+            Self::RedactorUnavailable { reason } => {
+                format!("Security issue: Secret redaction is unavailable: {reason}")
+            }
             Self::PacketOverflow {
                 used_bytes,
                 used_lines,
@@ -1782,6 +2002,9 @@ impl UserFriendlyError for XCheckerError {
             Self::ConcurrentExecution { id } => {
                 format!("Another xchecker process is already working on spec '{id}'")
             }
+            Self::ContextChangedDuringBuild { path } => {
+                format!("Context file '{path}' was modified while the packet was being built")
+            }
             Self::PacketPreviewTooLarge { size } => {
                 format!("Packet preview is too large: {size} bytes")
             }
@@ -1799,6 +2022,7 @@ impl UserFriendlyError for XCheckerError {
                 format!("Could not resolve model '{alias}': {reason}")
             }
             Self::Source(source_err) => source_err.user_message(),
+            Self::Template(template_err) => template_err.user_message(),
             Self::Fixup(fixup_err) => fixup_err.user_message(),
             Self::SpecId(spec_id_err) => spec_id_err.user_message(),
             Self::Lock(lock_err) => lock_err.user_message(),
@@ -1814,6 +2038,39 @@ impl UserFriendlyError for XCheckerError {
                     issue_list.join("; ")
                 )
             }
+            Self::ArtifactNameCollision { name, phases } => {
+                format!(
+                    "Artifact '{}' would be written by more than one phase: {}",
+                    name,
+                    phases.join(", ")
+                )
+            }
+            Self::RunnerFailed {
+                exit_code,
+                stderr_excerpt,
+            } => {
+                format!(
+                    "Claude CLI exited with code {exit_code}. Last stderr output:\n{stderr_excerpt}"
+                )
+            }
+            Self::SpecExists { id } => format!("Spec '{id}' is already initialized"),
+            Self::ArtifactModified { name } => {
+                format!("Artifact '{name}' no longer matches the hash recorded in its receipt")
+            }
+            Self::DeadlineExceeded { elapsed } => format!(
+                "The run was stopped after {:.1} seconds because it reached its deadline",
+                elapsed.as_secs_f64()
+            ),
+            Self::Cancelled => "The run was cancelled before its next phase started".to_string(),
+            Self::MissingDependencyArtifact { phase, dep } => format!(
+                "Cannot build the {phase} packet: the {dep} phase has not produced its artifacts"
+            ),
+            Self::TooManyConcurrentSpecs { limit, active } => format!(
+                "{active} xchecker runs are already active in this home (max_concurrent_specs = {limit})"
+            ),
+            Self::RunStateInvalid { path, reason } => {
+                format!("The saved run state at {path} cannot be restored: {reason}")
+            }
         }
     }
 
@@ -1828,12 +2085,18 @@ impl UserFriendlyError for XCheckerError {
             Self::SecretDetected { pattern, location: _ } => {
                 Some(format!("The pattern '{pattern}' matches common secret formats. This prevents accidental exposure of sensitive data."))
             }
+            Self::RedactorUnavailable { reason: _ } => {
+                Some("Packets are never built without redaction, so a misconfigured redactor stops the run before any content is sent.".to_string())
+            }
             Self::PacketOverflow { used_bytes: _, used_lines: _, limit_bytes: _, limit_lines: _ } => {
                 Some("Packet size limits prevent excessive token usage and ensure Claude API calls remain efficient.".to_string())
             }
             Self::ConcurrentExecution { id: _ } => {
                 Some("xchecker uses file locking to prevent data corruption from simultaneous executions.".to_string())
             }
+            Self::ContextChangedDuringBuild { path: _ } => {
+                Some("File size and modification time are recorded at selection and re-checked at read time so packet evidence always matches a single version of each file.".to_string())
+            }
             Self::PacketPreviewTooLarge { size: _ } => {
                 Some("Packet previews are limited to prevent excessive disk usage.".to_string())
             }
@@ -1847,12 +2110,40 @@ impl UserFriendlyError for XCheckerError {
                 Some("Model resolution maps short aliases to full model names for Claude API calls.".to_string())
             }
             Self::Source(source_err) => source_err.context(),
+            Self::Template(template_err) => template_err.context(),
             Self::Fixup(fixup_err) => fixup_err.context(),
             Self::SpecId(spec_id_err) => spec_id_err.context(),
             Self::Lock(lock_err) => lock_err.context(),
             Self::ValidationFailed { .. } => {
                 Some("Strict validation is enabled. LLM output must meet quality requirements: no meta-summaries, minimum length, and required sections.".to_string())
             }
+            Self::ArtifactNameCollision { .. } => {
+                Some("Artifacts share one directory per spec, so a second phase writing the same name would silently overwrite the first.".to_string())
+            }
+            Self::RunnerFailed { .. } => {
+                Some("The excerpt is the redacted tail of the process's stderr; the receipt records the same excerpt.".to_string())
+            }
+            Self::SpecExists { .. } => {
+                Some("Initialization was asked not to proceed over an existing spec, so nothing was changed.".to_string())
+            }
+            Self::ArtifactModified { .. } => {
+                Some("Resume re-uses upstream artifacts; one was edited or corrupted after its phase completed, so later phases could build on content no receipt accounts for.".to_string())
+            }
+            Self::DeadlineExceeded { .. } => {
+                Some("The deadline covers the whole run; the phase in progress was aborted and later phases were not started.".to_string())
+            }
+            Self::Cancelled => {
+                Some("Cancellation is checked between phases; phases that completed keep their artifacts and receipts.".to_string())
+            }
+            Self::MissingDependencyArtifact { .. } => {
+                Some("Each phase's packet is built from the artifacts of the phases it depends on; xchecker will not build a packet with that context missing.".to_string())
+            }
+            Self::TooManyConcurrentSpecs { .. } => {
+                Some("Runs sharing one xchecker home hold a slot in .xchecker/.global.lock; slots of crashed processes are reclaimed automatically.".to_string())
+            }
+            Self::RunStateInvalid { .. } => {
+                Some("run-state.json is written after each phase; it is only trusted while the spec's artifacts still match the snapshot.".to_string())
+            }
         }
     }
 
@@ -1876,6 +2167,10 @@ impl UserFriendlyError for XCheckerError {
                 "Remove or redact the sensitive data from the file".to_string(),
                 "Add the file to .gitignore if it contains test data".to_string(),
             ],
+            Self::RedactorUnavailable { reason: _ } => vec![
+                "Check extra_secret_patterns in config for invalid regular expressions".to_string(),
+                "Check --extra-secret-pattern values passed on the command line".to_string(),
+            ],
             Self::PacketOverflow {
                 used_bytes: _,
                 used_lines: _,
@@ -1901,6 +2196,12 @@ impl UserFriendlyError for XCheckerError {
                 "Use --force flag to override the lock (use with caution)".to_string(),
                 "Check if a previous process crashed and left a stale lock".to_string(),
             ],
+            Self::ContextChangedDuringBuild { path: _ } => vec![
+                "Re-run the phase once editors, formatters or build tools have finished writing"
+                    .to_string(),
+                "Exclude generated files that change frequently from the packet selectors"
+                    .to_string(),
+            ],
             Self::PacketPreviewTooLarge { size: _ } => vec![
                 "Reduce the packet size limits in configuration".to_string(),
                 "Use more restrictive include patterns".to_string(),
@@ -1928,6 +2229,7 @@ impl UserFriendlyError for XCheckerError {
                 "Try using the full model name instead of an alias".to_string(),
             ],
             Self::Source(source_err) => source_err.suggestions(),
+            Self::Template(template_err) => template_err.suggestions(),
             Self::Fixup(fixup_err) => fixup_err.suggestions(),
             Self::SpecId(spec_id_err) => spec_id_err.suggestions(),
             Self::Lock(lock_err) => lock_err.suggestions(),
@@ -1944,6 +2246,50 @@ impl UserFriendlyError for XCheckerError {
                 "Ensure the response meets minimum length requirements".to_string(),
                 "Verify required section headers are present in the output".to_string(),
             ],
+            Self::ArtifactNameCollision { name, .. } => vec![
+                format!("Rename '{name}' in one of the phases so each artifact has a single owner"),
+                "Prefix custom phase artifacts with a unique number, like the built-in phases"
+                    .to_string(),
+            ],
+            Self::RunnerFailed { .. } => vec![
+                "Check the stderr excerpt for authentication, rate limit or model errors"
+                    .to_string(),
+                "Run 'xchecker doctor' to verify the Claude CLI installation".to_string(),
+            ],
+            Self::SpecExists { id } => vec![
+                format!("Use 'xchecker status {id}' to inspect the existing spec"),
+                "Initialize with overwrite allowed to fill in missing directories; existing files are kept"
+                    .to_string(),
+            ],
+            Self::ArtifactModified { name } => vec![
+                "Re-run with --accept-modified to keep the edited artifact and record its new hash"
+                    .to_string(),
+                format!("Restore '{name}' or re-run the phase that produced it"),
+            ],
+            Self::DeadlineExceeded { .. } => vec![
+                "Resume from the interrupted phase; completed phases keep their artifacts"
+                    .to_string(),
+                "Allow a later deadline or lower per-phase work (packet budgets, max_turns)"
+                    .to_string(),
+            ],
+            Self::Cancelled => vec![
+                "Resume from the next phase; completed phases keep their artifacts".to_string(),
+                "Check which phases have completed: xchecker status <id>".to_string(),
+            ],
+            Self::MissingDependencyArtifact { dep, .. } => vec![
+                format!("Run the {dep} phase first: xchecker resume <id> --phase {dep}"),
+                "Check which phases have completed: xchecker status <id>".to_string(),
+            ],
+            Self::TooManyConcurrentSpecs { .. } => vec![
+                "Wait for another xchecker run to finish and try again".to_string(),
+                "Raise max_concurrent_specs under [defaults] in .xchecker/config.toml"
+                    .to_string(),
+            ],
+            Self::RunStateInvalid { .. } => vec![
+                "Check which phases have completed: xchecker status <id>".to_string(),
+                "Resume from the next phase explicitly: xchecker resume <id> --phase <phase>"
+                    .to_string(),
+            ],
         }
     }
 
@@ -1956,17 +2302,29 @@ impl UserFriendlyError for XCheckerError {
             Self::Llm(llm_err) => llm_err.category(),
             Self::Io(_) => ErrorCategory::FileSystem,
             Self::SecretDetected { .. } => ErrorCategory::Security,
+            Self::RedactorUnavailable { .. } => ErrorCategory::Security,
             Self::PacketOverflow { .. } => ErrorCategory::ResourceLimits,
             Self::ConcurrentExecution { .. } => ErrorCategory::Concurrency,
+            Self::ContextChangedDuringBuild { .. } => ErrorCategory::Concurrency,
             Self::PacketPreviewTooLarge { .. } => ErrorCategory::ResourceLimits,
             Self::CanonicalizationFailed { .. } => ErrorCategory::Validation,
             Self::ReceiptWriteFailed { .. } => ErrorCategory::FileSystem,
             Self::ModelResolutionError { .. } => ErrorCategory::ClaudeIntegration,
             Self::Source(_) => ErrorCategory::Configuration,
+            Self::Template(_) => ErrorCategory::Configuration,
             Self::Fixup(fixup_err) => fixup_err.category(),
             Self::SpecId(_) => ErrorCategory::Validation,
             Self::Lock(lock_err) => lock_err.category(),
             Self::ValidationFailed { .. } => ErrorCategory::Validation,
+            Self::ArtifactNameCollision { .. } => ErrorCategory::Validation,
+            Self::RunnerFailed { .. } => ErrorCategory::ClaudeIntegration,
+            Self::SpecExists { .. } => ErrorCategory::Configuration,
+            Self::ArtifactModified { .. } => ErrorCategory::Validation,
+            Self::DeadlineExceeded { .. } => ErrorCategory::ResourceLimits,
+            Self::Cancelled => ErrorCategory::PhaseExecution,
+            Self::MissingDependencyArtifact { .. } => ErrorCategory::PhaseExecution,
+            Self::TooManyConcurrentSpecs { .. } => ErrorCategory::Concurrency,
+            Self::RunStateInvalid { .. } => ErrorCategory::Validation,
         }
     }
 }
@@ -1975,7 +2333,41 @@ impl UserFriendlyError for XCheckerError {
 // XCheckerError methods for exit code mapping
 // ============================================================================
 
+/// Maximum stderr bytes kept in [`XCheckerError::RunnerFailed`]
+pub const STDERR_EXCERPT_MAX_BYTES: usize = 2048;
+
+/// Redact `stderr` and keep its last [`STDERR_EXCERPT_MAX_BYTES`] bytes.
+///
+/// Redaction runs before the cut, so a secret straddling it is never
+/// partially exposed.
+#[must_use]
+pub fn stderr_excerpt(stderr: &str, redactor: &xchecker_redaction::SecretRedactor) -> String {
+    let redacted = redactor.redact_string(stderr);
+    let mut start = redacted.len().saturating_sub(STDERR_EXCERPT_MAX_BYTES);
+    while !redacted.is_char_boundary(start) {
+        start += 1;
+    }
+    redacted[start..].trim_end().to_string()
+}
+
 impl XCheckerError {
+    /// Build [`XCheckerError::RunnerFailed`] from a failed process's stderr.
+    ///
+    /// The stderr is redacted first and then cut to its last
+    /// [`STDERR_EXCERPT_MAX_BYTES`] bytes, so a secret straddling the cut is
+    /// never partially exposed.
+    #[must_use]
+    pub fn runner_failed(
+        exit_code: i32,
+        stderr: &str,
+        redactor: &xchecker_redaction::SecretRedactor,
+    ) -> Self {
+        Self::RunnerFailed {
+            exit_code,
+            stderr_excerpt: stderr_excerpt(stderr, redactor),
+        }
+    }
+
     /// Get a user-friendly error message with context and actionable suggestions.
     ///
     /// This method combines the error message, context, and suggestions into
@@ -2077,16 +2469,42 @@ impl XCheckerError {
             // Configuration errors map to CLI_ARGS
             XCheckerError::Config(_) => ExitCode::CLI_ARGS,
 
+            // Unusable prompt templates are configuration errors
+            XCheckerError::Template(_) => ExitCode::CLI_ARGS,
+
             // Packet overflow before Claude invocation
             XCheckerError::PacketOverflow { .. } => ExitCode::PACKET_OVERFLOW,
 
             // Secret detection (redaction hard stop)
             XCheckerError::SecretDetected { .. } => ExitCode::SECRET_DETECTED,
 
+            // Redactor misconfiguration is a configuration error
+            XCheckerError::RedactorUnavailable { .. } => ExitCode::CLI_ARGS,
+
+            // Refusing to re-initialize an existing spec
+            XCheckerError::SpecExists { .. } => ExitCode::CLI_ARGS,
+
+            // Edited upstream artifacts need an explicit --accept-modified
+            XCheckerError::ArtifactModified { .. } => ExitCode::CLI_ARGS,
+
+            // A run-wide deadline is a timeout like a per-phase one
+            XCheckerError::DeadlineExceeded { .. } => ExitCode::PHASE_TIMEOUT,
+
+            // Cancellation comes from the embedding application; like an
+            // interrupted phase it has no dedicated exit code
+            XCheckerError::Cancelled => ExitCode::INTERNAL,
+
+            // Running a phase before its dependencies is a usage error
+            XCheckerError::MissingDependencyArtifact { .. } => ExitCode::CLI_ARGS,
+
             // Concurrent execution / lock held
             XCheckerError::ConcurrentExecution { .. } => ExitCode::LOCK_HELD,
+            XCheckerError::TooManyConcurrentSpecs { .. } => ExitCode::LOCK_HELD,
             XCheckerError::Lock(_) => ExitCode::LOCK_HELD,
 
+            // A snapshot that no longer matches the spec is a usage error
+            XCheckerError::RunStateInvalid { .. } => ExitCode::CLI_ARGS,
+
             // Phase errors
             XCheckerError::Phase(phase_err) => {
                 match phase_err {
@@ -2101,6 +2519,7 @@ impl XCheckerError {
             // Claude CLI failures
             XCheckerError::Claude(_) => ExitCode::CLAUDE_FAILURE,
             XCheckerError::Runner(_) => ExitCode::CLAUDE_FAILURE,
+            XCheckerError::RunnerFailed { .. } => ExitCode::CLAUDE_FAILURE,
 
             // LLM backend errors
             XCheckerError::Llm(llm_err) => {
@@ -2110,6 +2529,7 @@ impl XCheckerError {
                     LlmError::ProviderQuota(_) => ExitCode::CLAUDE_FAILURE,
                     LlmError::ProviderOutage(_) => ExitCode::CLAUDE_FAILURE,
                     LlmError::Timeout { .. } => ExitCode::PHASE_TIMEOUT,
+                    LlmError::ProviderTimeout { .. } => ExitCode::PHASE_TIMEOUT,
                     LlmError::Misconfiguration(_) => ExitCode::CLI_ARGS,
                     LlmError::Unsupported(_) => ExitCode::CLI_ARGS,
                     LlmError::Transport(_) => ExitCode::CLAUDE_FAILURE,
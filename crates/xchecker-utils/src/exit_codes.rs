@@ -158,6 +158,7 @@ pub fn error_to_exit_code_and_kind(error: &XCheckerError) -> (i32, ErrorKind) {
     match error {
         // Configuration errors map to CLI_ARGS
         XCheckerError::Config(_) => (codes::CLI_ARGS, ErrorKind::CliArgs),
+        XCheckerError::Template(_) => (codes::CLI_ARGS, ErrorKind::CliArgs),
 
         // Packet overflow before Claude invocation
         XCheckerError::PacketOverflow { .. } => (codes::PACKET_OVERFLOW, ErrorKind::PacketOverflow),
@@ -165,10 +166,34 @@ pub fn error_to_exit_code_and_kind(error: &XCheckerError) -> (i32, ErrorKind) {
         // Secret detection (redaction hard stop)
         XCheckerError::SecretDetected { .. } => (codes::SECRET_DETECTED, ErrorKind::SecretDetected),
 
+        // Redactor misconfiguration is a configuration error
+        XCheckerError::RedactorUnavailable { .. } => (codes::CLI_ARGS, ErrorKind::CliArgs),
+
+        // Refusing to re-initialize an existing spec
+        XCheckerError::SpecExists { .. } => (codes::CLI_ARGS, ErrorKind::CliArgs),
+
+        // Edited upstream artifacts need an explicit --accept-modified
+        XCheckerError::ArtifactModified { .. } => (codes::CLI_ARGS, ErrorKind::CliArgs),
+
+        // A run-wide deadline is a timeout like a per-phase one
+        XCheckerError::DeadlineExceeded { .. } => (codes::PHASE_TIMEOUT, ErrorKind::PhaseTimeout),
+
+        // Cancellation comes from the embedding application; like an
+        // interrupted phase it has no dedicated exit code
+        XCheckerError::Cancelled => (1, ErrorKind::Unknown),
+
+        // Running a phase before its dependencies is a usage error, like
+        // PhaseError::DependencyNotSatisfied
+        XCheckerError::MissingDependencyArtifact { .. } => (codes::CLI_ARGS, ErrorKind::CliArgs),
+
         // Concurrent execution / lock held
         XCheckerError::ConcurrentExecution { .. } => (codes::LOCK_HELD, ErrorKind::LockHeld),
+        XCheckerError::TooManyConcurrentSpecs { .. } => (codes::LOCK_HELD, ErrorKind::LockHeld),
         XCheckerError::Lock(_) => (codes::LOCK_HELD, ErrorKind::LockHeld),
 
+        // A snapshot that no longer matches the spec is a usage error
+        XCheckerError::RunStateInvalid { .. } => (codes::CLI_ARGS, ErrorKind::CliArgs),
+
         // Phase errors
         XCheckerError::Phase(phase_err) => {
             use crate::error::PhaseError;
@@ -177,6 +202,8 @@ pub fn error_to_exit_code_and_kind(error: &XCheckerError) -> (i32, ErrorKind) {
                 // Invalid transitions are CLI argument errors (FR-ORC-001, FR-ORC-002)
                 PhaseError::InvalidTransition { .. } => (codes::CLI_ARGS, ErrorKind::CliArgs),
                 PhaseError::DependencyNotSatisfied { .. } => (codes::CLI_ARGS, ErrorKind::CliArgs),
+                // A fixture run missing a phase's response is a setup error
+                PhaseError::FixtureMissing { .. } => (codes::CLI_ARGS, ErrorKind::CliArgs),
                 _ => (1, ErrorKind::Unknown),
             }
         }
@@ -184,6 +211,7 @@ pub fn error_to_exit_code_and_kind(error: &XCheckerError) -> (i32, ErrorKind) {
         // Claude CLI failures
         XCheckerError::Claude(_) => (codes::CLAUDE_FAILURE, ErrorKind::ClaudeFailure),
         XCheckerError::Runner(_) => (codes::CLAUDE_FAILURE, ErrorKind::ClaudeFailure),
+        XCheckerError::RunnerFailed { .. } => (codes::CLAUDE_FAILURE, ErrorKind::ClaudeFailure),
 
         // LLM backend errors
         XCheckerError::Llm(llm_err) => {
@@ -193,6 +221,7 @@ pub fn error_to_exit_code_and_kind(error: &XCheckerError) -> (i32, ErrorKind) {
                 LlmError::ProviderQuota(_) => (codes::CLAUDE_FAILURE, ErrorKind::ClaudeFailure),
                 LlmError::ProviderOutage(_) => (codes::CLAUDE_FAILURE, ErrorKind::ClaudeFailure),
                 LlmError::Timeout { .. } => (codes::PHASE_TIMEOUT, ErrorKind::PhaseTimeout),
+                LlmError::ProviderTimeout { .. } => (codes::PHASE_TIMEOUT, ErrorKind::PhaseTimeout),
                 LlmError::Misconfiguration(_) => (codes::CLI_ARGS, ErrorKind::CliArgs),
                 LlmError::Unsupported(_) => (codes::CLI_ARGS, ErrorKind::CliArgs),
                 LlmError::Transport(_) => (codes::CLAUDE_FAILURE, ErrorKind::ClaudeFailure),
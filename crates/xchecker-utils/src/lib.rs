@@ -1,6 +1,7 @@
 pub mod atomic_write;
 pub mod cache;
 pub mod canonicalization;
+pub use xchecker_runner::encoding;
 pub mod error;
 pub mod exit_codes;
 pub use xchecker_lock as lock;
@@ -8,6 +9,8 @@ pub mod logging;
 pub mod paths;
 pub mod process_memory;
 pub mod ring_buffer;
+#[cfg(feature = "schemas")]
+pub mod schemas;
 pub mod source;
 pub mod spec_id;
 pub mod types;
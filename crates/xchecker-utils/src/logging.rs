@@ -1009,6 +1009,12 @@ pub fn log_doctor_report(report: &crate::types::DoctorOutput) {
         }
 
         println!("  {}", check.details);
+        if let Some(remediation) = &check.remediation {
+            println!("  {}", "Fix:".with(Color::Cyan).attribute(Attribute::Bold));
+            for line in remediation.lines() {
+                println!("    {line}");
+            }
+        }
         println!();
     }
 
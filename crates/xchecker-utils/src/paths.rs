@@ -1,4 +1,4 @@
-use camino::Utf8PathBuf;
+use camino::{Utf8Path, Utf8PathBuf};
 use std::cell::RefCell;
 use std::path::{Path, PathBuf};
 use thiserror::Error;
@@ -520,6 +520,25 @@ pub fn xchecker_home() -> Utf8PathBuf {
     Utf8PathBuf::from(".xchecker")
 }
 
+/// Run `f` with `home` as this thread's xchecker home.
+///
+/// The previous thread-local home (if any) is restored afterwards, even if
+/// `f` panics. Lets a long-lived process serve a home other than its own.
+pub fn with_home<R>(home: &Utf8Path, f: impl FnOnce() -> R) -> R {
+    struct Restore(Option<Utf8PathBuf>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            THREAD_HOME.with(|tl| *tl.borrow_mut() = previous);
+        }
+    }
+
+    let previous = THREAD_HOME.with(|tl| tl.borrow_mut().replace(home.to_path_buf()));
+    let _restore = Restore(previous);
+    f()
+}
+
 /// Returns `<XCHECKER_HOME>/specs/<spec_id>`
 #[must_use]
 pub fn spec_root(spec_id: &str) -> Utf8PathBuf {
@@ -987,4 +1006,20 @@ mod tests {
         };
         assert_eq!(err1, err2);
     }
+
+    // ========================================================================
+    // with_home tests
+    // ========================================================================
+
+    #[test]
+    fn test_with_home_scopes_and_restores_thread_home() {
+        let outer = with_isolated_home();
+        let outer_home = Utf8PathBuf::from_path_buf(outer.path().to_path_buf()).unwrap();
+
+        let inner = Utf8PathBuf::from("/srv/xchecker-home");
+        let seen = with_home(&inner, || spec_root("demo"));
+
+        assert_eq!(seen, inner.join("specs").join("demo"));
+        assert_eq!(xchecker_home(), outer_home);
+    }
 }
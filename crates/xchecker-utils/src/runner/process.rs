@@ -1,2 +1,88 @@
 // Re-export ProcessOutput and ProcessRunner from xchecker-runner
 pub use xchecker_runner::process::{ProcessOutput, ProcessRunner};
+
+use std::time::Duration;
+
+use xchecker_redaction::SecretRedactor;
+use xchecker_runner::CommandSpec;
+
+use crate::error::{RunnerError, XCheckerError};
+
+/// Run `cmd` and turn a non-zero exit into [`XCheckerError::RunnerFailed`].
+///
+/// The error carries a redacted, length-capped excerpt of the process's
+/// stderr (see [`XCheckerError::runner_failed`]). A process killed by a
+/// signal reports exit code `-1`.
+///
+/// # Errors
+///
+/// Returns `XCheckerError::Runner` if the process cannot be run or times
+/// out, and `XCheckerError::RunnerFailed` if it exits unsuccessfully.
+pub fn run_checked<R: ProcessRunner + ?Sized>(
+    runner: &R,
+    cmd: &CommandSpec,
+    timeout: Duration,
+    redactor: &SecretRedactor,
+) -> Result<ProcessOutput, XCheckerError> {
+    let output = runner
+        .run(cmd, timeout)
+        .map_err(|e| XCheckerError::Runner(RunnerError::from(e)))?;
+
+    if output.success() {
+        return Ok(output);
+    }
+    Err(XCheckerError::runner_failed(
+        output.exit_code.unwrap_or(-1),
+        &output.stderr_string(),
+        redactor,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::native::NativeRunner;
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_checked_captures_redacted_stderr_excerpt() {
+        let token = format!("ghp_{}", "a".repeat(36));
+        let script = format!("echo 'auth failed for {token}' >&2; exit 3");
+        let cmd = CommandSpec::new("sh").arg("-c").arg(script);
+
+        let err = run_checked(
+            &NativeRunner::new(),
+            &cmd,
+            Duration::from_secs(10),
+            &SecretRedactor::default(),
+        )
+        .unwrap_err();
+
+        match err {
+            XCheckerError::RunnerFailed {
+                exit_code,
+                stderr_excerpt,
+            } => {
+                assert_eq!(exit_code, 3);
+                assert!(stderr_excerpt.contains("auth failed for"));
+                assert!(!stderr_excerpt.contains(&token));
+            }
+            other => panic!("expected RunnerFailed, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_runner_failed_keeps_only_the_tail() {
+        let stderr = format!("{}END", "x".repeat(5000));
+        let err = XCheckerError::runner_failed(1, &stderr, &SecretRedactor::default());
+
+        let XCheckerError::RunnerFailed { stderr_excerpt, .. } = err else {
+            panic!("expected RunnerFailed");
+        };
+        assert_eq!(
+            stderr_excerpt.len(),
+            crate::error::STDERR_EXCERPT_MAX_BYTES
+        );
+        assert!(stderr_excerpt.ends_with("END"));
+    }
+}
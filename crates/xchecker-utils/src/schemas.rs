@@ -0,0 +1,21 @@
+//! JSON Schema export for emitted JSON contracts
+//!
+//! Schemas are generated from the Rust types with `schemars`, so they always
+//! describe exactly what this build serializes, including `schema_version`.
+//! The hand-maintained files under `schemas/` remain the published contracts.
+
+use schemars::schema_for;
+
+use crate::types::{DoctorOutput, Receipt};
+
+/// JSON Schema for [`DoctorOutput`] as emitted by `xchecker doctor --json`
+#[must_use]
+pub fn schema_for_doctor_output() -> serde_json::Value {
+    schema_for!(DoctorOutput).to_value()
+}
+
+/// JSON Schema for [`Receipt`] as written under `receipts/`
+#[must_use]
+pub fn schema_for_receipt() -> serde_json::Value {
+    schema_for!(Receipt).to_value()
+}
@@ -60,6 +60,16 @@ pub enum PhaseId {
 }
 
 impl PhaseId {
+    /// Every phase, in workflow order.
+    pub const ALL: [Self; 6] = [
+        Self::Requirements,
+        Self::Design,
+        Self::Tasks,
+        Self::Review,
+        Self::Fixup,
+        Self::Final,
+    ];
+
     /// Returns the string representation of the phase.
     ///
     /// This is the canonical lowercase name used in receipts, status output,
@@ -89,6 +99,7 @@ impl PhaseId {
 
 /// Priority levels for content selection in packet building
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemas", derive(schemars::JsonSchema))]
 pub enum Priority {
     /// Upstream *.core.yaml files - never evicted
     Upstream,
@@ -161,11 +172,50 @@ impl OutputFormat {
     }
 }
 
+/// How phase output validation issues are handled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ValidationMode {
+    /// Log validation issues as warnings (default)
+    #[default]
+    Warn,
+    /// Fail the phase on any validation issue
+    Strict,
+    /// Complete the phase but mark its receipt `validation_degraded`
+    LenientFlagged,
+}
+
+impl std::fmt::Display for ValidationMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Warn => write!(f, "warn"),
+            Self::Strict => write!(f, "strict"),
+            Self::LenientFlagged => write!(f, "lenient_flagged"),
+        }
+    }
+}
+
+impl std::str::FromStr for ValidationMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "warn" => Ok(Self::Warn),
+            "strict" => Ok(Self::Strict),
+            "lenient_flagged" => Ok(Self::LenientFlagged),
+            other => Err(format!(
+                "unknown validation mode '{other}' (expected warn, strict or lenient_flagged)"
+            )),
+        }
+    }
+}
+
 /// Runner modes for cross-platform Claude CLI execution.
 pub use xchecker_runner::RunnerMode;
 
 /// LLM metadata for receipts (wires ClaudeResponse fields into receipts)
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schemas", derive(schemars::JsonSchema))]
 pub struct LlmInfo {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub provider: Option<String>,
@@ -203,9 +253,22 @@ impl LlmInfo {
     }
 }
 
+/// Breakdown of a phase's wall-clock time by sub-step, in milliseconds
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemas", derive(schemars::JsonSchema))]
+pub struct TimingSpans {
+    /// Building the context packet
+    pub packet_build_ms: u64,
+    /// The LLM invocation (or its simulation in dry-run mode)
+    pub llm_call_ms: u64,
+    /// Turning the LLM response into artifacts
+    pub postprocess_ms: u64,
+}
+
 /// Enhanced receipt structure for multi-file support and full auditability
 /// Records comprehensive information about phase execution including Claude CLI details
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemas", derive(schemars::JsonSchema))]
 pub struct Receipt {
     /// Schema version for this receipt format
     pub schema_version: String,
@@ -257,10 +320,113 @@ pub struct Receipt {
     pub llm: Option<LlmInfo>,
     /// Pipeline configuration metadata (V11+)
     pub pipeline: Option<PipelineInfo>,
+    /// Set when the phase completed despite output validation issues
+    /// (`lenient_flagged` validation mode)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validation_degraded: Option<bool>,
+    /// Redacted summaries of the issues behind `validation_degraded`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub validation_errors: Vec<String>,
+    /// BLAKE3 over the canonical JSON of the phase's inputs (problem
+    /// statement, context file hashes, model, versions and limits)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input_fingerprint: Option<String>,
+    /// BLAKE3 of the (redacted) packet sent to the LLM
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub packet_hash: Option<String>,
+    /// Wall-clock time the phase took, in milliseconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<u64>,
+    /// How `duration_ms` splits across packet building, the LLM call and
+    /// postprocessing
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timings: Option<TimingSpans>,
+}
+
+impl Receipt {
+    /// Current receipt schema version emitted by this build.
+    pub const CURRENT_SCHEMA_VERSION: &'static str = "1";
+
+    /// Mark the receipt degraded by output validation issues, if there are any
+    pub fn record_validation_errors(&mut self, errors: Vec<String>) {
+        if !errors.is_empty() {
+            self.validation_degraded = Some(true);
+            self.validation_errors = errors;
+        }
+    }
+
+    /// Load a receipt of any known schema version, upgrading it to the current shape.
+    ///
+    /// Receipts written before `schema_version` existed are treated as version `"0"`.
+    /// Fields that older receipts may lack are filled with these defaults:
+    ///
+    /// - `warnings`: `[]`
+    /// - `flags`: `{}`
+    /// - `runner`: `"native"`
+    /// - `canonicalization_backend`: `"jcs-rfc8785"`
+    /// - all optional fields (`llm`, `pipeline`, `diff_context`, ...): `null`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is not a JSON object, if `schema_version` is
+    /// newer than [`Receipt::CURRENT_SCHEMA_VERSION`] or not a version number, or
+    /// if the upgraded value still does not match the current receipt shape.
+    pub fn migrate(value: serde_json::Value) -> anyhow::Result<Self> {
+        let serde_json::Value::Object(mut map) = value else {
+            anyhow::bail!("receipt must be a JSON object");
+        };
+
+        let version = match map.get("schema_version") {
+            None | Some(serde_json::Value::Null) => 0,
+            Some(serde_json::Value::String(s)) => s.parse::<u32>().map_err(|_| {
+                anyhow::anyhow!("receipt schema_version {s:?} is not a version number")
+            })?,
+            Some(serde_json::Value::Number(n)) => n
+                .as_u64()
+                .and_then(|n| u32::try_from(n).ok())
+                .ok_or_else(|| {
+                    anyhow::anyhow!("receipt schema_version {n} is not a version number")
+                })?,
+            Some(other) => anyhow::bail!("receipt schema_version has invalid type: {other}"),
+        };
+
+        let current: u32 = Self::CURRENT_SCHEMA_VERSION
+            .parse()
+            .expect("CURRENT_SCHEMA_VERSION is numeric");
+        if version > current {
+            anyhow::bail!(
+                "receipt schema_version {version} is newer than supported version {current}; \
+                 upgrade xchecker to read this receipt"
+            );
+        }
+
+        // v0 -> v1: fields introduced over the course of v1 get documented defaults.
+        let defaults = [
+            ("warnings", serde_json::Value::Array(Vec::new())),
+            ("flags", serde_json::Value::Object(serde_json::Map::new())),
+            ("runner", serde_json::Value::String("native".to_string())),
+            (
+                "canonicalization_backend",
+                serde_json::Value::String("jcs-rfc8785".to_string()),
+            ),
+        ];
+        for (key, default) in defaults {
+            map.entry(key).or_insert(default);
+        }
+        map.insert(
+            "schema_version".to_string(),
+            serde_json::Value::String(Self::CURRENT_SCHEMA_VERSION.to_string()),
+        );
+
+        serde_json::from_value(serde_json::Value::Object(map)).map_err(|e| {
+            anyhow::anyhow!("receipt could not be migrated from schema_version {version}: {e}")
+        })
+    }
 }
 
 /// Error kinds for receipt error tracking
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "schemas", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 #[cfg_attr(any(test, feature = "test-utils"), derive(strum::VariantNames))]
 pub enum ErrorKind {
@@ -275,6 +441,7 @@ pub enum ErrorKind {
 
 /// Evidence of packet construction for auditability
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemas", derive(schemars::JsonSchema))]
 pub struct PacketEvidence {
     /// List of files included in the packet
     pub files: Vec<FileEvidence>,
@@ -282,10 +449,15 @@ pub struct PacketEvidence {
     pub max_bytes: usize,
     /// Maximum lines allowed in packet
     pub max_lines: usize,
+    /// Preview was written gzip-compressed as `context/<phase>-packet.txt.gz`
+    /// (`[packet] compress_preview`) instead of `context/<phase>-packet.txt`
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub preview_compressed: bool,
 }
 
 /// Evidence of a single file's inclusion in the packet
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemas", derive(schemars::JsonSchema))]
 pub struct FileEvidence {
     /// Path to the file relative to project root
     pub path: String,
@@ -295,10 +467,15 @@ pub struct FileEvidence {
     pub blake3_pre_redaction: String,
     /// Priority level of this file
     pub priority: Priority,
+    /// Included verbatim: exempted from secret scanning and redaction by
+    /// `[packet] redaction_exempt`
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub redaction_exempt: bool,
 }
 
 /// Represents a file hash in the receipt
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemas", derive(schemars::JsonSchema))]
 pub struct FileHash {
     /// Path to the file relative to the spec directory
     pub path: String,
@@ -384,6 +561,7 @@ pub struct StatusOutput {
 
 /// Doctor output structure for JSON emission (schema v1)
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemas", derive(schemars::JsonSchema))]
 pub struct DoctorOutput {
     /// Schema version for this doctor format
     pub schema_version: String,
@@ -400,6 +578,7 @@ pub struct DoctorOutput {
 
 /// Individual health check result
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemas", derive(schemars::JsonSchema))]
 pub struct DoctorCheck {
     /// Name of the check
     pub name: String,
@@ -407,10 +586,14 @@ pub struct DoctorCheck {
     pub status: CheckStatus,
     /// Details about the check result
     pub details: String,
+    /// Copy-pasteable fix for a failing or warning check, if one is known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remediation: Option<String>,
 }
 
 /// Status of a health check
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "schemas", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 #[cfg_attr(any(test, feature = "test-utils"), derive(strum::VariantNames))]
 pub enum CheckStatus {
@@ -514,6 +697,7 @@ pub struct PendingFixupsSummary {
 /// Pipeline configuration metadata (V11+)
 /// All fields are optional for backward compatibility
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemas", derive(schemars::JsonSchema))]
 pub struct PipelineInfo {
     /// Execution strategy used ("controlled" | "external_tool")
     pub execution_strategy: Option<String>,
@@ -724,6 +724,16 @@ fn generate_review_response() -> String {
 
 The specification is mostly complete but needs additional clarity on rate limiting.
 
+## Requirements Issues
+
+- Account lockout does not say how the client learns when to retry
+
+## Design Issues
+
+## Tasks Issues
+
+## Cross-Phase Issues
+
 **FIXUP PLAN:**
 
 ```diff
@@ -745,6 +755,16 @@ fn generate_review_with_fixups() -> String {
 
 The specification needs small corrections to improve clarity.
 
+## Requirements Issues
+
+## Design Issues
+
+- Rate limit responses omit retry-after information
+
+## Tasks Issues
+
+## Cross-Phase Issues
+
 **FIXUP PLAN:**
 
 ```diff
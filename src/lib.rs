@@ -225,6 +225,18 @@ pub mod error_reporter;
 #[doc(hidden)]
 pub mod tui;
 
+// Read-only HTTP status endpoint for local dashboards
+#[cfg(feature = "server")]
+pub mod server;
+
+// Metric names recorded through the `metrics` facade
+#[cfg(feature = "metrics")]
+pub use xchecker_engine::metrics;
+
+// JSON Schemas generated from the receipt and doctor output types
+#[cfg(feature = "schemas")]
+pub use xchecker_utils::schemas;
+
 // Legacy re-exports for backward compatibility (will be deprecated)
 #[doc(hidden)]
 pub use receipt::write_error_receipt_and_exit;
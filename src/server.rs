@@ -0,0 +1,247 @@
+//! Read-only HTTP status endpoint (`server` feature)
+//!
+//! Serves spec status over plain HTTP/1.1 for local dashboards:
+//!
+//! - `GET /specs` lists the spec IDs under `<home>/specs`
+//! - `GET /specs/<id>/status` returns the spec's status as canonical (JCS)
+//!   JSON matching `schemas/status.v1.json`
+//!
+//! There are no mutation endpoints: any method other than `GET` gets `405`.
+//! Status is read through [`OrchestratorHandle::readonly`], so serving never
+//! takes spec locks and never blocks a running phase.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> anyhow::Result<()> {
+//! use xchecker::server::{DEFAULT_SERVE_ADDR, serve_status};
+//!
+//! serve_status(DEFAULT_SERVE_ADDR, ".xchecker".into()).await
+//! # }
+//! ```
+
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+
+use anyhow::{Context, Result};
+use camino::{Utf8Path, Utf8PathBuf};
+use serde_json::json;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+use crate::OrchestratorHandle;
+use crate::canonicalization::emit_jcs;
+use crate::paths::with_home;
+use crate::spec_id::sanitize_spec_id;
+
+/// Default bind address: loopback only, port 7878
+pub const DEFAULT_SERVE_ADDR: SocketAddr = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 7878);
+
+/// Largest request head accepted before the connection is rejected
+const MAX_REQUEST_HEAD_BYTES: usize = 8 * 1024;
+
+/// Serve spec status for `home` on `addr` until the task is dropped.
+///
+/// # Errors
+///
+/// Returns an error if `addr` cannot be bound.
+pub async fn serve_status(addr: SocketAddr, home: Utf8PathBuf) -> Result<()> {
+    StatusServer::bind(addr, home).await?.run().await
+}
+
+/// A bound status server.
+///
+/// Binding separately from [`StatusServer::run`] lets callers bind port `0`
+/// and read the chosen port from [`StatusServer::local_addr`].
+#[derive(Debug)]
+pub struct StatusServer {
+    listener: TcpListener,
+    home: Utf8PathBuf,
+}
+
+impl StatusServer {
+    /// Bind the listener without accepting connections yet.
+    ///
+    /// Binding a non-loopback address is allowed but logged, since status
+    /// output then becomes reachable from the network.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `addr` cannot be bound.
+    pub async fn bind(addr: SocketAddr, home: Utf8PathBuf) -> Result<Self> {
+        if !addr.ip().is_loopback() {
+            tracing::warn!("Status server bound to non-loopback address {addr}");
+        }
+        let listener = TcpListener::bind(addr)
+            .await
+            .with_context(|| format!("Failed to bind status server to {addr}"))?;
+        Ok(Self { listener, home })
+    }
+
+    /// The address actually bound (resolves port `0`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the socket address cannot be read.
+    pub fn local_addr(&self) -> Result<SocketAddr> {
+        Ok(self.listener.local_addr()?)
+    }
+
+    /// Accept and answer connections until the task is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Never returns under normal operation; accept failures are logged
+    /// and skipped.
+    pub async fn run(self) -> Result<()> {
+        loop {
+            let stream = match self.listener.accept().await {
+                Ok((stream, _)) => stream,
+                Err(e) => {
+                    tracing::warn!("Status server failed to accept connection: {e}");
+                    continue;
+                }
+            };
+            let home = self.home.clone();
+            tokio::spawn(async move {
+                if let Err(e) = handle_connection(stream, home).await {
+                    tracing::debug!("Status server connection error: {e:#}");
+                }
+            });
+        }
+    }
+}
+
+/// A JSON response ready to be written
+struct Response {
+    status: u16,
+    reason: &'static str,
+    body: String,
+}
+
+impl Response {
+    fn ok(body: String) -> Self {
+        Self {
+            status: 200,
+            reason: "OK",
+            body,
+        }
+    }
+
+    fn error(status: u16, reason: &'static str, message: &str) -> Self {
+        let body = emit_jcs(&json!({ "error": message }))
+            .unwrap_or_else(|_| String::from(r#"{"error":"internal error"}"#));
+        Self {
+            status,
+            reason,
+            body,
+        }
+    }
+}
+
+async fn handle_connection(mut stream: TcpStream, home: Utf8PathBuf) -> Result<()> {
+    let response = match read_request_head(&mut stream).await? {
+        Some(head) => route(&head, home).await,
+        None => Response::error(400, "Bad Request", "malformed request"),
+    };
+
+    let mut out = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n",
+        response.status,
+        response.reason,
+        response.body.len()
+    );
+    if response.status == 405 {
+        out.push_str("Allow: GET\r\n");
+    }
+    out.push_str("\r\n");
+    out.push_str(&response.body);
+
+    stream.write_all(out.as_bytes()).await?;
+    stream.shutdown().await?;
+    Ok(())
+}
+
+/// Read up to the blank line ending the request head
+///
+/// Returns `None` if the head is oversized, not UTF-8 or the peer closes early.
+async fn read_request_head(stream: &mut TcpStream) -> Result<Option<String>> {
+    let mut head = Vec::new();
+    let mut buf = [0u8; 1024];
+    while !head.windows(4).any(|w| w == b"\r\n\r\n") {
+        let n = stream.read(&mut buf).await?;
+        if n == 0 || head.len() + n > MAX_REQUEST_HEAD_BYTES {
+            return Ok(None);
+        }
+        head.extend_from_slice(&buf[..n]);
+    }
+    Ok(String::from_utf8(head).ok())
+}
+
+async fn route(head: &str, home: Utf8PathBuf) -> Response {
+    let mut request_line = head.lines().next().unwrap_or_default().split_whitespace();
+    let (Some(method), Some(target)) = (request_line.next(), request_line.next()) else {
+        return Response::error(400, "Bad Request", "malformed request line");
+    };
+    if method != "GET" {
+        return Response::error(405, "Method Not Allowed", "status server is read-only");
+    }
+
+    let path = target.split('?').next().unwrap_or_default();
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    let result = match segments.as_slice() {
+        ["specs"] => list_specs(&home).and_then(|specs| emit_jcs(&json!({ "specs": specs }))),
+        ["specs", id, "status"] => return spec_status(id, home).await,
+        _ => return Response::error(404, "Not Found", "no such endpoint"),
+    };
+
+    match result {
+        Ok(body) => Response::ok(body),
+        Err(e) => Response::error(500, "Internal Server Error", &format!("{e:#}")),
+    }
+}
+
+async fn spec_status(id: &str, home: Utf8PathBuf) -> Response {
+    if sanitize_spec_id(id).ok().as_deref() != Some(id) {
+        return Response::error(400, "Bad Request", "invalid spec id");
+    }
+    if !home.join("specs").join(id).is_dir() {
+        return Response::error(404, "Not Found", "no such spec");
+    }
+
+    let id = id.to_string();
+    let status = tokio::task::spawn_blocking(move || {
+        with_home(&home, || -> Result<String> {
+            let handle = OrchestratorHandle::readonly(&id)?;
+            emit_jcs(&handle.status()?)
+        })
+    })
+    .await;
+
+    match status {
+        Ok(Ok(body)) => Response::ok(body),
+        Ok(Err(e)) => Response::error(500, "Internal Server Error", &format!("{e:#}")),
+        Err(e) => Response::error(500, "Internal Server Error", &e.to_string()),
+    }
+}
+
+/// Spec IDs under `<home>/specs`, sorted; empty if the directory is missing
+fn list_specs(home: &Utf8Path) -> Result<Vec<String>> {
+    let specs_dir = home.join("specs");
+    if !specs_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut specs = Vec::new();
+    for entry in specs_dir
+        .read_dir_utf8()
+        .with_context(|| format!("Failed to read {specs_dir}"))?
+    {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            specs.push(entry.file_name().to_string());
+        }
+    }
+    specs.sort();
+    Ok(specs)
+}
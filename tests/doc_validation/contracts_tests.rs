@@ -112,6 +112,7 @@ mod tests {
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            preview_compressed: false,
         };
 
         let fixed_timestamp = chrono::DateTime::parse_from_rfc3339("2025-01-01T00:00:00Z")
@@ -298,11 +299,13 @@ mod tests {
                 name: "zebra_check".to_string(),
                 status: CheckStatus::Pass,
                 details: "test".to_string(),
+                remediation: None,
             },
             DoctorCheck {
                 name: "alpha_check".to_string(),
                 status: CheckStatus::Pass,
                 details: "test".to_string(),
+                remediation: None,
             },
         ];
 
@@ -311,11 +314,13 @@ mod tests {
                 name: "alpha_check".to_string(),
                 status: CheckStatus::Pass,
                 details: "test".to_string(),
+                remediation: None,
             },
             DoctorCheck {
                 name: "zebra_check".to_string(),
                 status: CheckStatus::Pass,
                 details: "test".to_string(),
+                remediation: None,
             },
         ];
 
@@ -458,6 +463,7 @@ mod tests {
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            preview_compressed: false,
         };
 
         let receipt = manager.create_receipt(
@@ -527,16 +533,19 @@ mod tests {
                 name: "zebra_check".to_string(),
                 status: CheckStatus::Pass,
                 details: "test".to_string(),
+                remediation: None,
             },
             DoctorCheck {
                 name: "alpha_check".to_string(),
                 status: CheckStatus::Pass,
                 details: "test".to_string(),
+                remediation: None,
             },
             DoctorCheck {
                 name: "middle_check".to_string(),
                 status: CheckStatus::Pass,
                 details: "test".to_string(),
+                remediation: None,
             },
         ];
 
@@ -122,6 +122,7 @@ async fn test_m1_gate_validation_summary() -> Result<()> {
             files: Vec::new(),
             max_bytes: 65536,
             max_lines: 1200,
+            preview_compressed: false,
         },
         outputs: vec![
             FileHash {
@@ -144,6 +145,12 @@ async fn test_m1_gate_validation_summary() -> Result<()> {
 
         llm: None,
         pipeline: None,
+        validation_degraded: None,
+        validation_errors: Vec::new(),
+        input_fingerprint: None,
+        packet_hash: None,
+        duration_ms: None,
+        timings: None,
     };
 
     // Test JSON serialization/deserialization
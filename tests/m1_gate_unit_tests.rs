@@ -242,6 +242,7 @@ async fn test_m1_gate_receipt_structure() -> Result<()> {
             files: Vec::new(),
             max_bytes: 65536,
             max_lines: 1200,
+            preview_compressed: false,
         },
         outputs: vec![
             FileHash {
@@ -263,6 +264,12 @@ async fn test_m1_gate_receipt_structure() -> Result<()> {
         diff_context: None,
         llm: None,
         pipeline: None,
+        validation_degraded: None,
+        validation_errors: Vec::new(),
+        input_fingerprint: None,
+        packet_hash: None,
+        duration_ms: None,
+        timings: None,
     };
 
     // Validate receipt structure (R2.1)
@@ -52,6 +52,7 @@ fn test_receipt_jcs_emission() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let receipt = manager.create_receipt(
@@ -202,6 +203,7 @@ fn test_arrays_sorted_before_emission() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let receipt = manager.create_receipt(
@@ -480,6 +482,7 @@ fn test_different_insertion_orders_produce_identical_json() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     // Use a fixed timestamp for both receipts
@@ -365,6 +365,7 @@ fn test_file_locking_prevents_concurrent_execution() -> Result<()> {
         created_at: 0, // Very old timestamp (1970)
         spec_id: spec_id.to_string(),
         xchecker_version: "0.1.0".to_string(),
+        hostname: String::new(),
     };
 
     let lock_file_path = lock_path.join(".lock");
@@ -682,6 +682,8 @@ fn prop_doctor_never_triggers_llm_completions_for_cli_providers() {
                         check.name == "write_permissions" ||
                         check.name == "atomic_rename" ||
                         check.name == "config_parse" ||
+                        check.name == "secret_patterns" ||
+                        check.name == "model_name" ||
                         check.name == "llm_provider",
                         "Check name '{}' should be a standard validation check, not an LLM invocation",
                         check.name
@@ -940,6 +942,8 @@ fn prop_doctor_never_triggers_llm_completions_for_gemini_cli() {
                         check.name == "write_permissions" ||
                         check.name == "atomic_rename" ||
                         check.name == "config_parse" ||
+                        check.name == "secret_patterns" ||
+                        check.name == "model_name" ||
                         check.name == "llm_provider",
                         "Check name '{}' should be a standard validation check, not an LLM invocation",
                         check.name
@@ -2840,6 +2844,7 @@ fn prop_receipt_creation_applies_redaction() {
                 files: vec![],
                 max_bytes: 100000,
                 max_lines: 1000,
+                preview_compressed: false,
             },
             Some(stderr_with_secret.clone()), // stderr_tail
             Some(stderr_with_secret.clone()), // stderr_redacted
@@ -0,0 +1,114 @@
+//! Tests for `OrchestratorHandle::with_audit_log`
+//!
+//! Each phase appends one JSON line to the audit log, and a later handle
+//! configured with the same path keeps appending to it. Phases that abort
+//! are recorded too, and a failed append fails the run.
+
+use xchecker::OrchestratorHandle;
+use xchecker::orchestrator::{AUDIT_SCHEMA_VERSION, AuditEntry};
+use xchecker::types::{ErrorKind, PhaseId};
+
+#[tokio::test]
+async fn test_audit_log_records_each_phase_across_resume() {
+    let _home = xchecker::paths::with_isolated_home();
+    let spec_id = "audit-log-two-phases";
+
+    let mut handle = OrchestratorHandle::with_force(spec_id, true)
+        .unwrap()
+        .with_audit_log("audit.jsonl");
+    handle.set_dry_run(true);
+    let audit_path = handle.audit_log_path().unwrap().to_owned();
+    assert_eq!(
+        audit_path,
+        handle.artifact_manager().base_path().join("audit.jsonl")
+    );
+
+    handle.run_phase(PhaseId::Requirements).await.unwrap();
+    drop(handle);
+
+    // Resume on a fresh handle; the trail continues in the same file
+    let mut handle = OrchestratorHandle::with_force(spec_id, true)
+        .unwrap()
+        .with_audit_log("audit.jsonl");
+    handle.set_dry_run(true);
+    handle.run_phase(PhaseId::Design).await.unwrap();
+
+    let content = std::fs::read_to_string(&audit_path).unwrap();
+    let entries: Vec<AuditEntry> = content
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("each line is a JSON object"))
+        .collect();
+
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].phase, "requirements");
+    assert_eq!(entries[1].phase, "design");
+    for entry in &entries {
+        assert_eq!(entry.schema_version, AUDIT_SCHEMA_VERSION);
+        assert_eq!(entry.spec_id, spec_id);
+        assert!(entry.success);
+        assert_eq!(entry.exit_code, 0);
+        assert!(entry.started_at <= entry.finished_at);
+        assert!(entry.packet_hash.is_some());
+        assert!(!entry.artifacts.is_empty());
+    }
+    assert!(entries[0].finished_at <= entries[1].started_at);
+    assert!(
+        entries[1]
+            .artifacts
+            .iter()
+            .any(|artifact| artifact.path.ends_with("10-design.md"))
+    );
+}
+
+#[test]
+fn test_audit_log_is_off_by_default() {
+    let _home = xchecker::paths::with_isolated_home();
+
+    let handle = OrchestratorHandle::with_force("audit-log-off", true).unwrap();
+    assert!(handle.audit_log_path().is_none());
+}
+
+#[tokio::test]
+async fn test_audit_log_records_phase_that_aborts_with_error() {
+    let _home = xchecker::paths::with_isolated_home();
+    let fixtures = tempfile::TempDir::new().unwrap();
+
+    let mut handle = OrchestratorHandle::with_force("audit-log-phase-error", true)
+        .unwrap()
+        .with_audit_log("audit.jsonl");
+    // An empty fixture directory makes the phase abort before any receipt
+    handle.set_config("fixture_dir", fixtures.path().to_str().unwrap());
+    let audit_path = handle.audit_log_path().unwrap().to_owned();
+
+    assert!(handle.run_phase(PhaseId::Requirements).await.is_err());
+
+    let content = std::fs::read_to_string(&audit_path).unwrap();
+    let entries: Vec<AuditEntry> = content
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].phase, "requirements");
+    assert!(!entries[0].success);
+    assert_eq!(entries[0].exit_code, 2);
+    assert_eq!(entries[0].error_kind, Some(ErrorKind::CliArgs));
+    assert!(entries[0].artifacts.is_empty());
+}
+
+#[tokio::test]
+async fn test_audit_log_append_failure_fails_the_run() {
+    let _home = xchecker::paths::with_isolated_home();
+
+    let mut handle = OrchestratorHandle::with_force("audit-log-unwritable", true)
+        .unwrap()
+        .with_audit_log("audit.jsonl");
+    handle.set_dry_run(true);
+    // A directory in place of the log file makes every append fail
+    std::fs::create_dir_all(handle.audit_log_path().unwrap()).unwrap();
+
+    let error = handle.run_phase(PhaseId::Requirements).await.unwrap_err();
+    assert!(
+        format!("{error:#}").contains("Failed to append audit entry"),
+        "{error:#}"
+    );
+}
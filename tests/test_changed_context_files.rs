@@ -0,0 +1,205 @@
+//! Tests for `OrchestratorHandle::changed_context_files`
+//!
+//! The last receipt's packet evidence records a pre-redaction hash per
+//! context file; only files whose current content differs should be reported.
+//! Failed receipts and phases that do not pack context files are ignored.
+
+use std::collections::HashMap;
+
+use camino::{Utf8Path, Utf8PathBuf};
+use xchecker::receipt::ReceiptManager;
+use xchecker::types::{FileEvidence, PacketEvidence, Priority};
+use xchecker::{OrchestratorHandle, PhaseId};
+
+fn evidence_for(path: &Utf8Path) -> FileEvidence {
+    let content = std::fs::read(path).unwrap();
+    FileEvidence {
+        path: path.to_string(),
+        range: None,
+        blake3_pre_redaction: blake3::hash(&content).to_hex().to_string(),
+        priority: Priority::Medium,
+        redaction_exempt: false,
+    }
+}
+
+fn seed_receipt(receipts: &ReceiptManager, spec_id: &str, files: Vec<FileEvidence>) {
+    seed_phase_receipt(receipts, spec_id, PhaseId::Requirements, 0, files);
+}
+
+fn seed_phase_receipt(
+    receipts: &ReceiptManager,
+    spec_id: &str,
+    phase: PhaseId,
+    exit_code: i32,
+    files: Vec<FileEvidence>,
+) {
+    let packet = PacketEvidence {
+        files,
+        max_bytes: 65536,
+        max_lines: 1200,
+        preview_compressed: false,
+    };
+    let receipt = receipts.create_receipt(
+        spec_id,
+        phase,
+        exit_code,
+        vec![],
+        "0.1.0",
+        "0.8.1",
+        "haiku",
+        None,
+        HashMap::new(),
+        packet,
+        None,
+        None,
+        vec![],
+        None,
+        "native",
+        None,
+        None,
+        None,
+        None, // diff_context
+        None, // pipeline
+    );
+    receipts.write_receipt(&receipt).unwrap();
+}
+
+/// Write context files under the spec directory and return their paths
+fn write_context(handle: &OrchestratorHandle, files: &[(&str, &str)]) -> Vec<Utf8PathBuf> {
+    let base = handle.artifact_manager().base_path().clone();
+    files
+        .iter()
+        .map(|(name, content)| {
+            let path = base.join(name);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(&path, content).unwrap();
+            path
+        })
+        .collect()
+}
+
+#[test]
+fn test_changed_context_files_reports_only_modified_file() {
+    let _home = xchecker::paths::with_isolated_home();
+    let handle = OrchestratorHandle::with_force("changed-context-modified", true).unwrap();
+
+    let paths = write_context(
+        &handle,
+        &[
+            ("notes/a.core.yaml", "id: A\n"),
+            ("notes/b.core.yaml", "id: B\n"),
+            ("notes/c.core.yaml", "id: C\n"),
+        ],
+    );
+    seed_receipt(
+        handle.receipt_manager(),
+        handle.spec_id(),
+        paths.iter().map(|path| evidence_for(path)).collect(),
+    );
+    assert!(handle.changed_context_files().unwrap().is_empty());
+
+    std::fs::write(&paths[1], "id: B2\n").unwrap();
+
+    assert_eq!(
+        handle.changed_context_files().unwrap(),
+        vec![paths[1].clone()]
+    );
+}
+
+#[test]
+fn test_changed_context_files_reports_added_and_removed_files() {
+    let _home = xchecker::paths::with_isolated_home();
+    let handle = OrchestratorHandle::with_force("changed-context-added", true).unwrap();
+
+    let paths = write_context(
+        &handle,
+        &[
+            ("notes/kept.core.yaml", "kept\n"),
+            ("notes/gone.core.yaml", "gone\n"),
+        ],
+    );
+    seed_receipt(
+        handle.receipt_manager(),
+        handle.spec_id(),
+        paths.iter().map(|path| evidence_for(path)).collect(),
+    );
+
+    std::fs::remove_file(&paths[1]).unwrap();
+    let added = write_context(&handle, &[("notes/new.core.yaml", "new\n")]);
+
+    let mut expected = vec![paths[1].clone(), added[0].clone()];
+    expected.sort();
+    assert_eq!(handle.changed_context_files().unwrap(), expected);
+}
+
+#[test]
+fn test_changed_context_files_without_receipts_reports_all_candidates() {
+    let _home = xchecker::paths::with_isolated_home();
+    let handle = OrchestratorHandle::with_force("changed-context-fresh", true).unwrap();
+
+    let paths = write_context(&handle, &[("notes/only.core.yaml", "only\n")]);
+
+    assert_eq!(handle.changed_context_files().unwrap(), paths);
+}
+
+#[test]
+fn test_changed_context_files_ignores_failed_and_review_receipts() {
+    let _home = xchecker::paths::with_isolated_home();
+    let handle = OrchestratorHandle::with_force("changed-context-latest", true).unwrap();
+
+    let paths = write_context(&handle, &[("notes/a.core.yaml", "id: A\n")]);
+    seed_receipt(
+        handle.receipt_manager(),
+        handle.spec_id(),
+        paths.iter().map(|path| evidence_for(path)).collect(),
+    );
+    // Receipts are ordered by emission time
+    std::thread::sleep(std::time::Duration::from_millis(10));
+    seed_phase_receipt(
+        handle.receipt_manager(),
+        handle.spec_id(),
+        PhaseId::Design,
+        70,
+        vec![],
+    );
+    std::thread::sleep(std::time::Duration::from_millis(10));
+    seed_phase_receipt(
+        handle.receipt_manager(),
+        handle.spec_id(),
+        PhaseId::Review,
+        0,
+        vec![],
+    );
+
+    assert!(handle.changed_context_files().unwrap().is_empty());
+}
+
+#[test]
+fn test_changed_context_files_normalizes_recorded_paths() {
+    let _home = xchecker::paths::with_isolated_home();
+    let handle = OrchestratorHandle::with_force("changed-context-paths", true).unwrap();
+
+    let paths = write_context(
+        &handle,
+        &[
+            ("notes/a.core.yaml", "id: A\n"),
+            ("notes/b.core.yaml", "id: B\n"),
+        ],
+    );
+    // Evidence recorded relative to the spec root and with a `.` component
+    let mut relative = evidence_for(&paths[0]);
+    relative.path = "notes/a.core.yaml".to_string();
+    let mut dotted = evidence_for(&paths[1]);
+    dotted.path = handle
+        .artifact_manager()
+        .base_path()
+        .join("./notes/b.core.yaml")
+        .to_string();
+    seed_receipt(
+        handle.receipt_manager(),
+        handle.spec_id(),
+        vec![relative, dotted],
+    );
+
+    assert!(handle.changed_context_files().unwrap().is_empty());
+}
@@ -314,6 +314,7 @@ fn test_cli_flags_override_config() -> Result<()> {
     // Create CLI args with overrides
     let cli_args = CliArgs {
         config_path: None,
+        profile: None,
         model: Some("opus".to_string()),
         max_turns: Some(10),
         packet_max_bytes: Some(100000),
@@ -461,6 +462,7 @@ fn test_new_flags_in_config_system() -> Result<()> {
     // Create CLI args with new flags
     let cli_args = CliArgs {
         config_path: None,
+        profile: None,
         model: None,
         max_turns: None,
         packet_max_bytes: None,
@@ -195,6 +195,7 @@ fn test_json_receipt_written_with_lf() -> Result<()> {
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            preview_compressed: false,
         },
         outputs: vec![],
         exit_code: 0,
@@ -208,6 +209,12 @@ fn test_json_receipt_written_with_lf() -> Result<()> {
 
         llm: None,
         pipeline: None,
+        validation_degraded: None,
+        validation_errors: Vec::new(),
+        input_fingerprint: None,
+        packet_hash: None,
+        duration_ms: None,
+        timings: None,
     };
 
     // Write receipt
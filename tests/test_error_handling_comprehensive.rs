@@ -301,6 +301,7 @@ fn test_error_receipts_contain_required_fields() {
                 files: vec![],
                 max_bytes: 65536,
                 max_lines: 1200,
+                preview_compressed: false,
             },
             None,     // stderr_tail
             None,     // stderr_redacted
@@ -412,6 +413,7 @@ fn test_exit_code_matches_receipt_field() {
                 files: vec![],
                 max_bytes: 65536,
                 max_lines: 1200,
+                preview_compressed: false,
             },
             None,     // stderr_tail
             None,     // stderr_redacted
@@ -579,6 +581,7 @@ fn test_all_error_paths_write_receipts() {
                 files: vec![],
                 max_bytes: 65536,
                 max_lines: 1200,
+                preview_compressed: false,
             },
             None,     // stderr_tail
             None,     // stderr_redacted
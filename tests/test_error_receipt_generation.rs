@@ -65,6 +65,7 @@ fn test_cli_args_error_mapping() {
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            preview_compressed: false,
         },
         None,     // stderr_tail
         None,     // stderr_redacted
@@ -113,6 +114,7 @@ fn test_packet_overflow_error_mapping() {
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            preview_compressed: false,
         },
         None,     // stderr_tail
         None,     // stderr_redacted
@@ -160,6 +162,7 @@ fn test_secret_detected_error_mapping() {
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            preview_compressed: false,
         },
         None,     // stderr_tail
         None,     // stderr_redacted
@@ -216,6 +219,7 @@ fn test_lock_held_error_mapping() {
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            preview_compressed: false,
         },
         None,     // stderr_tail
         None,     // stderr_redacted
@@ -261,6 +265,7 @@ fn test_phase_timeout_error_mapping() {
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            preview_compressed: false,
         },
         None,     // stderr_tail
         None,     // stderr_redacted
@@ -315,6 +320,7 @@ fn test_claude_failure_error_mapping() {
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            preview_compressed: false,
         },
         None,     // stderr_tail
         None,     // stderr_redacted
@@ -361,6 +367,7 @@ fn test_unknown_error_mapping() {
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            preview_compressed: false,
         },
         None,     // stderr_tail
         None,     // stderr_redacted
@@ -428,6 +435,7 @@ fn test_error_receipts_contain_required_fields() {
                 files: vec![],
                 max_bytes: 65536,
                 max_lines: 1200,
+                preview_compressed: false,
             },
             None,     // stderr_tail
             None,     // stderr_redacted
@@ -543,6 +551,7 @@ fn test_exit_code_matches_receipt_field() {
                 files: vec![],
                 max_bytes: 65536,
                 max_lines: 1200,
+                preview_compressed: false,
             },
             None,     // stderr_tail
             None,     // stderr_redacted
@@ -588,6 +597,7 @@ fn test_receipt_written_on_error_path() {
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            preview_compressed: false,
         },
         None,     // stderr_tail
         None,     // stderr_redacted
@@ -637,6 +647,7 @@ fn test_error_receipts_have_no_outputs() {
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            preview_compressed: false,
         },
         None,     // stderr_tail
         None,     // stderr_redacted
@@ -681,6 +692,7 @@ fn test_invalid_transition_maps_to_cli_args() {
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            preview_compressed: false,
         },
         None,     // stderr_tail
         None,     // stderr_redacted
@@ -725,6 +737,7 @@ fn test_dependency_not_satisfied_maps_to_cli_args() {
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            preview_compressed: false,
         },
         None,     // stderr_tail
         None,     // stderr_redacted
@@ -765,6 +778,7 @@ fn test_error_receipts_include_standard_fields() {
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            preview_compressed: false,
         }, // packet
         Some("stderr output".to_string()), // stderr_tail
         None,                       // stderr_redacted
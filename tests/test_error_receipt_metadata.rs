@@ -170,6 +170,7 @@ fn test_error_receipt_packet_overflow() -> Result<()> {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let pipeline = Some(xchecker::types::PipelineInfo {
@@ -283,6 +284,7 @@ fn test_error_receipt_secret_detected() -> Result<()> {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let pipeline = Some(xchecker::types::PipelineInfo {
@@ -380,6 +382,7 @@ fn test_error_receipt_lock_held() -> Result<()> {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let pipeline = Some(xchecker::types::PipelineInfo {
@@ -466,6 +469,7 @@ fn test_error_receipt_claude_failure() -> Result<()> {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let pipeline = Some(xchecker::types::PipelineInfo {
@@ -563,6 +567,7 @@ fn test_error_receipt_unknown_error() -> Result<()> {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let pipeline = Some(xchecker::types::PipelineInfo {
@@ -649,6 +654,7 @@ fn test_error_receipt_write_and_read() -> Result<()> {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let pipeline = Some(xchecker::types::PipelineInfo {
@@ -737,16 +743,19 @@ fn test_error_receipt_preserves_packet_evidence() -> Result<()> {
                 range: Some("L1-L100".to_string()),
                 blake3_pre_redaction: "abc123".to_string(),
                 priority: xchecker::types::Priority::High,
+                redaction_exempt: false,
             },
             xchecker::types::FileEvidence {
                 path: "requirements.yaml".to_string(),
                 range: None,
                 blake3_pre_redaction: "def456".to_string(),
                 priority: xchecker::types::Priority::Upstream,
+                redaction_exempt: false,
             },
         ],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let pipeline = Some(xchecker::types::PipelineInfo {
@@ -812,6 +821,7 @@ fn test_error_receipt_json_structure() -> Result<()> {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let pipeline = Some(xchecker::types::PipelineInfo {
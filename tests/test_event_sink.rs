@@ -0,0 +1,99 @@
+//! Tests for `OrchestratorHandle::with_event_sink`
+//!
+//! A dry-run of a single phase must stream one valid JSON object per line,
+//! in lifecycle order, each stamped with the event schema version. A phase
+//! that aborts with an error still reports its completion.
+
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+
+use xchecker::{OrchestratorHandle, PhaseId};
+
+#[derive(Clone, Default)]
+struct SharedBuf(Arc<Mutex<Vec<u8>>>);
+
+impl Write for SharedBuf {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_dry_run_emits_lifecycle_events_as_ndjson() {
+    let _home = xchecker::paths::with_isolated_home();
+    let buf = SharedBuf::default();
+
+    let mut handle = OrchestratorHandle::with_force("event-sink", true)
+        .unwrap()
+        .with_event_sink(buf.clone());
+    handle.set_dry_run(true);
+    let result = handle.run_phase(PhaseId::Requirements).await.unwrap();
+    assert!(result.success);
+
+    let output = String::from_utf8(buf.0.lock().unwrap().clone()).unwrap();
+    let events: Vec<serde_json::Value> = output
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("each line is valid JSON"))
+        .collect();
+
+    let names: Vec<&str> = events
+        .iter()
+        .map(|event| event["event"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        names,
+        [
+            "phase_started",
+            "packet_built",
+            "llm_called",
+            "phase_completed",
+            "run_finished"
+        ]
+    );
+
+    for event in &events {
+        assert_eq!(event["schema_version"], "1");
+        assert_eq!(event["spec_id"], "event-sink");
+        assert!(event["emitted_at"].is_string());
+    }
+    assert_eq!(events[0]["phase"], "requirements");
+    assert_eq!(events[2]["dry_run"], true);
+    assert_eq!(events[4]["success"], true);
+    assert_eq!(events[4]["exit_code"], 0);
+}
+
+#[tokio::test]
+async fn test_failing_phase_still_emits_phase_completed() {
+    let _home = xchecker::paths::with_isolated_home();
+    let buf = SharedBuf::default();
+    let fixtures = tempfile::TempDir::new().unwrap();
+
+    let mut handle = OrchestratorHandle::with_force("event-sink-failure", true)
+        .unwrap()
+        .with_event_sink(buf.clone());
+    // An empty fixture directory makes the phase abort with an error
+    handle.set_config("fixture_dir", fixtures.path().to_str().unwrap());
+    assert!(handle.run_phase(PhaseId::Requirements).await.is_err());
+
+    let output = String::from_utf8(buf.0.lock().unwrap().clone()).unwrap();
+    let events: Vec<serde_json::Value> = output
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("each line is valid JSON"))
+        .collect();
+
+    let completed = events
+        .iter()
+        .find(|event| event["event"] == "phase_completed")
+        .expect("phase_completed is emitted for a failing phase");
+    assert_eq!(completed["phase"], "requirements");
+    assert_eq!(completed["success"], false);
+    assert_eq!(completed["exit_code"], 2);
+
+    let last = events.last().unwrap();
+    assert_eq!(last["event"], "run_finished");
+    assert_eq!(last["success"], false);
+}
@@ -42,6 +42,7 @@ fn test_generated_receipt_validates_against_schema() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let receipt = manager.create_receipt(
@@ -167,16 +168,19 @@ fn test_generated_doctor_validates_against_schema() {
             name: "claude_path".to_string(),
             status: CheckStatus::Pass,
             details: "Found claude at /usr/local/bin/claude".to_string(),
+            remediation: None,
         },
         DoctorCheck {
             name: "claude_version".to_string(),
             status: CheckStatus::Pass,
             details: "0.8.1".to_string(),
+            remediation: None,
         },
         DoctorCheck {
             name: "wsl_availability".to_string(),
             status: CheckStatus::Warn,
             details: "WSL not installed (Windows only)".to_string(),
+            remediation: None,
         },
     ];
 
@@ -238,6 +242,7 @@ fn test_generated_outputs_have_sorted_arrays() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let receipt = manager.create_receipt(
@@ -340,16 +345,19 @@ fn test_generated_outputs_have_sorted_arrays() {
             name: "wsl_availability".to_string(),
             status: CheckStatus::Warn,
             details: "WSL not installed".to_string(),
+            remediation: None,
         },
         DoctorCheck {
             name: "claude_path".to_string(),
             status: CheckStatus::Pass,
             details: "Found claude".to_string(),
+            remediation: None,
         },
         DoctorCheck {
             name: "claude_version".to_string(),
             status: CheckStatus::Pass,
             details: "0.8.1".to_string(),
+            remediation: None,
         },
     ];
 
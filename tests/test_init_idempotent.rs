@@ -0,0 +1,53 @@
+//! Tests for `OrchestratorHandle::init` and `init_with_overwrite`
+//!
+//! Initializing an existing spec must never delete user files; it either
+//! fills in missing standard directories or refuses with `SpecExists`.
+
+use xchecker::OrchestratorHandle;
+use xchecker::XCheckerError;
+
+#[test]
+fn test_init_twice_preserves_user_files() {
+    let _home = xchecker::paths::with_isolated_home();
+    let context = {
+        let handle = OrchestratorHandle::init("init-idempotent").unwrap();
+        handle.artifact_manager().context_path()
+    };
+    std::fs::write(context.join("notes.md"), "keep me").unwrap();
+
+    let handle = OrchestratorHandle::init("init-idempotent").unwrap();
+    assert_eq!(
+        std::fs::read_to_string(handle.artifact_manager().context_path().join("notes.md")).unwrap(),
+        "keep me"
+    );
+}
+
+#[test]
+fn test_init_without_overwrite_rejects_existing_spec() {
+    let _home = xchecker::paths::with_isolated_home();
+    drop(OrchestratorHandle::init("init-existing").unwrap());
+
+    let Err(err) = OrchestratorHandle::init_with_overwrite("init-existing", false) else {
+        panic!("init over an existing spec should fail without overwrite");
+    };
+    assert!(
+        matches!(&err, XCheckerError::SpecExists { id } if id == "init-existing"),
+        "{err}"
+    );
+}
+
+#[test]
+fn test_init_fills_in_missing_standard_dirs() {
+    let _home = xchecker::paths::with_isolated_home();
+    let (receipts, context) = {
+        let handle = OrchestratorHandle::init("init-missing-dirs").unwrap();
+        let manager = handle.artifact_manager();
+        (manager.receipts_path(), manager.context_path())
+    };
+    std::fs::remove_dir_all(&receipts).unwrap();
+    std::fs::remove_dir_all(&context).unwrap();
+
+    drop(OrchestratorHandle::init_with_overwrite("init-missing-dirs", true).unwrap());
+    assert!(receipts.is_dir());
+    assert!(context.is_dir());
+}
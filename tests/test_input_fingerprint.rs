@@ -0,0 +1,82 @@
+//! Tests for `OrchestratorHandle::input_fingerprint`
+//!
+//! Identical inputs must share a fingerprint, the fingerprint must be recorded
+//! in the phase receipt, and changing the model or a context file must change it.
+
+use anyhow::Result;
+use xchecker::OrchestratorHandle;
+use xchecker::paths::{spec_root, with_isolated_home};
+use xchecker::types::PhaseId;
+
+/// Handle for `spec_id` with one context file the default selectors pick up
+fn handle_with_context(spec_id: &str, context: &str) -> Result<OrchestratorHandle> {
+    let mut handle = OrchestratorHandle::with_force(spec_id, true)?;
+    handle.set_dry_run(true);
+    handle.set_config("problem_statement", "Fingerprint every run");
+    let notes = spec_root(spec_id).join("notes");
+    std::fs::create_dir_all(&notes)?;
+    std::fs::write(notes.join("project.core.yaml"), context)?;
+    Ok(handle)
+}
+
+#[tokio::test]
+async fn test_identical_inputs_share_recorded_fingerprint() -> Result<()> {
+    let _home = with_isolated_home();
+
+    let mut first = handle_with_context("fingerprint-first", "name: project\n")?;
+    let mut second = handle_with_context("fingerprint-second", "name: project\n")?;
+
+    let before = first.input_fingerprint(PhaseId::Requirements)?;
+    assert_eq!(before.len(), 64, "expected a hex BLAKE3 digest");
+    assert_eq!(before, first.input_fingerprint(PhaseId::Requirements)?);
+    assert_eq!(before, second.input_fingerprint(PhaseId::Requirements)?);
+
+    let first_receipt = first.run_phase_collect(PhaseId::Requirements).await?;
+    let second_receipt = second.run_phase_collect(PhaseId::Requirements).await?;
+    assert_eq!(first_receipt.input_fingerprint.as_deref(), Some(&*before));
+    assert_eq!(
+        second_receipt.input_fingerprint,
+        first_receipt.input_fingerprint
+    );
+    Ok(())
+}
+
+#[test]
+fn test_fingerprint_changes_with_model() -> Result<()> {
+    let _home = with_isolated_home();
+
+    let mut handle = handle_with_context("fingerprint-model", "name: project\n")?;
+    let original = handle.input_fingerprint(PhaseId::Requirements)?;
+
+    handle.set_config("model", "sonnet");
+    assert_ne!(original, handle.input_fingerprint(PhaseId::Requirements)?);
+    Ok(())
+}
+
+#[test]
+fn test_fingerprint_changes_with_context_file() -> Result<()> {
+    let _home = with_isolated_home();
+    let spec_id = "fingerprint-context";
+
+    let handle = handle_with_context(spec_id, "name: project\n")?;
+    let original = handle.input_fingerprint(PhaseId::Requirements)?;
+
+    std::fs::write(
+        spec_root(spec_id).join("notes/project.core.yaml"),
+        "name: project-revised\n",
+    )?;
+    assert_ne!(original, handle.input_fingerprint(PhaseId::Requirements)?);
+    Ok(())
+}
+
+#[test]
+fn test_fingerprint_differs_between_phases() -> Result<()> {
+    let _home = with_isolated_home();
+
+    let handle = handle_with_context("fingerprint-phases", "name: project\n")?;
+    assert_ne!(
+        handle.input_fingerprint(PhaseId::Requirements)?,
+        handle.input_fingerprint(PhaseId::Design)?
+    );
+    Ok(())
+}
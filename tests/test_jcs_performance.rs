@@ -117,36 +117,43 @@ fn create_typical_doctor() -> DoctorOutput {
             name: "atomic_rename".to_string(),
             status: CheckStatus::Pass,
             details: "Atomic rename test passed".to_string(),
+            remediation: None,
         },
         DoctorCheck {
             name: "claude_path".to_string(),
             status: CheckStatus::Pass,
             details: "Found claude at /usr/local/bin/claude".to_string(),
+            remediation: None,
         },
         DoctorCheck {
             name: "claude_version".to_string(),
             status: CheckStatus::Pass,
             details: "Claude CLI version 0.8.5".to_string(),
+            remediation: None,
         },
         DoctorCheck {
             name: "config_parse".to_string(),
             status: CheckStatus::Pass,
             details: "Configuration parsed successfully".to_string(),
+            remediation: None,
         },
         DoctorCheck {
             name: "runner_selection".to_string(),
             status: CheckStatus::Pass,
             details: "Runner mode: native".to_string(),
+            remediation: None,
         },
         DoctorCheck {
             name: "write_permissions".to_string(),
             status: CheckStatus::Pass,
             details: "Write permissions OK".to_string(),
+            remediation: None,
         },
         DoctorCheck {
             name: "wsl_availability".to_string(),
             status: CheckStatus::Warn,
             details: "WSL not available (not on Windows)".to_string(),
+            remediation: None,
         },
     ];
 
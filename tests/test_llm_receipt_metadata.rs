@@ -32,6 +32,7 @@ fn test_execution_strategy_in_receipt_controlled() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let pipeline = Some(PipelineInfo {
@@ -84,6 +85,7 @@ fn test_execution_strategy_optional_for_backward_compat() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let receipt = manager.create_receipt(
@@ -127,6 +129,7 @@ fn test_llm_metadata_in_receipt() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let mut receipt = manager.create_receipt(
@@ -561,6 +564,7 @@ fn test_complete_provider_metadata_in_receipt() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     // Create a receipt
@@ -674,6 +678,7 @@ mod property_tests {
                 files: vec![],
                 max_bytes: 65536,
                 max_lines: 1200,
+                preview_compressed: false,
             };
 
             let pipeline = strategy.map(|s| PipelineInfo {
@@ -744,6 +749,7 @@ mod property_tests {
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            preview_compressed: false,
         };
 
         let pipeline = Some(PipelineInfo {
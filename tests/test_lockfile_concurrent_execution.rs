@@ -259,6 +259,7 @@ fn test_stale_lock_detection_and_force_override() -> Result<()> {
         created_at: 0, // Very old timestamp
         spec_id: spec_id.to_string(),
         xchecker_version: "0.1.0".to_string(),
+        hostname: String::new(),
     };
 
     let lock_json = serde_json::to_string_pretty(&stale_lock_info)?;
@@ -302,6 +303,7 @@ fn test_lock_with_dead_process_recent_timestamp() -> Result<()> {
         created_at: recent_time,
         spec_id: spec_id.to_string(),
         xchecker_version: "0.1.0".to_string(),
+        hostname: String::new(),
     };
 
     let lock_json = serde_json::to_string_pretty(&dead_process_lock)?;
@@ -340,6 +342,7 @@ fn test_configurable_ttl() -> Result<()> {
         created_at: two_minutes_ago,
         spec_id: spec_id.to_string(),
         xchecker_version: "0.1.0".to_string(),
+        hostname: String::new(),
     };
 
     let lock_json = serde_json::to_string_pretty(&old_lock)?;
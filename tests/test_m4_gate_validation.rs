@@ -174,6 +174,7 @@ fn test_error_receipt_exit_code_alignment() {
                 files: vec![],
                 max_bytes: 65536,
                 max_lines: 1200,
+                preview_compressed: false,
             },
             None,     // stderr_tail
             None,     // stderr_redacted
@@ -480,6 +481,7 @@ fn test_receipt_schema_version() {
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            preview_compressed: false,
         },
         None,     // stderr_redacted
         None,     // stderr_redacted
@@ -522,6 +524,7 @@ fn test_receipt_canonical_json_emission() {
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            preview_compressed: false,
         },
         None,     // stderr_redacted
         None,     // stderr_redacted
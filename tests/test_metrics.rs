@@ -0,0 +1,148 @@
+//! Tests for the `metrics` feature
+//!
+//! A dry-run phase must count itself in `phases_run_total` and record its
+//! duration, using whatever recorder the embedding process installed.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+
+use metrics::{
+    Counter, CounterFn, Gauge, Histogram, HistogramFn, Key, KeyName, Metadata, Recorder,
+    SharedString, Unit,
+};
+use xchecker::metrics::{
+    LLM_CALLS_TOTAL, LOCK_WAIT_SECONDS, PACKET_BYTES, PHASE_DURATION_SECONDS, PHASES_RUN_TOTAL,
+};
+use xchecker::{OrchestratorHandle, PhaseId};
+
+struct TestCounter(AtomicU64);
+
+impl CounterFn for TestCounter {
+    fn increment(&self, value: u64) {
+        self.0.fetch_add(value, Ordering::SeqCst);
+    }
+
+    fn absolute(&self, value: u64) {
+        self.0.store(value, Ordering::SeqCst);
+    }
+}
+
+struct TestHistogram(Mutex<Vec<f64>>);
+
+impl HistogramFn for TestHistogram {
+    fn record(&self, value: f64) {
+        self.0.lock().unwrap().push(value);
+    }
+}
+
+/// Keeps every counter and histogram in memory, keyed by name and labels
+#[derive(Default)]
+struct TestRecorder {
+    counters: Mutex<HashMap<Key, Arc<TestCounter>>>,
+    histograms: Mutex<HashMap<Key, Arc<TestHistogram>>>,
+}
+
+impl TestRecorder {
+    fn counter(&self, key: &Key) -> u64 {
+        self.counters
+            .lock()
+            .unwrap()
+            .get(key)
+            .map_or(0, |counter| counter.0.load(Ordering::SeqCst))
+    }
+
+    fn histogram(&self, key: &Key) -> Vec<f64> {
+        self.histograms
+            .lock()
+            .unwrap()
+            .get(key)
+            .map(|histogram| histogram.0.lock().unwrap().clone())
+            .unwrap_or_default()
+    }
+}
+
+impl Recorder for TestRecorder {
+    fn describe_counter(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+    fn describe_gauge(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+    fn describe_histogram(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+    fn register_counter(&self, key: &Key, _: &Metadata<'_>) -> Counter {
+        let counter = self
+            .counters
+            .lock()
+            .unwrap()
+            .entry(key.clone())
+            .or_insert_with(|| Arc::new(TestCounter(AtomicU64::new(0))))
+            .clone();
+        Counter::from_arc(counter)
+    }
+
+    fn register_gauge(&self, _: &Key, _: &Metadata<'_>) -> Gauge {
+        Gauge::noop()
+    }
+
+    fn register_histogram(&self, key: &Key, _: &Metadata<'_>) -> Histogram {
+        let histogram = self
+            .histograms
+            .lock()
+            .unwrap()
+            .entry(key.clone())
+            .or_insert_with(|| Arc::new(TestHistogram(Mutex::new(Vec::new()))))
+            .clone();
+        Histogram::from_arc(histogram)
+    }
+}
+
+/// Install the recorder once for the whole test binary
+fn recorder() -> &'static TestRecorder {
+    static RECORDER: OnceLock<&'static TestRecorder> = OnceLock::new();
+    RECORDER.get_or_init(|| {
+        let recorder: &'static TestRecorder = Box::leak(Box::default());
+        metrics::set_global_recorder(recorder).expect("no other recorder installed");
+        recorder
+    })
+}
+
+fn key(name: &'static str, labels: &[(&'static str, &'static str)]) -> Key {
+    let labels: Vec<metrics::Label> = labels
+        .iter()
+        .map(|(key, value)| metrics::Label::new(*key, *value))
+        .collect();
+    Key::from_parts(name, labels)
+}
+
+#[tokio::test]
+async fn test_dry_run_phase_records_metrics() {
+    let recorder = recorder();
+    let _home = xchecker::paths::with_isolated_home();
+
+    let phases_run = key(
+        PHASES_RUN_TOTAL,
+        &[("phase", "requirements"), ("success", "true")],
+    );
+    let duration = key(PHASE_DURATION_SECONDS, &[("phase", "requirements")]);
+    let llm_calls = key(
+        LLM_CALLS_TOTAL,
+        &[("phase", "requirements"), ("dry_run", "true")],
+    );
+    let packet_bytes = key(PACKET_BYTES, &[("phase", "requirements")]);
+    let lock_wait = key(LOCK_WAIT_SECONDS, &[]);
+
+    let mut handle = OrchestratorHandle::with_force("metrics-dry-run", true).unwrap();
+    handle.set_dry_run(true);
+    let result = handle.run_phase(PhaseId::Requirements).await.unwrap();
+    assert!(result.success);
+
+    assert_eq!(recorder.counter(&phases_run), 1);
+    assert_eq!(recorder.counter(&llm_calls), 1);
+
+    let durations = recorder.histogram(&duration);
+    assert_eq!(durations.len(), 1);
+    assert!(durations[0] > 0.0);
+
+    // A fresh spec has no context files, so only the sample count is stable
+    assert_eq!(recorder.histogram(&packet_bytes).len(), 1);
+
+    assert!(!recorder.histogram(&lock_wait).is_empty());
+}
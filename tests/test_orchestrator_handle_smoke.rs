@@ -26,9 +26,10 @@
 use anyhow::Result;
 use std::collections::HashMap;
 
+use xchecker::error::XCheckerError;
 use xchecker::orchestrator::{OrchestratorConfig, OrchestratorHandle};
 use xchecker::paths::with_isolated_home;
-use xchecker::types::PhaseId;
+use xchecker::types::{PhaseId, Receipt};
 
 /// Create a dry-run config for testing
 fn dry_run_config() -> OrchestratorConfig {
@@ -301,3 +302,82 @@ async fn handle_sequential_phase_execution() -> Result<()> {
 
     Ok(())
 }
+
+/// Test 8: Collecting variants return the receipt they persisted
+///
+/// Validates:
+/// - `run_all_collect` returns the receipt of the last phase run
+/// - The returned receipt equals the one parsed back from disk
+/// - `run_phase_collect` does the same for a single phase
+#[tokio::test]
+async fn handle_collect_returns_persisted_receipt() -> Result<()> {
+    let _home = with_isolated_home();
+    let spec_id = unique_spec_id("collect");
+    let config = dry_run_config();
+
+    let mut handle = OrchestratorHandle::with_config_and_force(&spec_id, config, false)?;
+
+    let receipt = handle.run_all_collect().await?;
+    assert_eq!(receipt.phase, "tasks");
+    assert_eq!(receipt.exit_code, 0);
+
+    let on_disk: Receipt = serde_json::from_str(&std::fs::read_to_string(
+        handle
+            .last_receipt_path()
+            .expect("receipt should be written"),
+    )?)?;
+    assert_eq!(
+        serde_json::to_value(&receipt)?,
+        serde_json::to_value(&on_disk)?
+    );
+
+    let mut handle = OrchestratorHandle::with_config_and_force(
+        &unique_spec_id("collect-phase"),
+        dry_run_config(),
+        false,
+    )?;
+    let receipt = handle.run_phase_collect(PhaseId::Requirements).await?;
+    let latest = handle
+        .receipt_manager()
+        .read_latest_receipt(PhaseId::Requirements)?
+        .expect("requirements receipt should be written");
+    assert_eq!(
+        serde_json::to_value(&receipt)?,
+        serde_json::to_value(&latest)?
+    );
+
+    Ok(())
+}
+
+/// Test 9: `max_concurrent_specs` rejects runs beyond the home-level limit
+///
+/// Validates:
+/// - A run is refused with `TooManyConcurrentSpecs` while the limit is in use
+/// - The same run succeeds once the other slot is released
+#[tokio::test]
+async fn handle_rejects_run_beyond_max_concurrent_specs() -> Result<()> {
+    let _home = with_isolated_home();
+    let mut config = dry_run_config();
+    config
+        .config
+        .insert("max_concurrent_specs".to_string(), "1".to_string());
+
+    let other_run = xchecker_utils::lock::RunSlot::acquire("another-spec", 1)?;
+
+    let mut handle =
+        OrchestratorHandle::with_config_and_force(&unique_spec_id("concurrency"), config, false)?;
+    let err = handle.run_phase(PhaseId::Requirements).await.unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<XCheckerError>(),
+        Some(XCheckerError::TooManyConcurrentSpecs {
+            limit: 1,
+            active: 1
+        })
+    ));
+
+    other_run.release()?;
+    let result = handle.run_phase(PhaseId::Requirements).await?;
+    assert!(result.success);
+
+    Ok(())
+}
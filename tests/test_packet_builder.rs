@@ -8,7 +8,7 @@
 //! This test suite verifies:
 //! - FR-PKT-001: Deterministic ordering (sorted file paths)
 //! - FR-PKT-002: Priority-based selection (Upstream > High > Medium > Low)
-//! - FR-PKT-003: LIFO ordering within priority classes
+//! - FR-PKT-003: Path ordering within priority classes
 //! - FR-PKT-004: Byte and line counting during assembly
 //! - FR-PKT-005: Limit enforcement (exit 7 on overflow)
 //! - FR-PKT-006: Packet manifest generation on overflow
@@ -41,12 +41,11 @@ fn test_deterministic_ordering_sorted_paths() -> Result<()> {
     // Files should be sorted alphabetically within same priority
     let paths: Vec<String> = files.iter().map(|f| f.path.to_string()).collect();
 
-    // All these files have the same priority (Low), so they should be in LIFO order
-    // which means reverse alphabetical
+    // All these files have the same priority (Low), so they are in path order
     assert_eq!(paths.len(), 3);
-    assert!(paths[0].contains("zebra.md"));
+    assert!(paths[0].contains("alpha.md"));
     assert!(paths[1].contains("beta.md"));
-    assert!(paths[2].contains("alpha.md"));
+    assert!(paths[2].contains("zebra.md"));
 
     Ok(())
 }
@@ -83,9 +82,9 @@ fn test_priority_based_selection() -> Result<()> {
     Ok(())
 }
 
-/// Test FR-PKT-003: LIFO ordering within priority classes
+/// Test FR-PKT-003: Path ordering within priority classes
 #[test]
-fn test_lifo_ordering_within_priority() -> Result<()> {
+fn test_path_ordering_within_priority() -> Result<()> {
     let temp_dir = TempDir::new()?;
     let base_path = Utf8PathBuf::try_from(temp_dir.path().to_path_buf())?;
 
@@ -97,16 +96,15 @@ fn test_lifo_ordering_within_priority() -> Result<()> {
     let selector = ContentSelector::new()?;
     let files = selector.select_files(&base_path)?;
 
-    // All files have High priority, should be in LIFO (reverse) order
+    // All files have High priority, so they are in path order
     assert_eq!(files.len(), 3);
     assert_eq!(files[0].priority, Priority::High);
     assert_eq!(files[1].priority, Priority::High);
     assert_eq!(files[2].priority, Priority::High);
 
-    // LIFO means reverse alphabetical order
-    assert!(files[0].path.to_string().contains("SPEC-003.md"));
+    assert!(files[0].path.to_string().contains("SPEC-001.md"));
     assert!(files[1].path.to_string().contains("SPEC-002.md"));
-    assert!(files[2].path.to_string().contains("SPEC-001.md"));
+    assert!(files[2].path.to_string().contains("SPEC-003.md"));
 
     Ok(())
 }
@@ -495,17 +493,16 @@ fn test_file_path_sorting_same_priority() -> Result<()> {
     let selector = ContentSelector::new()?;
     let files = selector.select_files(&base_path)?;
 
-    // All files have same priority, should be in LIFO (reverse alphabetical) order
+    // All files have same priority, so they are in path order
     assert_eq!(files.len(), 3);
     let paths: Vec<String> = files
         .iter()
         .map(|f| f.path.file_name().unwrap().to_string())
         .collect();
 
-    // LIFO ordering means reverse alphabetical
-    assert_eq!(paths[0], "zebra.md");
+    assert_eq!(paths[0], "alpha.md");
     assert_eq!(paths[1], "middle.md");
-    assert_eq!(paths[2], "alpha.md");
+    assert_eq!(paths[2], "zebra.md");
 
     Ok(())
 }
@@ -799,14 +796,14 @@ fn test_comprehensive_deterministic_ordering() -> Result<()> {
         last_priority_value = current_priority_value;
     }
 
-    // Within each priority, verify LIFO (reverse alphabetical) ordering
+    // Within each priority, verify path ordering
     let upstream_files: Vec<_> = files
         .iter()
         .filter(|f| f.priority == Priority::Upstream)
         .collect();
     if upstream_files.len() > 1 {
         for i in 0..upstream_files.len() - 1 {
-            assert!(upstream_files[i].path >= upstream_files[i + 1].path);
+            assert!(upstream_files[i].path <= upstream_files[i + 1].path);
         }
     }
 
@@ -0,0 +1,97 @@
+//! Tests for `[packet] cache`, which lets phases reuse a packet whose
+//! selected files are unchanged
+
+use xchecker::OrchestratorHandle;
+use xchecker::types::PhaseId;
+use xchecker::{CliArgs, Config};
+
+const REQUIREMENTS_FIXTURE: &str = "# Requirements Document
+
+## Introduction
+
+Fixture-driven requirements for exercising the packet cache.
+
+## Requirements
+
+### Requirement 1
+
+**User Story:** As a maintainer, I want unchanged packets reused, so that reruns are fast.
+
+#### Acceptance Criteria
+
+1. WHEN the selected files are unchanged THEN the system SHALL reuse the cached packet
+2. WHEN a selected file changes THEN the system SHALL rebuild the packet
+";
+
+const MARKER: &str = "served-from-packet-cache";
+
+fn config_with_fixtures(home: &std::path::Path, packet_section: &str) -> Config {
+    let fixtures = home.join("fixtures");
+    std::fs::create_dir_all(&fixtures).unwrap();
+    std::fs::write(fixtures.join("requirements.md"), REQUIREMENTS_FIXTURE).unwrap();
+
+    let config_path = home.join("config.toml");
+    std::fs::write(
+        &config_path,
+        format!(
+            "{packet_section}\n[runner]\nfixture_dir = {:?}\n",
+            fixtures.to_str().unwrap()
+        ),
+    )
+    .unwrap();
+    Config::discover(&CliArgs {
+        config_path: Some(config_path),
+        ..Default::default()
+    })
+    .unwrap()
+}
+
+async fn run_requirements(spec_id: &str, config: &Config) -> OrchestratorHandle {
+    let mut handle = OrchestratorHandle::from_config(spec_id, config.clone()).unwrap();
+    let result = handle.run_phase(PhaseId::Requirements).await.unwrap();
+    assert!(result.success);
+    handle
+}
+
+#[tokio::test]
+async fn test_packet_cache_config_reuses_unchanged_packet() {
+    let home = xchecker::paths::with_isolated_home();
+    let config = config_with_fixtures(home.path(), "[packet]\ncache = true\n");
+    let spec_id = "packet-cache-config";
+
+    // The first runs settle the file set: each one writes the requirements
+    // artifact the next one selects
+    run_requirements(spec_id, &config).await;
+    run_requirements(spec_id, &config).await;
+
+    let cache_file = xchecker::paths::cache_dir()
+        .join(spec_id)
+        .join("packet_requirements.json");
+    assert!(cache_file.exists(), "{cache_file} should be written");
+
+    // Mark the cached packet so a reuse shows up in the preview
+    let mut cached: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&cache_file).unwrap()).unwrap();
+    let content = cached["packet"]["content"].as_str().unwrap().to_string();
+    cached["packet"]["content"] = serde_json::Value::String(format!("{content}\n{MARKER}\n"));
+    std::fs::write(&cache_file, serde_json::to_string(&cached).unwrap()).unwrap();
+
+    let handle = run_requirements(spec_id, &config).await;
+    let preview = handle
+        .artifact_manager()
+        .base_path()
+        .join("context/requirements-packet.txt");
+    assert!(std::fs::read_to_string(preview).unwrap().contains(MARKER));
+}
+
+#[tokio::test]
+async fn test_packet_cache_is_off_by_default() {
+    let home = xchecker::paths::with_isolated_home();
+    let config = config_with_fixtures(home.path(), "");
+    let spec_id = "packet-cache-default";
+
+    run_requirements(spec_id, &config).await;
+    run_requirements(spec_id, &config).await;
+
+    assert!(!xchecker::paths::cache_dir().join(spec_id).exists());
+}
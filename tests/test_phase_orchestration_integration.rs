@@ -295,6 +295,7 @@ async fn test_failed_phases_block_progression() -> Result<()> {
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            preview_compressed: false,
         },
         None,                                      // stderr_redacted
         None,                                      // stderr_tail_excerpt
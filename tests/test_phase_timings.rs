@@ -0,0 +1,30 @@
+//! Tests for the per-sub-step `timings` recorded on phase receipts
+
+use xchecker::OrchestratorHandle;
+use xchecker::types::PhaseId;
+
+#[tokio::test]
+async fn test_dry_run_phase_records_timing_spans() {
+    let _home = xchecker::paths::with_isolated_home();
+
+    let mut handle = OrchestratorHandle::with_force("phase-timings", true).unwrap();
+    handle.set_dry_run(true);
+    let result = handle.run_phase(PhaseId::Requirements).await.unwrap();
+    assert!(result.success);
+
+    let receipt = result.receipt.expect("successful phase writes a receipt");
+    let timings = receipt.timings.expect("receipt carries timing spans");
+    let sum = timings.packet_build_ms + timings.llm_call_ms + timings.postprocess_ms;
+    assert!(sum <= receipt.duration_ms.unwrap());
+
+    // The emitted receipt file spells out all three fields
+    let receipt_path = result.receipt_path.unwrap();
+    let json: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(receipt_path).unwrap()).unwrap();
+    for field in ["packet_build_ms", "llm_call_ms", "postprocess_ms"] {
+        assert!(
+            json["timings"][field].as_u64().is_some(),
+            "missing non-negative timings.{field}"
+        );
+    }
+}
@@ -11,10 +11,10 @@
 
 use anyhow::Result;
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tempfile::TempDir;
 use xchecker::phase::{
-    BudgetUsage, NextStep, Packet, Phase, PhaseContext, PhaseMetadata, PhaseResult,
+    BudgetUsage, NextStep, Packet, Phase, PhaseContext, PhaseMetadata, PhaseResult, ValidationMode,
 };
 use xchecker::phases::{DesignPhase, RequirementsPhase, TasksPhase};
 use xchecker::types::{FileEvidence, PacketEvidence, PhaseId, Priority};
@@ -30,8 +30,9 @@ fn create_test_context(spec_id: &str, spec_dir: PathBuf) -> PhaseContext {
         config,
         artifacts: vec![],
         selectors: None,
-        strict_validation: false,
+        validation_mode: ValidationMode::Warn,
         redactor: Default::default(),
+        problem_transform: None,
     }
 }
 
@@ -50,11 +51,25 @@ fn create_test_context_with_artifacts(
         config,
         artifacts,
         selectors: None,
-        strict_validation: false,
+        validation_mode: ValidationMode::Warn,
         redactor: Default::default(),
+        problem_transform: None,
     }
 }
 
+/// Write a phase's artifacts where downstream `make_packet` calls expect them
+fn write_phase_artifacts(spec_dir: &Path, result: &PhaseResult) -> Result<()> {
+    let artifacts_dir = spec_dir.join("artifacts");
+    std::fs::create_dir_all(&artifacts_dir)?;
+    for artifact in &result.artifacts {
+        std::fs::write(
+            artifacts_dir.join(&artifact.name),
+            artifact.content.as_bytes(),
+        )?;
+    }
+    Ok(())
+}
+
 // ============================================================================
 // Phase Trait Method Tests
 // ============================================================================
@@ -142,9 +157,11 @@ fn test_packet_creation() {
             range: None,
             blake3_pre_redaction: "abc123".to_string(),
             priority: Priority::High,
+            redaction_exempt: false,
         }],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let budget = BudgetUsage::new(65536, 1200);
@@ -171,12 +188,14 @@ fn test_packet_with_evidence() {
             range: None,
             blake3_pre_redaction: "hash1".to_string(),
             priority: Priority::Upstream,
+            redaction_exempt: false,
         },
         FileEvidence {
             path: "file2.txt".to_string(),
             range: Some("1-10".to_string()),
             blake3_pre_redaction: "hash2".to_string(),
             priority: Priority::High,
+            redaction_exempt: false,
         },
     ];
 
@@ -184,6 +203,7 @@ fn test_packet_with_evidence() {
         files: files.clone(),
         max_bytes: 100000,
         max_lines: 2000,
+        preview_compressed: false,
     };
 
     let budget = BudgetUsage::new(100000, 2000);
@@ -273,6 +293,7 @@ fn test_packet_is_within_budget() {
         files: vec![],
         max_bytes: 1000,
         max_lines: 100,
+        preview_compressed: false,
     };
 
     let mut budget = BudgetUsage::new(1000, 100);
@@ -289,6 +310,7 @@ fn test_packet_exceeds_budget() {
         files: vec![],
         max_bytes: 1000,
         max_lines: 100,
+        preview_compressed: false,
     };
 
     let mut budget = BudgetUsage::new(1000, 100);
@@ -310,13 +332,13 @@ fn test_phase_result_with_artifacts() {
     let artifacts = vec![
         Artifact {
             name: "test.md".to_string(),
-            content: "content".to_string(),
+            content: "content".into(),
             artifact_type: ArtifactType::Markdown,
             blake3_hash: "hash1".to_string(),
         },
         Artifact {
             name: "test.yaml".to_string(),
-            content: "yaml: content".to_string(),
+            content: "yaml: content".into(),
             artifact_type: ArtifactType::CoreYaml,
             blake3_hash: "hash2".to_string(),
         },
@@ -326,6 +348,8 @@ fn test_phase_result_with_artifacts() {
         packet_hash: Some("test_hash".to_string()),
         budget_used: None,
         duration_ms: Some(100),
+        timings: None,
+        validation_errors: Vec::new(),
     };
 
     let result = PhaseResult {
@@ -483,7 +507,13 @@ fn test_requirements_phase_artifact_generation() -> Result<()> {
         md_artifact.artifact_type,
         xchecker::artifact::ArtifactType::Markdown
     );
-    assert!(md_artifact.content.contains("Requirements"));
+    assert!(
+        md_artifact
+            .content
+            .as_text()
+            .unwrap()
+            .contains("Requirements")
+    );
     assert!(!md_artifact.blake3_hash.is_empty());
 
     // Check YAML artifact
@@ -497,9 +527,21 @@ fn test_requirements_phase_artifact_generation() -> Result<()> {
         yaml_artifact.artifact_type,
         xchecker::artifact::ArtifactType::CoreYaml
     );
-    assert!(yaml_artifact.content.contains("spec_id"));
-    assert!(yaml_artifact.content.contains("test-req-artifacts"));
-    assert!(yaml_artifact.content.contains("phase: \"requirements\""));
+    assert!(yaml_artifact.content.as_text().unwrap().contains("spec_id"));
+    assert!(
+        yaml_artifact
+            .content
+            .as_text()
+            .unwrap()
+            .contains("test-req-artifacts")
+    );
+    assert!(
+        yaml_artifact
+            .content
+            .as_text()
+            .unwrap()
+            .contains("phase: requirements")
+    );
     assert!(!yaml_artifact.blake3_hash.is_empty());
 
     Ok(())
@@ -606,7 +648,7 @@ fn test_design_phase_artifact_generation() -> Result<()> {
         md_artifact.artifact_type,
         xchecker::artifact::ArtifactType::Markdown
     );
-    assert!(md_artifact.content.contains("Design"));
+    assert!(md_artifact.content.as_text().unwrap().contains("Design"));
     assert!(!md_artifact.blake3_hash.is_empty());
 
     // Check YAML artifact
@@ -620,9 +662,21 @@ fn test_design_phase_artifact_generation() -> Result<()> {
         yaml_artifact.artifact_type,
         xchecker::artifact::ArtifactType::CoreYaml
     );
-    assert!(yaml_artifact.content.contains("spec_id"));
-    assert!(yaml_artifact.content.contains("test-design-artifacts"));
-    assert!(yaml_artifact.content.contains("phase: \"design\""));
+    assert!(yaml_artifact.content.as_text().unwrap().contains("spec_id"));
+    assert!(
+        yaml_artifact
+            .content
+            .as_text()
+            .unwrap()
+            .contains("test-design-artifacts")
+    );
+    assert!(
+        yaml_artifact
+            .content
+            .as_text()
+            .unwrap()
+            .contains("phase: design")
+    );
     assert!(!yaml_artifact.blake3_hash.is_empty());
 
     Ok(())
@@ -729,7 +783,13 @@ fn test_tasks_phase_artifact_generation() -> Result<()> {
         md_artifact.artifact_type,
         xchecker::artifact::ArtifactType::Markdown
     );
-    assert!(md_artifact.content.contains("Implementation Plan"));
+    assert!(
+        md_artifact
+            .content
+            .as_text()
+            .unwrap()
+            .contains("Implementation Plan")
+    );
     assert!(!md_artifact.blake3_hash.is_empty());
 
     // Check YAML artifact
@@ -743,9 +803,21 @@ fn test_tasks_phase_artifact_generation() -> Result<()> {
         yaml_artifact.artifact_type,
         xchecker::artifact::ArtifactType::CoreYaml
     );
-    assert!(yaml_artifact.content.contains("spec_id"));
-    assert!(yaml_artifact.content.contains("test-tasks-artifacts"));
-    assert!(yaml_artifact.content.contains("phase: \"tasks\""));
+    assert!(yaml_artifact.content.as_text().unwrap().contains("spec_id"));
+    assert!(
+        yaml_artifact
+            .content
+            .as_text()
+            .unwrap()
+            .contains("test-tasks-artifacts")
+    );
+    assert!(
+        yaml_artifact
+            .content
+            .as_text()
+            .unwrap()
+            .contains("phase: tasks")
+    );
     assert!(!yaml_artifact.blake3_hash.is_empty());
 
     Ok(())
@@ -792,6 +864,8 @@ fn test_design_phase_packet_assembly() -> Result<()> {
     std::fs::write(&test_file_path, "Test design content")?;
 
     let ctx = create_test_context("test-design-packet", temp_dir.path().to_path_buf());
+    let requirements = RequirementsPhase::new().postprocess("# Requirements\n\nTest", &ctx)?;
+    write_phase_artifacts(temp_dir.path(), &requirements)?;
 
     let phase = DesignPhase::new();
     let result = phase.make_packet(&ctx);
@@ -818,6 +892,8 @@ fn test_tasks_phase_packet_assembly() -> Result<()> {
     std::fs::write(&test_file_path, "Test tasks content")?;
 
     let ctx = create_test_context("test-tasks-packet", temp_dir.path().to_path_buf());
+    let design = DesignPhase::new().postprocess("# Design\n\nTest", &ctx)?;
+    write_phase_artifacts(temp_dir.path(), &design)?;
 
     let phase = TasksPhase::new();
     let result = phase.make_packet(&ctx);
@@ -960,6 +1036,7 @@ fn test_complete_phase_workflow() -> Result<()> {
     let req_result = req_phase.postprocess(req_response, &req_ctx)?;
     assert_eq!(req_result.artifacts.len(), 2);
     assert_eq!(req_result.next_step, NextStep::Continue);
+    write_phase_artifacts(temp_dir.path(), &req_result)?;
 
     // 2. Design Phase (depends on Requirements)
     let design_ctx = create_test_context("test-workflow", temp_dir.path().to_path_buf());
@@ -977,6 +1054,7 @@ fn test_complete_phase_workflow() -> Result<()> {
     let design_result = design_phase.postprocess(design_response, &design_ctx)?;
     assert_eq!(design_result.artifacts.len(), 2);
     assert_eq!(design_result.next_step, NextStep::Continue);
+    write_phase_artifacts(temp_dir.path(), &design_result)?;
 
     // 3. Tasks Phase (depends on Design)
     let tasks_ctx = create_test_context("test-workflow", temp_dir.path().to_path_buf());
@@ -266,6 +266,7 @@ async fn test_failed_dependency_prevents_transition() -> Result<()> {
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            preview_compressed: false,
         },
         None,                                      // stderr_tail
         None,                                      // stderr_redacted
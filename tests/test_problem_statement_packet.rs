@@ -9,16 +9,22 @@
 //! 1. Problem statement files (source/00-problem-statement.md) are included in packets
 //! 2. Problem statement content is prioritized in packet selection
 //! 3. Problem statement is included in phase prompts when provided via config
+//! 4. A handle's problem transform reaches the prompt sent to the LLM
 
 use anyhow::Result;
+use async_trait::async_trait;
 use camino::Utf8PathBuf;
 use std::collections::HashMap;
 use std::fs;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use tempfile::TempDir;
+use xchecker::orchestrator::{OrchestratorConfig, OrchestratorHandle};
 use xchecker::packet::{ContentSelector, PacketBuilder};
-use xchecker::phase::{Phase, PhaseContext};
+use xchecker::phase::{Phase, PhaseContext, ProblemTransform, ValidationMode};
 use xchecker::phases::RequirementsPhase;
-use xchecker::types::Priority;
+use xchecker::runner::{ClaudeResponse, ClaudeRunner, RunnerError, RunnerMode};
+use xchecker::types::{PhaseId, Priority};
 
 /// Test that problem statement files are included with high priority
 #[test]
@@ -111,8 +117,9 @@ fn test_prompt_includes_problem_statement() {
         config,
         artifacts: vec![],
         selectors: None,
-        strict_validation: false,
+        validation_mode: ValidationMode::Warn,
         redactor: Default::default(),
+        problem_transform: None,
     };
 
     let phase = RequirementsPhase::new();
@@ -144,8 +151,9 @@ fn test_prompt_fallback_without_problem_statement() {
         config: HashMap::new(),
         artifacts: vec![],
         selectors: None,
-        strict_validation: false,
+        validation_mode: ValidationMode::Warn,
         redactor: Default::default(),
+        problem_transform: None,
     };
 
     let phase = RequirementsPhase::new();
@@ -210,8 +218,9 @@ fn test_prompt_includes_spec_id() {
         config: HashMap::new(),
         artifacts: vec![],
         selectors: None,
-        strict_validation: false,
+        validation_mode: ValidationMode::Warn,
         redactor: Default::default(),
+        problem_transform: None,
     };
 
     let phase = RequirementsPhase::new();
@@ -222,3 +231,73 @@ fn test_prompt_includes_spec_id() {
         "Prompt should contain spec ID"
     );
 }
+
+/// Records the stdin of each call and answers with a minimal requirements document
+struct CapturingRunner {
+    stdin: Arc<Mutex<Vec<String>>>,
+}
+
+#[async_trait]
+impl ClaudeRunner for CapturingRunner {
+    async fn execute_claude(
+        &self,
+        _args: &[String],
+        stdin_content: &str,
+        _timeout_duration: Option<Duration>,
+    ) -> Result<ClaudeResponse, RunnerError> {
+        self.stdin.lock().unwrap().push(stdin_content.to_string());
+        let stdout = "# Requirements Document\n\n## Introduction\n\nCaptured.\n".to_string();
+        Ok(ClaudeResponse {
+            ndjson_result: xchecker::runner::Runner::parse_ndjson(&stdout),
+            stdout_total_bytes: stdout.len(),
+            stdout,
+            stderr: String::new(),
+            exit_code: 0,
+            runner_used: RunnerMode::Native,
+            runner_distro: None,
+            timed_out: false,
+            stdout_truncated: false,
+            stderr_truncated: false,
+            stderr_total_bytes: 0,
+        })
+    }
+}
+
+/// Test that the handle's problem transform is applied to the prompt the LLM receives
+#[tokio::test]
+async fn test_handle_problem_transform_reaches_llm_prompt() -> Result<()> {
+    let _home = xchecker::paths::with_isolated_home();
+
+    let config = OrchestratorConfig {
+        dry_run: false,
+        config: HashMap::from([
+            ("output_format".to_string(), "text".to_string()),
+            (
+                "problem_statement".to_string(),
+                "TICKET-42: build a todo app".to_string(),
+            ),
+        ]),
+        full_config: None,
+        selectors: None,
+        strict_validation: false,
+        redactor: Default::default(),
+        hooks: None,
+    };
+    let stdin = Arc::new(Mutex::new(Vec::new()));
+    let mut handle =
+        OrchestratorHandle::with_config_and_force("problem-transform-handle", config, false)?
+            .with_runner(Box::new(CapturingRunner {
+                stdin: Arc::clone(&stdin),
+            }))
+            .with_problem_transform(ProblemTransform::new(|statement| {
+                statement.trim_start_matches("TICKET-42: ").to_uppercase()
+            }));
+    let result = handle.run_phase(PhaseId::Requirements).await?;
+    assert!(result.success, "phase should succeed: {:?}", result.error);
+
+    let stdin = stdin.lock().unwrap();
+    assert_eq!(stdin.len(), 1);
+    assert!(stdin[0].contains("BUILD A TODO APP"), "{}", stdin[0]);
+    assert!(!stdin[0].contains("TICKET-42"), "{}", stdin[0]);
+    Ok(())
+}
@@ -0,0 +1,136 @@
+//! Tests for per-phase prompt template files
+//!
+//! `[phases.<phase>].prompt_file` replaces the built-in prompt: the rendered
+//! template must be what reaches the LLM, and an unusable template must stop
+//! the phase before any call is made.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use xchecker::error::XCheckerError;
+use xchecker::orchestrator::{OrchestratorConfig, OrchestratorHandle};
+use xchecker::paths::with_isolated_home;
+use xchecker::runner::{ClaudeResponse, ClaudeRunner, RunnerError, RunnerMode};
+use xchecker::types::PhaseId;
+
+const REQUIREMENTS: &str = "# Requirements Document\n\n## Introduction\n\nPrompt file fixture.\n\n## Requirements\n\n### Requirement 1\n\n**User Story:** As an author, I want my own prompt, so that output fits my team.\n\n#### Acceptance Criteria\n\n1. WHEN a prompt file is configured THEN the system SHALL send it\n";
+
+/// Records every prompt it is sent and answers with a requirements document
+#[derive(Default, Clone)]
+struct CapturingRunner {
+    prompts: Arc<Mutex<Vec<String>>>,
+}
+
+#[async_trait]
+impl ClaudeRunner for CapturingRunner {
+    async fn execute_claude(
+        &self,
+        _args: &[String],
+        stdin_content: &str,
+        _timeout_duration: Option<Duration>,
+    ) -> Result<ClaudeResponse, RunnerError> {
+        self.prompts.lock().unwrap().push(stdin_content.to_string());
+        Ok(ClaudeResponse {
+            stdout: REQUIREMENTS.to_string(),
+            stderr: String::new(),
+            exit_code: 0,
+            runner_used: RunnerMode::Native,
+            runner_distro: None,
+            timed_out: false,
+            ndjson_result: xchecker::runner::Runner::parse_ndjson(REQUIREMENTS),
+            stdout_truncated: false,
+            stderr_truncated: false,
+            stdout_total_bytes: REQUIREMENTS.len(),
+            stderr_total_bytes: 0,
+        })
+    }
+}
+
+fn config_with_prompt_file(path: &std::path::Path) -> OrchestratorConfig {
+    OrchestratorConfig {
+        dry_run: false,
+        config: HashMap::from([
+            ("output_format".to_string(), "text".to_string()),
+            (
+                "problem_statement".to_string(),
+                "Track reading lists".to_string(),
+            ),
+            (
+                "phases.requirements.prompt_file".to_string(),
+                path.display().to_string(),
+            ),
+        ]),
+        full_config: None,
+        selectors: None,
+        strict_validation: false,
+        redactor: Default::default(),
+        hooks: None,
+    }
+}
+
+#[tokio::test]
+async fn test_configured_prompt_file_is_sent_rendered() -> Result<()> {
+    let _home = with_isolated_home();
+    let dir = tempfile::TempDir::new()?;
+    let path = dir.path().join("requirements.md");
+    std::fs::write(
+        &path,
+        "Team prompt for {spec_id} ({phase}).\n\nProblem: {problem_statement}\n",
+    )?;
+
+    let runner = CapturingRunner::default();
+    let mut handle = OrchestratorHandle::with_config_and_force(
+        "prompt-file",
+        config_with_prompt_file(&path),
+        false,
+    )?
+    .with_runner(Box::new(runner.clone()));
+    let result = handle.run_phase(PhaseId::Requirements).await?;
+    assert!(result.success, "phase should succeed: {:?}", result.error);
+
+    let prompts = runner.prompts.lock().unwrap();
+    assert_eq!(prompts.len(), 1);
+    assert!(
+        prompts[0].contains(
+            "Team prompt for prompt-file (requirements).\n\nProblem: Track reading lists"
+        ),
+        "rendered template missing from prompt: {}",
+        prompts[0]
+    );
+    assert!(!prompts[0].contains("requirements analyst"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_invalid_prompt_file_fails_before_llm_call() -> Result<()> {
+    let _home = with_isolated_home();
+    let dir = tempfile::TempDir::new()?;
+    let path = dir.path().join("requirements.md");
+    std::fs::write(&path, "Write requirements for {spec_id}.\n")?;
+
+    let runner = CapturingRunner::default();
+    let mut handle = OrchestratorHandle::with_config_and_force(
+        "prompt-file-invalid",
+        config_with_prompt_file(&path),
+        false,
+    )?
+    .with_runner(Box::new(runner.clone()));
+    let err = handle
+        .run_phase(PhaseId::Requirements)
+        .await
+        .expect_err("template without {problem_statement} should fail");
+
+    assert!(
+        matches!(
+            err.downcast_ref::<XCheckerError>(),
+            Some(XCheckerError::Template(_))
+        ),
+        "unexpected error: {err:?}"
+    );
+    assert!(err.to_string().contains("{problem_statement}"));
+    assert!(runner.prompts.lock().unwrap().is_empty());
+    Ok(())
+}
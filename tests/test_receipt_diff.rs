@@ -0,0 +1,152 @@
+//! Tests for `OrchestratorHandle::diff_receipts`
+//!
+//! Two specs built from the same inputs should have receipts that differ
+//! only in timing; any other difference is reported field by field.
+
+use std::collections::HashMap;
+
+use xchecker::receipt::ReceiptManager;
+use xchecker::types::{FileEvidence, FileHash, PacketEvidence, Priority};
+use xchecker::{OrchestratorHandle, PhaseId};
+
+/// Write a successful requirements receipt for `handle`'s spec with one packet
+/// file under the spec root and the given output hashes
+fn seed_receipt(handle: &OrchestratorHandle, outputs: &[(&str, &str)], duration_ms: u64) {
+    seed_phase_receipt(handle, PhaseId::Requirements, outputs, duration_ms, 0);
+}
+
+fn seed_phase_receipt(
+    handle: &OrchestratorHandle,
+    phase: PhaseId,
+    outputs: &[(&str, &str)],
+    duration_ms: u64,
+    exit_code: i32,
+) {
+    let base = handle.artifact_manager().base_path();
+    let packet = PacketEvidence {
+        files: vec![FileEvidence {
+            path: base.join("context/problem.md").to_string(),
+            range: None,
+            blake3_pre_redaction: "1".repeat(64),
+            priority: Priority::High,
+            redaction_exempt: false,
+        }],
+        max_bytes: 65536,
+        max_lines: 1200,
+        preview_compressed: false,
+    };
+    let outputs = outputs
+        .iter()
+        .map(|(path, hash)| FileHash {
+            path: (*path).to_string(),
+            blake3_canonicalized: (*hash).to_string(),
+        })
+        .collect();
+
+    let receipts = ReceiptManager::new(base);
+    let mut receipt = receipts.create_receipt(
+        handle.spec_id(),
+        phase,
+        exit_code,
+        outputs,
+        "0.1.0",
+        "0.8.1",
+        "haiku",
+        None,
+        HashMap::new(),
+        packet,
+        None,
+        None,
+        vec![],
+        None,
+        "native",
+        None,
+        None,
+        None,
+        None, // diff_context
+        None, // pipeline
+    );
+    receipt.packet_hash = Some("2".repeat(64));
+    receipt.duration_ms = Some(duration_ms);
+    receipts.write_receipt(&receipt).unwrap();
+}
+
+#[test]
+fn test_diff_receipts_reports_single_artifact_mismatch() {
+    let _home = xchecker::paths::with_isolated_home();
+    let left = OrchestratorHandle::with_force("receipt-diff-left", true).unwrap();
+    let right = OrchestratorHandle::with_force("receipt-diff-right", true).unwrap();
+
+    let markdown = "artifacts/00-requirements.md";
+    let yaml = "artifacts/00-requirements.core.yaml";
+    seed_receipt(
+        &left,
+        &[(markdown, &"a".repeat(64)), (yaml, &"c".repeat(64))],
+        1200,
+    );
+    seed_receipt(
+        &right,
+        &[(markdown, &"b".repeat(64)), (yaml, &"c".repeat(64))],
+        1200,
+    );
+
+    let diff = left.diff_receipts("receipt-diff-right").unwrap();
+
+    assert!(!diff.identical);
+    assert_eq!(diff.mismatches.len(), 1, "{:?}", diff.mismatches);
+    let mismatch = &diff.mismatches[0];
+    assert_eq!(mismatch.field, format!("outputs[{markdown}]"));
+    assert_eq!(mismatch.left.as_deref(), Some("a".repeat(64).as_str()));
+    assert_eq!(mismatch.right.as_deref(), Some("b".repeat(64).as_str()));
+    assert!(!mismatch.informational);
+
+    let json = serde_json::to_value(&diff).unwrap();
+    assert_eq!(json["identical"], serde_json::Value::Bool(false));
+    assert_eq!(json["mismatches"].as_array().unwrap().len(), 1);
+}
+
+#[test]
+fn test_diff_receipts_duration_is_informational() {
+    let _home = xchecker::paths::with_isolated_home();
+    let left = OrchestratorHandle::with_force("receipt-diff-slow", true).unwrap();
+    let right = OrchestratorHandle::with_force("receipt-diff-fast", true).unwrap();
+
+    let outputs = [("artifacts/00-requirements.md", "a".repeat(64))];
+    let outputs: Vec<(&str, &str)> = outputs.iter().map(|(p, h)| (*p, h.as_str())).collect();
+    seed_receipt(&left, &outputs, 4000);
+    seed_receipt(&right, &outputs, 900);
+
+    let diff = left.diff_receipts("receipt-diff-fast").unwrap();
+
+    assert!(diff.identical, "{:?}", diff.mismatches);
+    assert_eq!(diff.mismatches.len(), 1);
+    assert_eq!(diff.mismatches[0].field, "duration_ms");
+    assert!(diff.mismatches[0].informational);
+}
+
+#[test]
+fn test_diff_receipts_requires_receipts_on_both_sides() {
+    let _home = xchecker::paths::with_isolated_home();
+    let left = OrchestratorHandle::with_force("receipt-diff-seeded", true).unwrap();
+    seed_receipt(&left, &[], 10);
+
+    let err = left.diff_receipts("receipt-diff-empty").unwrap_err();
+    assert!(err.to_string().contains("receipt-diff-empty"), "{err}");
+}
+
+#[test]
+fn test_diff_receipts_skips_later_failed_receipt() {
+    let _home = xchecker::paths::with_isolated_home();
+    let left = OrchestratorHandle::with_force("receipt-diff-retry", true).unwrap();
+    let right = OrchestratorHandle::with_force("receipt-diff-clean", true).unwrap();
+
+    let markdown = "artifacts/00-requirements.md";
+    seed_receipt(&left, &[(markdown, &"a".repeat(64))], 10);
+    seed_receipt(&right, &[(markdown, &"a".repeat(64))], 10);
+    // A later failed phase on the left must not replace its last good run
+    std::thread::sleep(std::time::Duration::from_millis(10));
+    seed_phase_receipt(&left, PhaseId::Design, &[], 10, 70);
+
+    let diff = left.diff_receipts("receipt-diff-clean").unwrap();
+    assert!(diff.identical, "{:?}", diff.mismatches);
+}
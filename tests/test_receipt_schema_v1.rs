@@ -29,6 +29,7 @@ fn test_receipt_schema_version_and_emitted_at() {
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            preview_compressed: false,
         },
         outputs: vec![],
         exit_code: 0,
@@ -42,6 +43,12 @@ fn test_receipt_schema_version_and_emitted_at() {
 
         llm: None,
         pipeline: None,
+        validation_degraded: None,
+        validation_errors: Vec::new(),
+        input_fingerprint: None,
+        packet_hash: None,
+        duration_ms: None,
+        timings: None,
     };
 
     // Verify schema_version is set
@@ -85,6 +92,7 @@ fn test_receipt_error_fields() {
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            preview_compressed: false,
         },
         outputs: vec![],
         exit_code: 70,
@@ -98,6 +106,12 @@ fn test_receipt_error_fields() {
 
         llm: None,
         pipeline: None,
+        validation_degraded: None,
+        validation_errors: Vec::new(),
+        input_fingerprint: None,
+        packet_hash: None,
+        duration_ms: None,
+        timings: None,
     };
 
     // Verify error fields
@@ -152,6 +166,7 @@ fn test_outputs_sorted_by_path() {
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            preview_compressed: false,
         },
         outputs,
         exit_code: 0,
@@ -165,6 +180,12 @@ fn test_outputs_sorted_by_path() {
 
         llm: None,
         pipeline: None,
+        validation_degraded: None,
+        validation_errors: Vec::new(),
+        input_fingerprint: None,
+        packet_hash: None,
+        duration_ms: None,
+        timings: None,
     };
 
     // Note: The ReceiptManager.create_receipt() sorts outputs, but when creating
@@ -202,6 +223,7 @@ fn test_jcs_canonical_json_emission() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let receipt = manager.create_receipt(
@@ -327,6 +349,7 @@ fn test_different_insertion_orders_produce_identical_json() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     // Use a fixed timestamp for both receipts to ensure identical output
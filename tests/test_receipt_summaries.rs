@@ -0,0 +1,127 @@
+//! Tests for `OrchestratorHandle::receipt_summaries`
+//!
+//! Summaries are read from the receipts on disk, one per phase that has a
+//! receipt, in workflow order.
+
+use std::collections::HashMap;
+
+use xchecker::lock::XCheckerLock;
+use xchecker::receipt::ReceiptManager;
+use xchecker::types::{FileHash, PacketEvidence};
+use xchecker::{OrchestratorHandle, PhaseId};
+
+/// Write a receipt for `phase` with the given packet hash and one output per
+/// entry in `outputs`
+fn seed_receipt(
+    handle: &OrchestratorHandle,
+    phase: PhaseId,
+    packet_hash: &str,
+    outputs: &[&str],
+    duration_ms: u64,
+) {
+    let packet = PacketEvidence {
+        files: vec![],
+        max_bytes: 65536,
+        max_lines: 1200,
+        preview_compressed: false,
+    };
+    let outputs = outputs
+        .iter()
+        .map(|path| FileHash {
+            path: (*path).to_string(),
+            blake3_canonicalized: "a".repeat(64),
+        })
+        .collect();
+
+    let receipts = ReceiptManager::new(handle.artifact_manager().base_path());
+    let mut receipt = receipts.create_receipt(
+        handle.spec_id(),
+        phase,
+        0,
+        outputs,
+        "0.1.0",
+        "0.8.1",
+        "haiku",
+        None,
+        HashMap::new(),
+        packet,
+        None,
+        None,
+        vec![],
+        None,
+        "native",
+        None,
+        None,
+        None,
+        None, // diff_context
+        None, // pipeline
+    );
+    receipt.packet_hash = Some(packet_hash.to_string());
+    receipt.duration_ms = Some(duration_ms);
+    receipts.write_receipt(&receipt).unwrap();
+}
+
+#[test]
+fn test_receipt_summaries_in_phase_order() {
+    let _home = xchecker::paths::with_isolated_home();
+    let handle = OrchestratorHandle::with_force("receipt-summaries", true).unwrap();
+    assert!(handle.receipt_summaries().unwrap().is_empty());
+
+    // Written out of workflow order; tasks and later phases have no receipt
+    seed_receipt(
+        &handle,
+        PhaseId::Design,
+        &"d".repeat(64),
+        &["artifacts/10-design.md"],
+        900,
+    );
+    seed_receipt(
+        &handle,
+        PhaseId::Requirements,
+        &"r".repeat(64),
+        &[
+            "artifacts/00-requirements.md",
+            "artifacts/00-requirements.core.yaml",
+        ],
+        1200,
+    );
+
+    let summaries = handle.receipt_summaries().unwrap();
+    let phases: Vec<PhaseId> = summaries.iter().map(|s| s.phase).collect();
+    assert_eq!(phases, vec![PhaseId::Requirements, PhaseId::Design]);
+
+    assert_eq!(
+        summaries[0].packet_hash.as_deref(),
+        Some("r".repeat(64).as_str())
+    );
+    assert_eq!(summaries[0].artifact_count, 2);
+    assert_eq!(summaries[0].duration_ms, Some(1200));
+    assert_eq!(
+        summaries[1].packet_hash.as_deref(),
+        Some("d".repeat(64).as_str())
+    );
+    assert_eq!(summaries[1].artifact_count, 1);
+    assert_eq!(summaries[1].exit_code, 0);
+    assert!(summaries.iter().all(|s| s.drift.is_none()));
+}
+
+#[test]
+fn test_receipt_summaries_report_lockfile_drift() {
+    let _home = xchecker::paths::with_isolated_home();
+    let handle = OrchestratorHandle::with_force("receipt-summaries-drift", true).unwrap();
+    seed_receipt(&handle, PhaseId::Requirements, &"r".repeat(64), &[], 10);
+
+    XCheckerLock::new("sonnet".to_string(), "0.8.1".to_string())
+        .save(handle.spec_id())
+        .unwrap();
+
+    let summaries = handle.receipt_summaries().unwrap();
+    let drift = summaries[0]
+        .drift
+        .as_ref()
+        .expect("model drifted from lock");
+    let model = drift.model_full_name.as_ref().unwrap();
+    assert_eq!(model.locked, "sonnet");
+    assert_eq!(model.current, "haiku");
+    assert!(drift.claude_cli_version.is_none());
+}
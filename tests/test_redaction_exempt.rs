@@ -0,0 +1,160 @@
+//! Tests for `[packet] redaction_exempt`
+//!
+//! Files matching an exemption glob reach the LLM verbatim, so every such
+//! inclusion must show up in the receipt: on the file's packet evidence and
+//! in the `redaction_exempt` flag.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use xchecker::orchestrator::{OrchestratorConfig, OrchestratorHandle};
+use xchecker::paths::{spec_root, with_isolated_home};
+use xchecker::receipt::ReceiptManager;
+use xchecker::runner::{ClaudeResponse, ClaudeRunner, RunnerError, RunnerMode};
+use xchecker::types::PhaseId;
+
+const REQUIREMENTS: &str = "# Requirements Document\n\n## Introduction\n\nRedaction exemption fixture.\n\n## Requirements\n\n### Requirement 1\n\n**User Story:** As an auditor, I want exempt files flagged, so that verbatim inclusions are visible.\n\n#### Acceptance Criteria\n\n1. WHEN a file is exempt THEN the system SHALL record it in the receipt\n";
+
+/// Records the packet it was sent and answers with fixed requirements
+struct CapturingRunner {
+    packet: std::sync::Arc<std::sync::Mutex<String>>,
+}
+
+#[async_trait]
+impl ClaudeRunner for CapturingRunner {
+    async fn execute_claude(
+        &self,
+        _args: &[String],
+        stdin_content: &str,
+        _timeout_duration: Option<Duration>,
+    ) -> Result<ClaudeResponse, RunnerError> {
+        *self.packet.lock().unwrap() = stdin_content.to_string();
+        Ok(ClaudeResponse {
+            stdout: REQUIREMENTS.to_string(),
+            stderr: String::new(),
+            exit_code: 0,
+            runner_used: RunnerMode::Native,
+            runner_distro: None,
+            timed_out: false,
+            ndjson_result: xchecker::runner::Runner::parse_ndjson(REQUIREMENTS),
+            stdout_truncated: false,
+            stderr_truncated: false,
+            stdout_total_bytes: REQUIREMENTS.len(),
+            stderr_total_bytes: 0,
+        })
+    }
+}
+
+/// Secret-shaped example text; assembled at runtime so the source itself
+/// does not trip secret scanners
+fn example_token() -> String {
+    format!("ghp_{}", "a1B2c3D4e5F6g7H8i9J0k1L2m3N4o5P6q7R8")
+}
+
+#[tokio::test]
+async fn test_exempt_file_is_sent_verbatim_and_flagged_in_receipt() -> Result<()> {
+    let _home = with_isolated_home();
+    let spec_id = "redaction-exempt";
+    let token = example_token();
+
+    let root = spec_root(spec_id);
+    std::fs::create_dir_all(root.join("docs"))?;
+    std::fs::write(
+        root.join("docs/token-formats.md"),
+        format!("# Token formats\n\nA GitHub PAT looks like `{token}`.\n"),
+    )?;
+
+    let config = OrchestratorConfig {
+        dry_run: false,
+        config: HashMap::from([
+            ("output_format".to_string(), "text".to_string()),
+            (
+                "packet_redaction_exempt".to_string(),
+                "**/docs/token-formats.md".to_string(),
+            ),
+        ]),
+        full_config: None,
+        selectors: None,
+        strict_validation: false,
+        redactor: Default::default(),
+        hooks: None,
+    };
+    let packet = std::sync::Arc::new(std::sync::Mutex::new(String::new()));
+    let mut handle = OrchestratorHandle::with_config_and_force(spec_id, config, false)?
+        .with_runner(Box::new(CapturingRunner {
+            packet: packet.clone(),
+        }));
+
+    let result = handle.run_phase(PhaseId::Requirements).await?;
+    assert!(result.success, "phase should succeed: {:?}", result.error);
+    assert!(packet.lock().unwrap().contains(&token));
+
+    let receipt = ReceiptManager::new(&root)
+        .read_latest_receipt(PhaseId::Requirements)?
+        .expect("requirements receipt");
+    let exempt_file = receipt
+        .packet
+        .files
+        .iter()
+        .find(|file| file.path.ends_with("docs/token-formats.md"))
+        .expect("exempt file in packet evidence");
+    assert!(exempt_file.redaction_exempt);
+    assert!(
+        receipt
+            .packet
+            .files
+            .iter()
+            .filter(|file| !file.path.ends_with("docs/token-formats.md"))
+            .all(|file| !file.redaction_exempt)
+    );
+    assert_eq!(
+        receipt.flags.get("redaction_exempt").map(String::as_str),
+        Some(exempt_file.path.as_str())
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_non_exempt_secret_still_fails_packet() -> Result<()> {
+    let _home = with_isolated_home();
+    let spec_id = "redaction-exempt-other";
+
+    let root = spec_root(spec_id);
+    std::fs::create_dir_all(root.join("docs"))?;
+    std::fs::write(
+        root.join("docs/token-formats.md"),
+        format!("`{}`\n", example_token()),
+    )?;
+
+    let config = OrchestratorConfig {
+        dry_run: false,
+        config: HashMap::from([
+            ("output_format".to_string(), "text".to_string()),
+            (
+                "packet_redaction_exempt".to_string(),
+                "**/docs/other.md".to_string(),
+            ),
+        ]),
+        full_config: None,
+        selectors: None,
+        strict_validation: false,
+        redactor: Default::default(),
+        hooks: None,
+    };
+    let packet = std::sync::Arc::new(std::sync::Mutex::new(String::new()));
+    let mut handle = OrchestratorHandle::with_config_and_force(spec_id, config, false)?
+        .with_runner(Box::new(CapturingRunner {
+            packet: packet.clone(),
+        }));
+
+    let result = handle.run_phase(PhaseId::Requirements).await;
+    let failed = match result {
+        Ok(result) => !result.success,
+        Err(_) => true,
+    };
+    assert!(failed, "a secret outside the exemption must fail the phase");
+    assert!(packet.lock().unwrap().is_empty());
+    Ok(())
+}
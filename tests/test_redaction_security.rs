@@ -98,6 +98,7 @@ fn test_packet_preview_secrets_redacted() -> Result<()> {
     let mut builder = PacketBuilder::new()?;
     builder
         .redactor_mut()
+        .unwrap()
         .add_ignored_pattern("github_pat".to_string());
 
     // Now create a file with a GitHub token (which will be ignored)
@@ -132,6 +133,7 @@ fn test_receipts_no_raw_packet_content() -> Result<()> {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     // Create a receipt
@@ -210,9 +212,11 @@ fn test_receipts_packet_evidence_no_content_leak() -> Result<()> {
             range: None,
             blake3_pre_redaction: "abc123def456".to_string(),
             priority: xchecker::types::Priority::High,
+            redaction_exempt: false,
         }],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     // Create a receipt
@@ -295,6 +299,7 @@ fn test_status_no_environment_variables() -> Result<()> {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let receipt = receipt_manager.create_receipt(
@@ -490,6 +495,7 @@ fn test_receipt_stderr_tail_no_secrets() -> Result<()> {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let stderr_tail =
@@ -0,0 +1,101 @@
+//! Tests for `OrchestratorHandle::refresh_core_yaml`
+//!
+//! Hand-editing a phase's markdown artifact leaves its companion core YAML
+//! stale; refreshing must re-run extraction and rewrite the YAML in place.
+
+use xchecker::{OrchestratorHandle, PhaseId};
+
+const TWO_REQUIREMENTS: &str = "# Requirements Document
+
+### Requirement 1
+
+**User Story:** As a user, I want to log in, so that I can see my data
+
+#### Acceptance Criteria
+
+1. WHEN the user submits valid credentials THEN the system SHALL sign them in
+
+### Requirement 2
+
+**User Story:** As a user, I want to log out, so that my session ends
+
+#### Acceptance Criteria
+
+1. WHEN the user clicks log out THEN the system SHALL end the session
+";
+
+fn core_yaml(handle: &OrchestratorHandle) -> serde_yaml::Value {
+    let path = handle
+        .artifact_manager()
+        .artifacts_path()
+        .join("00-requirements.core.yaml");
+    serde_yaml::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+}
+
+#[test]
+fn test_refresh_core_yaml_tracks_edited_requirement_count() {
+    let _home = xchecker::paths::with_isolated_home();
+    let mut handle = OrchestratorHandle::with_force("refresh-core-yaml", true).unwrap();
+    let artifacts = handle.artifact_manager().artifacts_path();
+
+    std::fs::write(artifacts.join("00-requirements.md"), TWO_REQUIREMENTS).unwrap();
+    handle.refresh_core_yaml(PhaseId::Requirements).unwrap();
+    let before = core_yaml(&handle);
+    assert_eq!(before["metadata"]["total_requirements"], 2);
+
+    // Hand-edit the markdown to add a third requirement
+    let edited = format!(
+        "{TWO_REQUIREMENTS}
+### Requirement 3
+
+**User Story:** As an admin, I want an audit log, so that I can review sign-ins
+"
+    );
+    std::fs::write(artifacts.join("00-requirements.md"), edited).unwrap();
+    handle.refresh_core_yaml(PhaseId::Requirements).unwrap();
+
+    let after = core_yaml(&handle);
+    assert_eq!(after["metadata"]["total_requirements"], 3);
+    assert_eq!(after["metadata"]["total_user_stories"], 3);
+    assert_eq!(after["spec_id"], "refresh-core-yaml");
+}
+
+#[test]
+fn test_refresh_core_yaml_is_byte_identical_for_same_markdown() {
+    let _home = xchecker::paths::with_isolated_home();
+    let mut handle = OrchestratorHandle::with_force("refresh-core-yaml-stable", true).unwrap();
+    let artifacts = handle.artifact_manager().artifacts_path();
+    let core_yaml_path = artifacts.join("00-requirements.core.yaml");
+
+    std::fs::write(artifacts.join("00-requirements.md"), TWO_REQUIREMENTS).unwrap();
+    handle.refresh_core_yaml(PhaseId::Requirements).unwrap();
+    let first = std::fs::read(&core_yaml_path).unwrap();
+    std::thread::sleep(std::time::Duration::from_millis(5));
+    handle.refresh_core_yaml(PhaseId::Requirements).unwrap();
+
+    assert_eq!(std::fs::read(&core_yaml_path).unwrap(), first);
+}
+
+#[test]
+fn test_refresh_core_yaml_rejects_phases_without_core_yaml() {
+    let _home = xchecker::paths::with_isolated_home();
+    let mut handle = OrchestratorHandle::with_force("refresh-core-yaml-final", true).unwrap();
+
+    let err = handle.refresh_core_yaml(PhaseId::Final).unwrap_err();
+    assert!(err.to_string().contains("no core YAML"), "{err}");
+}
+
+#[test]
+fn test_refresh_core_yaml_requires_markdown_artifact() {
+    let _home = xchecker::paths::with_isolated_home();
+    let mut handle = OrchestratorHandle::with_force("refresh-core-yaml-missing", true).unwrap();
+
+    assert!(handle.refresh_core_yaml(PhaseId::Design).is_err());
+    assert!(
+        !handle
+            .artifact_manager()
+            .artifacts_path()
+            .join("10-design.core.yaml")
+            .exists()
+    );
+}
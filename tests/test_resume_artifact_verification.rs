@@ -0,0 +1,146 @@
+//! Tests for upstream artifact verification on resume
+//!
+//! Resuming a phase re-hashes the artifacts of earlier phases and compares
+//! them with their receipts, so edits made outside xchecker are caught
+//! before later phases build on them.
+
+use xchecker::{OrchestratorHandle, PhaseId, XCheckerError};
+
+fn requirements_receipt(handle: &OrchestratorHandle) -> serde_json::Value {
+    let receipts = handle.artifact_manager().receipts_path();
+    let mut paths: Vec<_> = std::fs::read_dir(receipts)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("requirements-"))
+        })
+        .collect();
+    paths.sort();
+    let latest = paths.last().expect("requirements receipt");
+    serde_json::from_str(&std::fs::read_to_string(latest).unwrap()).unwrap()
+}
+
+async fn handle_with_edited_requirements(spec_id: &str) -> OrchestratorHandle {
+    let mut handle = OrchestratorHandle::with_force(spec_id, true).unwrap();
+    handle.set_dry_run(true);
+    handle.run_phase(PhaseId::Requirements).await.unwrap();
+
+    let artifact = handle
+        .artifact_manager()
+        .artifacts_path()
+        .join("00-requirements.md");
+    let mut content = std::fs::read_to_string(&artifact).unwrap();
+    content.push_str("\n### Requirement 99\n\nAdded by hand.\n");
+    std::fs::write(&artifact, content).unwrap();
+    handle
+}
+
+#[tokio::test]
+async fn test_resume_rejects_externally_modified_artifact() {
+    let _home = xchecker::paths::with_isolated_home();
+    let mut handle = handle_with_edited_requirements("resume-modified").await;
+
+    let err = handle.run_phase(PhaseId::Design).await.unwrap_err();
+    match err.downcast_ref::<XCheckerError>() {
+        Some(XCheckerError::ArtifactModified { name }) => {
+            assert_eq!(name, "00-requirements.md");
+        }
+        other => panic!("expected ArtifactModified, got {other:?}"),
+    }
+    assert!(
+        !handle
+            .artifact_manager()
+            .artifacts_path()
+            .join("10-design.md")
+            .exists()
+    );
+}
+
+#[tokio::test]
+async fn test_accept_modified_updates_receipt_and_proceeds() {
+    let _home = xchecker::paths::with_isolated_home();
+    let mut handle = handle_with_edited_requirements("resume-accept-modified").await;
+    let before = requirements_receipt(&handle);
+
+    handle.set_config("accept_modified", "true");
+    let result = handle.run_phase(PhaseId::Design).await.unwrap();
+    assert!(result.success);
+
+    let after = requirements_receipt(&handle);
+    assert_eq!(after["emitted_at"], before["emitted_at"]);
+    assert_ne!(after["outputs"], before["outputs"]);
+    assert!(
+        after["warnings"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|w| w.as_str().unwrap().contains("00-requirements.md"))
+    );
+
+    // The recorded hash now matches, so a plain resume succeeds
+    handle.set_config("accept_modified", "false");
+    assert!(handle.run_phase(PhaseId::Design).await.unwrap().success);
+}
+
+#[tokio::test]
+async fn test_applied_fixup_then_resume_needs_no_accept_modified() {
+    let _home = xchecker::paths::with_isolated_home();
+    let mut handle = OrchestratorHandle::with_force("resume-after-fixup", true).unwrap();
+    handle.set_dry_run(true);
+    for phase in [
+        PhaseId::Requirements,
+        PhaseId::Design,
+        PhaseId::Tasks,
+        PhaseId::Review,
+    ] {
+        assert!(handle.run_phase(phase).await.unwrap().success);
+    }
+
+    // Review output proposing an edit to the requirements artifact
+    let artifact = handle
+        .artifact_manager()
+        .artifacts_path()
+        .join("00-requirements.md");
+    let first_line = std::fs::read_to_string(&artifact)
+        .unwrap()
+        .lines()
+        .next()
+        .unwrap()
+        .to_string();
+    let fixtures = tempfile::TempDir::new().unwrap();
+    std::fs::write(
+        fixtures.path().join("fixup.md"),
+        format!(
+            "FIXUP PLAN:\n\n```diff\n--- a/artifacts/00-requirements.md\n+++ b/artifacts/00-requirements.md\n@@ -1,1 +1,2 @@\n {first_line}\n+Clarified by fixup.\n```\n"
+        ),
+    )
+    .unwrap();
+
+    handle.set_config("fixture_dir", fixtures.path().to_str().unwrap());
+    handle.set_config("apply_fixups", "true");
+    let result = handle.run_phase(PhaseId::Fixup).await.unwrap();
+    assert!(result.success, "{:?}", result.error);
+    assert!(
+        std::fs::read_to_string(&artifact)
+            .unwrap()
+            .contains("Clarified by fixup.")
+    );
+
+    // The fixup's edit is not an external modification
+    let after = requirements_receipt(&handle);
+    assert!(
+        after["warnings"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|w| w.as_str().unwrap().contains("applied fixup"))
+    );
+    // Resuming re-verifies every upstream artifact, without accept_modified
+    let mut handle = OrchestratorHandle::with_force("resume-after-fixup", true).unwrap();
+    handle.set_dry_run(true);
+    handle.set_config("fixture_dir", fixtures.path().to_str().unwrap());
+    let resumed = handle.run_phase(PhaseId::Fixup).await.unwrap();
+    assert!(resumed.success, "{:?}", resumed.error);
+}
@@ -162,6 +162,7 @@ async fn test_resume_with_failed_dependency_fails() -> Result<()> {
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            preview_compressed: false,
         },
         None,                             // stderr_tail
         None,                             // stderr_redacted
@@ -223,7 +224,7 @@ async fn test_resume_with_partial_artifact_deletes_and_restarts() -> Result<()>
     // Note: Design phase number is 10, not 01
     let partial_artifact = xchecker::artifact::Artifact {
         name: "10-design.partial.md".to_string(),
-        content: "# Partial Design\n\nThis is a partial artifact from a failed run.".to_string(),
+        content: "# Partial Design\n\nThis is a partial artifact from a failed run.".into(),
         artifact_type: xchecker::artifact::ArtifactType::Partial,
         blake3_hash: blake3::hash(b"partial content").to_hex().to_string(),
     };
@@ -371,6 +372,7 @@ async fn test_resume_after_timeout() -> Result<()> {
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            preview_compressed: false,
         },
         None,                                                  // stderr_tail
         None,                                                  // stderr_redacted
@@ -418,7 +420,7 @@ async fn test_resume_cleans_up_partial_on_success() -> Result<()> {
     // Note: Design phase number is 10, not 01
     let partial_artifact = xchecker::artifact::Artifact {
         name: "10-design.partial.md".to_string(),
-        content: "# Partial Design".to_string(),
+        content: "# Partial Design".into(),
         artifact_type: xchecker::artifact::ArtifactType::Partial,
         blake3_hash: blake3::hash(b"partial").to_hex().to_string(),
     };
@@ -0,0 +1,81 @@
+//! Tests for `OrchestratorHandle::run_all_cancellable`
+//!
+//! Cancelling the token stops the run before the next phase starts, keeps the
+//! completed phases' artifacts and releases the spec lock.
+
+use std::io::{self, Write};
+
+use tokio_util::sync::CancellationToken;
+use xchecker::OrchestratorHandle;
+use xchecker::error::XCheckerError;
+use xchecker::types::PhaseId;
+
+/// Event sink that cancels `token` as soon as a phase completes
+struct CancelOnPhaseCompleted {
+    token: CancellationToken,
+}
+
+impl Write for CancelOnPhaseCompleted {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if String::from_utf8_lossy(buf).contains("phase_completed") {
+            self.token.cancel();
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_cancel_after_first_phase_skips_the_rest() {
+    let _home = xchecker::paths::with_isolated_home();
+
+    let spec_id = "cancel-after-requirements";
+    let token = CancellationToken::new();
+    let mut handle = OrchestratorHandle::with_force(spec_id, true)
+        .unwrap()
+        .with_event_sink(CancelOnPhaseCompleted {
+            token: token.clone(),
+        });
+    handle.set_dry_run(true);
+
+    let err = handle.run_all_cancellable(token).await.unwrap_err();
+
+    assert!(
+        matches!(
+            err.downcast_ref::<XCheckerError>(),
+            Some(XCheckerError::Cancelled)
+        ),
+        "expected Cancelled, got {err:?}"
+    );
+    assert_eq!(handle.current_phase().unwrap(), Some(PhaseId::Requirements));
+
+    let artifacts = handle.artifact_manager().base_path().join("artifacts");
+    assert!(artifacts.join("00-requirements.md").exists());
+    assert!(!artifacts.join("10-design.md").exists());
+
+    // The lock is gone even though the first handle is still alive
+    let second = OrchestratorHandle::new(spec_id);
+    assert!(second.is_ok(), "lock should be released after cancellation");
+    drop(handle);
+}
+
+#[tokio::test]
+async fn test_cancelled_token_runs_no_phase() {
+    let _home = xchecker::paths::with_isolated_home();
+
+    let mut handle = OrchestratorHandle::with_force("cancel-before-start", true).unwrap();
+    handle.set_dry_run(true);
+
+    let token = CancellationToken::new();
+    token.cancel();
+    let err = handle.run_all_cancellable(token).await.unwrap_err();
+
+    assert!(matches!(
+        err.downcast_ref::<XCheckerError>(),
+        Some(XCheckerError::Cancelled)
+    ));
+    assert!(handle.current_phase().unwrap().is_none());
+}
@@ -0,0 +1,73 @@
+//! Tests for `OrchestratorHandle::run_all_with_deadline`
+//!
+//! A hanging Claude stub must be abandoned once the run-wide deadline passes,
+//! surfacing `DeadlineExceeded` and releasing the spec lock.
+
+use std::time::{Duration, Instant};
+
+use xchecker::OrchestratorHandle;
+use xchecker::error::XCheckerError;
+
+#[allow(clippy::duplicate_mod)]
+#[path = "test_support/mod.rs"]
+mod test_support;
+
+#[tokio::test]
+#[ignore = "requires_claude_stub"]
+async fn test_deadline_aborts_hanging_phase_and_releases_lock() {
+    let Some(stub_path) = test_support::claude_stub_path() else {
+        eprintln!("Skipping: claude-stub not available");
+        return;
+    };
+    let _home = xchecker::paths::with_isolated_home();
+    // Far longer than the deadline, so only the deadline can end the phase
+    let _hang = test_support::EnvVarGuard::set("CLAUDE_STUB_HANG_SECS", "60");
+
+    let spec_id = "deadline-hang";
+    let mut handle = OrchestratorHandle::with_force(spec_id, true).unwrap();
+    handle.set_dry_run(false);
+    handle.set_config("llm_claude_binary", &stub_path);
+    handle.set_config("claude_scenario", "hang");
+
+    let started = Instant::now();
+    let err = handle
+        .run_all_with_deadline(started + Duration::from_secs(1))
+        .await
+        .unwrap_err();
+
+    assert!(
+        started.elapsed() < Duration::from_secs(5),
+        "run should stop at the deadline, not the phase timeout"
+    );
+    match err.downcast_ref::<XCheckerError>() {
+        Some(XCheckerError::DeadlineExceeded { elapsed }) => {
+            assert!(*elapsed >= Duration::from_secs(1));
+        }
+        other => panic!("expected DeadlineExceeded, got {other:?}"),
+    }
+
+    // The lock is gone even though the first handle is still alive
+    let second = OrchestratorHandle::new(spec_id);
+    assert!(second.is_ok(), "lock should be released after the deadline");
+    drop(handle);
+}
+
+#[tokio::test]
+async fn test_deadline_already_passed_runs_no_phase() {
+    let _home = xchecker::paths::with_isolated_home();
+
+    let spec_id = "deadline-passed";
+    let mut handle = OrchestratorHandle::with_force(spec_id, true).unwrap();
+    handle.set_dry_run(true);
+
+    let err = handle
+        .run_all_with_deadline(Instant::now())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        err.downcast_ref::<XCheckerError>(),
+        Some(XCheckerError::DeadlineExceeded { .. })
+    ));
+    assert!(handle.current_phase().unwrap().is_none());
+}
@@ -0,0 +1,88 @@
+//! Tests for `OrchestratorHandle::run_selected`
+//!
+//! Selected phases run in workflow order on top of the artifacts already on
+//! disk; a selected phase whose skipped dependency never completed fails
+//! before anything runs.
+
+use xchecker::OrchestratorHandle;
+use xchecker::error::{PhaseError, XCheckerError};
+use xchecker::types::PhaseId;
+
+fn dry_run_handle(spec_id: &str) -> OrchestratorHandle {
+    let mut handle = OrchestratorHandle::with_force(spec_id, true).unwrap();
+    handle.set_dry_run(true);
+    handle
+}
+
+#[tokio::test]
+async fn test_only_design_without_requirements_fails() {
+    let _home = xchecker::paths::with_isolated_home();
+    let mut handle = dry_run_handle("run-selected-missing-dep");
+
+    let err = handle
+        .run_selected(&[PhaseId::Design], &[])
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(
+            err.downcast_ref::<XCheckerError>(),
+            Some(XCheckerError::Phase(PhaseError::DependencyNotSatisfied { phase, dependency }))
+                if phase == "design" && dependency == "requirements"
+        ),
+        "expected DependencyNotSatisfied, got {err:?}"
+    );
+    let artifacts = handle.artifact_manager().base_path().join("artifacts");
+    assert!(!artifacts.join("10-design.md").exists());
+    assert!(handle.current_phase().unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_skip_requirements_when_artifact_present_runs_the_rest() {
+    let _home = xchecker::paths::with_isolated_home();
+    let mut handle = dry_run_handle("run-selected-skip-requirements");
+
+    handle.run_phase(PhaseId::Requirements).await.unwrap();
+    let requirements_receipts = handle
+        .receipt_manager()
+        .list_receipts()
+        .unwrap()
+        .into_iter()
+        .filter(|receipt| receipt.phase == "requirements")
+        .count();
+
+    let result = handle
+        .run_selected(&[], &[PhaseId::Requirements])
+        .await
+        .unwrap();
+
+    assert!(result.success);
+    assert_eq!(result.phase, PhaseId::Tasks);
+    let artifacts = handle.artifact_manager().base_path().join("artifacts");
+    assert!(artifacts.join("10-design.md").exists());
+    assert!(artifacts.join("20-tasks.md").exists());
+
+    // Requirements was not run again
+    let receipts = handle.receipt_manager().list_receipts().unwrap();
+    assert_eq!(
+        receipts
+            .iter()
+            .filter(|receipt| receipt.phase == "requirements")
+            .count(),
+        requirements_receipts
+    );
+}
+
+#[tokio::test]
+async fn test_only_and_skip_are_mutually_exclusive() {
+    let _home = xchecker::paths::with_isolated_home();
+    let mut handle = dry_run_handle("run-selected-only-and-skip");
+
+    let err = handle
+        .run_selected(&[PhaseId::Requirements], &[PhaseId::Design])
+        .await
+        .unwrap_err();
+
+    assert!(err.to_string().contains("cannot both be set"));
+    assert!(handle.current_phase().unwrap().is_none());
+}
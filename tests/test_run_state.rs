@@ -0,0 +1,206 @@
+//! Tests for snapshotting and restoring orchestrator run state
+//!
+//! Each phase run leaves `run-state.json` in the spec root; a handle restored
+//! from it must resume at the next phase with the LLM call count carried over,
+//! and refuse a snapshot the artifacts no longer match.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use xchecker::error::XCheckerError;
+use xchecker::orchestrator::{
+    OrchestratorConfig, OrchestratorHandle, RESUMED_CONFIG_KEYS, RUN_STATE_FILE, RunState,
+};
+use xchecker::paths::{spec_root, with_isolated_home};
+use xchecker::runner::{ClaudeResponse, ClaudeRunner, RunnerError, RunnerMode};
+use xchecker::types::PhaseId;
+
+const REQUIREMENTS: &str = "# Requirements Document\n\n## Introduction\n\nRun state fixture.\n\n## Requirements\n\n### Requirement 1\n\n**User Story:** As an operator, I want interrupted runs to resume, so that no work is lost.\n\n#### Acceptance Criteria\n\n1. WHEN a run is restored THEN the system SHALL continue at the next phase\n";
+
+const DESIGN: &str = "# Design Document\n\n## Overview\n\nResume from a snapshot.\n\n## Architecture\n\nA run-state file written after each phase.\n";
+
+/// Answers with a design once the packet carries requirements, otherwise with requirements
+struct PhaseAwareRunner;
+
+#[async_trait]
+impl ClaudeRunner for PhaseAwareRunner {
+    async fn execute_claude(
+        &self,
+        _args: &[String],
+        stdin_content: &str,
+        _timeout_duration: Option<Duration>,
+    ) -> Result<ClaudeResponse, RunnerError> {
+        let stdout = if stdin_content.contains("# Requirements Document") {
+            DESIGN
+        } else {
+            REQUIREMENTS
+        };
+        Ok(ClaudeResponse {
+            stdout: stdout.to_string(),
+            stderr: String::new(),
+            exit_code: 0,
+            runner_used: RunnerMode::Native,
+            runner_distro: None,
+            timed_out: false,
+            ndjson_result: xchecker::runner::Runner::parse_ndjson(stdout),
+            stdout_truncated: false,
+            stderr_truncated: false,
+            stdout_total_bytes: stdout.len(),
+            stderr_total_bytes: 0,
+        })
+    }
+}
+
+fn live_config() -> OrchestratorConfig {
+    OrchestratorConfig {
+        dry_run: false,
+        config: HashMap::from([
+            ("output_format".to_string(), "text".to_string()),
+            // Machine-local; must not travel with the snapshot
+            (
+                "claude_path".to_string(),
+                "/opt/claude/bin/claude".to_string(),
+            ),
+            ("verbose".to_string(), "true".to_string()),
+        ]),
+        full_config: None,
+        selectors: None,
+        strict_validation: false,
+        redactor: Default::default(),
+        hooks: None,
+    }
+}
+
+/// Run Requirements in a handle that is dropped afterwards, as if the process exited
+async fn run_requirements(spec_id: &str) -> Result<()> {
+    let mut handle = OrchestratorHandle::with_config_and_force(spec_id, live_config(), false)?
+        .with_runner(Box::new(PhaseAwareRunner));
+    let result = handle.run_phase(PhaseId::Requirements).await?;
+    assert!(
+        result.success,
+        "requirements should succeed: {:?}",
+        result.error
+    );
+    assert_eq!(handle.llm_calls(), 1);
+    Ok(())
+}
+
+fn read_run_state(spec_id: &str) -> Result<RunState> {
+    let json = std::fs::read_to_string(spec_root(spec_id).join(RUN_STATE_FILE))?;
+    Ok(serde_json::from_str(&json)?)
+}
+
+#[tokio::test]
+async fn test_restored_handle_resumes_at_design_with_budget() -> Result<()> {
+    let _home = with_isolated_home();
+    let spec_id = "run-state-resume";
+    run_requirements(spec_id).await?;
+
+    let state = read_run_state(spec_id)?;
+    assert_eq!(state.last_completed_phase, Some(PhaseId::Requirements));
+    assert_eq!(state.next_phase, Some(PhaseId::Design));
+    assert_eq!(state.llm_calls, 1);
+    assert!(state.input_fingerprint.is_some());
+    assert!(state.artifacts.contains_key("00-requirements.md"));
+    assert_eq!(
+        state.config.get("output_format").map(String::as_str),
+        Some("text")
+    );
+
+    let mut handle = OrchestratorHandle::restore(spec_id)?.with_runner(Box::new(PhaseAwareRunner));
+    assert_eq!(handle.next_phase()?, Some(PhaseId::Design));
+    assert_eq!(handle.llm_calls(), 1);
+    assert_eq!(
+        handle.get_config("output_format").map(String::as_str),
+        Some("text")
+    );
+
+    let result = handle.run_phase(PhaseId::Design).await?;
+    assert!(result.success, "design should succeed: {:?}", result.error);
+    assert_eq!(handle.llm_calls(), 2);
+
+    let state = read_run_state(spec_id)?;
+    assert_eq!(state.last_completed_phase, Some(PhaseId::Design));
+    assert_eq!(state.next_phase, Some(PhaseId::Tasks));
+    assert_eq!(state.llm_calls, 2);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_restore_rejects_modified_artifact() -> Result<()> {
+    let _home = with_isolated_home();
+    let spec_id = "run-state-modified";
+    run_requirements(spec_id).await?;
+
+    std::fs::write(
+        spec_root(spec_id).join("artifacts/00-requirements.md"),
+        "# Requirements Document\n\nEdited after the snapshot.\n",
+    )?;
+
+    let err = OrchestratorHandle::restore(spec_id)
+        .err()
+        .expect("restore should reject a modified artifact");
+    assert!(
+        matches!(
+            err.downcast_ref::<XCheckerError>(),
+            Some(XCheckerError::RunStateInvalid { reason, .. }) if reason.contains("00-requirements.md")
+        ),
+        "unexpected error: {err:?}"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_restore_without_snapshot_fails() -> Result<()> {
+    let _home = with_isolated_home();
+
+    let err = OrchestratorHandle::restore("run-state-missing")
+        .err()
+        .expect("restore should fail without a snapshot");
+    assert!(
+        matches!(
+            err.downcast_ref::<XCheckerError>(),
+            Some(XCheckerError::RunStateInvalid { .. })
+        ),
+        "unexpected error: {err:?}"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_run_state_persists_only_resumed_config_keys() -> Result<()> {
+    let _home = with_isolated_home();
+    let spec_id = "run-state-allowlist";
+    run_requirements(spec_id).await?;
+
+    let mut state = read_run_state(spec_id)?;
+    assert!(
+        state
+            .config
+            .keys()
+            .all(|key| RESUMED_CONFIG_KEYS.contains(&key.as_str())),
+        "unexpected keys saved: {:?}",
+        state.config.keys().collect::<Vec<_>>()
+    );
+    assert!(state.config.contains_key("output_format"));
+    assert!(!state.config.contains_key("claude_path"));
+    assert!(!state.config.contains_key("verbose"));
+
+    // Keys outside the allowlist in a hand-edited snapshot are not restored
+    state
+        .config
+        .insert("claude_path".to_string(), "/tmp/evil-claude".to_string());
+    std::fs::write(
+        spec_root(spec_id).join(RUN_STATE_FILE),
+        serde_json::to_string(&state)?,
+    )?;
+    let handle = OrchestratorHandle::restore(spec_id)?;
+    assert_eq!(handle.get_config("claude_path"), None);
+    assert_eq!(
+        handle.get_config("output_format").map(String::as_str),
+        Some("text")
+    );
+    Ok(())
+}
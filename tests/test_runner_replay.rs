@@ -0,0 +1,127 @@
+//! Tests for recording and replaying Claude CLI interactions
+//!
+//! A two-phase run is recorded through `RecordingRunner` around a scripted
+//! runner, then replayed through `ReplayRunner` with nothing scripted: the
+//! replay must write byte-identical artifacts.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use xchecker::orchestrator::{OrchestratorConfig, OrchestratorHandle};
+use xchecker::paths::with_isolated_home;
+use xchecker::runner::{
+    ClaudeResponse, ClaudeRunner, RecordingRunner, ReplayRunner, RunnerError, RunnerMode,
+};
+use xchecker::types::PhaseId;
+
+const REQUIREMENTS: &str = "# Requirements Document\n\n## Introduction\n\nReplay fixture.\n\n## Requirements\n\n### Requirement 1\n\n**User Story:** As a tester, I want hermetic runs, so that pipelines are deterministic.\n\n#### Acceptance Criteria\n\n1. WHEN a run is replayed THEN the system SHALL write the recorded artifacts\n";
+
+const DESIGN: &str = "# Design Document\n\n## Overview\n\nServe recorded responses.\n\n## Architecture\n\nA runner keyed by argv and prompt.\n";
+
+/// Answers the first call with requirements and every later call with a design
+#[derive(Default)]
+struct ScriptedRunner {
+    calls: AtomicUsize,
+}
+
+#[async_trait]
+impl ClaudeRunner for ScriptedRunner {
+    async fn execute_claude(
+        &self,
+        _args: &[String],
+        _stdin_content: &str,
+        _timeout_duration: Option<Duration>,
+    ) -> Result<ClaudeResponse, RunnerError> {
+        let stdout = match self.calls.fetch_add(1, Ordering::SeqCst) {
+            0 => REQUIREMENTS,
+            _ => DESIGN,
+        };
+        Ok(ClaudeResponse {
+            stdout: stdout.to_string(),
+            stderr: String::new(),
+            exit_code: 0,
+            runner_used: RunnerMode::Native,
+            runner_distro: None,
+            timed_out: false,
+            ndjson_result: xchecker::runner::Runner::parse_ndjson(stdout),
+            stdout_truncated: false,
+            stderr_truncated: false,
+            stdout_total_bytes: stdout.len(),
+            stderr_total_bytes: 0,
+        })
+    }
+}
+
+fn live_config() -> OrchestratorConfig {
+    OrchestratorConfig {
+        dry_run: false,
+        config: HashMap::from([("output_format".to_string(), "text".to_string())]),
+        full_config: None,
+        selectors: None,
+        strict_validation: false,
+        redactor: Default::default(),
+        hooks: None,
+    }
+}
+
+async fn run_two_phases(spec_id: &str, runner: Box<dyn ClaudeRunner>) -> Result<Vec<String>> {
+    let mut handle = OrchestratorHandle::with_config_and_force(spec_id, live_config(), false)?
+        .with_runner(runner);
+    for phase in [PhaseId::Requirements, PhaseId::Design] {
+        let result = handle.run_phase(phase).await?;
+        assert!(
+            result.success,
+            "{phase:?} should succeed: {:?}",
+            result.error
+        );
+    }
+
+    let artifacts = xchecker::paths::spec_root(spec_id).join("artifacts");
+    ["00-requirements.md", "10-design.md"]
+        .iter()
+        .map(|name| Ok(std::fs::read_to_string(artifacts.join(name))?))
+        .collect()
+}
+
+#[tokio::test]
+async fn test_replayed_run_writes_identical_artifacts() -> Result<()> {
+    let _home = with_isolated_home();
+    let spec_id = "runner-replay";
+
+    let recorder = RecordingRunner::new(Box::<ScriptedRunner>::default());
+    let log = recorder.log();
+    let recorded = run_two_phases(spec_id, Box::new(recorder)).await?;
+    assert_eq!(log.interactions().len(), 2);
+
+    let recording = tempfile::TempDir::new()?;
+    let path = recording.path().join("interactions.json");
+    log.save(&path)?;
+
+    // Start over from an empty spec so only the recording can produce output
+    std::fs::remove_dir_all(xchecker::paths::spec_root(spec_id))?;
+    let replayed = run_two_phases(spec_id, Box::new(ReplayRunner::from_file(&path)?)).await?;
+
+    assert_eq!(recorded, replayed);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_replay_fails_on_unrecorded_interaction() -> Result<()> {
+    let _home = with_isolated_home();
+
+    let mut handle =
+        OrchestratorHandle::with_config_and_force("runner-replay-miss", live_config(), false)?
+            .with_runner(Box::new(ReplayRunner::new(vec![])));
+    let result = handle.run_phase(PhaseId::Requirements).await?;
+
+    assert!(!result.success);
+    let error = result.error.unwrap_or_default();
+    assert!(
+        error.contains("No recorded response"),
+        "unexpected error: {error}"
+    );
+    Ok(())
+}
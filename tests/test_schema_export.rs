@@ -0,0 +1,144 @@
+#![cfg(feature = "schemas")]
+//! Tests for the `schemas` feature: schemas generated from the Rust types
+//! must accept real emitted instances and reject ones missing required fields.
+
+use std::collections::HashMap;
+
+use camino::Utf8PathBuf;
+use chrono::Utc;
+use serde_json::Value;
+use tempfile::TempDir;
+
+use xchecker::doctor::{CheckStatus, DoctorCheck, DoctorOutput};
+use xchecker::receipt::ReceiptManager;
+use xchecker::schemas::{schema_for_doctor_output, schema_for_receipt};
+use xchecker::types::{FileHash, LlmInfo, PacketEvidence, PhaseId};
+
+fn doctor_output() -> Value {
+    let doctor = DoctorOutput {
+        schema_version: "1".to_string(),
+        emitted_at: Utc::now(),
+        ok: false,
+        checks: vec![
+            DoctorCheck {
+                name: "claude_path".to_string(),
+                status: CheckStatus::Pass,
+                details: "Found claude at /usr/local/bin/claude".to_string(),
+                remediation: None,
+            },
+            DoctorCheck {
+                name: "config_parse".to_string(),
+                status: CheckStatus::Fail,
+                details: "invalid TOML".to_string(),
+                remediation: None,
+            },
+        ],
+        cache_stats: Some(Default::default()),
+    };
+    serde_json::to_value(&doctor).unwrap()
+}
+
+fn receipt() -> Value {
+    let temp_dir = TempDir::new().unwrap();
+    let base_path = Utf8PathBuf::from_path_buf(temp_dir.path().to_path_buf()).unwrap();
+    let manager = ReceiptManager::new(&base_path);
+
+    let mut receipt = manager.create_receipt(
+        "schema-export",
+        PhaseId::Design,
+        0,
+        vec![FileHash {
+            path: "artifacts/10-design.md".to_string(),
+            blake3_canonicalized: "ab".repeat(32),
+        }],
+        "1.1.0",
+        "0.8.1",
+        "haiku",
+        Some("haiku".to_string()),
+        HashMap::from([("dry_run".to_string(), "false".to_string())]),
+        PacketEvidence {
+            files: vec![],
+            max_bytes: 65536,
+            max_lines: 1200,
+            preview_compressed: false,
+        },
+        None,
+        None,
+        vec!["packet near limit".to_string()],
+        Some(false),
+        "native",
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    receipt.llm = Some(LlmInfo {
+        provider: Some("claude-cli".to_string()),
+        model_used: Some("haiku".to_string()),
+        tokens_input: Some(120),
+        tokens_output: Some(480),
+        timed_out: Some(false),
+        timeout_seconds: None,
+        budget_exhausted: None,
+    });
+    serde_json::to_value(&receipt).unwrap()
+}
+
+fn assert_valid(schema: &Value, instance: &Value) {
+    let validator = jsonschema::validator_for(schema).expect("generated schema should compile");
+    if let Err(error) = validator.validate(instance) {
+        panic!("instance failed generated schema: {error}");
+    }
+}
+
+fn assert_invalid_without(schema: &Value, mut instance: Value, field: &str) {
+    instance.as_object_mut().unwrap().remove(field);
+    let validator = jsonschema::validator_for(schema).expect("generated schema should compile");
+    assert!(
+        !validator.is_valid(&instance),
+        "instance without `{field}` should be rejected"
+    );
+}
+
+#[test]
+fn test_doctor_output_schema_describes_schema_version() {
+    let schema = schema_for_doctor_output();
+    assert_eq!(schema["title"], "DoctorOutput");
+    assert_eq!(schema["properties"]["schema_version"]["type"], "string");
+    let required = schema["required"].as_array().unwrap();
+    assert!(required.contains(&Value::from("schema_version")));
+}
+
+#[test]
+fn test_doctor_output_schema_validates_real_output() {
+    assert_valid(&schema_for_doctor_output(), &doctor_output());
+}
+
+#[test]
+fn test_doctor_output_schema_rejects_missing_required_field() {
+    let schema = schema_for_doctor_output();
+    assert_invalid_without(&schema, doctor_output(), "checks");
+    assert_invalid_without(&schema, doctor_output(), "schema_version");
+}
+
+#[test]
+fn test_receipt_schema_describes_schema_version() {
+    let schema = schema_for_receipt();
+    assert_eq!(schema["title"], "Receipt");
+    assert_eq!(schema["properties"]["schema_version"]["type"], "string");
+    let required = schema["required"].as_array().unwrap();
+    assert!(required.contains(&Value::from("schema_version")));
+}
+
+#[test]
+fn test_receipt_schema_validates_real_receipt() {
+    assert_valid(&schema_for_receipt(), &receipt());
+}
+
+#[test]
+fn test_receipt_schema_rejects_missing_required_field() {
+    let schema = schema_for_receipt();
+    assert_invalid_without(&schema, receipt(), "outputs");
+    assert_invalid_without(&schema, receipt(), "schema_version");
+}
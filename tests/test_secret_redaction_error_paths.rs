@@ -43,6 +43,7 @@ fn test_at_sec_003_secret_in_error_reason() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let receipt = manager.create_receipt(
@@ -103,6 +104,7 @@ fn test_secret_in_stderr_tail() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let receipt = manager.create_receipt(
@@ -164,6 +166,7 @@ fn test_secret_in_warning_messages() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let receipt = manager.create_receipt(
@@ -230,6 +233,7 @@ fn test_multiple_secrets_in_error_reason() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let receipt = manager.create_receipt(
@@ -285,6 +289,7 @@ fn test_secret_in_bearer_token_error() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let receipt = manager.create_receipt(
@@ -338,6 +343,7 @@ fn test_secret_in_slack_token_warning() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let receipt = manager.create_receipt(
@@ -392,6 +398,7 @@ fn test_aws_secret_key_in_stderr() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let receipt = manager.create_receipt(
@@ -449,6 +456,7 @@ fn test_no_secrets_no_redaction() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let receipt = manager.create_receipt(
@@ -500,6 +508,7 @@ fn test_receipt_write_persists_redacted_content() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let receipt = manager.create_receipt(
@@ -560,6 +569,7 @@ fn test_error_receipt_creation_with_secrets() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let token = test_support::github_pat();
@@ -655,6 +665,7 @@ fn test_context_lines_redaction() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let receipt = manager.create_receipt(
@@ -713,6 +724,7 @@ fn test_all_error_fields_redacted_together() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let receipt = manager.create_receipt(
@@ -60,6 +60,7 @@ fn create_test_receipt(
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let receipt = receipt_manager.create_receipt(
@@ -211,6 +212,7 @@ fn test_artifact_enumeration_with_blake3() -> Result<()> {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let outputs = vec![
@@ -561,6 +563,7 @@ fn test_status_artifacts_sorted() -> Result<()> {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let outputs = vec![
@@ -0,0 +1,66 @@
+//! Tests for the read-only HTTP status server (`server` feature)
+
+use std::net::SocketAddr;
+
+use camino::Utf8PathBuf;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use xchecker::server::StatusServer;
+use xchecker::{OrchestratorHandle, PhaseId};
+
+/// Send a bare HTTP/1.1 request and split the reply into status code and body
+async fn request(addr: SocketAddr, method: &str, path: &str) -> (u16, String) {
+    let mut stream = TcpStream::connect(addr).await.unwrap();
+    let head = format!("{method} {path} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n");
+    stream.write_all(head.as_bytes()).await.unwrap();
+
+    let mut reply = String::new();
+    stream.read_to_string(&mut reply).await.unwrap();
+    let (head, body) = reply.split_once("\r\n\r\n").unwrap();
+    let status = head.split_whitespace().nth(1).unwrap().parse().unwrap();
+    (status, body.to_string())
+}
+
+#[tokio::test]
+async fn test_status_server_serves_seeded_spec() {
+    let home_dir = xchecker::paths::with_isolated_home();
+    let home = Utf8PathBuf::from_path_buf(home_dir.path().to_path_buf()).unwrap();
+
+    {
+        let mut handle = OrchestratorHandle::with_force("served-spec", true).unwrap();
+        handle.set_dry_run(true);
+        handle.run_phase(PhaseId::Requirements).await.unwrap();
+    }
+
+    let server = StatusServer::bind("127.0.0.1:0".parse().unwrap(), home)
+        .await
+        .unwrap();
+    let addr = server.local_addr().unwrap();
+    let serving = tokio::spawn(server.run());
+
+    let (status, body) = request(addr, "GET", "/specs").await;
+    assert_eq!(status, 200);
+    let specs: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(specs["specs"], serde_json::json!(["served-spec"]));
+
+    let (status, body) = request(addr, "GET", "/specs/served-spec/status").await;
+    assert_eq!(status, 200);
+    let spec_status: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(spec_status["schema_version"], "1");
+    assert!(
+        spec_status["artifacts"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|artifact| artifact["path"] == "artifacts/00-requirements.md"),
+        "{body}"
+    );
+
+    let (status, _) = request(addr, "GET", "/specs/missing-spec/status").await;
+    assert_eq!(status, 404);
+
+    let (status, _) = request(addr, "POST", "/specs").await;
+    assert_eq!(status, 405);
+
+    serving.abort();
+}
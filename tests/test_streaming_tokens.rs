@@ -0,0 +1,278 @@
+//! Tests for streaming LLM response text through a token callback
+//!
+//! A mock Anthropic endpoint streams a requirements document as several
+//! server-sent events; `run_phase_streaming_tokens` must hand the text to
+//! the callback in order, a line at a time, while the phase still sees the
+//! assembled response.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use xchecker::config::{CliArgs, Config};
+use xchecker::orchestrator::{OrchestratorConfig, OrchestratorHandle};
+use xchecker::paths::with_isolated_home;
+use xchecker::runner::{ClaudeResponse, ClaudeRunner, RunnerError, RunnerMode};
+use xchecker::types::PhaseId;
+
+const API_KEY_ENV: &str = "STREAMING_TOKENS_TEST_API_KEY";
+
+const CHUNKS: &[&str] = &[
+    "# Requirements Document\n\n## Introduction\n\n",
+    "Streamed fixture.\n\n## Requirements\n\n### Requirement 1\n\n",
+    "**User Story:** As a reader, I want output as it is generated, ",
+    "so that long phases show progress.\n\n#### Acceptance Criteria\n\n",
+    "1. WHEN a provider streams THEN the system SHALL forward each chunk\n",
+];
+
+fn sse_event(event: &str, data: &serde_json::Value) -> String {
+    format!("event: {event}\ndata: {data}\n\n")
+}
+
+/// Serve one request with `chunks` as an Anthropic event stream
+///
+/// Resolves to the JSON body of the request it answered.
+async fn serve_stream(
+    listener: TcpListener,
+    chunks: &'static [&'static str],
+) -> Result<serde_json::Value> {
+    let (mut socket, _) = listener.accept().await?;
+
+    // Read headers, then as much body as Content-Length announces
+    let mut request = Vec::new();
+    let mut buf = [0u8; 4096];
+    let body_start = loop {
+        let n = socket.read(&mut buf).await?;
+        anyhow::ensure!(n > 0, "connection closed before request headers ended");
+        request.extend_from_slice(&buf[..n]);
+        if let Some(pos) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+            break pos + 4;
+        }
+    };
+    let headers = String::from_utf8_lossy(&request[..body_start]).to_lowercase();
+    let content_length: usize = headers
+        .lines()
+        .find_map(|line| line.strip_prefix("content-length:"))
+        .map_or(Ok(0), |value| value.trim().parse())?;
+    while request.len() < body_start + content_length {
+        let n = socket.read(&mut buf).await?;
+        anyhow::ensure!(n > 0, "connection closed before request body ended");
+        request.extend_from_slice(&buf[..n]);
+    }
+    let body = serde_json::from_slice(&request[body_start..body_start + content_length])?;
+
+    socket
+        .write_all(
+            b"HTTP/1.1 200 OK\r\ncontent-type: text/event-stream\r\nconnection: close\r\n\r\n",
+        )
+        .await?;
+
+    let mut events = vec![sse_event(
+        "message_start",
+        &serde_json::json!({
+            "type": "message_start",
+            "message": { "usage": { "input_tokens": 42, "output_tokens": 1 } }
+        }),
+    )];
+    events.extend(chunks.iter().map(|text| {
+        sse_event(
+            "content_block_delta",
+            &serde_json::json!({
+                "type": "content_block_delta",
+                "index": 0,
+                "delta": { "type": "text_delta", "text": text }
+            }),
+        )
+    }));
+    events.push(sse_event(
+        "message_delta",
+        &serde_json::json!({ "type": "message_delta", "usage": { "output_tokens": 64 } }),
+    ));
+    events.push(sse_event(
+        "message_stop",
+        &serde_json::json!({ "type": "message_stop" }),
+    ));
+
+    for event in events {
+        socket.write_all(event.as_bytes()).await?;
+        socket.flush().await?;
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    socket.shutdown().await?;
+    Ok(body)
+}
+
+fn anthropic_config(base_url: &str) -> Result<OrchestratorConfig> {
+    let workspace = tempfile::TempDir::new()?;
+    let config_path = workspace.path().join("config.toml");
+    std::fs::write(
+        &config_path,
+        format!(
+            "[llm]\nprovider = \"anthropic\"\n\n[llm.anthropic]\napi_key_env = \"{API_KEY_ENV}\"\nbase_url = \"{base_url}\"\nmodel = \"claude-stream-test\"\n"
+        ),
+    )?;
+    let config = Config::discover(&CliArgs {
+        config_path: Some(config_path),
+        ..Default::default()
+    })?;
+
+    Ok(OrchestratorConfig {
+        dry_run: false,
+        config: HashMap::new(),
+        full_config: Some(config),
+        selectors: None,
+        strict_validation: false,
+        redactor: Default::default(),
+        hooks: None,
+    })
+}
+
+#[tokio::test]
+async fn test_streamed_chunks_reach_callback_in_order() -> Result<()> {
+    let _home = with_isolated_home();
+    // SAFETY: this variable is read by this test only
+    unsafe { std::env::set_var(API_KEY_ENV, "test-api-key") };
+
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let base_url = format!("http://{}/v1/messages", listener.local_addr()?);
+    let server = tokio::spawn(serve_stream(listener, CHUNKS));
+
+    let spec_id = "streaming-tokens";
+    let mut handle =
+        OrchestratorHandle::with_config_and_force(spec_id, anthropic_config(&base_url)?, false)?;
+    let mut received = Vec::new();
+    let result = handle
+        .run_phase_streaming_tokens(PhaseId::Requirements, |chunk| {
+            received.push(chunk.to_string());
+        })
+        .await?;
+    assert!(result.success, "phase should succeed: {:?}", result.error);
+
+    let request = server.await??;
+    assert_eq!(request["stream"], serde_json::json!(true));
+    // Chunks are delivered as whole lines: the third chunk ends mid-line, so
+    // it is held back and sent with the fourth
+    assert_eq!(
+        received,
+        [
+            CHUNKS[0].to_string(),
+            CHUNKS[1].to_string(),
+            format!("{}{}", CHUNKS[2], CHUNKS[3]),
+            CHUNKS[4].to_string(),
+        ]
+    );
+
+    let artifact = std::fs::read_to_string(
+        xchecker::paths::spec_root(spec_id).join("artifacts/00-requirements.md"),
+    )?;
+    // Postprocessing sees the assembled response; only trailing whitespace is trimmed
+    assert_eq!(artifact, CHUNKS.concat().trim_end());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_secret_split_across_chunks_is_redacted() -> Result<()> {
+    const SPLIT_SECRET_CHUNKS: &[&str] = &[
+        "# Requirements Document\n\nDeploy token: ghp_abcdefghijklmn",
+        "opqrstuvwxyz0123456789 for CI\n",
+        "Trailing line without a newline",
+    ];
+
+    let _home = with_isolated_home();
+    // SAFETY: this variable is read by this test only
+    unsafe { std::env::set_var(API_KEY_ENV, "test-api-key") };
+
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let base_url = format!("http://{}/v1/messages", listener.local_addr()?);
+    let server = tokio::spawn(serve_stream(listener, SPLIT_SECRET_CHUNKS));
+
+    let mut handle = OrchestratorHandle::with_config_and_force(
+        "streaming-tokens-split-secret",
+        anthropic_config(&base_url)?,
+        false,
+    )?;
+    let mut received = Vec::new();
+    // The short document may fail validation; only the streamed text matters here
+    let _ = handle
+        .run_phase_streaming_tokens(PhaseId::Requirements, |chunk| {
+            received.push(chunk.to_string());
+        })
+        .await;
+    server.await??;
+
+    let streamed = received.concat();
+    assert!(!streamed.contains("ghp_"), "secret leaked: {streamed}");
+    assert!(
+        !streamed.contains("0123456789"),
+        "secret leaked: {streamed}"
+    );
+    assert!(
+        streamed.contains("Deploy token: *** for CI\n"),
+        "{streamed}"
+    );
+    // The unterminated last line is flushed when the stream ends
+    assert_eq!(
+        received.last().map(String::as_str),
+        Some("Trailing line without a newline")
+    );
+    Ok(())
+}
+
+/// Answers every call with the assembled requirements document
+struct WholeResponseRunner;
+
+#[async_trait]
+impl ClaudeRunner for WholeResponseRunner {
+    async fn execute_claude(
+        &self,
+        _args: &[String],
+        _stdin_content: &str,
+        _timeout_duration: Option<Duration>,
+    ) -> Result<ClaudeResponse, RunnerError> {
+        let stdout = CHUNKS.concat();
+        Ok(ClaudeResponse {
+            ndjson_result: xchecker::runner::Runner::parse_ndjson(&stdout),
+            stdout_total_bytes: stdout.len(),
+            stdout,
+            stderr: String::new(),
+            exit_code: 0,
+            runner_used: RunnerMode::Native,
+            runner_distro: None,
+            timed_out: false,
+            stdout_truncated: false,
+            stderr_truncated: false,
+            stderr_total_bytes: 0,
+        })
+    }
+}
+
+#[tokio::test]
+async fn test_non_streaming_provider_delivers_full_text_once() -> Result<()> {
+    let _home = with_isolated_home();
+
+    let config = OrchestratorConfig {
+        dry_run: false,
+        config: HashMap::from([("output_format".to_string(), "text".to_string())]),
+        full_config: None,
+        selectors: None,
+        strict_validation: false,
+        redactor: Default::default(),
+        hooks: None,
+    };
+    let mut handle =
+        OrchestratorHandle::with_config_and_force("streaming-tokens-cli", config, false)?
+            .with_runner(Box::new(WholeResponseRunner));
+    let mut received = Vec::new();
+    let result = handle
+        .run_phase_streaming_tokens(PhaseId::Requirements, |chunk| {
+            received.push(chunk.to_string());
+        })
+        .await?;
+
+    assert!(result.success, "phase should succeed: {:?}", result.error);
+    assert_eq!(received, vec![CHUNKS.concat()]);
+    Ok(())
+}
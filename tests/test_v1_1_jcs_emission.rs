@@ -77,6 +77,7 @@ fn test_jcs_byte_identical_reserialization() -> Result<()> {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let mut receipt1 = Receipt {
@@ -105,6 +106,12 @@ fn test_jcs_byte_identical_reserialization() -> Result<()> {
         diff_context: None,
         llm: None,
         pipeline: None,
+        validation_degraded: None,
+        validation_errors: Vec::new(),
+        input_fingerprint: None,
+        packet_hash: None,
+        duration_ms: None,
+        timings: None,
     };
 
     let mut receipt2 = Receipt {
@@ -133,6 +140,12 @@ fn test_jcs_byte_identical_reserialization() -> Result<()> {
         diff_context: None,
         llm: None,
         pipeline: None,
+        validation_degraded: None,
+        validation_errors: Vec::new(),
+        input_fingerprint: None,
+        packet_hash: None,
+        duration_ms: None,
+        timings: None,
     };
 
     // Sort outputs to ensure deterministic ordering
@@ -183,6 +196,7 @@ fn test_jcs_sorted_arrays_receipts() -> Result<()> {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let receipt = manager.create_receipt(
@@ -366,6 +380,7 @@ fn test_receipts_use_jcs() -> Result<()> {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let receipt = manager.create_receipt(
@@ -586,6 +601,7 @@ fn test_error_receipts_use_jcs() -> Result<()> {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        preview_compressed: false,
     };
 
     let receipt = manager.create_receipt(
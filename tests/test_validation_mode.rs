@@ -0,0 +1,132 @@
+//! Tests for `validation_mode = "lenient_flagged"`
+//!
+//! Invalid phase output must not fail the phase under `lenient_flagged`:
+//! the artifact is still written and the receipt is marked degraded with
+//! the validation issues, so CI can gate on degraded phases separately.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use xchecker::orchestrator::{ExecutionResult, OrchestratorConfig, OrchestratorHandle};
+use xchecker::paths::with_isolated_home;
+use xchecker::runner::{ClaudeResponse, ClaudeRunner, RunnerError, RunnerMode};
+use xchecker::types::{PhaseId, Receipt};
+use xchecker::validation::OutputValidator;
+
+/// Far too short and missing every required section
+const INVALID_REQUIREMENTS: &str = "Requirements pending.";
+
+/// Answers every prompt with `INVALID_REQUIREMENTS`
+struct InvalidOutputRunner;
+
+#[async_trait]
+impl ClaudeRunner for InvalidOutputRunner {
+    async fn execute_claude(
+        &self,
+        _args: &[String],
+        _stdin_content: &str,
+        _timeout_duration: Option<Duration>,
+    ) -> Result<ClaudeResponse, RunnerError> {
+        Ok(ClaudeResponse {
+            stdout: INVALID_REQUIREMENTS.to_string(),
+            stderr: String::new(),
+            exit_code: 0,
+            runner_used: RunnerMode::Native,
+            runner_distro: None,
+            timed_out: false,
+            ndjson_result: xchecker::runner::Runner::parse_ndjson(INVALID_REQUIREMENTS),
+            stdout_truncated: false,
+            stderr_truncated: false,
+            stdout_total_bytes: INVALID_REQUIREMENTS.len(),
+            stderr_total_bytes: 0,
+        })
+    }
+}
+
+async fn run_requirements(spec_id: &str, validation_mode: &str) -> Result<ExecutionResult> {
+    let config = OrchestratorConfig {
+        dry_run: false,
+        config: HashMap::from([
+            ("output_format".to_string(), "text".to_string()),
+            ("validation_mode".to_string(), validation_mode.to_string()),
+        ]),
+        full_config: None,
+        selectors: None,
+        strict_validation: false,
+        redactor: Default::default(),
+        hooks: None,
+    };
+    let mut handle = OrchestratorHandle::with_config_and_force(spec_id, config, false)?
+        .with_runner(Box::new(InvalidOutputRunner));
+    handle.run_phase(PhaseId::Requirements).await
+}
+
+fn written_receipt(result: &ExecutionResult) -> Result<Receipt> {
+    let path = result
+        .receipt_path
+        .as_ref()
+        .expect("receipt should be written");
+    Ok(serde_json::from_str(&std::fs::read_to_string(path)?)?)
+}
+
+#[tokio::test]
+async fn test_lenient_flagged_completes_phase_and_marks_receipt_degraded() -> Result<()> {
+    let _home = with_isolated_home();
+    let spec_id = "validation-lenient-flagged";
+
+    let result = run_requirements(spec_id, "lenient_flagged").await?;
+    assert!(result.success, "phase should complete: {:?}", result.error);
+
+    let artifact = xchecker::paths::spec_root(spec_id).join("artifacts/00-requirements.md");
+    assert_eq!(std::fs::read_to_string(artifact)?, INVALID_REQUIREMENTS);
+
+    let expected_errors = OutputValidator::validate(INVALID_REQUIREMENTS, PhaseId::Requirements)
+        .unwrap_err()
+        .len();
+    let receipt = written_receipt(&result)?;
+    assert_eq!(receipt.exit_code, 0);
+    assert_eq!(receipt.validation_degraded, Some(true));
+    assert_eq!(receipt.validation_errors.len(), expected_errors);
+    assert!(
+        receipt
+            .validation_errors
+            .iter()
+            .any(|error| error.contains("too short")),
+        "unexpected errors: {:?}",
+        receipt.validation_errors
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_warn_mode_does_not_mark_receipt_degraded() -> Result<()> {
+    let _home = with_isolated_home();
+
+    let result = run_requirements("validation-warn", "warn").await?;
+    assert!(result.success, "phase should complete: {:?}", result.error);
+
+    let receipt = written_receipt(&result)?;
+    assert_eq!(receipt.validation_degraded, None);
+    assert!(receipt.validation_errors.is_empty());
+
+    // Receipts of clean phases keep their existing shape
+    let json: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(result.receipt_path.unwrap())?)?;
+    assert!(json.get("validation_degraded").is_none());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_strict_mode_fails_phase_on_invalid_output() -> Result<()> {
+    let _home = with_isolated_home();
+
+    let outcome = run_requirements("validation-strict", "strict").await;
+    let failed = match outcome {
+        Ok(result) => !result.success,
+        Err(_) => true,
+    };
+    assert!(failed, "strict validation should fail the phase");
+    Ok(())
+}